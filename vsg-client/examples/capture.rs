@@ -0,0 +1,30 @@
+//! Turn the display gray, wait for the change to be presented, and save
+//! what was shown to frame.png.
+
+use vsg_client::vsg_messages::{CaptureFormat, EventKind};
+use vsg_client::{Client, Request};
+
+fn main() -> Result<(), vsg_client::Error> {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:7878".into());
+    let mut client = Client::connect(addr)?;
+    let hello = client.call(Request::hello("capture example", &["capture"]))?;
+    println!("Connected to {}", hello.agent);
+
+    client.call(Request::subscribe(&[EventKind::Onset]))?;
+    let reply = client.call(Request::set_bg_color([0.5, 0.5, 0.5]).flip_ack())?;
+    let onset = reply.onset.expect("flip_ack replies carry the onset");
+    println!("Gray from frame {}", onset.frame);
+
+    let captured = client.call(Request::capture_frame(CaptureFormat::Png, None))?;
+    std::fs::write("frame.png", &captured.capture.data)?;
+    println!(
+        "Saved frame {} ({}x{})",
+        captured.onset.frame, captured.capture.width, captured.capture.height
+    );
+
+    let event = client.events().next().expect("events never end")?;
+    println!("First onset event: {:?}", event);
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use std::io::Write;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::thread;
+
+use vsg_messages::{Event, RootMessage, RootResponse};
+
+use crate::{frame, or_disconnect, Reconnect, Request, Result, Session};
+
+/// Blocking connection to a VSG server.
+///
+/// When the connection breaks, the next request connects again and repeats
+/// the last [`Request::hello`] and [`Request::subscribe`] first.
+pub struct Client {
+    addrs: Vec<SocketAddr>,
+    reconnect: Reconnect,
+    stream: Option<TcpStream>,
+    session: Session,
+}
+
+impl Client {
+    pub fn connect(addr: impl ToSocketAddrs) -> Result<Self> {
+        let addrs: Vec<_> = addr.to_socket_addrs()?.collect();
+        let stream = open(&addrs)?;
+        Ok(Self {
+            addrs,
+            reconnect: Reconnect::default(),
+            stream: Some(stream),
+            session: Session::default(),
+        })
+    }
+
+    pub fn with_reconnect(self, reconnect: Reconnect) -> Self {
+        Self { reconnect, ..self }
+    }
+
+    /// Send `request` and wait for its reply, keeping events that arrive in
+    /// the meantime for [`Client::next_event`].
+    pub fn call<T>(&mut self, request: Request<T>) -> Result<T> {
+        let response = self.round_trip(request.message)?;
+        (request.decode)(response)
+    }
+
+    fn round_trip(&mut self, mut message: RootMessage) -> Result<RootResponse> {
+        let sequence = self.session.prepare(&mut message);
+        let result = self
+            .stream()
+            .and_then(|stream| frame::write(stream, &message));
+        or_disconnect(&mut self.stream, result)?;
+        loop {
+            let response = self.read()?;
+            if let Some(reply) = self.session.accept(response, sequence) {
+                return reply;
+            }
+        }
+    }
+
+    /// Wait for the next event pushed by the server.
+    pub fn next_event(&mut self) -> Result<Event> {
+        loop {
+            if let Some(event) = self.session.events.pop_front() {
+                return Ok(event);
+            }
+            let response = self.read()?;
+            self.session.accept_event(response);
+        }
+    }
+
+    fn read(&mut self) -> Result<RootResponse> {
+        let result = self.stream().and_then(frame::read);
+        or_disconnect(&mut self.stream, result)
+    }
+
+    /// Events as they arrive, see [`Request::subscribe`]. Never ends, but
+    /// yields an error when the connection breaks.
+    pub fn events(&mut self) -> Events<'_> {
+        Events { client: self }
+    }
+
+    /// The connection, opened again if it broke.
+    fn stream(&mut self) -> Result<&mut TcpStream> {
+        if self.stream.is_none() {
+            let mut failures = 0;
+            let mut stream = loop {
+                match open(&self.addrs) {
+                    Ok(stream) => break stream,
+                    Err(e) => {
+                        failures += 1;
+                        thread::sleep(self.reconnect.retry_after(failures).ok_or(e)?);
+                    }
+                }
+            };
+            for message in self.session.replay() {
+                frame::write(&mut stream, &message)?;
+                loop {
+                    let response = frame::read(&mut stream)?;
+                    if let Some(reply) = self.session.accept(response, message.sequence) {
+                        reply?;
+                        break;
+                    }
+                }
+            }
+            self.stream = Some(stream);
+        }
+        Ok(self.stream.as_mut().expect("connected above"))
+    }
+}
+
+fn open(addrs: &[SocketAddr]) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(addrs)?;
+    stream.set_nodelay(true)?;
+    stream.write_all(&[frame::HANDSHAKE])?;
+    Ok(stream)
+}
+
+/// Iterator over pushed events, see [`Client::events`].
+pub struct Events<'a> {
+    client: &'a mut Client,
+}
+
+impl Iterator for Events<'_> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.client.next_event())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    use vsg_messages::{event, root_message, ErrorCode, EventKind, InputEvent};
+
+    use crate::Error;
+
+    use super::*;
+
+    /// Read requests from one connection and answer each with `answer`.
+    fn serve(
+        listener: &TcpListener,
+        mut answer: impl FnMut(RootMessage, &mut TcpStream) -> bool,
+    ) -> Vec<RootMessage> {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut handshake = [0];
+        stream.read_exact(&mut handshake).unwrap();
+        assert_eq!(handshake[0], frame::HANDSHAKE);
+        let mut received = Vec::new();
+        loop {
+            let mut len = [0; 4];
+            if stream.read_exact(&mut len).is_err() {
+                return received;
+            }
+            let mut payload = vec![0; u32::from_be_bytes(len) as usize];
+            stream.read_exact(&mut payload).unwrap();
+            let message = <RootMessage as prost::Message>::decode(&payload[..]).unwrap();
+            received.push(message.clone());
+            if !answer(message, &mut stream) {
+                return received;
+            }
+        }
+    }
+
+    fn send(stream: &mut TcpStream, response: RootResponse) {
+        let payload = prost::Message::encode_to_vec(&response);
+        stream
+            .write_all(&(payload.len() as u32).to_be_bytes())
+            .unwrap();
+        stream.write_all(&payload).unwrap();
+    }
+
+    fn input() -> RootResponse {
+        RootResponse::event(Event {
+            timestamp_us: 1,
+            kind: Some(event::Kind::Input(InputEvent::default())),
+        })
+    }
+
+    #[test]
+    fn replies_are_matched_and_events_kept() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = Client::connect(listener.local_addr().unwrap()).unwrap();
+        let server = thread::spawn(move || {
+            serve(&listener, |message, stream| {
+                // An event comes in before the reply.
+                send(stream, input());
+                match message.command {
+                    Some(root_message::Command::RemoveStimulus(_)) => send(
+                        stream,
+                        RootResponse::error(message.sequence, ErrorCode::NotFound, "Stimulus 3"),
+                    ),
+                    _ => send(stream, RootResponse::ok(message.sequence)),
+                }
+                true
+            })
+        });
+
+        client
+            .call(Request::subscribe(&[EventKind::Input]))
+            .unwrap();
+        let reply = client.call(Request::set_visibility(3, true)).unwrap();
+        assert_eq!(reply.onset, None);
+        match client.call(Request::remove_stimulus(3)) {
+            Err(Error::Server { code, .. }) => assert_eq!(code, ErrorCode::NotFound),
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(
+            client.call(Request::get_status()),
+            Err(Error::UnexpectedReply("display_status"))
+        ));
+        let events: Vec<_> = client.events().take(4).collect::<Result<_>>().unwrap();
+        assert_eq!(events.len(), 4);
+
+        drop(client);
+        let sequences: Vec<_> = server.join().unwrap().iter().map(|m| m.sequence).collect();
+        assert_eq!(sequences, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn reconnects_and_subscribes_again() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = Client::connect(listener.local_addr().unwrap()).unwrap();
+        let server = thread::spawn(move || {
+            // The first connection breaks right after the subscription.
+            let first = serve(&listener, |message, stream| {
+                send(stream, RootResponse::ok(message.sequence));
+                false
+            });
+            let second = serve(&listener, |message, stream| {
+                send(stream, RootResponse::ok(message.sequence));
+                true
+            });
+            (first, second)
+        });
+
+        client
+            .call(Request::subscribe(&[EventKind::Resize]))
+            .unwrap();
+        // Fails or succeeds depending on when the broken connection is noticed.
+        if client.call(Request::show_texture(0)).is_err() {
+            client.call(Request::show_texture(0)).unwrap();
+        }
+        drop(client);
+
+        let (first, second) = server.join().unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(matches!(
+            second[0].command,
+            Some(root_message::Command::Subscribe(_))
+        ));
+        assert!(matches!(
+            second[1].command,
+            Some(root_message::Command::ShowTexture(_))
+        ));
+    }
+}
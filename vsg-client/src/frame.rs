@@ -0,0 +1,59 @@
+use std::io::{Read, Write};
+
+use prost::Message;
+use vsg_messages::{RootMessage, RootResponse};
+
+use crate::Result;
+
+/// Sent first on every connection so that replies come as protobuf even
+/// from a server started with `--encoding json`.
+pub const HANDSHAKE: u8 = b'P';
+
+/// `message` prefixed with its length.
+pub fn encode(message: &RootMessage) -> Vec<u8> {
+    let len = message.encoded_len() as u32;
+    let mut buf = Vec::with_capacity(4 + len as usize);
+    buf.extend_from_slice(&len.to_be_bytes());
+    message
+        .encode(&mut buf)
+        .expect("buffer has room for the message");
+    buf
+}
+
+pub fn decode(payload: &[u8]) -> Result<RootResponse> {
+    Ok(RootResponse::decode(payload)?)
+}
+
+pub fn write(stream: &mut impl Write, message: &RootMessage) -> Result<()> {
+    stream.write_all(&encode(message))?;
+    stream.flush()?;
+    Ok(())
+}
+
+pub fn read(stream: &mut impl Read) -> Result<RootResponse> {
+    let mut len = [0; 4];
+    stream.read_exact(&mut len)?;
+    let mut payload = vec![0; u32::from_be_bytes(len) as usize];
+    stream.read_exact(&mut payload)?;
+    decode(&payload)
+}
+
+#[cfg(feature = "async")]
+pub async fn write_async(
+    stream: &mut (impl tokio::io::AsyncWrite + Unpin),
+    message: &RootMessage,
+) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+    stream.write_all(&encode(message)).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+pub async fn read_async(stream: &mut (impl tokio::io::AsyncRead + Unpin)) -> Result<RootResponse> {
+    use tokio::io::AsyncReadExt;
+    let len = stream.read_u32().await?;
+    let mut payload = vec![0; len as usize];
+    stream.read_exact(&mut payload).await?;
+    decode(&payload)
+}
@@ -0,0 +1,180 @@
+//! Client for the VSG protocol: RootMessage requests and RootResponse replies
+//! over TCP, each framed with a 4-byte big-endian length.
+//!
+//! ```no_run
+//! use vsg_client::{Client, Request};
+//!
+//! let mut client = Client::connect("127.0.0.1:7878")?;
+//! let reply = client.call(Request::set_bg_color([0.5, 0.5, 0.5, 1.0]).flip_ack())?;
+//! println!("Gray from frame {}", reply.onset.unwrap().frame);
+//! # Ok::<(), vsg_client::Error>(())
+//! ```
+//!
+//! [`AsyncClient`] offers the same with tokio behind the `async` feature.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::Duration;
+
+pub use vsg_messages;
+use vsg_messages::{root_message, ErrorCode, Event, RootMessage, RootResponse, Status};
+
+mod blocking;
+mod frame;
+#[cfg(feature = "async")]
+mod nonblocking;
+mod request;
+
+pub use blocking::{Client, Events};
+#[cfg(feature = "async")]
+pub use nonblocking::AsyncClient;
+pub use request::{Captured, Reply, Request};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    /// A reply could not be decoded as a RootResponse.
+    Decode(prost::DecodeError),
+    /// The server refused the request.
+    Server {
+        code: ErrorCode,
+        message: String,
+    },
+    /// The reply lacks what the request should have been answered with.
+    UnexpectedReply(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Decode(e) => write!(f, "Cannot decode reply: {}", e),
+            Error::Server { code, message } => write!(f, "{:?}: {}", code, message),
+            Error::UnexpectedReply(missing) => write!(f, "Reply without {}", missing),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<prost::DecodeError> for Error {
+    fn from(e: prost::DecodeError) -> Self {
+        Error::Decode(e)
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// How often to try connecting again after the connection broke.
+///
+/// A request whose reply was lost fails with the I/O error instead of being
+/// sent twice; the next request reconnects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reconnect {
+    pub attempts: u32,
+    /// Wait between attempts.
+    pub delay: Duration,
+}
+
+impl Reconnect {
+    pub fn never() -> Self {
+        Self {
+            attempts: 0,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// How long to wait before trying again after `failures` attempts to
+    /// connect failed in a row, or None to give up.
+    fn retry_after(&self, failures: u32) -> Option<Duration> {
+        (failures <= self.attempts).then_some(self.delay)
+    }
+}
+
+impl Default for Reconnect {
+    fn default() -> Self {
+        Self {
+            attempts: 5,
+            delay: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Connection state that outlives a single connection: sequence numbers,
+/// events received while waiting for a reply, and what sets up a new
+/// connection like the old one.
+#[derive(Default)]
+struct Session {
+    last_sequence: u64,
+    events: VecDeque<Event>,
+    hello: Option<RootMessage>,
+    subscribe: Option<RootMessage>,
+}
+
+impl Session {
+    /// Number `message` and remember it if it sets up the connection.
+    fn prepare(&mut self, message: &mut RootMessage) -> u64 {
+        self.last_sequence += 1;
+        message.sequence = self.last_sequence;
+        match message.command {
+            Some(root_message::Command::Hello(_)) => self.hello = Some(message.clone()),
+            Some(root_message::Command::Subscribe(_)) => self.subscribe = Some(message.clone()),
+            _ => {}
+        }
+        message.sequence
+    }
+
+    /// Messages to send again after reconnecting, numbered anew.
+    fn replay(&mut self) -> Vec<RootMessage> {
+        let mut messages: Vec<_> = self.hello.iter().chain(&self.subscribe).cloned().collect();
+        for message in &mut messages {
+            self.last_sequence += 1;
+            message.sequence = self.last_sequence;
+        }
+        messages
+    }
+
+    /// Queue events and return the reply to `sequence` once it arrives,
+    /// with an error status turned into [`Error::Server`].
+    fn accept(&mut self, response: RootResponse, sequence: u64) -> Option<Result<RootResponse>> {
+        if let Some(event) = response.event {
+            self.events.push_back(event);
+            return None;
+        }
+        // Errors about the connection itself carry sequence 0.
+        let matches = response.sequence == sequence
+            || (response.sequence == 0 && response.status() == Status::Error);
+        matches.then(|| check(response))
+    }
+
+    /// Queue events that arrive while no request is waiting. Stray replies
+    /// to requests that already failed are dropped.
+    fn accept_event(&mut self, response: RootResponse) {
+        self.accept(response, u64::MAX);
+    }
+}
+
+/// Forget a connection that failed, so that the next request reconnects.
+fn or_disconnect<S, T>(stream: &mut Option<S>, result: Result<T>) -> Result<T> {
+    if let Err(Error::Io(_) | Error::Decode(_)) = result {
+        *stream = None;
+    }
+    result
+}
+
+/// Turn an error status into [`Error::Server`].
+fn check(response: RootResponse) -> Result<RootResponse> {
+    match response.status() {
+        Status::Ok => Ok(response),
+        Status::Error => Err(Error::Server {
+            code: response.error_code(),
+            message: response.error,
+        }),
+    }
+}
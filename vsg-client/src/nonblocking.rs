@@ -0,0 +1,156 @@
+use std::net::SocketAddr;
+
+use tokio::io::AsyncWriteExt;
+use tokio::net::{lookup_host, TcpStream, ToSocketAddrs};
+use vsg_messages::{Event, RootMessage, RootResponse};
+
+use crate::{frame, or_disconnect, Reconnect, Request, Result, Session};
+
+/// Connection to a VSG server for tokio, like [`Client`](crate::Client).
+pub struct AsyncClient {
+    addrs: Vec<SocketAddr>,
+    reconnect: Reconnect,
+    stream: Option<TcpStream>,
+    session: Session,
+}
+
+impl AsyncClient {
+    pub async fn connect(addr: impl ToSocketAddrs) -> Result<Self> {
+        let addrs: Vec<_> = lookup_host(addr).await?.collect();
+        let stream = open(&addrs).await?;
+        Ok(Self {
+            addrs,
+            reconnect: Reconnect::default(),
+            stream: Some(stream),
+            session: Session::default(),
+        })
+    }
+
+    pub fn with_reconnect(self, reconnect: Reconnect) -> Self {
+        Self { reconnect, ..self }
+    }
+
+    /// Send `request` and wait for its reply, keeping events that arrive in
+    /// the meantime for [`AsyncClient::next_event`].
+    pub async fn call<T>(&mut self, request: Request<T>) -> Result<T> {
+        let response = self.round_trip(request.message).await?;
+        (request.decode)(response)
+    }
+
+    async fn round_trip(&mut self, mut message: RootMessage) -> Result<RootResponse> {
+        let sequence = self.session.prepare(&mut message);
+        let result = match self.stream().await {
+            Ok(stream) => frame::write_async(stream, &message).await,
+            Err(e) => Err(e),
+        };
+        or_disconnect(&mut self.stream, result)?;
+        loop {
+            let response = self.read().await?;
+            if let Some(reply) = self.session.accept(response, sequence) {
+                return reply;
+            }
+        }
+    }
+
+    /// Wait for the next event pushed by the server, see [`Request::subscribe`].
+    pub async fn next_event(&mut self) -> Result<Event> {
+        loop {
+            if let Some(event) = self.session.events.pop_front() {
+                return Ok(event);
+            }
+            let response = self.read().await?;
+            self.session.accept_event(response);
+        }
+    }
+
+    async fn read(&mut self) -> Result<RootResponse> {
+        let result = match self.stream().await {
+            Ok(stream) => frame::read_async(stream).await,
+            Err(e) => Err(e),
+        };
+        or_disconnect(&mut self.stream, result)
+    }
+
+    /// The connection, opened again if it broke.
+    async fn stream(&mut self) -> Result<&mut TcpStream> {
+        if self.stream.is_none() {
+            let mut failures = 0;
+            let mut stream = loop {
+                match open(&self.addrs).await {
+                    Ok(stream) => break stream,
+                    Err(e) => {
+                        failures += 1;
+                        let delay = self.reconnect.retry_after(failures).ok_or(e)?;
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+            };
+            for message in self.session.replay() {
+                frame::write_async(&mut stream, &message).await?;
+                loop {
+                    let response = frame::read_async(&mut stream).await?;
+                    if let Some(reply) = self.session.accept(response, message.sequence) {
+                        reply?;
+                        break;
+                    }
+                }
+            }
+            self.stream = Some(stream);
+        }
+        Ok(self.stream.as_mut().expect("connected above"))
+    }
+}
+
+async fn open(addrs: &[SocketAddr]) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(addrs).await?;
+    stream.set_nodelay(true)?;
+    stream.write_all(&[frame::HANDSHAKE]).await?;
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+    use vsg_messages::{event, EventKind, Resize};
+
+    use super::*;
+
+    #[test]
+    fn calls_and_receives_events() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let mut client = AsyncClient::connect(listener.local_addr().unwrap())
+                .await
+                .unwrap();
+            let server = tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                assert_eq!(stream.read_u8().await.unwrap(), frame::HANDSHAKE);
+                let len = stream.read_u32().await.unwrap();
+                let mut payload = vec![0; len as usize];
+                stream.read_exact(&mut payload).await.unwrap();
+                let message = <RootMessage as prost::Message>::decode(&payload[..]).unwrap();
+                let resize = RootResponse::event(Event {
+                    timestamp_us: 5,
+                    kind: Some(event::Kind::Resize(Resize::default())),
+                });
+                for response in [RootResponse::ok(message.sequence), resize] {
+                    let payload = prost::Message::encode_to_vec(&response);
+                    stream.write_u32(payload.len() as u32).await.unwrap();
+                    stream.write_all(&payload).await.unwrap();
+                }
+            });
+
+            client
+                .call(Request::subscribe(&[EventKind::Resize]))
+                .await
+                .unwrap();
+            assert_eq!(client.next_event().await.unwrap().timestamp_us, 5);
+            server.await.unwrap();
+        });
+    }
+}
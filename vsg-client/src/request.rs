@@ -0,0 +1,200 @@
+use vsg_messages::root_message::Command;
+use vsg_messages::{
+    schedule, texture, BgColor, Capture, CaptureFormat, CaptureFrame, DisplayStatus, EventKind,
+    GetStatus, Hello, Onset, RemoveStimulus, RootMessage, RootResponse, Schedule, SetScene,
+    SetVisibility, Shape, Stimulus, Subscribe, Texture, TextureIndex, UpdateStimulus,
+    PROTOCOL_VERSION,
+};
+
+use crate::{Error, Result};
+
+/// Answer to a display command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    /// When the frame showing the command was handed to the display, for
+    /// requests sent with [`Request::flip_ack`]. It is scanned out at the
+    /// display's next refresh.
+    pub onset: Option<Onset>,
+    /// Server clock when the reply was sent.
+    pub server_time_us: u64,
+}
+
+/// A frame read back by [`Request::capture_frame`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Captured {
+    pub capture: Capture,
+    /// The frame the image shows.
+    pub onset: Onset,
+}
+
+/// A RootMessage together with how to read the reply it gets.
+#[derive(Debug, Clone)]
+pub struct Request<T> {
+    pub(crate) message: RootMessage,
+    pub(crate) decode: fn(RootResponse) -> Result<T>,
+}
+
+impl<T> Request<T> {
+    fn new(command: Command, decode: fn(RootResponse) -> Result<T>) -> Self {
+        Self {
+            message: RootMessage {
+                command: Some(command),
+                ..Default::default()
+            },
+            decode,
+        }
+    }
+
+    /// Wait for the frame showing the command to be presented before replying.
+    pub fn flip_ack(mut self) -> Self {
+        self.message.flip_ack = true;
+        self
+    }
+
+    /// Apply the command on the frame with this number.
+    pub fn at_frame(mut self, frame: u64) -> Self {
+        self.message.schedule = Some(Schedule {
+            target: Some(schedule::Target::Frame(frame)),
+        });
+        self
+    }
+
+    /// Apply the command on the first frame at or after this server time.
+    pub fn at_deadline_us(mut self, deadline_us: u64) -> Self {
+        self.message.schedule = Some(Schedule {
+            target: Some(schedule::Target::DeadlineUs(deadline_us)),
+        });
+        self
+    }
+
+    /// The message as it will be sent, apart from its sequence number.
+    pub fn message(&self) -> &RootMessage {
+        &self.message
+    }
+}
+
+fn reply(response: RootResponse) -> Result<Reply> {
+    Ok(Reply {
+        onset: response.onset,
+        server_time_us: response.server_time_us,
+    })
+}
+
+impl Request<Reply> {
+    /// Replace every stimulus with `shape`, which takes up no stimulus id.
+    pub fn set_shape(shape: Shape) -> Self {
+        Self::new(Command::SetShape(shape), reply)
+    }
+
+    /// RGB or RGBA, each from 0 to 1.
+    pub fn set_bg_color(color: impl Into<Vec<f32>>) -> Self {
+        let color = BgColor {
+            color: color.into(),
+        };
+        Self::new(Command::SetBgColor(color), reply)
+    }
+
+    /// Store the texture, unless `source` is None, and show only that one.
+    pub fn set_texture(index: u32, source: Option<texture::Source>) -> Self {
+        Self::new(Command::SetTexture(Texture { index, source }), reply)
+    }
+
+    /// Store the texture without showing it.
+    pub fn preload_texture(index: u32, source: texture::Source) -> Self {
+        let texture = Texture {
+            index,
+            source: Some(source),
+        };
+        Self::new(Command::PreloadTexture(texture), reply)
+    }
+
+    pub fn show_texture(index: u32) -> Self {
+        Self::new(Command::ShowTexture(TextureIndex { index }), reply)
+    }
+
+    pub fn hide_texture(index: u32) -> Self {
+        Self::new(Command::HideTexture(TextureIndex { index }), reply)
+    }
+
+    pub fn delete_texture(index: u32) -> Self {
+        Self::new(Command::DeleteTexture(TextureIndex { index }), reply)
+    }
+
+    pub fn add_stimulus(stimulus: Stimulus) -> Self {
+        Self::new(Command::AddStimulus(stimulus), reply)
+    }
+
+    pub fn update_stimulus(id: u32, shape: Shape) -> Self {
+        let update = UpdateStimulus {
+            id,
+            shape: Some(shape),
+        };
+        Self::new(Command::UpdateStimulus(update), reply)
+    }
+
+    pub fn set_visibility(id: u32, visible: bool) -> Self {
+        Self::new(Command::SetVisibility(SetVisibility { id, visible }), reply)
+    }
+
+    pub fn remove_stimulus(id: u32) -> Self {
+        Self::new(Command::RemoveStimulus(RemoveStimulus { id }), reply)
+    }
+
+    pub fn set_scene(scene: SetScene) -> Self {
+        Self::new(Command::SetScene(scene), reply)
+    }
+}
+
+impl Request<DisplayStatus> {
+    pub fn get_status() -> Self {
+        Self::new(Command::GetStatus(GetStatus {}), |response| {
+            response
+                .display_status
+                .ok_or(Error::UnexpectedReply("display_status"))
+        })
+    }
+}
+
+impl Request<Hello> {
+    /// Check that the server speaks this protocol version and has `features`.
+    /// Answered with the server's own Hello.
+    pub fn hello(agent: impl Into<String>, features: &[&str]) -> Self {
+        let hello = Hello {
+            protocol_version: PROTOCOL_VERSION,
+            features: features.iter().map(|f| f.to_string()).collect(),
+            agent: agent.into(),
+        };
+        Self::new(Command::Hello(hello), |response| {
+            response.hello.ok_or(Error::UnexpectedReply("hello"))
+        })
+    }
+}
+
+impl Request<()> {
+    /// Push events of `kinds` to this connection from now on, replacing the
+    /// previous subscription. Empty unsubscribes.
+    pub fn subscribe(kinds: &[EventKind]) -> Self {
+        let subscribe = Subscribe {
+            kinds: kinds.iter().map(|&kind| kind as i32).collect(),
+        };
+        Self::new(Command::Subscribe(subscribe), |_| Ok(()))
+    }
+}
+
+impl Request<Captured> {
+    /// Read back the next frame, written to `path` on the server when given.
+    /// The path is relative to the server's `--capture-dir`, without which
+    /// only returning the image is allowed.
+    pub fn capture_frame(format: CaptureFormat, path: Option<String>) -> Self {
+        let capture = CaptureFrame {
+            format: format as i32,
+            path: path.unwrap_or_default(),
+        };
+        Self::new(Command::CaptureFrame(capture), |response| {
+            Ok(Captured {
+                capture: response.capture.ok_or(Error::UnexpectedReply("capture"))?,
+                onset: response.onset.ok_or(Error::UnexpectedReply("onset"))?,
+            })
+        })
+    }
+}
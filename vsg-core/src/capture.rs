@@ -0,0 +1,302 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+
+use anyhow::{bail, ensure, Context, Result};
+use vsg_messages::{Capture, CaptureFormat, ErrorCode};
+
+use crate::messages::CommandError;
+
+/// Create a texture that frames can be rendered into and read back from.
+pub fn target(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    width: u32,
+    height: u32,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("capture"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    })
+}
+
+/// Copy an 8-bit RGBA or BGRA texture back from the GPU as RGBA rows.
+pub fn read_rgba(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+) -> Result<Vec<u8>> {
+    use wgpu::TextureFormat::*;
+    let swap_red_blue = match texture.format() {
+        Rgba8Unorm | Rgba8UnormSrgb => false,
+        Bgra8Unorm | Bgra8UnormSrgb => true,
+        format => bail!("Cannot read back {:?} frames", format),
+    };
+    let size = texture.size();
+    let row_bytes = 4 * size.width;
+    // Rows in the buffer must be aligned, the padding is dropped below.
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let padded_row_bytes = row_bytes.div_ceil(align) * align;
+
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("capture readback"),
+        size: padded_row_bytes as u64 * size.height as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Capture Encoder"),
+    });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_row_bytes),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    queue.submit(std::iter::once(encoder.finish()));
+
+    let slice = buffer.slice(..);
+    let (tx, rx) = mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = tx.send(result);
+    });
+    device.poll(wgpu::Maintain::Wait);
+    rx.recv()??;
+
+    let mut rgba = Vec::with_capacity((row_bytes * size.height) as usize);
+    for row in slice.get_mapped_range().chunks(padded_row_bytes as usize) {
+        rgba.extend_from_slice(&row[..row_bytes as usize]);
+    }
+    if swap_red_blue {
+        for pixel in rgba.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+    Ok(rgba)
+}
+
+/// Encode a captured frame, and write it to `path` instead of returning it
+/// when one is given.
+pub fn encode(
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+    format: CaptureFormat,
+    path: Option<&Path>,
+) -> Result<Capture> {
+    let data = match format {
+        CaptureFormat::Rgba => rgba,
+        CaptureFormat::Png => {
+            let image = image_rs::RgbaImage::from_raw(width, height, rgba)
+                .context("Frame does not match its size")?;
+            let mut png = Vec::new();
+            image.write_to(
+                &mut std::io::Cursor::new(&mut png),
+                image_rs::ImageOutputFormat::Png,
+            )?;
+            png
+        }
+    };
+    let mut capture = Capture {
+        width,
+        height,
+        format: format as i32,
+        ..Default::default()
+    };
+    match path {
+        Some(path) => {
+            write_file(path, &data).with_context(|| format!("Cannot write {}", path.display()))?;
+            capture.path = path.display().to_string();
+        }
+        None => capture.data = data,
+    }
+    Ok(capture)
+}
+
+/// Write `data` to a path returned by [`resolve_path`].
+///
+/// The path was checked on the connection thread, but is written to later
+/// on the render thread. So its directory is checked again for symlinks
+/// swapped in since, and the file is written next to it and renamed over
+/// it, which replaces a symlink at `path` instead of following it.
+fn write_file(path: &Path, data: &[u8]) -> Result<()> {
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        bail!("Not a file path");
+    };
+    ensure!(
+        parent.canonicalize()? == parent,
+        "{} changed since the capture was requested",
+        parent.display()
+    );
+    let mut partial = name.to_os_string();
+    partial.push(format!(".{}.partial", std::process::id()));
+    let partial = parent.join(partial);
+    // Fails instead of following a symlink put in its place.
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&partial)?;
+    let written = file
+        .write_all(data)
+        .and_then(|_| fs::rename(&partial, path));
+    if written.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    Ok(written?)
+}
+
+/// Where a frame that a client asked to save at `path` is written: inside
+/// `dir`, which must be given for clients to write files at all.
+///
+/// Absolute paths, `..` and symlinks leading out of `dir` are rejected, so
+/// that clients cannot overwrite other files of the server's user.
+pub fn resolve_path(dir: Option<&Path>, path: &Path) -> Result<PathBuf, CommandError> {
+    let invalid = |message: String| CommandError::new(ErrorCode::InvalidArgument, message);
+    let dir = dir.ok_or_else(|| {
+        CommandError::new(
+            ErrorCode::PermissionDenied,
+            "Saving captures on the server needs --capture-dir",
+        )
+    })?;
+    if !path
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(invalid(format!(
+            "Capture path {} must be relative and without ..",
+            path.display()
+        )));
+    }
+    let name = path
+        .file_name()
+        .ok_or_else(|| invalid(format!("Capture path {} has no file name", path.display())))?;
+    let dir = dir
+        .canonicalize()
+        .map_err(|e| invalid(format!("Cannot use {}: {}", dir.display(), e)))?;
+    let joined = dir.join(path);
+    let parent = joined
+        .parent()
+        .and_then(|parent| parent.canonicalize().ok())
+        .ok_or_else(|| invalid(format!("No directory for {}", path.display())))?;
+    let resolved = parent.join(name);
+    // An existing file may itself be a symlink to somewhere else.
+    let target = match fs::symlink_metadata(&resolved) {
+        Ok(_) => resolved
+            .canonicalize()
+            .map_err(|e| invalid(format!("Cannot write {}: {}", path.display(), e)))?,
+        Err(_) => resolved.clone(),
+    };
+    if !target.starts_with(&dir) {
+        return Err(invalid(format!(
+            "Capture path {} leads out of {}",
+            path.display(),
+            dir.display()
+        )));
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_outside_the_capture_dir_are_rejected() {
+        let root = std::env::temp_dir().join(format!("vsg-capture-dir-{}", std::process::id()));
+        let dir = root.join("captures");
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        let outside = root.join("outside.png");
+
+        assert_eq!(
+            resolve_path(None, Path::new("frame.png")).unwrap_err().code,
+            ErrorCode::PermissionDenied
+        );
+        let dir_canonical = dir.canonicalize().unwrap();
+        assert_eq!(
+            resolve_path(Some(&dir), Path::new("sub/frame.png")).unwrap(),
+            dir_canonical.join("sub").join("frame.png")
+        );
+
+        let mut rejected = vec![
+            outside.clone(),
+            PathBuf::from("../outside.png"),
+            PathBuf::from("sub/../../outside.png"),
+            PathBuf::from("missing/frame.png"),
+        ];
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&root, dir.join("up")).unwrap();
+            std::os::unix::fs::symlink(&outside, dir.join("link.png")).unwrap();
+            rejected.push(PathBuf::from("up/outside.png"));
+            rejected.push(PathBuf::from("link.png"));
+        }
+        for path in rejected {
+            let err = resolve_path(Some(&dir), &path).unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidArgument, "{}", path.display());
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_swapped_in_after_the_check_are_not_followed() {
+        use std::os::unix::fs::symlink;
+
+        let root = std::env::temp_dir().join(format!("vsg-capture-swap-{}", std::process::id()));
+        let dir = root.join("captures");
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        let outside = root.join("outside.png");
+        std::fs::write(&outside, b"keep").unwrap();
+        let rgba = vec![0; 4];
+
+        // The file itself turns into a link: the link is replaced.
+        let path = resolve_path(Some(&dir), Path::new("frame.png")).unwrap();
+        symlink(&outside, &path).unwrap();
+        encode(rgba.clone(), 1, 1, CaptureFormat::Rgba, Some(&path)).unwrap();
+        assert!(!std::fs::symlink_metadata(&path).unwrap().is_symlink());
+        assert_eq!(std::fs::read(&outside).unwrap(), b"keep");
+
+        // Its directory turns into a link: nothing is written.
+        let path = resolve_path(Some(&dir), Path::new("sub/frame.png")).unwrap();
+        std::fs::remove_dir(dir.join("sub")).unwrap();
+        symlink(&root, dir.join("sub")).unwrap();
+        assert!(encode(rgba, 1, 1, CaptureFormat::Rgba, Some(&path)).is_err());
+        assert!(!root.join("frame.png").exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn frames_are_written_as_png() {
+        let rgba = vec![255, 0, 0, 255, 0, 0, 255, 255];
+        let capture = encode(rgba.clone(), 2, 1, CaptureFormat::Rgba, None).unwrap();
+        assert_eq!(capture.data, rgba);
+
+        let path = std::env::temp_dir()
+            .canonicalize()
+            .unwrap()
+            .join(format!("vsg-capture-{}.png", std::process::id()));
+        let capture = encode(rgba.clone(), 2, 1, CaptureFormat::Png, Some(&path)).unwrap();
+        assert!(capture.data.is_empty());
+        let image = image_rs::open(&path).unwrap().to_rgba8();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(image.dimensions(), (2, 1));
+        assert_eq!(image.into_raw(), rgba);
+    }
+}
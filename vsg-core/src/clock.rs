@@ -0,0 +1,21 @@
+//! Monotonic clock shared by everything that reports timestamps to clients.
+use std::sync::OnceLock;
+use std::time::Instant;
+
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// Start the clock. Timestamps are counted from the first call.
+pub fn init() {
+    EPOCH.get_or_init(Instant::now);
+}
+
+/// Microseconds elapsed between the clock start and `instant`.
+pub fn timestamp_us(instant: Instant) -> u64 {
+    let epoch = *EPOCH.get_or_init(Instant::now);
+    instant.saturating_duration_since(epoch).as_micros() as u64
+}
+
+/// Microseconds elapsed since the clock start.
+pub fn now_us() -> u64 {
+    timestamp_us(Instant::now())
+}
@@ -0,0 +1,369 @@
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use vsg_messages::{event, Capture, CommandFailed, Event, Onset, RootResponse, StimulusOffset};
+
+use crate::clock;
+use crate::messages::{Command, CommandError};
+
+/// Where the outcome of a flip-acknowledged command is delivered.
+pub type Ack = Sender<Result<Acked, CommandError>>;
+
+/// What an acknowledged command is answered with once its frame is presented.
+#[derive(Debug, Default, PartialEq)]
+pub struct Acked {
+    pub onset: Onset,
+    pub capture: Option<Capture>,
+}
+
+/// When a held command becomes due.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Schedule {
+    /// At the frame with this number.
+    Frame(u64),
+    /// At the first frame boundary at or after this time, see [`clock`].
+    Deadline(u64),
+}
+
+impl Schedule {
+    pub fn from_proto(schedule: Option<vsg_messages::Schedule>) -> Option<Self> {
+        use vsg_messages::schedule::Target;
+        match schedule?.target? {
+            Target::Frame(frame) => Some(Schedule::Frame(frame)),
+            Target::DeadlineUs(deadline) => Some(Schedule::Deadline(deadline)),
+        }
+    }
+
+    fn is_due(&self, frame: u64, now_us: u64) -> bool {
+        match *self {
+            Schedule::Frame(target) => frame >= target,
+            Schedule::Deadline(deadline) => now_us >= deadline,
+        }
+    }
+}
+
+/// A command waiting for the frame boundary it should be applied at.
+#[derive(Debug)]
+pub struct QueuedCommand {
+    pub command: Command,
+    /// Notified once the frame containing the command has been presented,
+    /// or as soon as applying the command failed.
+    pub ack: Option<Ack>,
+    /// Applied at the next frame boundary when not set.
+    pub schedule: Option<Schedule>,
+    /// Sequence number and outbox of a client that got its reply before the
+    /// command was applied, told with a CommandFailed event if it fails.
+    pub failure: Option<(u64, Sender<RootResponse>)>,
+}
+
+impl QueuedCommand {
+    pub fn new(command: Command) -> Self {
+        Self {
+            command,
+            ack: None,
+            schedule: None,
+            failure: None,
+        }
+    }
+
+    pub fn with_ack(self, ack: Ack) -> Self {
+        Self {
+            ack: Some(ack),
+            ..self
+        }
+    }
+
+    pub fn scheduled(self, schedule: Option<Schedule>) -> Self {
+        Self { schedule, ..self }
+    }
+
+    pub fn reporting_failure(self, sequence: u64, outbox: Sender<RootResponse>) -> Self {
+        Self {
+            failure: Some((sequence, outbox)),
+            ..self
+        }
+    }
+}
+
+/// Commands received from clients that wait for the next frame boundary.
+pub type CommandQueue = Arc<Mutex<Vec<QueuedCommand>>>;
+
+/// What applying a command set in motion.
+#[derive(Debug, Default, PartialEq)]
+pub struct Applied {
+    /// Stimuli added with a lifetime, and how many frames they stay.
+    pub lifetimes: Vec<(u32, u64)>,
+    /// The frame as read back by a capture command.
+    pub capture: Option<Capture>,
+}
+
+/// Anything the dispatcher can apply commands to.
+pub trait CommandTarget {
+    fn apply(&mut self, command: &Command) -> Result<Applied, CommandError>;
+}
+
+/// Applies queued commands at frame boundaries.
+///
+/// Every command that arrived since the previous boundary is applied in
+/// arrival order, so all of them become visible on the same frame. Scheduled
+/// commands are held back until their frame or deadline comes.
+#[derive(Default)]
+pub struct Dispatcher {
+    queue: CommandQueue,
+    frame: u64,
+    held: Vec<QueuedCommand>,
+    awaiting_onset: Vec<(Ack, Applied)>,
+    /// Whether a command took effect since the last present, and the
+    /// lifetimes it started.
+    changed: bool,
+    lifetimes: Vec<(u32, u64)>,
+    last_onset: Option<Onset>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Self {
+            queue: Arc::new(Mutex::new(Vec::new())),
+            frame: 0,
+            held: Vec::new(),
+            awaiting_onset: Vec::new(),
+            changed: false,
+            lifetimes: Vec::new(),
+            last_onset: None,
+        }
+    }
+
+    /// Handle used by the network threads to enqueue commands.
+    pub fn queue(&self) -> CommandQueue {
+        Arc::clone(&self.queue)
+    }
+
+    /// Number of the frame the next dispatched commands will appear on.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// The most recent presented frame on which a command took effect.
+    pub fn last_onset(&self) -> Option<&Onset> {
+        self.last_onset.as_ref()
+    }
+
+    /// Apply every command that is due to `target` and return how many were applied.
+    pub fn dispatch(&mut self, target: &mut impl CommandTarget) -> usize {
+        self.held.append(&mut self.queue.lock().unwrap());
+        let (frame, now_us) = (self.frame, clock::now_us());
+        let (due, held): (Vec<_>, Vec<_>) = std::mem::take(&mut self.held)
+            .into_iter()
+            .partition(|c| c.schedule.is_none_or(|s| s.is_due(frame, now_us)));
+        self.held = held;
+
+        let n = due.len();
+        for QueuedCommand {
+            command,
+            ack,
+            schedule,
+            failure,
+        } in due
+        {
+            if let Some(Schedule::Frame(scheduled)) = schedule {
+                if scheduled < frame {
+                    log::warn!("{:?} was scheduled for past frame {}", command, scheduled);
+                }
+            }
+            match target.apply(&command) {
+                Ok(applied) => {
+                    log::debug!("{:?} takes effect on frame {}", command, self.frame);
+                    self.changed |= command.changes_display();
+                    self.lifetimes.extend_from_slice(&applied.lifetimes);
+                    if let Some(ack) = ack {
+                        self.awaiting_onset.push((ack, applied));
+                    }
+                }
+                Err(e) => {
+                    log::error!("{:?} failed: {}", command, e);
+                    if let Some(ack) = ack {
+                        let _ = ack.send(Err(e));
+                    } else if let Some((sequence, outbox)) = failure {
+                        let failed = CommandFailed {
+                            sequence,
+                            error_code: e.code.into(),
+                            error: e.message,
+                        };
+                        let _ = outbox.send(RootResponse::event(Event {
+                            timestamp_us: now_us,
+                            kind: Some(event::Kind::CommandFailed(failed)),
+                        }));
+                    }
+                }
+            }
+        }
+        n
+    }
+
+    /// Must be called right after the frame has been presented. Returns the
+    /// onset of the frame if any command took effect on it, timestamped now:
+    /// when the frame was queued for display, not when it was scanned out.
+    pub fn frame_presented(&mut self) -> Option<&Onset> {
+        let timestamp_us = clock::now_us();
+        let onset = std::mem::take(&mut self.changed).then(|| Onset {
+            frame: self.frame,
+            timestamp_us,
+            offsets: offsets(self.frame, self.lifetimes.drain(..)),
+        });
+        for (ack, applied) in self.awaiting_onset.drain(..) {
+            let offsets = offsets(self.frame, applied.lifetimes);
+            // The client may have disconnected in the meantime.
+            let _ = ack.send(Ok(Acked {
+                onset: Onset {
+                    frame: self.frame,
+                    timestamp_us,
+                    offsets,
+                },
+                capture: applied.capture,
+            }));
+        }
+        self.frame += 1;
+        if onset.is_some() {
+            self.last_onset = onset;
+            self.last_onset.as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+/// When stimuli with these lifetimes disappear if they first show on `frame`.
+fn offsets(frame: u64, lifetimes: impl IntoIterator<Item = (u32, u64)>) -> Vec<StimulusOffset> {
+    lifetimes
+        .into_iter()
+        .map(|(id, frames)| StimulusOffset {
+            id,
+            frame: frame + frames,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Vec<Command>);
+
+    impl CommandTarget for Recorder {
+        fn apply(&mut self, command: &Command) -> Result<Applied, CommandError> {
+            self.0.push(command.clone());
+            Ok(Applied::default())
+        }
+    }
+
+    /// Knows no stimuli at all.
+    struct Empty;
+
+    impl CommandTarget for Empty {
+        fn apply(&mut self, command: &Command) -> Result<Applied, CommandError> {
+            Err(CommandError::new(
+                vsg_messages::ErrorCode::NotFound,
+                format!("Nothing to apply {:?} to", command),
+            ))
+        }
+    }
+
+    #[test]
+    fn applies_all_commands_in_order() {
+        let mut dispatcher = Dispatcher::new();
+        let queue = dispatcher.queue();
+        let commands = vec![
+            Command::Clear([0.5, 0.5, 0.5, 1.0]),
+            Command::ShowTexture(0),
+            Command::Clear([0.0, 0.0, 0.0, 1.0]),
+        ];
+        queue
+            .lock()
+            .unwrap()
+            .extend(commands.iter().cloned().map(QueuedCommand::new));
+
+        let mut recorder = Recorder::default();
+        assert_eq!(dispatcher.dispatch(&mut recorder), 3);
+        assert_eq!(recorder.0, commands);
+        assert!(queue.lock().unwrap().is_empty());
+
+        dispatcher.frame_presented();
+        assert_eq!(dispatcher.frame(), 1);
+        assert_eq!(dispatcher.dispatch(&mut recorder), 0);
+    }
+
+    #[test]
+    fn acknowledges_onset_after_present() {
+        let mut dispatcher = Dispatcher::new();
+        dispatcher.frame_presented();
+        let (tx, rx) = std::sync::mpsc::channel();
+        dispatcher
+            .queue()
+            .lock()
+            .unwrap()
+            .push(QueuedCommand::new(Command::Clear([0.0, 0.0, 0.0, 1.0])).with_ack(tx));
+
+        dispatcher.dispatch(&mut Recorder::default());
+        assert!(rx.try_recv().is_err());
+        assert!(dispatcher.last_onset().is_none());
+        assert_eq!(dispatcher.frame_presented().unwrap().frame, 1);
+        assert_eq!(rx.try_recv().unwrap().unwrap().onset.frame, 1);
+        assert_eq!(dispatcher.last_onset().unwrap().frame, 1);
+
+        // Frames without changes are no onsets, captures do not change anything.
+        let capture = Command::Capture {
+            format: vsg_messages::CaptureFormat::Png,
+            path: None,
+        };
+        dispatcher
+            .queue()
+            .lock()
+            .unwrap()
+            .push(QueuedCommand::new(capture));
+        dispatcher.dispatch(&mut Recorder::default());
+        assert!(dispatcher.frame_presented().is_none());
+        assert_eq!(dispatcher.last_onset().unwrap().frame, 1);
+    }
+
+    #[test]
+    fn holds_scheduled_commands_until_due() {
+        let mut dispatcher = Dispatcher::new();
+        let late = Command::Clear([1.0, 1.0, 1.0, 1.0]);
+        let never = Command::Clear([0.5, 0.5, 0.5, 1.0]);
+        let now = Command::ShowTexture(0);
+        dispatcher.queue().lock().unwrap().extend([
+            QueuedCommand::new(late.clone()).scheduled(Some(Schedule::Frame(2))),
+            QueuedCommand::new(never).scheduled(Some(Schedule::Deadline(u64::MAX))),
+            QueuedCommand::new(now.clone()),
+        ]);
+
+        let mut recorder = Recorder::default();
+        for _ in 0..4 {
+            dispatcher.dispatch(&mut recorder);
+            dispatcher.frame_presented();
+        }
+        assert_eq!(recorder.0, vec![now, late]);
+    }
+
+    #[test]
+    fn reports_failures_of_commands_answered_early() {
+        let mut dispatcher = Dispatcher::new();
+        let (tx, rx) = std::sync::mpsc::channel();
+        dispatcher.queue().lock().unwrap().extend([
+            QueuedCommand::new(Command::RemoveStimulus(3)).reporting_failure(7, tx.clone()),
+            QueuedCommand::new(Command::ShowTexture(0)),
+        ]);
+
+        dispatcher.dispatch(&mut Empty);
+        let event = rx.try_recv().unwrap().event.unwrap();
+        match event.kind {
+            Some(event::Kind::CommandFailed(failed)) => {
+                assert_eq!(failed.sequence, 7);
+                assert_eq!(failed.error_code(), vsg_messages::ErrorCode::NotFound);
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(rx.try_recv().is_err());
+    }
+}
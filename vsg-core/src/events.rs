@@ -0,0 +1,100 @@
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use vsg_messages::{event, Event, EventKind, RootResponse};
+
+struct Subscriber {
+    session: u64,
+    kinds: Vec<EventKind>,
+    outbox: Sender<RootResponse>,
+}
+
+/// Delivers events from the render loop to the connections that asked for them.
+#[derive(Clone, Default)]
+pub struct EventBus {
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push events of `kinds` to `outbox` from now on, replacing what
+    /// `session` subscribed to before.
+    pub fn subscribe(&self, session: u64, kinds: Vec<EventKind>, outbox: Sender<RootResponse>) {
+        self.unsubscribe(session);
+        if !kinds.is_empty() {
+            self.subscribers.lock().unwrap().push(Subscriber {
+                session,
+                kinds,
+                outbox,
+            });
+        }
+    }
+
+    pub fn unsubscribe(&self, session: u64) {
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|s| s.session != session);
+    }
+
+    /// Send an event that happened at `timestamp_us` to its subscribers.
+    pub fn publish(&self, timestamp_us: u64, kind: event::Kind) {
+        let event = Event {
+            timestamp_us,
+            kind: Some(kind),
+        };
+        let event_kind = kind_of(&event);
+        let mut subscribers = self.subscribers.lock().unwrap();
+        // Connections that went away are dropped on the way.
+        subscribers.retain(|s| {
+            !s.kinds.contains(&event_kind)
+                || s.outbox.send(RootResponse::event(event.clone())).is_ok()
+        });
+    }
+}
+
+fn kind_of(event: &Event) -> EventKind {
+    match event.kind {
+        Some(event::Kind::Input(_)) => EventKind::Input,
+        Some(event::Kind::FrameDrop(_)) => EventKind::FrameDrop,
+        Some(event::Kind::Onset(_)) => EventKind::Onset,
+        Some(event::Kind::Offset(_)) => EventKind::Offset,
+        Some(event::Kind::SurfaceLost(_)) => EventKind::SurfaceLost,
+        Some(event::Kind::Resize(_)) => EventKind::Resize,
+        // Only sent to the connection whose command failed.
+        Some(event::Kind::CommandFailed(_)) | None => EventKind::Unspecified,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use vsg_messages::InputEvent;
+
+    use super::*;
+
+    #[test]
+    fn delivers_to_subscribers_only() {
+        let bus = EventBus::new();
+        let (tx, rx) = mpsc::channel();
+        let input = || event::Kind::Input(InputEvent::default());
+
+        bus.publish(0, input());
+        bus.subscribe(1, vec![EventKind::Input], tx.clone());
+        bus.subscribe(2, vec![EventKind::Unspecified], tx);
+        bus.publish(0, input());
+        bus.unsubscribe(1);
+        bus.publish(0, input());
+
+        let received: Vec<_> = rx.try_iter().collect();
+        assert_eq!(received.len(), 1);
+        assert!(matches!(
+            received[0].event.as_ref().unwrap().kind,
+            Some(event::Kind::Input(_))
+        ));
+    }
+}
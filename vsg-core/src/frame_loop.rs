@@ -0,0 +1,128 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use vsg_messages::event::Kind;
+
+use crate::clock;
+use crate::dispatcher::Dispatcher;
+use crate::events::EventBus;
+use crate::state::{State, HEADLESS_FRAME_INTERVAL};
+use crate::status::StatusBoard;
+
+/// What the render loop keeps from one frame to the next.
+pub struct FrameLoop {
+    pub dispatcher: Dispatcher,
+    pub events: EventBus,
+    pub status: StatusBoard,
+    last_frame_inst: Instant,
+    frames_skipped: u64,
+}
+
+impl FrameLoop {
+    pub fn new(dispatcher: Dispatcher, events: EventBus, status: StatusBoard) -> Self {
+        Self {
+            dispatcher,
+            events,
+            status,
+            last_frame_inst: Instant::now(),
+            frames_skipped: 0,
+        }
+    }
+
+    /// Apply the commands that are due, render a frame and report on it.
+    /// Returns false when the server cannot go on.
+    pub fn run_frame(&mut self, state: &mut State, scale_factor: f64) -> bool {
+        let Self {
+            dispatcher, events, ..
+        } = self;
+        dispatcher.dispatch(state);
+        let frame = dispatcher.frame();
+        match state.render() {
+            Ok(_) => {
+                if let Some(onset) = dispatcher.frame_presented() {
+                    events.publish(onset.timestamp_us, Kind::Onset(onset.clone()));
+                }
+                for id in state.scene_mut().frame_presented() {
+                    log::debug!("Stimulus {} reached the end of its lifetime", id);
+                    let offset = vsg_messages::StimulusOffset {
+                        id,
+                        frame: dispatcher.frame(),
+                    };
+                    events.publish(clock::now_us(), Kind::Offset(offset));
+                }
+            }
+            // Reconfigure the surface if lost
+            Err(e @ (wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated)) => {
+                if let wgpu::SurfaceError::Lost = e {
+                    state.resize(state.size());
+                } else {
+                    log::error!("{:?}", e);
+                }
+                let lost = vsg_messages::SurfaceLost {
+                    reason: format!("{:?}", e),
+                };
+                events.publish(clock::now_us(), Kind::SurfaceLost(lost));
+            }
+            // The system is out of memory, we should probably quit
+            Err(wgpu::SurfaceError::OutOfMemory) => return false,
+            // All other errors (Timeout) should be resolved by the next frame
+            Err(e) => log::error!("{:?}", e),
+        }
+        log::trace!("Render finished");
+        let interval = self.last_frame_inst.elapsed();
+        if is_drop(interval, state.frame_interval_us()) {
+            log::info!("Frame {} was skipped {:?}", frame, interval);
+            self.frames_skipped += 1;
+            let drop = vsg_messages::FrameDrop {
+                frame,
+                interval_us: interval.as_micros() as u64,
+            };
+            self.events.publish(clock::now_us(), Kind::FrameDrop(drop));
+        }
+        self.last_frame_inst = Instant::now();
+
+        let mut current = self.status.lock().unwrap();
+        state.update_status(&mut current);
+        current.scale_factor = scale_factor;
+        current.frames_presented = self.dispatcher.frame();
+        current.frames_skipped = self.frames_skipped;
+        true
+    }
+
+    /// Render frames at a steady rate with nothing to present them to, until
+    /// rendering fails.
+    pub fn run_headless(mut self, mut state: State) {
+        let mut next_frame = Instant::now();
+        while self.run_frame(&mut state, 1.0) {
+            next_frame += HEADLESS_FRAME_INTERVAL;
+            match next_frame.checked_duration_since(Instant::now()) {
+                Some(wait) => thread::sleep(wait),
+                // Do not try to catch up on frames that took too long.
+                None => next_frame = Instant::now(),
+            }
+        }
+    }
+}
+
+/// Whether `interval` between two frames is long enough that a refresh was
+/// missed, given the measured time between refreshes.
+fn is_drop(interval: Duration, frame_interval_us: f64) -> bool {
+    interval.as_micros() as f64 > 1.5 * frame_interval_us
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_follow_the_refresh_rate() {
+        let ms = |ms: f64| Duration::from_secs_f64(ms / 1000.0);
+        let hz = |hz: f64| 1e6 / hz;
+        assert!(!is_drop(ms(8.4), hz(120.0)));
+        assert!(is_drop(ms(16.7), hz(120.0)));
+        assert!(is_drop(ms(13.9), hz(144.0)));
+        assert!(!is_drop(ms(16.9), hz(60.0)));
+        assert!(!is_drop(ms(20.2), hz(50.0)));
+        assert!(is_drop(ms(40.0), hz(50.0)));
+    }
+}
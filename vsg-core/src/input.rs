@@ -0,0 +1,86 @@
+use vsg_messages::{input_event, Coordinates, InputEvent, Key, MouseButton, Onset};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{ElementState, KeyboardInput};
+
+/// Convert a position in window pixels to stimulus coordinates, where the
+/// window spans -1 to 1 with y pointing up.
+pub fn to_stimulus(position: PhysicalPosition<f64>, size: PhysicalSize<u32>) -> Coordinates {
+    Coordinates {
+        x: (position.x / size.width.max(1) as f64 * 2.0 - 1.0) as f32,
+        y: (1.0 - position.y / size.height.max(1) as f64 * 2.0) as f32,
+    }
+}
+
+pub fn key(input: &KeyboardInput) -> input_event::Source {
+    input_event::Source::Key(Key {
+        name: input
+            .virtual_keycode
+            .map(|code| format!("{:?}", code))
+            .unwrap_or_default(),
+        scancode: input.scancode,
+    })
+}
+
+pub fn mouse_button(
+    button: winit::event::MouseButton,
+    position: Coordinates,
+) -> input_event::Source {
+    use winit::event::MouseButton::*;
+    let name = match button {
+        Left => "Left".to_string(),
+        Right => "Right".to_string(),
+        Middle => "Middle".to_string(),
+        Other(n) => format!("Other{}", n),
+    };
+    input_event::Source::MouseButton(MouseButton {
+        name,
+        position: Some(position),
+    })
+}
+
+/// An input event that happened at `timestamp_us`, timed from `last_onset`.
+pub fn event(
+    state: ElementState,
+    source: input_event::Source,
+    timestamp_us: u64,
+    last_onset: Option<&Onset>,
+) -> InputEvent {
+    InputEvent {
+        pressed: state == ElementState::Pressed,
+        source: Some(source),
+        onset_frame: last_onset.map(|onset| onset.frame),
+        since_onset_us: last_onset.map(|onset| timestamp_us.saturating_sub(onset.timestamp_us)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_map_to_stimulus_coordinates() {
+        let size = PhysicalSize::new(200, 100);
+        let corner = to_stimulus(PhysicalPosition::new(0.0, 0.0), size);
+        assert_eq!((corner.x, corner.y), (-1.0, 1.0));
+        let center = to_stimulus(PhysicalPosition::new(100.0, 50.0), size);
+        assert_eq!((center.x, center.y), (0.0, 0.0));
+    }
+
+    #[test]
+    fn latency_is_measured_from_the_last_onset() {
+        let source = mouse_button(winit::event::MouseButton::Other(4), Coordinates::default());
+        let onset = Onset {
+            frame: 3,
+            timestamp_us: 1_000,
+            offsets: Vec::new(),
+        };
+        let pressed = event(ElementState::Pressed, source.clone(), 1_250, Some(&onset));
+        assert!(pressed.pressed);
+        assert_eq!(pressed.onset_frame, Some(3));
+        assert_eq!(pressed.since_onset_us, Some(250));
+
+        let released = event(ElementState::Released, source, 1_250, None);
+        assert!(!released.pressed);
+        assert_eq!(released.since_onset_us, None);
+    }
+}
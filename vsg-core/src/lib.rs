@@ -0,0 +1,24 @@
+//! Renders visual stimuli on command, for embedding in other tools. The
+//! `vsg-core` binary serves this renderer to network clients.
+
+pub mod capture;
+pub mod clock;
+pub mod dispatcher;
+pub mod events;
+pub mod frame_loop;
+mod graphics;
+pub mod input;
+pub mod messages;
+pub mod renderers;
+pub mod server;
+pub mod state;
+pub mod status;
+pub mod texture;
+mod vertex;
+
+pub use dispatcher::{CommandTarget, Dispatcher};
+pub use frame_loop::FrameLoop;
+pub use renderers::scene::Scene;
+pub use renderers::StimulusRenderer;
+pub use state::State;
+pub use texture::Texture;
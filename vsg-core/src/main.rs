@@ -1,32 +1,19 @@
-use std::io::Write;
-use std::net::TcpStream;
-use std::sync::{Arc, Mutex};
+use std::net::TcpListener;
 use std::thread;
-use std::time::Instant;
-use std::{io::Read, net::TcpListener};
 
 use env_logger::TimestampPrecision;
 use winit::{
+    dpi::PhysicalSize,
     event::*,
     event_loop::{ControlFlow, EventLoop},
     monitor::MonitorHandle,
-    window::{Fullscreen, Window, WindowBuilder},
+    window::{Fullscreen, WindowBuilder},
 };
 
-use anyhow::Result;
 use clap::Parser;
 
-mod graphics;
-mod renderers;
-mod texture;
-mod vertex;
-use renderers::scene::Scene;
-use renderers::*;
-
-mod messages;
-use messages::{Command, Coordinates};
-
-use prost::Message;
+use vsg_core::{clock, events, input, server, status, Dispatcher, FrameLoop, State};
+use vsg_messages::event::Kind;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -42,258 +29,59 @@ struct Args {
 
     #[clap(short, long, default_value = "0")]
     monitor: usize,
-}
 
-struct State {
-    surface: wgpu::Surface,
-    device: wgpu::Device,
-    queue: wgpu::Queue,
-    config: wgpu::SurfaceConfiguration,
-    picture: Option<Picture>,
-    size: winit::dpi::PhysicalSize<u32>,
-    scene: Scene,
-    bg_color: wgpu::Color,
+    /// Render offscreen instead of opening a window, for machines without a
+    /// display or GPU.
+    #[clap(long)]
+    headless: bool,
+
+    /// Size of the window, or of the frames rendered with --headless, as WxH.
+    #[clap(long, value_parser = parse_size)]
+    size: Option<PhysicalSize<u32>>,
+
+    /// Also accept WebSocket clients on this port.
+    #[clap(long)]
+    ws_port: Option<u16>,
+
+    /// Let browsers on pages from this origin, like http://localhost:8000,
+    /// connect over WebSocket. May be given more than once. Browsers from
+    /// other origins are refused.
+    #[clap(long)]
+    allow_origin: Vec<String>,
+
+    /// Also accept commands as UDP datagrams on this port, without replies.
+    #[clap(long)]
+    udp_port: Option<u16>,
+
+    /// Encoding of connections that do not choose one with a handshake byte,
+    /// and of UDP datagrams.
+    #[clap(long, value_enum, default_value = "protobuf")]
+    encoding: server::Encoding,
+
+    /// Longest message accepted from clients, in bytes, at most 1 GiB.
+    #[clap(long, default_value_t = 64 << 20, value_parser = clap::value_parser!(u64).range(1..=1 << 30))]
+    max_message_size: u64,
+
+    /// Let clients save captured frames as files in this directory. Without
+    /// it, captures are only returned in replies.
+    #[clap(long)]
+    capture_dir: Option<std::path::PathBuf>,
+
+    /// Also listen on a Unix domain socket at this path.
+    #[cfg(unix)]
+    #[clap(long)]
+    unix_socket: Option<std::path::PathBuf>,
 }
 
-impl State {
-    async fn new(window: &Window) -> Self {
-        // The instance is a handle to our GPU
-        // BackendBit::PRIMARY => Vulkan + Metal + DX12 + Browser WebGPU
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::all(),
-            flags: wgpu::InstanceFlags::default(),
-            dx12_shader_compiler: Default::default(),
-            gles_minor_version: wgpu::Gles3MinorVersion::default(),
-        });
-        let (size, surface) = unsafe {
-            let size = window.inner_size();
-            let surface = instance.create_surface(&window).unwrap();
-
-            (size, surface)
-        };
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::default(),
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .await
-            .unwrap();
-
-        let (device, queue) = adapter
-            .request_device(
-                &wgpu::DeviceDescriptor {
-                    label: None,
-                    features: wgpu::Features::empty(),
-                    limits: wgpu::Limits::downlevel_defaults(),
-                },
-                None, // Trace path
-            )
-            .await
-            .unwrap();
-
-        let surface_caps = surface.get_capabilities(&adapter);
-        let surface_format = surface_caps
-            .formats
-            .iter()
-            .copied()
-            .next()
-            .unwrap_or(surface_caps.formats[0]);
-
-        let config = wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            format: surface_format,
-            width: size.width,
-            height: size.height,
-            present_mode: wgpu::PresentMode::Fifo,
-            alpha_mode: wgpu::CompositeAlphaMode::Auto,
-            view_formats: vec![],
-        };
-        surface.configure(&device, &config);
-
-        let bg_color = wgpu::Color::BLACK;
-
-        let scene = renderers::scene::Scene::new();
-
-        Self {
-            surface,
-            device,
-            queue,
-            config,
-            picture: None,
-            size,
-            scene,
-            bg_color,
-        }
-    }
-
-    pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
-        if new_size.width > 0 && new_size.height > 0 {
-            self.size = new_size;
-            self.config.width = new_size.width;
-            self.config.height = new_size.height;
-            self.surface.configure(&self.device, &self.config);
-        }
-    }
-
-    pub fn update_shape(&mut self, shape: &vsg_messages::shape::Shape) {
-        use vsg_messages::shape::Shape;
-        let bundle: Box<dyn StimulusRenderer> = match shape {
-            Shape::Circle(vsg_messages::Circle { radius, ctr }) => {
-                let ctr = ctr.as_ref().unwrap();
-                Box::new(Circle::new(
-                    &self.device,
-                    &self.config.format,
-                    ctr.x,
-                    ctr.y,
-                    *radius,
-                    &[0.2, 0.0, 0.0, 1.0],
-                ))
-            }
-            Shape::Square(vsg_messages::Square { size, ctr }) => {
-                let ctr = ctr.as_ref().unwrap();
-                Box::new(Rectangle::new(
-                    &self.device,
-                    &self.config.format,
-                    ctr.x,
-                    ctr.y,
-                    *size,
-                    *size,
-                    &[0.0, 0.2, 0.0, 1.0],
-                ))
-            }
-            Shape::Cross(vsg_messages::Cross {
-                size,
-                line_width,
-                ctr,
-            }) => {
-                let ctr = ctr.as_ref().unwrap();
-                Box::new(Cross::new(
-                    &self.device,
-                    &self.config.format,
-                    &Coordinates { x: ctr.x, y: ctr.y },
-                    &Coordinates { x: *size, y: *size },
-                    *line_width,
-                    &[0.0, 0.0, 0.2, 1.0],
-                ))
-            }
-        };
-        let mut scene = Scene::new();
-        scene.add_stimulus(bundle);
-        self.scene = scene;
-    }
-
-    pub fn update_bg_color(&mut self, bg_color: &[f32; 4]) {
-        self.bg_color = wgpu::Color {
-            r: bg_color[0] as f64,
-            g: bg_color[1] as f64,
-            b: bg_color[2] as f64,
-            a: bg_color[3] as f64,
-        };
-    }
-
-    pub fn update_texture(&mut self, _idx: u32, texture: &[u8]) {
-        self.picture = Some(Picture::new(
-            &self.device,
-            &self.queue,
-            &self.config.format,
-            texture,
-        ));
-    }
-
-    fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
-        let output = self.surface.get_current_texture()?;
-        let view = output
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
-
-        let mut encoder = self
-            .device
-            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("Render Encoder"),
-            });
-
-        {
-            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(self.bg_color),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
-            if let Some(image) = &self.picture {
-                image.render(&mut rpass);
-            }
-            self.scene.render(&mut rpass);
-        }
-
-        self.queue.submit(std::iter::once(encoder.finish()));
-        output.present();
-
-        Ok(())
-    }
-}
-
-fn handle_connection(
-    mut stream: TcpStream,
-    message_bucket: Arc<Mutex<Vec<Command>>>,
-) -> Result<()> {
-    let mut msg_size = [0; 4];
-    let mut buffer = vec![0; 1024];
-
-    log::debug!("Connection established! : {}", stream.peer_addr()?);
-    loop {
-        stream.read_exact(&mut msg_size)?;
-        let len = u32::from_be_bytes(msg_size) as usize;
-        log::trace!("{}", len);
-        if len > buffer.len() {
-            buffer.resize(len, 0);
-        }
-        stream.read_exact(&mut buffer[..len])?;
-        let msg = vsg_messages::RootMessage::decode(&buffer[..len])?;
-
-        log::debug!("Contents : {:?}", msg);
-        match msg.command {
-            Some(vsg_messages::root_message::Command::SetShape(shape)) => {
-                let mut t = message_bucket.lock().unwrap();
-                t.push(Command::Draw(shape.shape.unwrap()));
-            }
-            Some(vsg_messages::root_message::Command::SetBgColor(color)) => {
-                let mut t = message_bucket.lock().unwrap();
-                let color = [
-                    color.color[0],
-                    color.color[1],
-                    color.color[2],
-                    color.color[3],
-                ];
-                t.push(Command::Clear(color));
-            }
-            Some(vsg_messages::root_message::Command::SetTexture(texture)) => {
-                let idx = texture.index;
-                let data = texture.data;
-                let mut t = message_bucket.lock().unwrap();
-                t.push(Command::Texture(idx, data));
-            }
-            None => {
-                log::error!("Unknown command");
-                break;
-            }
-        }
-        let msg = b"{\"type\": \"success\"}";
-        let len = msg.len() as u32;
-        log::trace!("{}", len);
-        let _ = stream.write(&len.to_be_bytes())?;
-        let _ = stream.write(msg)?;
-        stream.flush()?;
-    }
-    Ok(())
+fn parse_size(s: &str) -> Result<PhysicalSize<u32>, String> {
+    let (width, height) = s
+        .split_once('x')
+        .ok_or_else(|| format!("{:?} is not of the form WxH", s))?;
+    let parse = |n: &str| match n.trim().parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("{:?} is not a positive number of pixels", n)),
+    };
+    Ok(PhysicalSize::new(parse(width)?, parse(height)?))
 }
 
 // Enumerate monitors and prompt user to choose one
@@ -312,15 +100,62 @@ fn main() {
     env_logger::builder()
         .format_timestamp(Some(TimestampPrecision::Millis))
         .init();
+    clock::init();
     let args = Args::parse();
     log::debug!("{:?}", &args);
 
+    let status = status::new_board();
+    let dispatcher = Dispatcher::new();
+    let events = events::EventBus::new();
+    let shared = server::Shared {
+        queue: dispatcher.queue(),
+        status: status.clone(),
+        events: events.clone(),
+        encoding: args.encoding,
+        max_message_size: args.max_message_size as usize,
+        capture_dir: args.capture_dir.clone(),
+        allowed_origins: args.allow_origin.clone(),
+    };
+    // Removes the socket file when rendering stops, in either mode.
+    #[cfg(unix)]
+    let mut socket_file = args.unix_socket.as_deref().map(|path| {
+        let (listener, socket_file) = server::bind_unix(path).unwrap();
+        let shared = shared.clone();
+        thread::spawn(move || server::serve_unix(listener, shared));
+        socket_file
+    });
+    if let Some(ws_port) = args.ws_port {
+        let listener = TcpListener::bind((args.host.as_str(), ws_port)).unwrap();
+        let shared = shared.clone();
+        thread::spawn(move || server::serve_websocket(listener, shared));
+    }
+    if let Some(udp_port) = args.udp_port {
+        let socket = std::net::UdpSocket::bind((args.host.as_str(), udp_port)).unwrap();
+        let shared = shared.clone();
+        thread::spawn(move || server::serve_udp(socket, shared));
+    }
+    let listener = TcpListener::bind(format!("{}:{}", args.host, args.port)).unwrap();
+    let _handler = thread::spawn(move || server::serve(listener, shared));
+
+    if args.headless {
+        let size = args.size.unwrap_or(PhysicalSize::new(1920, 1080));
+        let state = pollster::block_on(State::headless(size));
+        log::info!(
+            "Rendering {}x{} frames offscreen on {}",
+            size.width,
+            size.height,
+            state.adapter_info().name
+        );
+        FrameLoop::new(dispatcher, events, status).run_headless(state);
+        return;
+    }
+
     let event_loop = EventLoop::new();
-    let window = WindowBuilder::new()
-        .with_visible(false)
-        // .with_inner_size(PhysicalSize::new(1920u32, 1080u32))
-        .build(&event_loop)
-        .unwrap();
+    let mut window = WindowBuilder::new().with_visible(false);
+    if let Some(size) = args.size {
+        window = window.with_inner_size(size);
+    }
+    let window = window.build(&event_loop).unwrap();
 
     if args.fullscreen {
         // window.set_fullscreen(Some(Fullscreen::Borderless(None)));
@@ -337,27 +172,22 @@ fn main() {
     let mut state = pollster::block_on(State::new(&window));
     window.set_visible(true);
 
-    let mut last_frame_inst = Instant::now();
+    status.lock().unwrap().monitors = status::monitors(
+        event_loop.available_monitors(),
+        window.current_monitor().as_ref(),
+    );
+    let mut cursor = vsg_messages::Coordinates::default();
+    let mut frame_loop = FrameLoop::new(dispatcher, events, status);
 
-    let message_bucket = Arc::new(Mutex::new(Vec::<Command>::new()));
-    let send_message = Arc::clone(&message_bucket);
-    let _handler = thread::spawn(move || {
-        let host = &args.host;
-        let port = &args.port;
-        let listner = TcpListener::bind(format!("{host}:{port}")).unwrap();
-        loop {
-            for stream in listner.incoming() {
-                let stream = stream.unwrap();
-                match handle_connection(stream, Arc::clone(&send_message)) {
-                    Ok(_) => (),
-                    Err(e) => log::error!("{}", e),
-                }
-            }
-        }
-    });
-    event_loop.run(move |event, _, control_flow| {
+    event_loop.run(move |event, target, control_flow| {
         // *control_flow = ControlFlow::Wait;
         // info!("{:?}", event);
+        let FrameLoop {
+            dispatcher,
+            events,
+            status,
+            ..
+        } = &frame_loop;
         match event {
             Event::WindowEvent {
                 ref event,
@@ -381,40 +211,65 @@ fn main() {
                         // new_inner_size is &mut so w have to dereference it twice
                         state.resize(**new_inner_size);
                     }
+                    WindowEvent::KeyboardInput { input, .. } => {
+                        let timestamp_us = clock::now_us();
+                        let source = input::key(input);
+                        let input = input::event(
+                            input.state,
+                            source,
+                            timestamp_us,
+                            dispatcher.last_onset(),
+                        );
+                        events.publish(timestamp_us, Kind::Input(input));
+                    }
+                    WindowEvent::CursorMoved { position, .. } => {
+                        cursor = input::to_stimulus(*position, state.size());
+                    }
+                    WindowEvent::MouseInput {
+                        state: button_state,
+                        button,
+                        ..
+                    } => {
+                        let timestamp_us = clock::now_us();
+                        let source = input::mouse_button(*button, cursor.clone());
+                        let input = input::event(
+                            *button_state,
+                            source,
+                            timestamp_us,
+                            dispatcher.last_onset(),
+                        );
+                        events.publish(timestamp_us, Kind::Input(input));
+                    }
                     _ => {} //state.update(event),
                 }
+                if let WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. } = event {
+                    let resize = vsg_messages::Resize {
+                        width: state.size().width,
+                        height: state.size().height,
+                        scale_factor: window.scale_factor(),
+                    };
+                    events.publish(clock::now_us(), Kind::Resize(resize));
+                }
+                if let WindowEvent::Moved(_) | WindowEvent::ScaleFactorChanged { .. } = event {
+                    status.lock().unwrap().monitors = status::monitors(
+                        target.available_monitors(),
+                        window.current_monitor().as_ref(),
+                    );
+                }
             }
             Event::RedrawRequested(window_id) if window_id == window.id() => {
                 // window.request_redraw(); is not called and this pass will not be executed.
                 // This is for continuous rendering.
             }
+            Event::LoopDestroyed => {
+                #[cfg(unix)]
+                drop(socket_file.take());
+            }
             Event::MainEventsCleared => {
                 log::trace!("MainEventsCleared");
-                {
-                    let mut t = message_bucket.lock().unwrap();
-                    // 暫定：最後のコマンドだけを実行する
-                    match t.last() {
-                        Some(Command::Draw(shape)) => state.update_shape(shape),
-                        Some(Command::Clear(color)) => state.update_bg_color(color),
-                        Some(Command::Texture(idx, texture)) => state.update_texture(*idx, texture),
-                        _ => {}
-                    }
-                    t.clear();
-                }
-                match state.render() {
-                    Ok(_) => {}
-                    // Reconfigure the surface if lost
-                    Err(wgpu::SurfaceError::Lost) => state.resize(state.size),
-                    // The system is out of memory, we should probably quit
-                    Err(wgpu::SurfaceError::OutOfMemory) => *control_flow = ControlFlow::Exit,
-                    // All other errors (Outdated, Timeout) should be resolved by the next frame
-                    Err(e) => log::error!("{:?}", e),
-                }
-                log::trace!("Render finished");
-                if last_frame_inst.elapsed().as_millis() > 20 {
-                    log::info!("Frame was skipped {:?}", last_frame_inst.elapsed());
+                if !frame_loop.run_frame(&mut state, window.scale_factor()) {
+                    *control_flow = ControlFlow::Exit;
                 }
-                last_frame_inst = Instant::now();
             }
             _ => {}
         }
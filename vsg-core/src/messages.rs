@@ -1,40 +1,298 @@
 use serde::{Deserialize, Serialize};
 pub use vsg_messages;
+pub use vsg_messages::stimulus::Lifetime;
+pub use vsg_messages::texture::Source as TextureSource;
+use vsg_messages::{root_message, CaptureFormat, ErrorCode, RawPixels};
 
-#[derive(Debug, Clone)]
+use crate::texture;
+
+/// A validated stimulus to be kept in the scene.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StimulusSpec {
+    pub id: u32,
+    pub shape: vsg_messages::shape::Shape,
+    pub visible: bool,
+    pub lifetime: Option<Lifetime>,
+}
+
+impl StimulusSpec {
+    fn from_proto(stimulus: vsg_messages::Stimulus, command: &str) -> Result<Self, CommandError> {
+        let valid = match stimulus.lifetime {
+            Some(Lifetime::DurationFrames(frames)) => frames > 0,
+            Some(Lifetime::DurationMs(ms)) => ms > 0.0 && ms.is_finite(),
+            None => true,
+        };
+        if !valid {
+            return Err(CommandError::new(
+                ErrorCode::InvalidArgument,
+                format!("Lifetime of stimulus {} must be positive", stimulus.id),
+            ));
+        }
+        if stimulus.hidden && stimulus.lifetime.is_some() {
+            return Err(CommandError::new(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "Stimulus {} would expire before it is shown: hidden with a lifetime",
+                    stimulus.id
+                ),
+            ));
+        }
+        Ok(Self {
+            id: stimulus.id,
+            shape: require_shape(stimulus.shape, command)?,
+            visible: !stimulus.hidden,
+            lifetime: stimulus.lifetime,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Command {
     Draw(vsg_messages::shape::Shape),
     Clear([f32; 4]),
-    Texture(u32, Vec<u8>),
+    /// Store a texture (unless there are no pixels) and show only that one.
+    /// Images arrive decoded, so the render thread only uploads them.
+    Texture(u32, Option<RawPixels>),
+    PreloadTexture(u32, RawPixels),
+    ShowTexture(u32),
+    HideTexture(u32),
+    DeleteTexture(u32),
+    AddStimulus(StimulusSpec),
+    UpdateStimulus(u32, vsg_messages::shape::Shape),
+    SetVisibility(u32, bool),
+    RemoveStimulus(u32),
+    /// Replace stimuli, picture and optionally the background in one go.
+    SetScene {
+        stimuli: Vec<StimulusSpec>,
+        textures: Vec<(u32, Option<RawPixels>)>,
+        bg_color: Option<[f32; 4]>,
+    },
+    /// Read back the frame, to a file on the server when a path is given.
+    Capture {
+        format: CaptureFormat,
+        path: Option<std::path::PathBuf>,
+    },
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Coordinates {
-    pub x: f32,
-    pub y: f32,
+impl Command {
+    /// Whether the command alters what is on screen.
+    pub fn changes_display(&self) -> bool {
+        !matches!(self, Command::Capture { .. })
+    }
+
+    pub fn from_proto(command: Option<root_message::Command>) -> Result<Self, CommandError> {
+        match command {
+            Some(root_message::Command::SetShape(shape)) => {
+                Ok(Command::Draw(require_shape(Some(shape), "SetShape")?))
+            }
+            Some(root_message::Command::SetBgColor(color)) => Ok(Command::Clear(bg_color(color)?)),
+            Some(root_message::Command::SetTexture(texture)) => Ok(Command::Texture(
+                texture.index,
+                texture_pixels(texture.index, texture.source)?,
+            )),
+            Some(root_message::Command::AddStimulus(stimulus)) => Ok(Command::AddStimulus(
+                StimulusSpec::from_proto(stimulus, "AddStimulus")?,
+            )),
+            Some(root_message::Command::UpdateStimulus(update)) => Ok(Command::UpdateStimulus(
+                update.id,
+                require_shape(update.shape, "UpdateStimulus")?,
+            )),
+            Some(root_message::Command::SetVisibility(visibility)) => {
+                Ok(Command::SetVisibility(visibility.id, visibility.visible))
+            }
+            Some(root_message::Command::RemoveStimulus(remove)) => {
+                Ok(Command::RemoveStimulus(remove.id))
+            }
+            Some(root_message::Command::PreloadTexture(texture)) => {
+                match texture_pixels(texture.index, texture.source)? {
+                    Some(pixels) => Ok(Command::PreloadTexture(texture.index, pixels)),
+                    None => Err(CommandError::new(
+                        ErrorCode::InvalidArgument,
+                        "PreloadTexture without data",
+                    )),
+                }
+            }
+            Some(root_message::Command::ShowTexture(texture)) => {
+                Ok(Command::ShowTexture(texture.index))
+            }
+            Some(root_message::Command::HideTexture(texture)) => {
+                Ok(Command::HideTexture(texture.index))
+            }
+            Some(root_message::Command::DeleteTexture(texture)) => {
+                Ok(Command::DeleteTexture(texture.index))
+            }
+            Some(root_message::Command::SetScene(scene)) => Ok(Command::SetScene {
+                stimuli: scene
+                    .stimuli
+                    .into_iter()
+                    .map(|s| StimulusSpec::from_proto(s, "SetScene"))
+                    .collect::<Result<_, _>>()?,
+                textures: scene
+                    .textures
+                    .into_iter()
+                    .map(|t| Ok((t.index, texture_pixels(t.index, t.source)?)))
+                    .collect::<Result<_, _>>()?,
+                bg_color: scene.bg_color.map(bg_color).transpose()?,
+            }),
+            Some(root_message::Command::CaptureFrame(capture)) => Ok(Command::Capture {
+                format: capture.format(),
+                path: (!capture.path.is_empty()).then(|| capture.path.into()),
+            }),
+            Some(
+                root_message::Command::GetStatus(_)
+                | root_message::Command::Hello(_)
+                | root_message::Command::Subscribe(_),
+            ) => Err(CommandError::new(
+                ErrorCode::InvalidArgument,
+                "Not a display command",
+            )),
+            None => Err(CommandError::new(
+                ErrorCode::UnknownCommand,
+                "Unknown command",
+            )),
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub enum Shape {
-    Square {
-        size: f32,
-        ctr: Coordinates,
-    },
-    Circle {
-        radius: f32,
-        ctr: Coordinates,
-    },
-    Cross {
-        size: f32,
-        line_width: f32,
-        ctr: Coordinates,
-    },
+/// RGBA from three or four components, opaque unless alpha is given.
+fn bg_color(color: vsg_messages::BgColor) -> Result<[f32; 4], CommandError> {
+    let rgba = match color.color[..] {
+        [r, g, b] => [r, g, b, 1.0],
+        [r, g, b, a] => [r, g, b, a],
+        _ => {
+            return Err(CommandError::new(
+                ErrorCode::InvalidArgument,
+                format!("BgColor needs 3 or 4 components, got {}", color.color.len()),
+            ))
+        }
+    };
+    validate_rgba(rgba)?;
+    Ok(rgba)
+}
+
+/// Decode image data, or check the layout of raw pixels. Empty image data is
+/// treated like a missing source, which refers to a stored texture.
+fn texture_pixels(
+    index: u32,
+    source: Option<TextureSource>,
+) -> Result<Option<RawPixels>, CommandError> {
+    let pixels = match source {
+        None => return Ok(None),
+        Some(TextureSource::Data(data)) if data.is_empty() => return Ok(None),
+        Some(TextureSource::Data(data)) => texture::decode(&data),
+        Some(TextureSource::Raw(raw)) => texture::check_layout(&raw).map(|_| raw),
+    };
+    pixels.map(Some).map_err(|e| {
+        CommandError::new(
+            ErrorCode::InvalidArgument,
+            format!("Cannot load texture {}: {}", index, e),
+        )
+    })
+}
+
+fn require_shape(
+    shape: Option<vsg_messages::Shape>,
+    command: &str,
+) -> Result<vsg_messages::shape::Shape, CommandError> {
+    let shape = shape.and_then(|s| s.shape).ok_or_else(|| {
+        CommandError::new(
+            ErrorCode::InvalidArgument,
+            format!("{} without a shape", command),
+        )
+    })?;
+    validate_shape(&shape)?;
+    Ok(shape)
+}
+
+/// Reject shapes that cannot be drawn: no center, coordinates that are not
+/// finite, sizes that are not positive, or colors out of range.
+fn validate_shape(shape: &vsg_messages::shape::Shape) -> Result<(), CommandError> {
+    use vsg_messages::shape::Shape;
+    let (name, ctr, sizes) = match shape {
+        Shape::Square(square) => ("Square", &square.ctr, vec![("size", square.size)]),
+        Shape::Circle(circle) => ("Circle", &circle.ctr, vec![("radius", circle.radius)]),
+        Shape::Cross(cross) => (
+            "Cross",
+            &cross.ctr,
+            vec![("size", cross.size), ("line_width", cross.line_width)],
+        ),
+    };
+    let invalid = |message: String| Err(CommandError::new(ErrorCode::InvalidArgument, message));
+    match ctr {
+        None => return invalid(format!("{} without ctr", name)),
+        Some(ctr) if !(ctr.x.is_finite() && ctr.y.is_finite()) => {
+            return invalid(format!("{} ctr must be finite: {:?}", name, ctr))
+        }
+        Some(_) => {}
+    }
+    for (field, value) in sizes {
+        if !(value.is_finite() && value > 0.0) {
+            return invalid(format!("{} {} must be positive: {}", name, field, value));
+        }
+    }
+    if let Some(color) = shape_color(shape) {
+        validate_color(color)?;
+    }
+    Ok(())
+}
+
+pub fn shape_color(shape: &vsg_messages::shape::Shape) -> Option<&vsg_messages::Color> {
+    use vsg_messages::shape::Shape;
+    match shape {
+        Shape::Square(square) => square.color.as_ref(),
+        Shape::Circle(circle) => circle.color.as_ref(),
+        Shape::Cross(cross) => cross.color.as_ref(),
+    }
+}
+
+fn validate_color(color: &vsg_messages::Color) -> Result<(), CommandError> {
+    validate_rgba(rgba(color))
+}
+
+fn validate_rgba(rgba: [f32; 4]) -> Result<(), CommandError> {
+    if rgba.iter().all(|c| (0.0..=1.0).contains(c)) {
+        Ok(())
+    } else {
+        Err(CommandError::new(
+            ErrorCode::InvalidArgument,
+            format!("Color components must be within [0, 1]: {:?}", rgba),
+        ))
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Message {
-    SetShape(Shape),
-    SetBgColor([f64; 4]),
+/// RGBA of a protobuf color, opaque unless alpha is given.
+pub fn rgba(color: &vsg_messages::Color) -> [f32; 4] {
+    [color.r, color.g, color.b, color.a.unwrap_or(1.0)]
+}
+
+/// A request that could not be carried out.
+#[derive(Debug)]
+pub struct CommandError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl CommandError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code.as_str_name(), self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Coordinates {
+    pub x: f32,
+    pub y: f32,
 }
 
 #[cfg(test)]
@@ -42,16 +300,182 @@ mod tests {
     use super::*;
 
     #[test]
-    fn a() {
-        let msg = Message::SetShape(Shape::Square {
+    fn shape_color_is_validated() {
+        let circle = |color| vsg_messages::Shape {
+            shape: Some(vsg_messages::shape::Shape::Circle(vsg_messages::Circle {
+                radius: 0.1,
+                ctr: Some(vsg_messages::Coordinates { x: 0.0, y: 0.0 }),
+                color: Some(color),
+            })),
+        };
+        let valid = vsg_messages::Color {
+            r: 1.0,
+            g: 0.5,
+            b: 0.0,
+            a: None,
+        };
+        let shape = require_shape(Some(circle(valid)), "SetShape").unwrap();
+        assert_eq!(rgba(shape_color(&shape).unwrap()), [1.0, 0.5, 0.0, 1.0]);
+
+        for invalid in [-0.1, 1.5, f32::NAN] {
+            let color = vsg_messages::Color {
+                r: invalid,
+                g: 0.0,
+                b: 0.0,
+                a: Some(1.0),
+            };
+            let err = require_shape(Some(circle(color)), "SetShape").unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn malformed_commands_are_rejected() {
+        use root_message::Command as Proto;
+        use vsg_messages::shape::Shape;
+
+        let circle = |radius, ctr| {
+            Proto::SetShape(vsg_messages::Shape {
+                shape: Some(Shape::Circle(vsg_messages::Circle {
+                    radius,
+                    ctr,
+                    color: None,
+                })),
+            })
+        };
+        let center = Some(vsg_messages::Coordinates { x: 0.0, y: 0.0 });
+        let bg_color = |color: &[f32]| {
+            Proto::SetBgColor(vsg_messages::BgColor {
+                color: color.to_vec(),
+            })
+        };
+
+        for command in [
+            circle(0.1, None),
+            circle(-0.1, center.clone()),
+            circle(f32::INFINITY, center.clone()),
+            circle(
+                0.1,
+                Some(vsg_messages::Coordinates {
+                    x: f32::NAN,
+                    y: 0.0,
+                }),
+            ),
+            bg_color(&[]),
+            bg_color(&[0.5, 0.5]),
+            bg_color(&[0.5, 0.5, 0.5, 1.0, 1.0]),
+            bg_color(&[2.0, 0.5, 0.5]),
+        ] {
+            let err = Command::from_proto(Some(command.clone())).unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidArgument, "{:?}", command);
+        }
+
+        assert!(Command::from_proto(Some(circle(0.1, center))).is_ok());
+        assert_eq!(
+            Command::from_proto(Some(bg_color(&[0.5, 0.5, 0.5]))).unwrap(),
+            Command::Clear([0.5, 0.5, 0.5, 1.0])
+        );
+    }
+
+    #[test]
+    fn hidden_stimuli_cannot_have_a_lifetime() {
+        let stimulus = |hidden, lifetime| {
+            Some(root_message::Command::AddStimulus(vsg_messages::Stimulus {
+                id: 4,
+                shape: Some(vsg_messages::Shape {
+                    shape: Some(vsg_messages::shape::Shape::Circle(vsg_messages::Circle {
+                        radius: 0.1,
+                        ctr: Some(vsg_messages::Coordinates { x: 0.0, y: 0.0 }),
+                        color: None,
+                    })),
+                }),
+                hidden,
+                lifetime,
+            }))
+        };
+        let frames = Some(Lifetime::DurationFrames(3));
+
+        let err = Command::from_proto(stimulus(true, frames.clone())).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidArgument);
+        assert!(Command::from_proto(stimulus(true, None)).is_ok());
+        assert!(Command::from_proto(stimulus(false, frames)).is_ok());
+    }
+
+    #[test]
+    fn images_are_decoded_before_queueing() {
+        use vsg_messages::{texture::Source, PixelFormat, Texture};
+
+        let mut png = Vec::new();
+        image_rs::RgbaImage::from_pixel(2, 1, image_rs::Rgba([255, 0, 0, 255]))
+            .write_to(
+                &mut std::io::Cursor::new(&mut png),
+                image_rs::ImageOutputFormat::Png,
+            )
+            .unwrap();
+        let set_texture = |source| {
+            Command::from_proto(Some(root_message::Command::SetTexture(Texture {
+                index: 4,
+                source: Some(source),
+            })))
+        };
+
+        match set_texture(Source::Data(png)).unwrap() {
+            Command::Texture(4, Some(pixels)) => {
+                assert_eq!((pixels.width, pixels.height), (2, 1));
+                assert_eq!(pixels.format(), PixelFormat::Rgba8);
+                assert_eq!(pixels.data, [255, 0, 0, 255, 255, 0, 0, 255]);
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(
+            set_texture(Source::Data(Vec::new())).unwrap(),
+            Command::Texture(4, None)
+        );
+
+        let short = RawPixels {
+            width: 2,
+            height: 2,
+            format: PixelFormat::R8.into(),
+            stride: 0,
+            data: vec![0; 3],
+        };
+        for source in [Source::Data(b"not an image".to_vec()), Source::Raw(short)] {
+            let err = set_texture(source).unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn root_message_round_trips_through_json() {
+        use vsg_messages::RootMessage;
+
+        let square = vsg_messages::Square {
             size: 0.2,
-            ctr: Coordinates { x: 0.0, y: 0.0 },
-        });
+            ctr: Some(vsg_messages::Coordinates { x: 0.0, y: 0.0 }),
+            color: None,
+        };
+        let msg = RootMessage {
+            command: Some(root_message::Command::SetShape(vsg_messages::Shape {
+                shape: Some(vsg_messages::shape::Shape::Square(square)),
+            })),
+            ..Default::default()
+        };
         let serialized = serde_json::to_string(&msg).unwrap();
-        println!("{}", serialized);
+        assert_eq!(
+            serde_json::from_str::<RootMessage>(&serialized).unwrap(),
+            msg
+        );
 
-        let msg = Message::SetBgColor([0.1, 0.2, 0.3, 1.0]);
+        let msg = RootMessage {
+            command: Some(root_message::Command::SetBgColor(vsg_messages::BgColor {
+                color: vec![0.1, 0.2, 0.3, 1.0],
+            })),
+            ..Default::default()
+        };
         let serialized = serde_json::to_string(&msg).unwrap();
-        println!("{}", serialized);
+        assert_eq!(
+            serde_json::from_str::<RootMessage>(&serialized).unwrap(),
+            msg
+        );
     }
 }
@@ -1,5 +1,6 @@
 use super::{triangle::Triangle, StimulusRenderer};
-use crate::{graphics::triangle::Vertex2D, Coordinates};
+use crate::graphics::triangle::Vertex2D;
+use crate::messages::Coordinates;
 
 pub struct Circle {
     pub size: f32,
@@ -1,5 +1,5 @@
 use super::{Rectangle, StimulusRenderer};
-use crate::Coordinates;
+use crate::messages::Coordinates;
 
 pub struct Cross {
     pub ctr: Coordinates,
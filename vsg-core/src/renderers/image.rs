@@ -1,4 +1,5 @@
 use super::StimulusRenderer;
+use crate::texture::Texture;
 use crate::vertex::VertexTexture;
 use wgpu::util::DeviceExt;
 
@@ -37,7 +38,22 @@ impl Picture {
         queue: &wgpu::Queue,
         format: &wgpu::TextureFormat,
         buf: &[u8],
+    ) -> anyhow::Result<Self> {
+        let texture = Texture::from_bytes(device, queue, buf, "xx")?;
+        Ok(Self::from_texture(device, format, &texture))
+    }
+
+    pub fn from_texture(
+        device: &wgpu::Device,
+        format: &wgpu::TextureFormat,
+        diffuse_texture: &Texture,
     ) -> Self {
+        let filterable = diffuse_texture.is_filterable();
+        let sampler_binding = if filterable {
+            wgpu::SamplerBindingType::Filtering
+        } else {
+            wgpu::SamplerBindingType::NonFiltering
+        };
         let texture_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 entries: &[
@@ -47,23 +63,20 @@ impl Picture {
                         ty: wgpu::BindingType::Texture {
                             multisampled: false,
                             view_dimension: wgpu::TextureViewDimension::D2,
-                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            sample_type: wgpu::TextureSampleType::Float { filterable },
                         },
                         count: None,
                     },
                     wgpu::BindGroupLayoutEntry {
                         binding: 1,
                         visibility: wgpu::ShaderStages::FRAGMENT,
-                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        ty: wgpu::BindingType::Sampler(sampler_binding),
                         count: None,
                     },
                 ],
                 label: Some("texture_bind_group_layout"),
             });
 
-        let diffuse_texture =
-            crate::texture::Texture::from_bytes(device, queue, buf, "xx").unwrap();
-
         let diffuse_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             layout: &texture_bind_group_layout,
             entries: &[
@@ -104,7 +117,11 @@ impl Picture {
                 },
                 fragment: Some(wgpu::FragmentState {
                     module: &shader_with_texture,
-                    entry_point: "fs_main",
+                    entry_point: if diffuse_texture.is_single_channel() {
+                        "fs_gray"
+                    } else {
+                        "fs_main"
+                    },
                     targets: &[Some(wgpu::ColorTargetState {
                         format: *format,
                         blend: Some(wgpu::BlendState {
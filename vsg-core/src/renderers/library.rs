@@ -0,0 +1,129 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use vsg_messages::ErrorCode;
+
+use super::{Picture, StimulusRenderer};
+use crate::messages::CommandError;
+
+/// Textures uploaded to the GPU, keyed by client-chosen index.
+///
+/// Uploading and showing are separate steps, so a whole block of images can
+/// be preloaded before a trial starts. Shown textures are drawn in ascending
+/// index order.
+pub struct TextureLibrary<P = Picture> {
+    pictures: BTreeMap<u32, P>,
+    shown: BTreeSet<u32>,
+}
+
+impl<P> Default for TextureLibrary<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> TextureLibrary<P> {
+    pub fn new() -> Self {
+        Self {
+            pictures: BTreeMap::new(),
+            shown: BTreeSet::new(),
+        }
+    }
+
+    /// Store `picture` under `index`, replacing any previous one but keeping
+    /// whether that index is shown.
+    pub fn insert(&mut self, index: u32, picture: P) {
+        self.pictures.insert(index, picture);
+    }
+
+    pub fn show(&mut self, index: u32) -> Result<(), CommandError> {
+        self.check(index)?;
+        self.shown.insert(index);
+        Ok(())
+    }
+
+    pub fn hide(&mut self, index: u32) -> Result<(), CommandError> {
+        self.check(index)?;
+        self.shown.remove(&index);
+        Ok(())
+    }
+
+    pub fn hide_all(&mut self) {
+        self.shown.clear();
+    }
+
+    pub fn remove(&mut self, index: u32) -> Result<(), CommandError> {
+        self.check(index)?;
+        self.pictures.remove(&index);
+        self.shown.remove(&index);
+        Ok(())
+    }
+
+    /// Fails unless a texture is stored under `index`.
+    pub fn check(&self, index: u32) -> Result<(), CommandError> {
+        if self.pictures.contains_key(&index) {
+            Ok(())
+        } else {
+            Err(CommandError::new(
+                ErrorCode::TextureNotFound,
+                format!("Texture {} not found", index),
+            ))
+        }
+    }
+}
+
+impl<P: StimulusRenderer> StimulusRenderer for TextureLibrary<P> {
+    fn render<'rpass, 'pass>(&'rpass self, render_pass: &mut wgpu::RenderPass<'pass>)
+    where
+        'rpass: 'pass,
+    {
+        for index in &self.shown {
+            self.pictures[index].render(render_pass);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pictures stand in as their names.
+    fn shown(library: &TextureLibrary<&'static str>) -> Vec<&'static str> {
+        library.shown.iter().map(|i| library.pictures[i]).collect()
+    }
+
+    #[test]
+    fn shows_stored_pictures_in_index_order() {
+        let mut library = TextureLibrary::new();
+        library.insert(2, "cat");
+        library.insert(1, "dog");
+        library.show(2).unwrap();
+        library.show(1).unwrap();
+        assert_eq!(shown(&library), ["dog", "cat"]);
+
+        // Replacing a picture keeps it shown.
+        library.insert(2, "fish");
+        assert_eq!(shown(&library), ["dog", "fish"]);
+        library.hide(1).unwrap();
+        assert_eq!(shown(&library), ["fish"]);
+        library.hide_all();
+        assert!(shown(&library).is_empty());
+    }
+
+    #[test]
+    fn unknown_indices_are_rejected() {
+        let mut library = TextureLibrary::new();
+        library.insert(1, "dog");
+        library.show(1).unwrap();
+        library.remove(1).unwrap();
+        assert!(shown(&library).is_empty());
+
+        for result in [
+            library.check(1),
+            library.show(1),
+            library.hide(1),
+            library.remove(1),
+        ] {
+            assert_eq!(result.unwrap_err().code, ErrorCode::TextureNotFound);
+        }
+    }
+}
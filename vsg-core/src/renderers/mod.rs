@@ -4,6 +4,8 @@ mod cross;
 pub use cross::Cross;
 mod image;
 pub use image::Picture;
+mod library;
+pub use library::TextureLibrary;
 mod rectangle;
 pub use rectangle::Rectangle;
 pub mod triangle;
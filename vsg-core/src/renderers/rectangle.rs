@@ -3,7 +3,7 @@ use super::{
     StimulusRenderer,
 };
 use crate::graphics::triangle::Vertex2D;
-use crate::Coordinates;
+use crate::messages::Coordinates;
 
 pub struct Rectangle {
     pub size: f32,
@@ -1,28 +1,186 @@
+use std::collections::BTreeMap;
+
+use vsg_messages::ErrorCode;
+
 use super::StimulusRenderer;
+use crate::messages::CommandError;
 
+struct Entry {
+    stimulus: Box<dyn StimulusRenderer>,
+    visible: bool,
+    /// Frames left to present before the stimulus is removed.
+    remaining_frames: Option<u64>,
+}
+
+/// Stimuli keyed by client-chosen ids, drawn in ascending id order.
+#[derive(Default)]
 pub struct Scene {
-    stimuli: Vec<Box<dyn StimulusRenderer>>,
+    stimuli: BTreeMap<u32, Entry>,
+    /// Set by SetShape, which predates ids, and drawn below the stimuli.
+    shape: Option<Box<dyn StimulusRenderer>>,
 }
 
 impl Scene {
     pub fn new() -> Self {
         Self {
-            stimuli: Vec::new(),
+            stimuli: BTreeMap::new(),
+            shape: None,
+        }
+    }
+
+    /// A scene of only `shape`, which no id refers to.
+    pub fn with_shape(shape: Box<dyn StimulusRenderer>) -> Self {
+        Self {
+            shape: Some(shape),
+            ..Self::new()
+        }
+    }
+
+    pub fn add_stimulus(
+        &mut self,
+        id: u32,
+        stimulus: Box<dyn StimulusRenderer>,
+        visible: bool,
+        duration_frames: Option<u64>,
+    ) -> Result<(), CommandError> {
+        if self.stimuli.contains_key(&id) {
+            return Err(CommandError::new(
+                ErrorCode::AlreadyExists,
+                format!("Stimulus {} already exists", id),
+            ));
+        }
+        self.stimuli.insert(
+            id,
+            Entry {
+                stimulus,
+                visible,
+                remaining_frames: duration_frames,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn update_stimulus(
+        &mut self,
+        id: u32,
+        stimulus: Box<dyn StimulusRenderer>,
+    ) -> Result<(), CommandError> {
+        self.entry(id)?.stimulus = stimulus;
+        Ok(())
+    }
+
+    pub fn set_visible(&mut self, id: u32, visible: bool) -> Result<(), CommandError> {
+        self.entry(id)?.visible = visible;
+        Ok(())
+    }
+
+    pub fn remove_stimulus(&mut self, id: u32) -> Result<(), CommandError> {
+        self.stimuli
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| not_found(id))
+    }
+
+    /// Count down the lifetimes after a frame has been presented and remove
+    /// the stimuli whose time is up. Returns their ids.
+    pub fn frame_presented(&mut self) -> Vec<u32> {
+        let mut expired = Vec::new();
+        for (id, entry) in self.stimuli.iter_mut() {
+            if let Some(remaining) = entry.remaining_frames.as_mut() {
+                *remaining = remaining.saturating_sub(1);
+                if *remaining == 0 {
+                    expired.push(*id);
+                }
+            }
         }
+        for id in &expired {
+            self.stimuli.remove(id);
+        }
+        expired
     }
 
-    pub fn add_stimulus(&mut self, stimulus: Box<dyn StimulusRenderer>) {
-        self.stimuli.push(stimulus);
+    fn entry(&mut self, id: u32) -> Result<&mut Entry, CommandError> {
+        self.stimuli.get_mut(&id).ok_or_else(|| not_found(id))
     }
 }
 
+fn not_found(id: u32) -> CommandError {
+    CommandError::new(ErrorCode::NotFound, format!("Stimulus {} not found", id))
+}
+
 impl StimulusRenderer for Scene {
     fn render<'rpass, 'pass>(&'rpass self, render_pass: &mut wgpu::RenderPass<'pass>)
     where
         'rpass: 'pass,
     {
-        for stimulus in &self.stimuli {
-            stimulus.render(render_pass);
+        if let Some(shape) = &self.shape {
+            shape.render(render_pass);
+        }
+        for entry in self.stimuli.values().filter(|e| e.visible) {
+            entry.stimulus.render(render_pass);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+
+    impl StimulusRenderer for Dummy {
+        fn render<'rpass, 'pass>(&'rpass self, _render_pass: &mut wgpu::RenderPass<'pass>)
+        where
+            'rpass: 'pass,
+        {
         }
     }
+
+    #[test]
+    fn stimuli_are_addressed_by_id() {
+        let mut scene = Scene::new();
+        scene.add_stimulus(1, Box::new(Dummy), true, None).unwrap();
+        scene.add_stimulus(7, Box::new(Dummy), false, None).unwrap();
+
+        let err = scene
+            .add_stimulus(1, Box::new(Dummy), true, None)
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::AlreadyExists);
+
+        scene.set_visible(7, true).unwrap();
+        assert!(scene.stimuli[&7].visible);
+        scene.update_stimulus(7, Box::new(Dummy)).unwrap();
+        assert!(scene.stimuli[&7].visible);
+
+        scene.remove_stimulus(1).unwrap();
+        let err = scene.update_stimulus(1, Box::new(Dummy)).unwrap_err();
+        assert_eq!(err.code, ErrorCode::NotFound);
+        assert_eq!(scene.stimuli.keys().collect::<Vec<_>>(), vec![&7]);
+    }
+
+    #[test]
+    fn the_set_shape_stimulus_has_no_id() {
+        let mut scene = Scene::with_shape(Box::new(Dummy));
+        assert_eq!(
+            scene.remove_stimulus(0).unwrap_err().code,
+            ErrorCode::NotFound
+        );
+        scene.add_stimulus(0, Box::new(Dummy), true, None).unwrap();
+        scene.remove_stimulus(0).unwrap();
+        assert!(scene.shape.is_some());
+    }
+
+    #[test]
+    fn stimuli_expire_after_their_lifetime() {
+        let mut scene = Scene::new();
+        scene
+            .add_stimulus(1, Box::new(Dummy), true, Some(2))
+            .unwrap();
+        scene.add_stimulus(2, Box::new(Dummy), true, None).unwrap();
+
+        assert!(scene.frame_presented().is_empty());
+        assert_eq!(scene.frame_presented(), vec![1]);
+        assert!(scene.frame_presented().is_empty());
+        assert_eq!(scene.stimuli.keys().collect::<Vec<_>>(), vec![&2]);
+    }
 }
@@ -0,0 +1,74 @@
+use prost::Message;
+use vsg_messages::{RootMessage, RootResponse};
+
+/// How RootMessage and RootResponse are encoded on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Encoding {
+    #[default]
+    Protobuf,
+    /// The canonical protobuf JSON mapping, so that scripts can do without
+    /// generated code. Field names may be given in snake_case or lowerCamelCase.
+    Json,
+}
+
+impl Encoding {
+    /// A stream connection may open with one of these bytes to choose its
+    /// encoding. A length prefix only starts with one of them for messages
+    /// over 1 GiB, which is more than the server accepts.
+    pub fn from_handshake(byte: u8) -> Option<Self> {
+        match byte {
+            b'P' => Some(Encoding::Protobuf),
+            b'J' => Some(Encoding::Json),
+            _ => None,
+        }
+    }
+
+    pub fn decode(self, payload: &[u8]) -> Result<RootMessage, String> {
+        match self {
+            Encoding::Protobuf => RootMessage::decode(payload).map_err(|e| e.to_string()),
+            Encoding::Json => serde_json::from_slice(payload).map_err(|e| e.to_string()),
+        }
+    }
+
+    pub fn encode(self, response: &RootResponse) -> Vec<u8> {
+        match self {
+            Encoding::Protobuf => response.encode_to_vec(),
+            Encoding::Json => {
+                serde_json::to_vec(response).expect("responses always serialize to JSON")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vsg_messages::{root_message, BgColor, ErrorCode};
+
+    use super::*;
+
+    #[test]
+    fn decodes_json_messages() {
+        let json =
+            br#"{"setBgColor": {"color": [0.5, 0.5, 0.5, 1.0]}, "sequence": 7, "flip_ack": true}"#;
+        let msg = Encoding::Json.decode(json).unwrap();
+        assert_eq!(
+            msg,
+            RootMessage {
+                command: Some(root_message::Command::SetBgColor(BgColor {
+                    color: vec![0.5, 0.5, 0.5, 1.0],
+                })),
+                sequence: 7,
+                flip_ack: true,
+                ..Default::default()
+            }
+        );
+        assert!(Encoding::Json.decode(b"[]").is_err());
+
+        let response = RootResponse::error(7, ErrorCode::NotFound, "Stimulus 1 not found");
+        let json: serde_json::Value =
+            serde_json::from_slice(&Encoding::Json.encode(&response)).unwrap();
+        assert_eq!(json["errorCode"], "ERROR_CODE_NOT_FOUND");
+        assert_eq!(json["sequence"], "7");
+    }
+}
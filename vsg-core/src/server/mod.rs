@@ -0,0 +1,662 @@
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use anyhow::Result;
+use vsg_messages::{root_message, ErrorCode, Hello, RootMessage, RootResponse, PROTOCOL_VERSION};
+
+use crate::capture;
+use crate::clock;
+use crate::dispatcher::{CommandQueue, QueuedCommand, Schedule};
+use crate::events::EventBus;
+use crate::messages::{Command, CommandError};
+use crate::status::StatusBoard;
+
+mod encoding;
+pub use encoding::Encoding;
+mod udp;
+pub use udp::serve_udp;
+mod websocket;
+pub use websocket::serve_websocket;
+
+/// Optional parts of the protocol this server supports, announced in Hello.
+pub const FEATURES: &[&str] = &[
+    "flip_ack",
+    "schedule",
+    "stimulus_lifetime",
+    "raw_pixels",
+    "get_status",
+    "json",
+    "events",
+    "capture",
+];
+
+/// Handles shared by every client connection and the render loop.
+#[derive(Clone)]
+pub struct Shared {
+    pub queue: CommandQueue,
+    pub status: StatusBoard,
+    pub events: EventBus,
+    /// Used by connections that do not choose an encoding themselves.
+    pub encoding: Encoding,
+    /// Longest message accepted, in bytes.
+    pub max_message_size: usize,
+    /// Where clients may save captured frames. Captures are only returned in
+    /// replies when not set.
+    pub capture_dir: Option<PathBuf>,
+    /// Origins of web pages whose browsers may connect over WebSocket.
+    pub allowed_origins: Vec<String>,
+}
+
+impl Shared {
+    fn push(&self, command: QueuedCommand) {
+        self.queue.lock().unwrap().push(command);
+    }
+
+    /// Validate a display command from a client, keeping the files it may
+    /// write inside the capture directory.
+    fn command(&self, command: Option<root_message::Command>) -> Result<Command, CommandError> {
+        match Command::from_proto(command)? {
+            Command::Capture {
+                format,
+                path: Some(path),
+            } => Ok(Command::Capture {
+                format,
+                path: Some(capture::resolve_path(self.capture_dir.as_deref(), &path)?),
+            }),
+            command => Ok(command),
+        }
+    }
+
+    /// Handles that no render loop reads from, with the default encoding and
+    /// a 1 KiB message limit.
+    #[cfg(test)]
+    fn for_test() -> Self {
+        Self {
+            queue: Default::default(),
+            status: crate::status::new_board(),
+            events: EventBus::new(),
+            encoding: Encoding::Protobuf,
+            max_message_size: 1024,
+            capture_dir: None,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+/// State kept for a single client connection.
+pub struct Session {
+    id: u64,
+    peer: String,
+    shared: Shared,
+    encoding: Encoding,
+    /// Where events for this connection are pushed.
+    outbox: Sender<RootResponse>,
+    /// Set once the client announced a protocol version other than ours.
+    mismatch: Option<String>,
+    /// Messages received on this connection.
+    received: u64,
+}
+
+impl Session {
+    pub fn new(peer: String, shared: Shared, outbox: Sender<RootResponse>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            peer,
+            encoding: shared.encoding,
+            shared,
+            outbox,
+            mismatch: None,
+            received: 0,
+        }
+    }
+
+    /// Decode a payload and produce the reply to send back.
+    pub fn handle(&mut self, payload: &[u8]) -> Result<RootResponse> {
+        self.received += 1;
+        match self.encoding.decode(payload) {
+            Ok(msg) => {
+                log::debug!("Client {} : {:?}", self.id, msg);
+                self.respond(msg)
+            }
+            Err(e) => {
+                log::error!("Client {} : {}", self.id, e);
+                Ok(RootResponse::error(0, ErrorCode::Decode, e))
+            }
+        }
+    }
+
+    /// Answer a query, or queue a command and reply once it has been accepted
+    /// or, with flip_ack, presented. Commands answered when accepted report a
+    /// later failure with a CommandFailed event.
+    fn respond(&mut self, msg: RootMessage) -> Result<RootResponse> {
+        if let Some(mismatch) = &self.mismatch {
+            return Ok(RootResponse::error(
+                msg.sequence,
+                ErrorCode::VersionMismatch,
+                mismatch.clone(),
+            ));
+        }
+        match msg.command {
+            Some(root_message::Command::Hello(hello)) => return Ok(self.greet(msg.sequence, hello)),
+            Some(root_message::Command::GetStatus(_)) => {
+                return Ok(RootResponse {
+                    display_status: Some(self.shared.status.lock().unwrap().clone()),
+                    ..RootResponse::ok(msg.sequence)
+                })
+            }
+            Some(root_message::Command::Subscribe(subscribe)) => {
+                let kinds: Vec<_> = subscribe.kinds().collect();
+                log::debug!("Client {} subscribed to {:?}", self.id, kinds);
+                self.shared
+                    .events
+                    .subscribe(self.id, kinds, self.outbox.clone());
+                return Ok(RootResponse::ok(msg.sequence));
+            }
+            _ => {}
+        }
+        let schedule = Schedule::from_proto(msg.schedule);
+        let response = match self.shared.command(msg.command) {
+            // Captures are always answered once their frame is presented.
+            Ok(command) if msg.flip_ack || matches!(command, Command::Capture { .. }) => {
+                let (tx, rx) = mpsc::channel();
+                self.shared
+                    .push(QueuedCommand::new(command).with_ack(tx).scheduled(schedule));
+                // Blocks this connection until the render loop has presented the frame.
+                match rx.recv()? {
+                    Ok(acked) => {
+                        log::debug!("Onset : {:?}", acked.onset);
+                        RootResponse {
+                            onset: Some(acked.onset),
+                            capture: acked.capture,
+                            ..RootResponse::ok(msg.sequence)
+                        }
+                    }
+                    Err(e) => RootResponse::error(msg.sequence, e.code, e.message),
+                }
+            }
+            Ok(command) => {
+                self.shared.push(
+                    QueuedCommand::new(command)
+                        .scheduled(schedule)
+                        .reporting_failure(msg.sequence, self.outbox.clone()),
+                );
+                RootResponse::ok(msg.sequence)
+            }
+            Err(e) => {
+                log::error!("Client {} : {}", self.id, e);
+                RootResponse::error(msg.sequence, e.code, e.message)
+            }
+        };
+        Ok(response)
+    }
+
+    /// Check the client's Hello and introduce the server in return.
+    fn greet(&mut self, sequence: u64, hello: Hello) -> RootResponse {
+        log::info!(
+            "Client {} is {:?}, protocol version {}",
+            self.id,
+            hello.agent,
+            hello.protocol_version
+        );
+        if hello.protocol_version != PROTOCOL_VERSION {
+            let mismatch = format!(
+                "Client speaks protocol version {} but the server speaks {}",
+                hello.protocol_version, PROTOCOL_VERSION
+            );
+            log::error!("Client {} : {}", self.id, mismatch);
+            self.mismatch = Some(mismatch.clone());
+            return RootResponse::error(sequence, ErrorCode::VersionMismatch, mismatch);
+        }
+        let missing: Vec<_> = hello
+            .features
+            .iter()
+            .filter(|f| !FEATURES.contains(&f.as_str()))
+            .collect();
+        if !missing.is_empty() {
+            return RootResponse::error(
+                sequence,
+                ErrorCode::UnsupportedFeature,
+                format!("Features not supported by this server: {:?}", missing),
+            );
+        }
+        RootResponse {
+            hello: Some(Hello {
+                protocol_version: PROTOCOL_VERSION,
+                features: FEATURES.iter().map(|f| f.to_string()).collect(),
+                agent: format!("vsg-core {}", env!("CARGO_PKG_VERSION")),
+            }),
+            ..RootResponse::ok(sequence)
+        }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.shared.events.unsubscribe(self.id);
+        log::info!(
+            "Client {} ({}) disconnected after {} messages",
+            self.id,
+            self.peer,
+            self.received
+        );
+    }
+}
+
+/// Accept TCP clients forever, serving each connection on its own thread.
+pub fn serve(listener: TcpListener, shared: Shared) {
+    accept(
+        listener.incoming(),
+        shared,
+        |stream| stream.peer_addr().map(|addr| addr.to_string()),
+        handle_connection,
+    );
+}
+
+/// Bind a Unix domain socket at `path`, replacing a socket file left behind
+/// by a server that is no longer running. The file is removed again when
+/// the returned [`SocketFile`] is dropped.
+#[cfg(unix)]
+pub fn bind_unix(path: &Path) -> Result<(UnixListener, SocketFile)> {
+    use std::os::unix::fs::FileTypeExt;
+
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            anyhow::bail!("{} exists and is not a socket", path.display());
+        }
+        if UnixStream::connect(path).is_ok() {
+            anyhow::bail!("{} is in use by another server", path.display());
+        }
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    Ok((listener, SocketFile(path.to_owned())))
+}
+
+/// Removes the file of a socket bound with [`bind_unix`] when dropped, so
+/// that it is not left behind however the server stops rendering.
+#[cfg(unix)]
+pub struct SocketFile(PathBuf);
+
+#[cfg(unix)]
+impl Drop for SocketFile {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.0) {
+            log::error!("Could not remove {}: {}", self.0.display(), e);
+        }
+    }
+}
+
+/// Accept Unix domain socket clients forever, like [`serve`].
+#[cfg(unix)]
+pub fn serve_unix(listener: UnixListener, shared: Shared) {
+    let path = listener
+        .local_addr()
+        .ok()
+        .and_then(|addr| addr.as_pathname().map(|p| p.display().to_string()))
+        .unwrap_or_default();
+    // Clients of a Unix socket are usually unnamed, so use the socket path.
+    accept(
+        listener.incoming(),
+        shared,
+        move |_| Ok(path.clone()),
+        handle_connection,
+    );
+}
+
+/// Hand every accepted stream to `handler` on a thread of its own.
+fn accept<S, I, P>(
+    incoming: I,
+    shared: Shared,
+    peer: P,
+    handler: fn(S, String, Shared) -> Result<()>,
+) where
+    S: Send + 'static,
+    I: Iterator<Item = io::Result<S>>,
+    P: Fn(&S) -> io::Result<String>,
+{
+    for stream in incoming {
+        let (stream, peer) = match stream.and_then(|s| peer(&s).map(|p| (s, p))) {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                log::error!("{}", e);
+                continue;
+            }
+        };
+        let shared = shared.clone();
+        thread::spawn(move || {
+            if let Err(e) = handler(stream, peer, shared) {
+                log::error!("{}", e);
+            }
+        });
+    }
+}
+
+/// A stream that can be written from one thread while another reads it.
+pub trait Duplex: Read + Write + Send + Sized + 'static {
+    fn try_clone(&self) -> io::Result<Self>;
+}
+
+impl Duplex for TcpStream {
+    fn try_clone(&self) -> io::Result<Self> {
+        TcpStream::try_clone(self)
+    }
+}
+
+#[cfg(unix)]
+impl Duplex for UnixStream {
+    fn try_clone(&self) -> io::Result<Self> {
+        UnixStream::try_clone(self)
+    }
+}
+
+/// Serve length-prefixed messages until the client disconnects.
+fn handle_connection<S: Duplex>(mut stream: S, peer: String, shared: Shared) -> Result<()> {
+    let max_message_size = shared.max_message_size;
+    let (outbox, responses) = mpsc::channel();
+    let mut session = Session::new(peer, shared, outbox.clone());
+    log::info!("Client {} connected from {}", session.id, session.peer);
+
+    let mut msg_size = [0; 4];
+    if !read_or_eof(&mut stream, &mut msg_size[..1])? {
+        return Ok(());
+    }
+    let mut pending = match Encoding::from_handshake(msg_size[0]) {
+        Some(encoding) => {
+            log::debug!("Client {} uses {:?}", session.id, encoding);
+            session.encoding = encoding;
+            false
+        }
+        None => true,
+    };
+
+    // Replies and events are written on their own thread, so that events
+    // are not held up while waiting for the next request.
+    let writer = stream.try_clone()?;
+    let encoding = session.encoding;
+    thread::spawn(move || write_responses(writer, responses, encoding));
+
+    let mut buffer = vec![0; 1024];
+    loop {
+        if !std::mem::take(&mut pending) && !read_or_eof(&mut stream, &mut msg_size[..1])? {
+            return Ok(());
+        }
+        stream.read_exact(&mut msg_size[1..])?;
+        let len = u32::from_be_bytes(msg_size) as usize;
+        log::trace!("{}", len);
+        if len > max_message_size {
+            let error = format!(
+                "Message of {} bytes exceeds the limit of {}",
+                len, max_message_size
+            );
+            let _ = outbox.send(RootResponse::error(
+                0,
+                ErrorCode::MessageTooLarge,
+                error.clone(),
+            ));
+            anyhow::bail!("Client {} : {}", session.id, error);
+        }
+        if len > buffer.len() {
+            buffer.resize(len, 0);
+        }
+        stream.read_exact(&mut buffer[..len])?;
+        let response = session.handle(&buffer[..len])?;
+        if outbox.send(response).is_err() {
+            // The writer gave up on the connection.
+            return Ok(());
+        }
+    }
+}
+
+/// Fill `buf`, or return false if the client closed the connection before
+/// sending anything.
+fn read_or_eof(stream: &mut impl Read, buf: &mut [u8]) -> Result<bool> {
+    match stream.read_exact(buf) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Write everything sent to the session's outbox until the session ends.
+fn write_responses(mut stream: impl Write, responses: Receiver<RootResponse>, encoding: Encoding) {
+    for response in responses {
+        let msg = encode_response(encoding, response);
+        if let Err(e) = write_response(&mut stream, &msg) {
+            log::debug!("{}", e);
+            return;
+        }
+    }
+}
+
+/// Stamp the reply with the server clock and encode it.
+fn encode_response(encoding: Encoding, mut response: RootResponse) -> Vec<u8> {
+    response.server_time_us = clock::now_us();
+    encoding.encode(&response)
+}
+
+fn write_response(stream: &mut impl Write, msg: &[u8]) -> Result<()> {
+    let len = msg.len() as u32;
+    log::trace!("{}", len);
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(msg)?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpStream;
+
+    use prost::Message;
+    use vsg_messages::{BgColor, GetStatus};
+
+    use super::*;
+
+    fn request(stream: &mut impl Write, msg: RootMessage) {
+        let buf = msg.encode_to_vec();
+        stream.write_all(&(buf.len() as u32).to_be_bytes()).unwrap();
+        stream.write_all(&buf).unwrap();
+    }
+
+    fn reply(stream: &mut impl Read) -> RootResponse {
+        let mut len = [0; 4];
+        stream.read_exact(&mut len).unwrap();
+        let mut buf = vec![0; u32::from_be_bytes(len) as usize];
+        stream.read_exact(&mut buf).unwrap();
+        RootResponse::decode(&buf[..]).unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn serves_unix_socket_clients() {
+        let path = std::env::temp_dir().join(format!("vsg-test-{}.sock", std::process::id()));
+        let (listener, socket_file) = bind_unix(&path).unwrap();
+        let shared = Shared::for_test();
+        thread::spawn(move || serve_unix(listener, shared));
+
+        let mut stream = UnixStream::connect(&path).unwrap();
+        request(
+            &mut stream,
+            RootMessage {
+                command: Some(root_message::Command::GetStatus(GetStatus {})),
+                sequence: 3,
+                ..Default::default()
+            },
+        );
+        assert_eq!(reply(&mut stream).sequence, 3);
+        assert!(bind_unix(&path).is_err());
+        drop(socket_file);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn serves_clients_concurrently() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let shared = Shared::for_test();
+        let queue = shared.queue.clone();
+        thread::spawn(move || serve(listener, shared));
+
+        // The first client waits for a frame that is not presented yet...
+        let mut waiting = TcpStream::connect(addr).unwrap();
+        request(
+            &mut waiting,
+            RootMessage {
+                command: Some(root_message::Command::SetBgColor(BgColor {
+                    color: vec![0.0, 0.0, 0.0, 1.0],
+                })),
+                flip_ack: true,
+                sequence: 1,
+                ..Default::default()
+            },
+        );
+
+        // ...while the second one is still answered.
+        let mut other = TcpStream::connect(addr).unwrap();
+        request(
+            &mut other,
+            RootMessage {
+                command: Some(root_message::Command::GetStatus(GetStatus {})),
+                sequence: 2,
+                ..Default::default()
+            },
+        );
+        let response = reply(&mut other);
+        assert_eq!(response.sequence, 2);
+        assert!(response.display_status.is_some());
+
+        let queued = loop {
+            if let Some(queued) = queue.lock().unwrap().pop() {
+                break queued;
+            }
+            thread::yield_now();
+        };
+        let ack = queued.ack.expect("flip_ack command carries an ack");
+        ack.send(Ok(Default::default())).unwrap();
+        let response = reply(&mut waiting);
+        assert_eq!(response.sequence, 1);
+        assert!(response.onset.is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn handshake_byte_selects_json() {
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let shared = Shared::for_test();
+        thread::spawn(move || handle_connection(server, "test".into(), shared));
+
+        let json = br#"{"setVisibility": {"id": 2, "visible": true}, "sequence": 9}"#;
+        client.write_all(b"J").unwrap();
+        client
+            .write_all(&(json.len() as u32).to_be_bytes())
+            .unwrap();
+        client.write_all(json).unwrap();
+        let mut len = [0; 4];
+        client.read_exact(&mut len).unwrap();
+        let mut buf = vec![0; u32::from_be_bytes(len) as usize];
+        client.read_exact(&mut buf).unwrap();
+        let response: RootResponse = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(response.sequence, 9);
+        assert_eq!(response.status(), vsg_messages::Status::Ok);
+    }
+
+    #[test]
+    fn hello_checks_version_and_features() {
+        let shared = Shared::for_test();
+        let hello = |protocol_version, features: &[&str]| RootMessage {
+            command: Some(root_message::Command::Hello(Hello {
+                protocol_version,
+                features: features.iter().map(|f| f.to_string()).collect(),
+                agent: "test".into(),
+            })),
+            ..Default::default()
+        };
+        let get_status = RootMessage {
+            command: Some(root_message::Command::GetStatus(GetStatus {})),
+            ..Default::default()
+        };
+
+        let mut session = Session::new("test".into(), shared.clone(), mpsc::channel().0);
+        let response = session.respond(hello(PROTOCOL_VERSION, &["json"])).unwrap();
+        assert_eq!(response.hello.unwrap().protocol_version, PROTOCOL_VERSION);
+        let response = session
+            .respond(hello(PROTOCOL_VERSION, &["teleport"]))
+            .unwrap();
+        assert_eq!(response.error_code(), ErrorCode::UnsupportedFeature);
+
+        let mut session = Session::new("test".into(), shared, mpsc::channel().0);
+        let response = session.respond(hello(PROTOCOL_VERSION + 1, &[])).unwrap();
+        assert_eq!(response.error_code(), ErrorCode::VersionMismatch);
+        let response = session.respond(get_status).unwrap();
+        assert_eq!(response.error_code(), ErrorCode::VersionMismatch);
+    }
+
+    #[test]
+    fn captures_are_only_saved_in_the_capture_dir() {
+        let capture = |path: &str| RootMessage {
+            command: Some(root_message::Command::CaptureFrame(
+                vsg_messages::CaptureFrame {
+                    path: path.into(),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        };
+        let mut session = Session::new("test".into(), Shared::for_test(), mpsc::channel().0);
+        let response = session.respond(capture("frame.png")).unwrap();
+        assert_eq!(response.error_code(), ErrorCode::PermissionDenied);
+
+        let shared = Shared {
+            capture_dir: Some(std::env::temp_dir()),
+            ..Shared::for_test()
+        };
+        let mut session = Session::new("test".into(), shared, mpsc::channel().0);
+        let response = session.respond(capture("/etc/passwd")).unwrap();
+        assert_eq!(response.error_code(), ErrorCode::InvalidArgument);
+        assert!(session.shared.queue.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn failures_after_the_reply_are_reported_to_the_session() {
+        let shared = Shared::for_test();
+        let (outbox, responses) = mpsc::channel();
+        let mut session = Session::new("test".into(), shared.clone(), outbox);
+        let remove = RootMessage {
+            command: Some(root_message::Command::RemoveStimulus(
+                vsg_messages::RemoveStimulus { id: 3 },
+            )),
+            sequence: 5,
+            ..Default::default()
+        };
+        let response = session.respond(remove).unwrap();
+        assert_eq!(response.status(), vsg_messages::Status::Ok);
+
+        // The dispatcher reports failures to where the command says.
+        let queued = shared.queue.lock().unwrap().pop().unwrap();
+        let (sequence, report) = queued.failure.expect("answered before it was applied");
+        assert_eq!(sequence, 5);
+        report.send(RootResponse::ok(0)).unwrap();
+        assert!(responses.try_recv().is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn oversized_messages_close_the_connection() {
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let shared = Shared::for_test();
+        let server = thread::spawn(move || handle_connection(server, "test".into(), shared));
+
+        client.write_all(&u32::MAX.to_be_bytes()).unwrap();
+        assert_eq!(reply(&mut client).error_code(), ErrorCode::MessageTooLarge);
+        assert!(server.join().unwrap().is_err());
+    }
+}
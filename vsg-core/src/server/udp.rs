@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::net::{SocketAddr, UdpSocket};
+
+use vsg_messages::{root_message, ErrorCode, RootMessage};
+
+use super::Shared;
+use crate::dispatcher::{QueuedCommand, Schedule};
+use crate::messages::{Command, CommandError};
+
+/// Largest payload of a UDP datagram.
+const MAX_DATAGRAM: usize = 65507;
+
+/// Senders whose last sequence is remembered. The one heard from least
+/// recently is forgotten to make room for a new one.
+const MAX_SENDERS: usize = 1024;
+
+/// The last sequence received from each sender, and when it arrived as
+/// counted in datagrams.
+struct Sequences {
+    last: HashMap<SocketAddr, (u64, u64)>,
+    received: u64,
+    capacity: usize,
+}
+
+impl Sequences {
+    fn new(capacity: usize) -> Self {
+        Self {
+            last: HashMap::new(),
+            received: 0,
+            capacity,
+        }
+    }
+
+    /// Whether `sequence` is above the last one from `sender`, remembering
+    /// it if so. Sequence 0 leaves the datagram unordered and always passes.
+    fn is_new(&mut self, sender: SocketAddr, sequence: u64) -> bool {
+        if sequence == 0 {
+            return true;
+        }
+        if let Some(&(last, _)) = self.last.get(&sender) {
+            if sequence <= last {
+                log::debug!(
+                    "Dropped datagram {} from {}, already at {}",
+                    sequence,
+                    sender,
+                    last
+                );
+                return false;
+            }
+        } else if self.last.len() >= self.capacity {
+            let oldest = self
+                .last
+                .iter()
+                .min_by_key(|(_, &(_, seen))| seen)
+                .map(|(&addr, _)| addr);
+            if let Some(oldest) = oldest {
+                self.last.remove(&oldest);
+            }
+        }
+        self.received += 1;
+        self.last.insert(sender, (sequence, self.received));
+        true
+    }
+}
+
+/// Receive one RootMessage per datagram forever and queue its command.
+///
+/// Nothing is sent back, so flip_ack, GetStatus, Hello and events are not
+/// available here, and frames can only be captured to a file in the
+/// capture directory.
+/// A datagram whose sequence is not above the last one from the same sender
+/// arrived late or out of order and is dropped, since a newer command has
+/// already been queued. Datagrams with sequence 0 are never dropped.
+pub fn serve_udp(socket: UdpSocket, shared: Shared) {
+    let mut sequences = Sequences::new(MAX_SENDERS);
+    let mut buffer = vec![0; MAX_DATAGRAM];
+    loop {
+        let (len, sender) = match socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            Err(e) => {
+                log::error!("{}", e);
+                continue;
+            }
+        };
+        if len > shared.max_message_size {
+            log::error!("Dropped datagram of {} bytes from {}", len, sender);
+            continue;
+        }
+        match shared.encoding.decode(&buffer[..len]) {
+            Ok(msg) => handle_datagram(msg, sender, &mut sequences, &shared),
+            Err(e) => log::error!("Datagram from {} : {}", sender, e),
+        }
+    }
+}
+
+fn handle_datagram(
+    msg: RootMessage,
+    sender: SocketAddr,
+    sequences: &mut Sequences,
+    shared: &Shared,
+) {
+    log::trace!("Datagram from {} : {:?}", sender, msg);
+    if !sequences.is_new(sender, msg.sequence) {
+        return;
+    }
+
+    if let Some(
+        root_message::Command::GetStatus(_)
+        | root_message::Command::Hello(_)
+        | root_message::Command::Subscribe(_),
+    ) = msg.command
+    {
+        log::error!("Request from {} needs a connection to reply on", sender);
+        return;
+    }
+    if msg.flip_ack {
+        log::warn!("flip_ack from {} is ignored over UDP", sender);
+    }
+    // The pixels of a capture without a path could not be sent anywhere.
+    let command = shared
+        .command(msg.command)
+        .and_then(|command| match command {
+            Command::Capture { path: None, .. } => Err(CommandError::new(
+                ErrorCode::InvalidArgument,
+                "CaptureFrame over UDP needs a path to write to",
+            )),
+            command => Ok(command),
+        });
+    match command {
+        Ok(command) => {
+            shared.push(QueuedCommand::new(command).scheduled(Schedule::from_proto(msg.schedule)))
+        }
+        Err(e) => log::error!("Datagram from {} : {}", sender, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vsg_messages::BgColor;
+
+    use super::*;
+
+    fn bg_color(sequence: u64, level: f32) -> RootMessage {
+        RootMessage {
+            command: Some(root_message::Command::SetBgColor(BgColor {
+                color: vec![level, level, level, 1.0],
+            })),
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn drops_late_datagrams() {
+        let shared = Shared::for_test();
+        let mut last_sequence = Sequences::new(MAX_SENDERS);
+        let first: SocketAddr = "127.0.0.1:5000".parse().unwrap();
+        let second: SocketAddr = "127.0.0.1:5001".parse().unwrap();
+
+        handle_datagram(bg_color(1, 0.1), first, &mut last_sequence, &shared);
+        handle_datagram(bg_color(3, 0.3), first, &mut last_sequence, &shared);
+        handle_datagram(bg_color(2, 0.2), first, &mut last_sequence, &shared);
+        handle_datagram(bg_color(3, 0.3), first, &mut last_sequence, &shared);
+        // Other senders count on their own.
+        handle_datagram(bg_color(2, 0.2), second, &mut last_sequence, &shared);
+
+        let queued: Vec<_> = shared
+            .queue
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|c| c.command)
+            .collect();
+        assert_eq!(
+            queued,
+            vec![
+                Command::Clear([0.1, 0.1, 0.1, 1.0]),
+                Command::Clear([0.3, 0.3, 0.3, 1.0]),
+                Command::Clear([0.2, 0.2, 0.2, 1.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn sequence_zero_is_unordered() {
+        let shared = Shared::for_test();
+        let mut sequences = Sequences::new(MAX_SENDERS);
+        let sender: SocketAddr = "127.0.0.1:5000".parse().unwrap();
+
+        handle_datagram(bg_color(0, 0.1), sender, &mut sequences, &shared);
+        handle_datagram(bg_color(0, 0.2), sender, &mut sequences, &shared);
+        handle_datagram(bg_color(4, 0.4), sender, &mut sequences, &shared);
+        handle_datagram(bg_color(0, 0.3), sender, &mut sequences, &shared);
+        assert_eq!(shared.queue.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn forgets_the_sender_heard_from_least_recently() {
+        let mut sequences = Sequences::new(2);
+        let senders: Vec<SocketAddr> = (5000..5003)
+            .map(|port| SocketAddr::from(([127, 0, 0, 1], port)))
+            .collect();
+
+        assert!(sequences.is_new(senders[0], 5));
+        assert!(sequences.is_new(senders[1], 5));
+        assert!(sequences.is_new(senders[0], 6));
+        assert!(sequences.is_new(senders[2], 5));
+        assert_eq!(sequences.last.len(), 2);
+        // The second sender was forgotten, the first one was not.
+        assert!(!sequences.is_new(senders[0], 6));
+        assert!(!sequences.last.contains_key(&senders[1]));
+    }
+
+    #[test]
+    fn captures_need_a_path() {
+        let shared = Shared {
+            capture_dir: Some(std::env::temp_dir()),
+            ..Shared::for_test()
+        };
+        let mut sequences = Sequences::new(MAX_SENDERS);
+        let sender: SocketAddr = "127.0.0.1:5000".parse().unwrap();
+        let capture = |path: &str| RootMessage {
+            command: Some(root_message::Command::CaptureFrame(
+                vsg_messages::CaptureFrame {
+                    path: path.into(),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        };
+
+        handle_datagram(capture(""), sender, &mut sequences, &shared);
+        assert!(shared.queue.lock().unwrap().is_empty());
+        handle_datagram(capture("frame.png"), sender, &mut sequences, &shared);
+        let queued = shared.queue.lock().unwrap().pop().unwrap();
+        assert!(matches!(
+            queued.command,
+            Command::Capture { path: Some(_), .. }
+        ));
+    }
+}
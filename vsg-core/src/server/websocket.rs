@@ -0,0 +1,271 @@
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use anyhow::{anyhow, Result};
+use tungstenite::handshake::server::{Callback, ErrorResponse, Request, Response};
+use tungstenite::protocol::{Role, WebSocketConfig};
+use tungstenite::{http, Error, Message, WebSocket};
+
+use vsg_messages::RootResponse;
+
+use super::{accept, encode_response, Encoding, Session, Shared};
+
+/// Accept WebSocket clients forever, serving each connection on its own thread.
+///
+/// Every binary frame carries one protobuf RootMessage and every text frame
+/// one in JSON. Each is answered with a RootResponse in a frame of the same
+/// kind, so browsers can drive the display the same way as TCP clients.
+/// Browsers are only let in from the origins in [`Shared::allowed_origins`],
+/// so that any web page open on this machine cannot.
+pub fn serve_websocket(listener: TcpListener, shared: Shared) {
+    accept(
+        listener.incoming(),
+        shared,
+        |stream| stream.peer_addr().map(|addr| format!("ws://{}", addr)),
+        handle_websocket,
+    );
+}
+
+/// Refuses the handshake of browsers on pages from origins other than
+/// these. Clients other than browsers send no Origin and are let in.
+struct AllowedOrigins<'a>(&'a [String]);
+
+impl Callback for AllowedOrigins<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        let Some(origin) = request.headers().get(http::header::ORIGIN) else {
+            return Ok(response);
+        };
+        let origin = origin.to_str().unwrap_or_default();
+        if self.0.iter().any(|a| a.eq_ignore_ascii_case(origin)) {
+            return Ok(response);
+        }
+        log::warn!("Refused WebSocket client from origin {:?}", origin);
+        Err(http::Response::builder()
+            .status(http::StatusCode::FORBIDDEN)
+            .body(Some(format!("Origin {} is not allowed", origin)))
+            .expect("static response is valid"))
+    }
+}
+
+/// The stream read by the connection thread. Once the handshake is done,
+/// what tungstenite writes from there, like pongs, is dropped: everything is
+/// written through the one writer so that frames do not interleave.
+struct ReadHalf {
+    stream: TcpStream,
+    handshake_done: bool,
+}
+
+impl Read for ReadHalf {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.read(buf)
+    }
+}
+
+impl Write for ReadHalf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.handshake_done {
+            Ok(buf.len())
+        } else {
+            self.stream.write(buf)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+fn handle_websocket(stream: TcpStream, peer: String, shared: Shared) -> Result<()> {
+    stream.set_nodelay(true)?;
+    let config = WebSocketConfig::default()
+        .max_message_size(Some(shared.max_message_size))
+        .max_frame_size(Some(shared.max_message_size));
+    let writer = stream.try_clone()?;
+    let read_half = ReadHalf {
+        stream,
+        handshake_done: false,
+    };
+    let origins = AllowedOrigins(&shared.allowed_origins);
+    let mut socket = tungstenite::accept_hdr_with_config(read_half, origins, Some(config))
+        .map_err(|e| anyhow!("{}: {}", peer, e))?;
+    socket.get_mut().handshake_done = true;
+    let writer = Arc::new(Mutex::new(WebSocket::from_raw_socket(
+        writer,
+        Role::Server,
+        None,
+    )));
+
+    let (outbox, events) = mpsc::channel();
+    let mut session = Session::new(peer, shared, outbox);
+    log::info!("Client {} connected from {}", session.id, session.peer);
+    // Events follow the kind of the last frame the client sent.
+    let encoding = Arc::new(Mutex::new(session.encoding));
+    {
+        let (writer, encoding) = (writer.clone(), encoding.clone());
+        thread::spawn(move || write_events(writer, events, encoding));
+    }
+
+    loop {
+        let response = match socket.read() {
+            Ok(Message::Binary(payload)) => {
+                session.encoding = Encoding::Protobuf;
+                *encoding.lock().unwrap() = session.encoding;
+                session.handle(&payload)?
+            }
+            Ok(Message::Text(payload)) => {
+                session.encoding = Encoding::Json;
+                *encoding.lock().unwrap() = session.encoding;
+                session.handle(payload.as_bytes())?
+            }
+            Ok(Message::Ping(payload)) => {
+                writer.lock().unwrap().send(Message::Pong(payload))?;
+                continue;
+            }
+            Ok(Message::Pong(_) | Message::Frame(_)) => continue,
+            Ok(Message::Close(frame)) => {
+                // Finish the closing handshake, the client may be gone already.
+                let _ = writer.lock().unwrap().close(frame);
+                let _ = writer.lock().unwrap().flush();
+                return Ok(());
+            }
+            Err(Error::ConnectionClosed | Error::AlreadyClosed) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        writer
+            .lock()
+            .unwrap()
+            .send(frame(session.encoding, response)?)?;
+    }
+}
+
+/// Write the events pushed to the session until the session ends.
+fn write_events(
+    writer: Arc<Mutex<WebSocket<TcpStream>>>,
+    events: Receiver<RootResponse>,
+    encoding: Arc<Mutex<Encoding>>,
+) {
+    for event in events {
+        let encoding = *encoding.lock().unwrap();
+        let result =
+            frame(encoding, event).and_then(|message| Ok(writer.lock().unwrap().send(message)?));
+        if let Err(e) = result {
+            log::debug!("{}", e);
+            return;
+        }
+    }
+}
+
+/// Binary frames carry protobuf and text frames JSON.
+fn frame(encoding: Encoding, response: RootResponse) -> Result<Message> {
+    let payload = encode_response(encoding, response);
+    Ok(match encoding {
+        Encoding::Protobuf => Message::binary(payload),
+        Encoding::Json => Message::text(String::from_utf8(payload)?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use prost::Message as _;
+    use tungstenite::client::IntoClientRequest;
+    use vsg_messages::{
+        event, root_message, BgColor, EventKind, GetStatus, InputEvent, RootMessage, Subscribe,
+    };
+
+    use super::*;
+
+    fn serve(shared: Shared) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve_websocket(listener, shared));
+        format!("ws://{}", addr)
+    }
+
+    #[test]
+    fn answers_in_the_frame_kind_of_the_request() {
+        let (mut socket, _) = tungstenite::connect(serve(Shared::for_test())).unwrap();
+        let msg = RootMessage {
+            command: Some(root_message::Command::GetStatus(GetStatus {})),
+            sequence: 4,
+            ..Default::default()
+        };
+        socket.send(Message::binary(msg.encode_to_vec())).unwrap();
+        let Message::Binary(payload) = socket.read().unwrap() else {
+            panic!("expected a binary reply");
+        };
+        let response = RootResponse::decode(&payload[..]).unwrap();
+        assert_eq!(response.sequence, 4);
+        assert!(response.display_status.is_some());
+
+        socket
+            .send(Message::text(r#"{"getStatus": {}, "sequence": 5}"#))
+            .unwrap();
+        let Message::Text(payload) = socket.read().unwrap() else {
+            panic!("expected a text reply");
+        };
+        let response: RootResponse = serde_json::from_str(&payload).unwrap();
+        assert_eq!(response.sequence, 5);
+        assert!(response.display_status.is_some());
+    }
+
+    #[test]
+    fn browsers_from_other_origins_are_refused() {
+        let url = serve(Shared {
+            allowed_origins: vec!["http://localhost:8000".into()],
+            ..Shared::for_test()
+        });
+        let from = |origin: &str| {
+            let mut request = url.as_str().into_client_request().unwrap();
+            request
+                .headers_mut()
+                .insert(http::header::ORIGIN, origin.parse().unwrap());
+            tungstenite::connect(request)
+        };
+        assert!(from("http://localhost:8000").is_ok());
+        assert!(from("https://example.com").is_err());
+        assert!(tungstenite::connect(url.as_str()).is_ok());
+    }
+
+    #[test]
+    fn events_are_sent_while_waiting_for_a_flip() {
+        let shared = Shared::for_test();
+        let (queue, bus) = (shared.queue.clone(), shared.events.clone());
+        let (mut socket, _) = tungstenite::connect(serve(shared)).unwrap();
+        let send = |socket: &mut WebSocket<_>, command, flip_ack| {
+            let msg = RootMessage {
+                command: Some(command),
+                flip_ack,
+                ..Default::default()
+            };
+            socket.send(Message::binary(msg.encode_to_vec())).unwrap();
+        };
+        let subscribe = Subscribe {
+            kinds: vec![EventKind::Input as i32],
+        };
+        send(
+            &mut socket,
+            root_message::Command::Subscribe(subscribe),
+            false,
+        );
+        socket.read().unwrap();
+
+        // Never presented, since no render loop takes it from the queue.
+        let black = BgColor {
+            color: vec![0.0, 0.0, 0.0],
+        };
+        send(&mut socket, root_message::Command::SetBgColor(black), true);
+        while queue.lock().unwrap().is_empty() {
+            thread::yield_now();
+        }
+        bus.publish(1, event::Kind::Input(InputEvent::default()));
+        let Message::Binary(payload) = socket.read().unwrap() else {
+            panic!("expected a binary event");
+        };
+        assert!(RootResponse::decode(&payload[..]).unwrap().event.is_some());
+    }
+}
@@ -32,3 +32,10 @@ var s_diffuse: sampler;
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     return textureSample(t_diffuse, s_diffuse, in.tex_coords);
 }
+
+// Single channel textures are shown as gray levels
+@fragment
+fn fs_gray(in: VertexOutput) -> @location(0) vec4<f32> {
+    let v = textureSample(t_diffuse, s_diffuse, in.tex_coords).r;
+    return vec4<f32>(v, v, v, 1.0);
+}
@@ -0,0 +1,571 @@
+use std::time::{Duration, Instant};
+
+use vsg_messages::{CaptureFormat, DisplayStatus, ErrorCode, RawPixels};
+use winit::dpi::PhysicalSize;
+use winit::window::Window;
+
+use crate::capture;
+use crate::dispatcher::{Applied, CommandTarget};
+use crate::messages::{self, Command, CommandError, Coordinates, Lifetime};
+use crate::renderers::scene::Scene;
+use crate::renderers::*;
+use crate::texture;
+
+/// Where frames are drawn.
+enum Output {
+    Surface(wgpu::Surface),
+    /// A texture that is never shown, for running without a display.
+    Offscreen(wgpu::Texture),
+}
+
+/// Time between frames rendered headless, which have no display to follow:
+/// 60 Hz.
+pub const HEADLESS_FRAME_INTERVAL: Duration = Duration::from_nanos(16_666_667);
+
+/// The renderer: the device, what is drawn and where it is drawn to.
+pub struct State {
+    adapter_info: wgpu::AdapterInfo,
+    output: Output,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    config: wgpu::SurfaceConfiguration,
+    textures: TextureLibrary,
+    size: PhysicalSize<u32>,
+    scene: Scene,
+    bg_color: wgpu::Color,
+    /// Measured time between presented frames.
+    frame_interval_us: f64,
+    last_present: Option<Instant>,
+}
+
+impl State {
+    fn instance() -> wgpu::Instance {
+        // The instance is a handle to our GPU
+        // BackendBit::PRIMARY => Vulkan + Metal + DX12 + Browser WebGPU
+        wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            flags: wgpu::InstanceFlags::default(),
+            dx12_shader_compiler: Default::default(),
+            gles_minor_version: wgpu::Gles3MinorVersion::default(),
+        })
+    }
+
+    async fn request_device(adapter: &wgpu::Adapter) -> (wgpu::Device, wgpu::Queue) {
+        adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: None,
+                    // Needed for R16 raw textures, which are rejected without it.
+                    features: adapter.features() & wgpu::Features::TEXTURE_FORMAT_16BIT_NORM,
+                    limits: wgpu::Limits::downlevel_defaults(),
+                },
+                None, // Trace path
+            )
+            .await
+            .unwrap()
+    }
+
+    /// Draw to a surface on `window`.
+    pub async fn new(window: &Window) -> Self {
+        let instance = Self::instance();
+        let (size, surface) = unsafe {
+            let size = window.inner_size();
+            let surface = instance.create_surface(&window).unwrap();
+
+            (size, surface)
+        };
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::default(),
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
+            .unwrap();
+
+        let (device, queue) = Self::request_device(&adapter).await;
+
+        let surface_caps = surface.get_capabilities(&adapter);
+        let surface_format = surface_caps
+            .formats
+            .iter()
+            .copied()
+            .next()
+            .unwrap_or(surface_caps.formats[0]);
+
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: surface_format,
+            width: size.width,
+            height: size.height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+        };
+        surface.configure(&device, &config);
+
+        // Start from the nominal refresh rate until frames have been measured
+        let refresh_rate_millihertz = window
+            .current_monitor()
+            .and_then(|m| m.refresh_rate_millihertz())
+            .unwrap_or(60_000);
+        let frame_interval = Duration::from_secs_f64(1000.0 / refresh_rate_millihertz as f64);
+
+        Self::with_output(
+            adapter.get_info(),
+            Output::Surface(surface),
+            device,
+            queue,
+            config,
+            frame_interval,
+        )
+    }
+
+    /// Render offscreen at `size`, on the software adapter when there is no
+    /// GPU, so that scripts can be checked on machines without a display.
+    pub async fn headless(size: PhysicalSize<u32>) -> Self {
+        let instance = Self::instance();
+        let mut options = wgpu::RequestAdapterOptions::default();
+        let adapter = match instance.request_adapter(&options).await {
+            Some(adapter) => adapter,
+            None => {
+                log::info!("No GPU adapter found, using the fallback adapter");
+                options.force_fallback_adapter = true;
+                instance
+                    .request_adapter(&options)
+                    .await
+                    .expect("No adapter, not even a fallback one")
+            }
+        };
+        let (device, queue) = Self::request_device(&adapter).await;
+
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            width: size.width,
+            height: size.height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+        };
+        let texture = capture::target(&device, config.format, size.width, size.height);
+
+        Self::with_output(
+            adapter.get_info(),
+            Output::Offscreen(texture),
+            device,
+            queue,
+            config,
+            HEADLESS_FRAME_INTERVAL,
+        )
+    }
+
+    fn with_output(
+        adapter_info: wgpu::AdapterInfo,
+        output: Output,
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        config: wgpu::SurfaceConfiguration,
+        frame_interval: Duration,
+    ) -> Self {
+        Self {
+            adapter_info,
+            output,
+            device,
+            queue,
+            size: PhysicalSize::new(config.width, config.height),
+            config,
+            textures: TextureLibrary::new(),
+            scene: Scene::new(),
+            bg_color: wgpu::Color::BLACK,
+            frame_interval_us: frame_interval.as_secs_f64() * 1e6,
+            last_present: None,
+        }
+    }
+
+    pub fn size(&self) -> PhysicalSize<u32> {
+        self.size
+    }
+
+    pub fn adapter_info(&self) -> &wgpu::AdapterInfo {
+        &self.adapter_info
+    }
+
+    /// Device, queue and texture format to create stimuli for this renderer with.
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.config.format
+    }
+
+    /// Time between presented frames, as measured so far.
+    pub fn frame_interval_us(&self) -> f64 {
+        self.frame_interval_us
+    }
+
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
+    }
+
+    pub fn resize(&mut self, new_size: PhysicalSize<u32>) {
+        if new_size.width > 0 && new_size.height > 0 {
+            self.size = new_size;
+            self.config.width = new_size.width;
+            self.config.height = new_size.height;
+            if let Output::Surface(surface) = &self.output {
+                surface.configure(&self.device, &self.config);
+            }
+        }
+    }
+
+    fn build_stimulus(&self, shape: &vsg_messages::shape::Shape) -> Box<dyn StimulusRenderer> {
+        use vsg_messages::shape::Shape;
+        let color = messages::shape_color(shape).map(messages::rgba);
+        // Shapes are validated when commands are decoded, so ctr is always set.
+        match shape {
+            Shape::Circle(vsg_messages::Circle { radius, ctr, .. }) => {
+                let ctr = ctr.clone().unwrap_or_default();
+                Box::new(Circle::new(
+                    &self.device,
+                    &self.config.format,
+                    ctr.x,
+                    ctr.y,
+                    *radius,
+                    &color.unwrap_or([0.2, 0.0, 0.0, 1.0]),
+                ))
+            }
+            Shape::Square(vsg_messages::Square { size, ctr, .. }) => {
+                let ctr = ctr.clone().unwrap_or_default();
+                Box::new(Rectangle::new(
+                    &self.device,
+                    &self.config.format,
+                    ctr.x,
+                    ctr.y,
+                    *size,
+                    *size,
+                    &color.unwrap_or([0.0, 0.2, 0.0, 1.0]),
+                ))
+            }
+            Shape::Cross(vsg_messages::Cross {
+                size,
+                line_width,
+                ctr,
+                ..
+            }) => {
+                let ctr = ctr.clone().unwrap_or_default();
+                Box::new(Cross::new(
+                    &self.device,
+                    &self.config.format,
+                    &Coordinates { x: ctr.x, y: ctr.y },
+                    &Coordinates { x: *size, y: *size },
+                    *line_width,
+                    &color.unwrap_or([0.0, 0.0, 0.2, 1.0]),
+                ))
+            }
+        }
+    }
+
+    pub fn update_shape(&mut self, shape: &vsg_messages::shape::Shape) {
+        self.scene = Scene::with_shape(self.build_stimulus(shape));
+    }
+
+    /// Number of frames a stimulus with `lifetime` stays on screen.
+    fn lifetime_frames(&self, lifetime: &Lifetime) -> u64 {
+        match *lifetime {
+            Lifetime::DurationFrames(frames) => frames as u64,
+            Lifetime::DurationMs(ms) => {
+                ((ms as f64 * 1000.0 / self.frame_interval_us).round() as u64).max(1)
+            }
+        }
+    }
+
+    /// Fill in the parts of `status` that the renderer knows about.
+    pub fn update_status(&self, status: &mut DisplayStatus) {
+        status.adapter_name = self.adapter_info.name.clone();
+        status.backend = format!("{:?}", self.adapter_info.backend);
+        status.surface_format = format!("{:?}", self.config.format);
+        status.width = self.size.width;
+        status.height = self.size.height;
+        status.refresh_rate_hz = 1e6 / self.frame_interval_us;
+    }
+
+    pub fn update_bg_color(&mut self, bg_color: &[f32; 4]) {
+        self.bg_color = wgpu::Color {
+            r: bg_color[0] as f64,
+            g: bg_color[1] as f64,
+            b: bg_color[2] as f64,
+            a: bg_color[3] as f64,
+        };
+    }
+
+    fn load_texture(&self, idx: u32, pixels: &RawPixels) -> Result<Picture, CommandError> {
+        texture::Texture::from_raw(&self.device, &self.queue, pixels, Some("texture"))
+            .map(|texture| Picture::from_texture(&self.device, &self.config.format, &texture))
+            .map_err(|e| {
+                CommandError::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Cannot load texture {}: {}", idx, e),
+                )
+            })
+    }
+
+    /// Upload the textures that come with pixels and check that the others are
+    /// already in the library, without changing what is displayed.
+    fn stage_textures<'a>(
+        &self,
+        textures: impl IntoIterator<Item = (u32, Option<&'a RawPixels>)>,
+    ) -> Result<Vec<(u32, Option<Picture>)>, CommandError> {
+        let mut staged: Vec<(u32, Option<Picture>)> = Vec::new();
+        for (idx, source) in textures {
+            if let Some(source) = source {
+                staged.push((idx, Some(self.load_texture(idx, source)?)));
+            } else {
+                if !staged.iter().any(|(i, _)| *i == idx) {
+                    self.textures.check(idx)?;
+                }
+                staged.push((idx, None));
+            }
+        }
+        Ok(staged)
+    }
+
+    /// Make the staged textures the only ones shown.
+    fn show_only(&mut self, staged: Vec<(u32, Option<Picture>)>) {
+        self.textures.hide_all();
+        for (idx, picture) in staged {
+            if let Some(picture) = picture {
+                self.textures.insert(idx, picture);
+            }
+            self.textures
+                .show(idx)
+                .expect("staged texture is in the library");
+        }
+    }
+
+    pub fn update_texture(
+        &mut self,
+        idx: u32,
+        source: Option<&RawPixels>,
+    ) -> Result<(), CommandError> {
+        let staged = self.stage_textures([(idx, source)])?;
+        self.show_only(staged);
+        Ok(())
+    }
+
+    /// Read back the background, textures and stimuli as they are drawn on
+    /// the next frame.
+    pub fn capture(
+        &self,
+        format: CaptureFormat,
+        path: Option<&std::path::Path>,
+    ) -> Result<vsg_messages::Capture, CommandError> {
+        let (width, height) = (self.config.width, self.config.height);
+        let target = capture::target(&self.device, self.config.format, width, height);
+        let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        self.queue
+            .submit(std::iter::once(self.draw(&view).finish()));
+        capture::read_rgba(&self.device, &self.queue, &target)
+            .and_then(|rgba| capture::encode(rgba, width, height, format, path))
+            .map_err(|e| {
+                CommandError::new(
+                    ErrorCode::InvalidArgument,
+                    format!("Cannot capture frame: {}", e),
+                )
+            })
+    }
+
+    /// Record drawing the background, textures and stimuli into `view`.
+    fn draw(&self, view: &wgpu::TextureView) -> wgpu::CommandEncoder {
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Render Encoder"),
+            });
+
+        {
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(self.bg_color),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            self.textures.render(&mut rpass);
+            self.scene.render(&mut rpass);
+        }
+        encoder
+    }
+
+    /// Draw a frame and present it, or wait for it to be finished offscreen.
+    pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        match &self.output {
+            Output::Surface(surface) => {
+                let output = surface.get_current_texture()?;
+                let view = output
+                    .texture
+                    .create_view(&wgpu::TextureViewDescriptor::default());
+
+                let encoder = self.draw(&view);
+                self.queue.submit(std::iter::once(encoder.finish()));
+                output.present();
+            }
+            Output::Offscreen(texture) => {
+                let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+                let encoder = self.draw(&view);
+                self.queue.submit(std::iter::once(encoder.finish()));
+                // Finish the frame before the next one, as presenting would.
+                self.device.poll(wgpu::Maintain::Wait);
+            }
+        }
+
+        let now = Instant::now();
+        if let Some(last) = self.last_present {
+            let interval_us = (now - last).as_micros() as f64;
+            // Skipped frames would bias the estimate
+            if interval_us < 1.5 * self.frame_interval_us {
+                self.frame_interval_us += 0.05 * (interval_us - self.frame_interval_us);
+            }
+        }
+        self.last_present = Some(now);
+
+        Ok(())
+    }
+}
+
+impl CommandTarget for State {
+    fn apply(&mut self, command: &Command) -> Result<Applied, CommandError> {
+        let mut applied = Applied::default();
+        match command {
+            Command::Draw(shape) => self.update_shape(shape),
+            Command::Clear(color) => self.update_bg_color(color),
+            Command::Texture(idx, source) => self.update_texture(*idx, source.as_ref())?,
+            Command::PreloadTexture(idx, texture) => {
+                let picture = self.load_texture(*idx, texture)?;
+                self.textures.insert(*idx, picture);
+            }
+            Command::ShowTexture(idx) => self.textures.show(*idx)?,
+            Command::HideTexture(idx) => self.textures.hide(*idx)?,
+            Command::DeleteTexture(idx) => self.textures.remove(*idx)?,
+            Command::AddStimulus(spec) => {
+                let frames = spec.lifetime.as_ref().map(|l| self.lifetime_frames(l));
+                let stimulus = self.build_stimulus(&spec.shape);
+                self.scene
+                    .add_stimulus(spec.id, stimulus, spec.visible, frames)?;
+                if let Some(frames) = frames {
+                    applied.lifetimes.push((spec.id, frames));
+                }
+            }
+            Command::UpdateStimulus(id, shape) => {
+                let stimulus = self.build_stimulus(shape);
+                self.scene.update_stimulus(*id, stimulus)?
+            }
+            Command::SetVisibility(id, visible) => self.scene.set_visible(*id, *visible)?,
+            Command::RemoveStimulus(id) => self.scene.remove_stimulus(*id)?,
+            Command::SetScene {
+                stimuli,
+                textures,
+                bg_color,
+            } => {
+                // Build everything first so that a rejected scene leaves the display untouched.
+                let mut scene = Scene::new();
+                for spec in stimuli {
+                    let frames = spec.lifetime.as_ref().map(|l| self.lifetime_frames(l));
+                    scene.add_stimulus(
+                        spec.id,
+                        self.build_stimulus(&spec.shape),
+                        spec.visible,
+                        frames,
+                    )?;
+                    if let Some(frames) = frames {
+                        applied.lifetimes.push((spec.id, frames));
+                    }
+                }
+                let staged = self.stage_textures(textures.iter().map(|(i, s)| (*i, s.as_ref())))?;
+                self.scene = scene;
+                self.show_only(staged);
+                if let Some(color) = bg_color {
+                    self.update_bg_color(color);
+                }
+            }
+            Command::Capture { format, path } => {
+                applied.capture = Some(self.capture(*format, path.as_deref())?);
+            }
+        }
+        Ok(applied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headless_frames_have_the_requested_size() {
+        // Rows of 50 pixels need padding when read back.
+        let mut state = pollster::block_on(State::headless(PhysicalSize::new(50, 30)));
+        assert_eq!(state.size(), PhysicalSize::new(50, 30));
+        state.render().unwrap();
+        let capture = state.capture(CaptureFormat::Rgba, None).unwrap();
+        assert_eq!((capture.width, capture.height), (50, 30));
+        assert_eq!(capture.data.len(), 50 * 30 * 4);
+    }
+
+    #[test]
+    fn rejected_scenes_leave_the_display_untouched() {
+        let mut state = pollster::block_on(State::headless(PhysicalSize::new(32, 32)));
+        let square = |id, x| messages::StimulusSpec {
+            id,
+            shape: vsg_messages::shape::Shape::Square(vsg_messages::Square {
+                size: 0.5,
+                ctr: Some(vsg_messages::Coordinates { x, y: 0.0 }),
+                color: None,
+            }),
+            visible: true,
+            lifetime: None,
+        };
+        let set_scene = |stimuli, textures| Command::SetScene {
+            stimuli,
+            textures,
+            bg_color: Some([1.0, 1.0, 1.0, 1.0]),
+        };
+        let frame = |state: &State| state.capture(CaptureFormat::Rgba, None).unwrap().data;
+
+        state
+            .apply(&Command::SetScene {
+                stimuli: vec![square(1, -0.5)],
+                textures: vec![],
+                bg_color: Some([0.0, 0.0, 0.5, 1.0]),
+            })
+            .unwrap();
+        let before = frame(&state);
+
+        // The same id twice, and a texture that was never stored.
+        let duplicate = set_scene(vec![square(2, 0.5), square(2, 0.0)], vec![]);
+        let err = state.apply(&duplicate).unwrap_err();
+        assert_eq!(err.code, ErrorCode::AlreadyExists);
+        let unknown = set_scene(vec![square(3, 0.5)], vec![(7, None)]);
+        let err = state.apply(&unknown).unwrap_err();
+        assert_eq!(err.code, ErrorCode::TextureNotFound);
+
+        assert_eq!(frame(&state), before);
+        state.scene_mut().set_visible(1, false).unwrap();
+        assert!(state.scene_mut().set_visible(3, false).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::{Arc, Mutex};
+
+use vsg_messages::{DisplayStatus, Monitor};
+use winit::monitor::MonitorHandle;
+
+/// Latest display status, kept up to date by the render loop so that the
+/// network threads can answer status queries without waiting for a frame.
+pub type StatusBoard = Arc<Mutex<DisplayStatus>>;
+
+pub fn new_board() -> StatusBoard {
+    Arc::new(Mutex::new(DisplayStatus::default()))
+}
+
+/// Describe `monitors`, marking the one the window is on.
+pub fn monitors(
+    monitors: impl Iterator<Item = MonitorHandle>,
+    current: Option<&MonitorHandle>,
+) -> Vec<Monitor> {
+    monitors
+        .map(|monitor| {
+            let size = monitor.size();
+            let position = monitor.position();
+            Monitor {
+                name: monitor.name().unwrap_or_default(),
+                width: size.width,
+                height: size.height,
+                x: position.x,
+                y: position.y,
+                scale_factor: monitor.scale_factor(),
+                refresh_rate_millihertz: monitor.refresh_rate_millihertz().unwrap_or(0),
+                current: current == Some(&monitor),
+            }
+        })
+        .collect()
+}
@@ -1,11 +1,97 @@
 use anyhow::*;
 use image_rs::{DynamicImage, GenericImageView};
+use vsg_messages::{PixelFormat, RawPixels};
+
 pub struct Texture {
     pub texture: wgpu::Texture,
     pub view: wgpu::TextureView,
     pub sampler: wgpu::Sampler,
 }
 
+/// Fail for images larger than `max` in either direction, the limit of the
+/// device, instead of letting wgpu panic.
+fn check_size(width: u32, height: u32, max: u32) -> Result<()> {
+    ensure!(width > 0 && height > 0, "Empty image: {}x{}", width, height);
+    ensure!(
+        width <= max && height <= max,
+        "{}x{} exceeds the largest texture of {}x{}",
+        width,
+        height,
+        max,
+        max
+    );
+    Ok(())
+}
+
+/// The texture format pixels are uploaded as, and its bytes per pixel.
+fn formats(format: PixelFormat) -> (wgpu::TextureFormat, u64) {
+    match format {
+        PixelFormat::Rgba8 => (wgpu::TextureFormat::Rgba8UnormSrgb, 4),
+        PixelFormat::R8 => (wgpu::TextureFormat::R8Unorm, 1),
+        PixelFormat::R16 => (wgpu::TextureFormat::R16Unorm, 2),
+        PixelFormat::Rgba32f => (wgpu::TextureFormat::Rgba32Float, 16),
+    }
+}
+
+/// Fail for formats that need device features missing from `features`.
+fn check_features(format: PixelFormat, features: wgpu::Features) -> Result<()> {
+    let (texture_format, _) = formats(format);
+    ensure!(
+        features.contains(texture_format.required_features()),
+        "{:?} is not supported by this adapter",
+        format
+    );
+    Ok(())
+}
+
+/// Check that `raw` holds as many rows as it claims and return the stride
+/// in bytes. Needs no device, so it can be done as the pixels arrive.
+pub fn check_layout(raw: &RawPixels) -> Result<u32> {
+    ensure!(
+        raw.width > 0 && raw.height > 0,
+        "Empty image: {}x{}",
+        raw.width,
+        raw.height
+    );
+    let (_, bytes_per_pixel) = formats(raw.format());
+    let row_bytes = raw.width as u64 * bytes_per_pixel;
+    let stride = match raw.stride {
+        0 => row_bytes,
+        stride => stride as u64,
+    };
+    ensure!(
+        stride >= row_bytes,
+        "Stride {} is shorter than a row of {} bytes",
+        stride,
+        row_bytes
+    );
+    let stride = u32::try_from(stride).context("Rows of more than 4 GiB")?;
+    let expected = stride as u64 * (raw.height as u64 - 1) + row_bytes;
+    ensure!(
+        raw.data.len() as u64 >= expected,
+        "{}x{} {:?} with stride {} needs {} bytes, got {}",
+        raw.width,
+        raw.height,
+        raw.format(),
+        stride,
+        expected,
+        raw.data.len()
+    );
+    Ok(stride)
+}
+
+/// Decode an image file into tightly packed RGBA8 pixels.
+pub fn decode(bytes: &[u8]) -> Result<RawPixels> {
+    let rgba = image_rs::load_from_memory(bytes)?.to_rgba8();
+    Ok(RawPixels {
+        width: rgba.width(),
+        height: rgba.height(),
+        format: PixelFormat::Rgba8.into(),
+        stride: 0,
+        data: rgba.into_raw(),
+    })
+}
+
 impl Texture {
     pub fn from_bytes(
         device: &wgpu::Device,
@@ -23,9 +109,54 @@ impl Texture {
         img: &DynamicImage,
         label: Option<&str>,
     ) -> Result<Self> {
-        let rgba = img.to_rgba8();
         let (width, height) = img.dimensions();
+        check_size(width, height, device.limits().max_texture_dimension_2d)?;
+        let rgba = img.to_rgba8();
+
+        Ok(Self::from_pixels(
+            device,
+            queue,
+            wgpu::TextureFormat::Rgba8UnormSrgb,
+            width,
+            height,
+            4 * width,
+            &rgba,
+            label,
+        ))
+    }
+
+    /// Upload pixels generated by the client or decoded with [`decode`].
+    pub fn from_raw(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        raw: &RawPixels,
+        label: Option<&str>,
+    ) -> Result<Self> {
+        check_features(raw.format(), device.features())?;
+        check_size(
+            raw.width,
+            raw.height,
+            device.limits().max_texture_dimension_2d,
+        )?;
+        let stride = check_layout(raw)?;
+        let (format, _) = formats(raw.format());
+
+        Ok(Self::from_pixels(
+            device, queue, format, raw.width, raw.height, stride, &raw.data, label,
+        ))
+    }
 
+    #[allow(clippy::too_many_arguments)]
+    fn from_pixels(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+        bytes_per_row: u32,
+        data: &[u8],
+        label: Option<&str>,
+    ) -> Self {
         let size = wgpu::Extent3d {
             width,
             height,
@@ -37,37 +168,113 @@ impl Texture {
             mip_level_count: 1,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            format,
             usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
             view_formats: &[],
         });
 
         queue.write_texture(
             texture.as_image_copy(),
-            &rgba,
+            data,
             wgpu::ImageDataLayout {
                 offset: 0,
-                bytes_per_row: Some(4 * width),
+                bytes_per_row: Some(bytes_per_row),
                 rows_per_image: Some(height),
             },
             size,
         );
 
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        // Float textures cannot be filtered without an extra device feature.
+        let mag_filter = if Self::format_is_filterable(format) {
+            wgpu::FilterMode::Linear
+        } else {
+            wgpu::FilterMode::Nearest
+        };
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
             address_mode_v: wgpu::AddressMode::ClampToEdge,
             address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
+            mag_filter,
             min_filter: wgpu::FilterMode::Nearest,
             mipmap_filter: wgpu::FilterMode::Nearest,
             ..Default::default()
         });
 
-        Ok(Self {
+        Self {
             texture,
             view,
             sampler,
-        })
+        }
+    }
+
+    fn format_is_filterable(format: wgpu::TextureFormat) -> bool {
+        format.sample_type(None) == Some(wgpu::TextureSampleType::Float { filterable: true })
+    }
+
+    /// Whether the texture may be bound with a filtering sampler.
+    pub fn is_filterable(&self) -> bool {
+        Self::format_is_filterable(self.texture.format())
+    }
+
+    /// Whether the texture only has a red channel, to be shown as gray levels.
+    pub fn is_single_channel(&self) -> bool {
+        self.texture.format().components() == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(width: u32, height: u32, format: PixelFormat, stride: u32, len: usize) -> RawPixels {
+        RawPixels {
+            width,
+            height,
+            format: format.into(),
+            stride,
+            data: vec![0; len],
+        }
+    }
+
+    #[test]
+    fn layout_must_fit_the_data() {
+        // Rows of 3 R16 pixels padded to 8 bytes, without padding after the last.
+        assert_eq!(
+            check_layout(&raw(3, 2, PixelFormat::R16, 8, 14)).unwrap(),
+            8
+        );
+        assert_eq!(
+            check_layout(&raw(2, 2, PixelFormat::Rgba8, 0, 16)).unwrap(),
+            8
+        );
+
+        for (name, pixels) in [
+            ("short buffer", raw(2, 2, PixelFormat::Rgba8, 0, 15)),
+            ("short padded buffer", raw(3, 2, PixelFormat::R16, 8, 13)),
+            ("stride below a row", raw(3, 2, PixelFormat::R16, 5, 16)),
+            ("zero width", raw(0, 2, PixelFormat::R8, 0, 4)),
+            ("zero height", raw(2, 0, PixelFormat::R8, 0, 4)),
+        ] {
+            assert!(check_layout(&pixels).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn size_is_limited_by_the_device() {
+        assert!(check_size(2048, 16, 2048).is_ok());
+        assert!(check_size(2049, 16, 2048).is_err());
+        assert!(check_size(16, 2049, 2048).is_err());
+        assert!(check_size(0, 16, 2048).is_err());
+    }
+
+    #[test]
+    fn r16_needs_its_feature() {
+        let norm16 = wgpu::Features::TEXTURE_FORMAT_16BIT_NORM;
+        assert!(check_features(PixelFormat::R16, wgpu::Features::empty()).is_err());
+        assert!(check_features(PixelFormat::R16, norm16).is_ok());
+        for format in [PixelFormat::Rgba8, PixelFormat::R8, PixelFormat::Rgba32f] {
+            assert!(check_features(format, wgpu::Features::empty()).is_ok());
+        }
     }
 }
@@ -0,0 +1,230 @@
+//! Renders each stimulus offscreen on the fallback adapter and compares the
+//! pixels with reference images in `tests/golden`.
+//!
+//! Run with `VSG_BLESS=1` to write the references from the current output
+//! after checking that the new images are right. Without a fallback adapter
+//! the tests fail, unless `VSG_SKIP_GOLDEN` is set to skip them.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use image_rs::RgbaImage;
+
+use vsg_core::capture;
+use vsg_core::messages::vsg_messages::{PixelFormat, RawPixels};
+use vsg_core::messages::Coordinates;
+use vsg_core::renderers::{Circle, Cross, Picture, Rectangle};
+use vsg_core::{Scene, StimulusRenderer, Texture};
+
+const SIZE: u32 = 64;
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+/// Largest difference in any channel for pixels to count as equal.
+const TOLERANCE: u8 = 16;
+/// Pixels along edges may be rasterized differently by other drivers.
+const MAX_MISMATCHED: usize = (SIZE * SIZE / 100) as usize;
+
+struct Gpu {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
+
+/// The device shared by all golden tests, or None without a fallback adapter.
+fn gpu() -> Option<&'static Gpu> {
+    static GPU: OnceLock<Option<Gpu>> = OnceLock::new();
+    GPU.get_or_init(|| {
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            force_fallback_adapter: true,
+            ..Default::default()
+        }))?;
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                features: wgpu::Features::empty(),
+                limits: wgpu::Limits::downlevel_defaults(),
+            },
+            None,
+        ))
+        .ok()?;
+        Some(Gpu { device, queue })
+    })
+    .as_ref()
+}
+
+fn render(gpu: &Gpu, stimulus: &dyn StimulusRenderer) -> RgbaImage {
+    let target = capture::target(&gpu.device, FORMAT, SIZE, SIZE);
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut encoder = gpu
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    {
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        stimulus.render(&mut rpass);
+    }
+    gpu.queue.submit(std::iter::once(encoder.finish()));
+    let rgba = capture::read_rgba(&gpu.device, &gpu.queue, &target).unwrap();
+    RgbaImage::from_raw(SIZE, SIZE, rgba).unwrap()
+}
+
+/// Render the stimulus made by `build` and compare it with `tests/golden/<name>.png`.
+fn check(name: &str, build: impl FnOnce(&Gpu) -> Box<dyn StimulusRenderer>) {
+    let Some(gpu) = gpu() else {
+        if std::env::var_os("VSG_SKIP_GOLDEN").is_some() {
+            eprintln!("Skipping {}: no fallback adapter", name);
+            return;
+        }
+        panic!(
+            "No fallback adapter to render {}, set VSG_SKIP_GOLDEN to skip",
+            name
+        );
+    };
+    let actual = render(gpu, build(gpu).as_ref());
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "golden", name]
+        .iter()
+        .collect::<PathBuf>()
+        .with_extension("png");
+    if std::env::var_os("VSG_BLESS").is_some() {
+        actual.save(&path).unwrap();
+        return;
+    }
+    let expected = image_rs::open(&path)
+        .unwrap_or_else(|e| panic!("Cannot open {}: {}", path.display(), e))
+        .to_rgba8();
+    let mismatched = expected
+        .pixels()
+        .zip(actual.pixels())
+        .filter(|(e, a)| e.0.iter().zip(a.0).any(|(e, a)| e.abs_diff(a) > TOLERANCE))
+        .count();
+    if mismatched > MAX_MISMATCHED {
+        let actual_path = std::env::temp_dir().join(format!("{}.actual.png", name));
+        actual.save(&actual_path).unwrap();
+        panic!(
+            "{} differs from {} in {} pixels, see {}",
+            name,
+            path.display(),
+            mismatched,
+            actual_path.display()
+        );
+    }
+}
+
+#[test]
+fn circle() {
+    check("circle", |gpu| {
+        Box::new(Circle::new(
+            &gpu.device,
+            &FORMAT,
+            0.25,
+            -0.25,
+            0.5,
+            &[1.0, 0.5, 0.0, 1.0],
+        ))
+    });
+}
+
+#[test]
+fn rectangle() {
+    check("rectangle", |gpu| {
+        Box::new(Rectangle::new(
+            &gpu.device,
+            &FORMAT,
+            -0.25,
+            0.25,
+            1.0,
+            0.5,
+            &[0.0, 0.5, 1.0, 1.0],
+        ))
+    });
+}
+
+#[test]
+fn cross() {
+    check("cross", |gpu| {
+        Box::new(Cross::new(
+            &gpu.device,
+            &FORMAT,
+            &Coordinates { x: 0.0, y: 0.0 },
+            &Coordinates { x: 1.5, y: 1.0 },
+            0.25,
+            &[1.0, 1.0, 1.0, 1.0],
+        ))
+    });
+}
+
+#[test]
+fn picture() {
+    check("picture", |gpu| {
+        // Quadrants in distinct colors show both orientation and color.
+        let image = RgbaImage::from_fn(2, 2, |x, y| match (x, y) {
+            (0, 0) => image_rs::Rgba([255, 0, 0, 255]),
+            (1, 0) => image_rs::Rgba([0, 255, 0, 255]),
+            (0, 1) => image_rs::Rgba([0, 0, 255, 255]),
+            _ => image_rs::Rgba([255, 255, 255, 255]),
+        });
+        let mut png = Vec::new();
+        image
+            .write_to(
+                &mut std::io::Cursor::new(&mut png),
+                image_rs::ImageOutputFormat::Png,
+            )
+            .unwrap();
+        Box::new(Picture::new(&gpu.device, &gpu.queue, &FORMAT, &png).unwrap())
+    });
+}
+
+#[test]
+fn raw() {
+    check("raw", |gpu| {
+        // Gray levels from black to white, each row padded with bytes that
+        // must not show up.
+        let raw = RawPixels {
+            width: 2,
+            height: 2,
+            format: PixelFormat::R8.into(),
+            stride: 4,
+            data: vec![0, 255, 99, 99, 128, 64, 99, 99],
+        };
+        let texture = Texture::from_raw(&gpu.device, &gpu.queue, &raw, None).unwrap();
+        Box::new(Picture::from_texture(&gpu.device, &FORMAT, &texture))
+    });
+}
+
+#[test]
+fn scene() {
+    check("scene", |gpu| {
+        let mut scene = Scene::new();
+        let rectangle = Rectangle::new(
+            &gpu.device,
+            &FORMAT,
+            0.0,
+            0.0,
+            1.0,
+            1.0,
+            &[0.0, 0.0, 1.0, 1.0],
+        );
+        // Drawn over the rectangle, since ids are drawn in ascending order.
+        let circle = Circle::new(&gpu.device, &FORMAT, 0.0, 0.0, 0.4, &[1.0, 0.0, 0.0, 1.0]);
+        let hidden = Circle::new(&gpu.device, &FORMAT, -0.5, 0.5, 0.3, &[0.0, 1.0, 0.0, 1.0]);
+        scene
+            .add_stimulus(1, Box::new(rectangle), true, None)
+            .unwrap();
+        scene.add_stimulus(2, Box::new(circle), true, None).unwrap();
+        scene
+            .add_stimulus(3, Box::new(hidden), false, None)
+            .unwrap();
+        Box::new(scene)
+    });
+}
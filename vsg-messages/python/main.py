@@ -5,9 +5,19 @@ import message_pb2
 import shapes_pb2
 
 addr = ("localhost", 7878)
+
+
+def recv_response(s):
+    n = struct.unpack(">I", s.recv(4))[0]
+    buf = b""
+    while len(buf) < n:
+        buf += s.recv(n - len(buf))
+    return message_pb2.RootResponse.FromString(buf)
+
+
 # %%
 bg_color = message_pb2.BgColor(color=[0.0, 0.0, 0.2, 1.0])
-message = message_pb2.RootMessage(set_bg_color=bg_color)
+message = message_pb2.RootMessage(set_bg_color=bg_color, sequence=1)
 buf = message.SerializeToString()
 n = struct.pack(">i", len(buf))
 
@@ -15,13 +25,14 @@ with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
     s.connect(addr)
     s.sendall(n)
     s.sendall(buf)
-    ret = s.recv(1024)
+    ret = recv_response(s)
     print(ret)
 # %%
 ctr = shapes_pb2.Coordinates(x=0.1,y =-0.5)
-square = shapes_pb2.Square(size=0.2, ctr=ctr)
+color = shapes_pb2.Color(r=1.0, g=1.0, b=1.0)
+square = shapes_pb2.Square(size=0.2, ctr=ctr, color=color)
 shape = shapes_pb2.Shape(square=square)
-message = message_pb2.RootMessage(set_shape=shape)
+message = message_pb2.RootMessage(set_shape=shape, sequence=2)
 buf = message.SerializeToString()
 n = struct.pack(">i", len(buf))
 
@@ -29,14 +40,14 @@ with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
     s.connect(addr)
     s.sendall(n)
     s.sendall(buf)
-    ret = s.recv(1024)
+    ret = recv_response(s)
     print(ret)
 # %%
 ctr = shapes_pb2.Coordinates(x=0.1,y =-0.5)
 with open("macaque.jpg", "rb") as f:
     data = f.read()
 tex = message_pb2.Texture(index=0, data=data)
-message = message_pb2.RootMessage(set_texture=tex)
+message = message_pb2.RootMessage(set_texture=tex, sequence=3)
 buf = message.SerializeToString()
 n = struct.pack(">i", len(buf))
 
@@ -45,6 +56,157 @@ with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
     s.connect(addr)
     s.sendall(n)
     s.sendall(buf)
-    ret = s.recv(1024)
+    ret = recv_response(s)
     print(ret)
 # %%
+# flip_ack: the reply arrives once the frame showing the circle has been presented
+ctr = shapes_pb2.Coordinates(x=0.0, y=0.0)
+circle = shapes_pb2.Circle(radius=0.1, ctr=ctr)
+shape = shapes_pb2.Shape(circle=circle)
+message = message_pb2.RootMessage(set_shape=shape, sequence=4, flip_ack=True)
+buf = message.SerializeToString()
+n = struct.pack(">i", len(buf))
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(n)
+    s.sendall(buf)
+    ret = recv_response(s)
+    print(ret.onset.frame, ret.onset.timestamp_us)
+# %%
+# preload a texture now and show it later
+with open("macaque.jpg", "rb") as f:
+    data = f.read()
+messages = [
+    message_pb2.RootMessage(preload_texture=message_pb2.Texture(index=1, data=data), sequence=5),
+    message_pb2.RootMessage(show_texture=message_pb2.TextureIndex(index=1), sequence=6),
+]
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    for message in messages:
+        buf = message.SerializeToString()
+        s.sendall(struct.pack(">i", len(buf)))
+        s.sendall(buf)
+        print(recv_response(s))
+# %%
+# raw 8 bit noise, uploaded without image decoding
+import os
+
+raw = message_pb2.RawPixels(
+    width=256, height=256, format=message_pb2.PIXEL_FORMAT_R8, data=os.urandom(256 * 256)
+)
+message = message_pb2.RootMessage(set_texture=message_pb2.Texture(index=2, raw=raw), sequence=7)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    print(recv_response(s))
+# %%
+# flash a circle for 6 frames and get the frame it disappears on
+circle = shapes_pb2.Circle(radius=0.1, ctr=shapes_pb2.Coordinates(x=0.0, y=0.0))
+stimulus = shapes_pb2.Stimulus(id=3, shape=shapes_pb2.Shape(circle=circle), duration_frames=6)
+message = message_pb2.RootMessage(add_stimulus=stimulus, flip_ack=True, sequence=8)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    ret = recv_response(s)
+    print(ret.onset.frame, [(o.id, o.frame) for o in ret.onset.offsets])
+# %%
+# ask what the server is drawing to
+message = message_pb2.RootMessage(get_status=message_pb2.GetStatus(), sequence=9)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    print(recv_response(s).display_status)
+# %%
+# stream positions over UDP (server started with --udp-port 7879), no replies
+import math
+import time
+
+with socket.socket(socket.AF_INET, socket.SOCK_DGRAM) as s:
+    for i in range(600):
+        ctr = shapes_pb2.Coordinates(x=0.5 * math.cos(i / 30), y=0.5 * math.sin(i / 30))
+        shape = shapes_pb2.Shape(circle=shapes_pb2.Circle(radius=0.05, ctr=ctr))
+        message = message_pb2.RootMessage(set_shape=shape, sequence=i + 1)
+        s.sendto(message.SerializeToString(), (addr[0], 7879))
+        time.sleep(1 / 60)
+# %%
+# introduce the client and check the server speaks the same protocol
+hello = message_pb2.Hello(
+    protocol_version=message_pb2.PROTOCOL_VERSION_CURRENT, features=["flip_ack"], agent="main.py"
+)
+message = message_pb2.RootMessage(hello=hello, sequence=10)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    print(recv_response(s))
+# %%
+# print key presses and clicks with their latency from the last change on screen
+subscribe = message_pb2.Subscribe(kinds=[message_pb2.EVENT_KIND_INPUT])
+message = message_pb2.RootMessage(subscribe=subscribe, sequence=11)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    recv_response(s)
+    for _ in range(10):
+        event = recv_response(s).event
+        print(event.timestamp_us, event.input)
+# %%
+# watch what happens on the display: onsets, offsets, dropped frames and resizes
+kinds = [
+    message_pb2.EVENT_KIND_FRAME_DROP,
+    message_pb2.EVENT_KIND_ONSET,
+    message_pb2.EVENT_KIND_OFFSET,
+    message_pb2.EVENT_KIND_SURFACE_LOST,
+    message_pb2.EVENT_KIND_RESIZE,
+]
+message = message_pb2.RootMessage(subscribe=message_pb2.Subscribe(kinds=kinds), sequence=12)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    recv_response(s)
+    for _ in range(20):
+        event = recv_response(s).event
+        print(event.timestamp_us, event.WhichOneof("kind"), getattr(event, event.WhichOneof("kind")))
+# %%
+# archive what is on screen, as PNG in the reply or written by the server
+message = message_pb2.RootMessage(capture_frame=message_pb2.CaptureFrame(), sequence=13)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    ret = recv_response(s)
+    with open(f"frame_{ret.onset.frame}.png", "wb") as f:
+        f.write(ret.capture.data)
+
+# saving on the server needs vsg-core --capture-dir, the path is relative to it
+capture = message_pb2.CaptureFrame(path="frame.png")
+message = message_pb2.RootMessage(capture_frame=capture, sequence=14)
+buf = message.SerializeToString()
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.connect(addr)
+    s.sendall(struct.pack(">i", len(buf)))
+    s.sendall(buf)
+    print(recv_response(s).capture.path)
+# %%
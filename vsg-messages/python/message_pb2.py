@@ -2,6 +2,7 @@
 # Generated by the protocol buffer compiler.  DO NOT EDIT!
 # source: message.proto
 """Generated protocol buffer code."""
+from google.protobuf.internal import enum_type_wrapper
 from google.protobuf.internal import builder as _builder
 from google.protobuf import descriptor as _descriptor
 from google.protobuf import descriptor_pool as _descriptor_pool
@@ -14,7 +15,7 @@ _sym_db = _symbol_database.Default()
 import shapes_pb2 as shapes__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rmessage.proto\x12\x03vsg\x1a\x0cshapes.proto\"\x84\x01\n\x0bRootMessage\x12\x1f\n\tset_shape\x18\x01 \x01(\x0b\x32\n.vsg.ShapeH\x00\x12$\n\x0cset_bg_color\x18\x02 \x01(\x0b\x32\x0c.vsg.BgColorH\x00\x12#\n\x0bset_texture\x18\x03 \x01(\x0b\x32\x0c.vsg.TextureH\x00\x42\t\n\x07\x63ommand\"\x18\n\x07\x42gColor\x12\r\n\x05\x63olor\x18\x01 \x03(\x02\"&\n\x07Texture\x12\r\n\x05index\x18\x01 \x01(\r\x12\x0c\n\x04\x64\x61ta\x18\x02 \x01(\x0c\x42\x0c\n\x03vsgB\x03VsgP\x01\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rmessage.proto\x12\x03vsg\x1a\x0cshapes.proto\"\xe2\x05\n\x0bRootMessage\x12\x1f\n\tset_shape\x18\x01 \x01(\x0b\x32\n.vsg.ShapeH\x00\x12$\n\x0cset_bg_color\x18\x02 \x01(\x0b\x32\x0c.vsg.BgColorH\x00\x12#\n\x0bset_texture\x18\x03 \x01(\x0b\x32\x0c.vsg.TextureH\x00\x12%\n\x0c\x61\x64\x64_stimulus\x18\x04 \x01(\x0b\x32\r.vsg.StimulusH\x00\x12.\n\x0fupdate_stimulus\x18\x05 \x01(\x0b\x32\x13.vsg.UpdateStimulusH\x00\x12,\n\x0eset_visibility\x18\x06 \x01(\x0b\x32\x12.vsg.SetVisibilityH\x00\x12.\n\x0fremove_stimulus\x18\x07 \x01(\x0b\x32\x13.vsg.RemoveStimulusH\x00\x12\"\n\tset_scene\x18\x08 \x01(\x0b\x32\r.vsg.SetSceneH\x00\x12\'\n\x0fpreload_texture\x18\t \x01(\x0b\x32\x0c.vsg.TextureH\x00\x12)\n\x0cshow_texture\x18\n \x01(\x0b\x32\x11.vsg.TextureIndexH\x00\x12)\n\x0chide_texture\x18\x0b \x01(\x0b\x32\x11.vsg.TextureIndexH\x00\x12+\n\x0e\x64\x65lete_texture\x18\x0c \x01(\x0b\x32\x11.vsg.TextureIndexH\x00\x12$\n\nget_status\x18\r \x01(\x0b\x32\x0e.vsg.GetStatusH\x00\x12\x1b\n\x05hello\x18\x0e \x01(\x0b\x32\n.vsg.HelloH\x00\x12#\n\tsubscribe\x18\x0f \x01(\x0b\x32\x0e.vsg.SubscribeH\x00\x12*\n\rcapture_frame\x18\x10 \x01(\x0b\x32\x11.vsg.CaptureFrameH\x00\x12\x10\n\x08sequence\x18  \x01(\x04\x12\x10\n\x08\x66lip_ack\x18! \x01(\x08\x12\x1f\n\x08schedule\x18\" \x01(\x0b\x32\r.vsg.ScheduleB\t\n\x07\x63ommand\"<\n\x08Schedule\x12\x0f\n\x05\x66rame\x18\x01 \x01(\x04H\x00\x12\x15\n\x0b\x64\x65\x61\x64line_us\x18\x02 \x01(\x04H\x00\x42\x08\n\x06target\"\xa4\x02\n\x0cRootResponse\x12\x1b\n\x06status\x18\x01 \x01(\x0e\x32\x0b.vsg.Status\x12\"\n\nerror_code\x18\x02 \x01(\x0e\x32\x0e.vsg.ErrorCode\x12\r\n\x05\x65rror\x18\x03 \x01(\t\x12\x10\n\x08sequence\x18\x04 \x01(\x04\x12\x19\n\x05onset\x18\x05 \x01(\x0b\x32\n.vsg.Onset\x12\x16\n\x0eserver_time_us\x18\x06 \x01(\x04\x12*\n\x0e\x64isplay_status\x18\x07 \x01(\x0b\x32\x12.vsg.DisplayStatus\x12\x19\n\x05hello\x18\x08 \x01(\x0b\x32\n.vsg.Hello\x12\x19\n\x05\x65vent\x18\t \x01(\x0b\x32\n.vsg.Event\x12\x1d\n\x07\x63\x61pture\x18\n \x01(\x0b\x32\x0c.vsg.Capture\"R\n\x05Onset\x12\r\n\x05\x66rame\x18\x01 \x01(\x04\x12\x14\n\x0ctimestamp_us\x18\x02 \x01(\x04\x12$\n\x07offsets\x18\x03 \x03(\x0b\x32\x13.vsg.StimulusOffset\"+\n\x0eStimulusOffset\x12\n\n\x02id\x18\x01 \x01(\r\x12\r\n\x05\x66rame\x18\x02 \x01(\x04\"\x18\n\x07\x42gColor\x12\r\n\x05\x63olor\x18\x01 \x03(\x02\"7\n\x0eUpdateStimulus\x12\n\n\x02id\x18\x01 \x01(\r\x12\x19\n\x05shape\x18\x02 \x01(\x0b\x32\n.vsg.Shape\",\n\rSetVisibility\x12\n\n\x02id\x18\x01 \x01(\r\x12\x0f\n\x07visible\x18\x02 \x01(\x08\"\x1c\n\x0eRemoveStimulus\x12\n\n\x02id\x18\x01 \x01(\r\"Q\n\x07Texture\x12\r\n\x05index\x18\x01 \x01(\r\x12\x0e\n\x04\x64\x61ta\x18\x02 \x01(\x0cH\x00\x12\x1d\n\x03raw\x18\x03 \x01(\x0b\x32\x0e.vsg.RawPixelsH\x00\x42\x08\n\x06source\"j\n\tRawPixels\x12\r\n\x05width\x18\x01 \x01(\r\x12\x0e\n\x06height\x18\x02 \x01(\r\x12 \n\x06\x66ormat\x18\x03 \x01(\x0e\x32\x10.vsg.PixelFormat\x12\x0e\n\x06stride\x18\x04 \x01(\r\x12\x0c\n\x04\x64\x61ta\x18\x05 \x01(\x0c\"\x1d\n\x0cTextureIndex\x12\r\n\x05index\x18\x01 \x01(\r\"j\n\x08SetScene\x12\x1e\n\x07stimuli\x18\x01 \x03(\x0b\x32\r.vsg.Stimulus\x12\x1e\n\x08textures\x18\x02 \x03(\x0b\x32\x0c.vsg.Texture\x12\x1e\n\x08\x62g_color\x18\x03 \x01(\x0b\x32\x0c.vsg.BgColor\"@\n\x0c\x43\x61ptureFrame\x12\"\n\x06\x66ormat\x18\x01 \x01(\x0e\x32\x12.vsg.CaptureFormat\x12\x0c\n\x04path\x18\x02 \x01(\t\"h\n\x07\x43\x61pture\x12\r\n\x05width\x18\x01 \x01(\r\x12\x0e\n\x06height\x18\x02 \x01(\r\x12\"\n\x06\x66ormat\x18\x03 \x01(\x0e\x32\x12.vsg.CaptureFormat\x12\x0c\n\x04\x64\x61ta\x18\x04 \x01(\x0c\x12\x0c\n\x04path\x18\x05 \x01(\t\"\x0b\n\tGetStatus\"\xee\x01\n\rDisplayStatus\x12\x14\n\x0c\x61\x64\x61pter_name\x18\x01 \x01(\t\x12\x0f\n\x07\x62\x61\x63kend\x18\x02 \x01(\t\x12\x16\n\x0esurface_format\x18\x03 \x01(\t\x12\r\n\x05width\x18\x04 \x01(\r\x12\x0e\n\x06height\x18\x05 \x01(\r\x12\x14\n\x0cscale_factor\x18\x06 \x01(\x01\x12\x17\n\x0frefresh_rate_hz\x18\x07 \x01(\x01\x12\x1e\n\x08monitors\x18\x08 \x03(\x0b\x32\x0c.vsg.Monitor\x12\x18\n\x10\x66rames_presented\x18\t \x01(\x04\x12\x16\n\x0e\x66rames_skipped\x18\n \x01(\x04\"\x94\x01\n\x07Monitor\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\r\n\x05width\x18\x02 \x01(\r\x12\x0e\n\x06height\x18\x03 \x01(\r\x12\t\n\x01x\x18\x04 \x01(\x05\x12\t\n\x01y\x18\x05 \x01(\x05\x12\x14\n\x0cscale_factor\x18\x06 \x01(\x01\x12\x1f\n\x17refresh_rate_millihertz\x18\x07 \x01(\r\x12\x0f\n\x07\x63urrent\x18\x08 \x01(\x08\"B\n\x05Hello\x12\x18\n\x10protocol_version\x18\x01 \x01(\r\x12\x10\n\x08\x66\x65\x61tures\x18\x02 \x03(\t\x12\r\n\x05\x61gent\x18\x03 \x01(\t\"*\n\tSubscribe\x12\x1d\n\x05kinds\x18\x01 \x03(\x0e\x32\x0e.vsg.EventKind\"\xa8\x02\n\x05\x45vent\x12\x14\n\x0ctimestamp_us\x18\x01 \x01(\x04\x12 \n\x05input\x18\x02 \x01(\x0b\x32\x0f.vsg.InputEventH\x00\x12$\n\nframe_drop\x18\x03 \x01(\x0b\x32\x0e.vsg.FrameDropH\x00\x12\x1b\n\x05onset\x18\x04 \x01(\x0b\x32\n.vsg.OnsetH\x00\x12%\n\x06offset\x18\x05 \x01(\x0b\x32\x13.vsg.StimulusOffsetH\x00\x12(\n\x0csurface_lost\x18\x06 \x01(\x0b\x32\x10.vsg.SurfaceLostH\x00\x12\x1d\n\x06resize\x18\x07 \x01(\x0b\x32\x0b.vsg.ResizeH\x00\x12,\n\x0e\x63ommand_failed\x18\x08 \x01(\x0b\x32\x12.vsg.CommandFailedH\x00\x42\x06\n\x04kind\"/\n\tFrameDrop\x12\r\n\x05\x66rame\x18\x01 \x01(\x04\x12\x13\n\x0binterval_us\x18\x02 \x01(\x04\"\x1d\n\x0bSurfaceLost\x12\x0e\n\x06reason\x18\x01 \x01(\t\"T\n\rCommandFailed\x12\x10\n\x08sequence\x18\x01 \x01(\x04\x12\"\n\nerror_code\x18\x02 \x01(\x0e\x32\x0e.vsg.ErrorCode\x12\r\n\x05\x65rror\x18\x03 \x01(\t\"=\n\x06Resize\x12\r\n\x05width\x18\x01 \x01(\r\x12\x0e\n\x06height\x18\x02 \x01(\r\x12\x14\n\x0cscale_factor\x18\x03 \x01(\x01\"\xc4\x01\n\nInputEvent\x12\x0f\n\x07pressed\x18\x01 \x01(\x08\x12\x17\n\x03key\x18\x02 \x01(\x0b\x32\x08.vsg.KeyH\x00\x12(\n\x0cmouse_button\x18\x03 \x01(\x0b\x32\x10.vsg.MouseButtonH\x00\x12\x18\n\x0bonset_frame\x18\x04 \x01(\x04H\x01\x88\x01\x01\x12\x1b\n\x0esince_onset_us\x18\x05 \x01(\x04H\x02\x88\x01\x01\x42\x08\n\x06sourceB\x0e\n\x0c_onset_frameB\x11\n\x0f_since_onset_us\"%\n\x03Key\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x10\n\x08scancode\x18\x02 \x01(\r\"?\n\x0bMouseButton\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\"\n\x08position\x18\x02 \x01(\x0b\x32\x10.vsg.Coordinates*)\n\x06Status\x12\r\n\tSTATUS_OK\x10\x00\x12\x10\n\x0cSTATUS_ERROR\x10\x01*\xdc\x02\n\tErrorCode\x12\x13\n\x0f\x45RROR_CODE_NONE\x10\x00\x12\x15\n\x11\x45RROR_CODE_DECODE\x10\x01\x12\x1e\n\x1a\x45RROR_CODE_UNKNOWN_COMMAND\x10\x02\x12\x1f\n\x1b\x45RROR_CODE_INVALID_ARGUMENT\x10\x03\x12\x18\n\x14\x45RROR_CODE_NOT_FOUND\x10\x04\x12\x1d\n\x19\x45RROR_CODE_ALREADY_EXISTS\x10\x05\x12 \n\x1c\x45RROR_CODE_TEXTURE_NOT_FOUND\x10\x06\x12\x1f\n\x1b\x45RROR_CODE_VERSION_MISMATCH\x10\x07\x12\"\n\x1e\x45RROR_CODE_UNSUPPORTED_FEATURE\x10\x08\x12 \n\x1c\x45RROR_CODE_MESSAGE_TOO_LARGE\x10\t\x12 \n\x1c\x45RROR_CODE_PERMISSION_DENIED\x10\n*j\n\x0bPixelFormat\x12\x16\n\x12PIXEL_FORMAT_RGBA8\x10\x00\x12\x13\n\x0fPIXEL_FORMAT_R8\x10\x01\x12\x14\n\x10PIXEL_FORMAT_R16\x10\x02\x12\x18\n\x14PIXEL_FORMAT_RGBA32F\x10\x03*@\n\rCaptureFormat\x12\x16\n\x12\x43\x41PTURE_FORMAT_PNG\x10\x00\x12\x17\n\x13\x43\x41PTURE_FORMAT_RGBA\x10\x01*Q\n\x0fProtocolVersion\x12 \n\x1cPROTOCOL_VERSION_UNSPECIFIED\x10\x00\x12\x1c\n\x18PROTOCOL_VERSION_CURRENT\x10\x01*\xb9\x01\n\tEventKind\x12\x1a\n\x16\x45VENT_KIND_UNSPECIFIED\x10\x00\x12\x14\n\x10\x45VENT_KIND_INPUT\x10\x01\x12\x19\n\x15\x45VENT_KIND_FRAME_DROP\x10\x02\x12\x14\n\x10\x45VENT_KIND_ONSET\x10\x03\x12\x15\n\x11\x45VENT_KIND_OFFSET\x10\x04\x12\x1b\n\x17\x45VENT_KIND_SURFACE_LOST\x10\x05\x12\x15\n\x11\x45VENT_KIND_RESIZE\x10\x06\x42\x0c\n\x03vsgB\x03VsgP\x01\x62\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'message_pb2', globals())
@@ -22,10 +23,72 @@ if _descriptor._USE_C_DESCRIPTORS == False:
 
   DESCRIPTOR._options = None
   DESCRIPTOR._serialized_options = b'\n\003vsgB\003VsgP\001'
+  _STATUS._serialized_start=3272
+  _STATUS._serialized_end=3313
+  _ERRORCODE._serialized_start=3316
+  _ERRORCODE._serialized_end=3664
+  _PIXELFORMAT._serialized_start=3666
+  _PIXELFORMAT._serialized_end=3772
+  _CAPTUREFORMAT._serialized_start=3774
+  _CAPTUREFORMAT._serialized_end=3838
+  _PROTOCOLVERSION._serialized_start=3840
+  _PROTOCOLVERSION._serialized_end=3921
+  _EVENTKIND._serialized_start=3924
+  _EVENTKIND._serialized_end=4109
   _ROOTMESSAGE._serialized_start=37
-  _ROOTMESSAGE._serialized_end=169
-  _BGCOLOR._serialized_start=171
-  _BGCOLOR._serialized_end=195
-  _TEXTURE._serialized_start=197
-  _TEXTURE._serialized_end=235
+  _ROOTMESSAGE._serialized_end=775
+  _SCHEDULE._serialized_start=777
+  _SCHEDULE._serialized_end=837
+  _ROOTRESPONSE._serialized_start=840
+  _ROOTRESPONSE._serialized_end=1132
+  _ONSET._serialized_start=1134
+  _ONSET._serialized_end=1216
+  _STIMULUSOFFSET._serialized_start=1218
+  _STIMULUSOFFSET._serialized_end=1261
+  _BGCOLOR._serialized_start=1263
+  _BGCOLOR._serialized_end=1287
+  _UPDATESTIMULUS._serialized_start=1289
+  _UPDATESTIMULUS._serialized_end=1344
+  _SETVISIBILITY._serialized_start=1346
+  _SETVISIBILITY._serialized_end=1390
+  _REMOVESTIMULUS._serialized_start=1392
+  _REMOVESTIMULUS._serialized_end=1420
+  _TEXTURE._serialized_start=1422
+  _TEXTURE._serialized_end=1503
+  _RAWPIXELS._serialized_start=1505
+  _RAWPIXELS._serialized_end=1611
+  _TEXTUREINDEX._serialized_start=1613
+  _TEXTUREINDEX._serialized_end=1642
+  _SETSCENE._serialized_start=1644
+  _SETSCENE._serialized_end=1750
+  _CAPTUREFRAME._serialized_start=1752
+  _CAPTUREFRAME._serialized_end=1816
+  _CAPTURE._serialized_start=1818
+  _CAPTURE._serialized_end=1922
+  _GETSTATUS._serialized_start=1924
+  _GETSTATUS._serialized_end=1935
+  _DISPLAYSTATUS._serialized_start=1938
+  _DISPLAYSTATUS._serialized_end=2176
+  _MONITOR._serialized_start=2179
+  _MONITOR._serialized_end=2327
+  _HELLO._serialized_start=2329
+  _HELLO._serialized_end=2395
+  _SUBSCRIBE._serialized_start=2397
+  _SUBSCRIBE._serialized_end=2439
+  _EVENT._serialized_start=2442
+  _EVENT._serialized_end=2738
+  _FRAMEDROP._serialized_start=2740
+  _FRAMEDROP._serialized_end=2787
+  _SURFACELOST._serialized_start=2789
+  _SURFACELOST._serialized_end=2818
+  _COMMANDFAILED._serialized_start=2820
+  _COMMANDFAILED._serialized_end=2904
+  _RESIZE._serialized_start=2906
+  _RESIZE._serialized_end=2967
+  _INPUTEVENT._serialized_start=2970
+  _INPUTEVENT._serialized_end=3166
+  _KEY._serialized_start=3168
+  _KEY._serialized_end=3205
+  _MOUSEBUTTON._serialized_start=3207
+  _MOUSEBUTTON._serialized_end=3270
 # @@protoc_insertion_point(module_scope)
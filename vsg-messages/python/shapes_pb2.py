@@ -13,7 +13,7 @@ _sym_db = _symbol_database.Default()
 
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0cshapes.proto\x12\x03vsg\"k\n\x05Shape\x12\x1d\n\x06square\x18\x01 \x01(\x0b\x32\x0b.vsg.SquareH\x00\x12\x1d\n\x06\x63ircle\x18\x02 \x01(\x0b\x32\x0b.vsg.CircleH\x00\x12\x1b\n\x05\x63ross\x18\x03 \x01(\x0b\x32\n.vsg.CrossH\x00\x42\x07\n\x05shape\"#\n\x0b\x43oordinates\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\"5\n\x06Square\x12\x0c\n\x04size\x18\x01 \x01(\x02\x12\x1d\n\x03\x63tr\x18\x02 \x01(\x0b\x32\x10.vsg.Coordinates\"7\n\x06\x43ircle\x12\x0e\n\x06radius\x18\x01 \x01(\x02\x12\x1d\n\x03\x63tr\x18\x02 \x01(\x0b\x32\x10.vsg.Coordinates\"H\n\x05\x43ross\x12\x0c\n\x04size\x18\x01 \x01(\x02\x12\x12\n\nline_width\x18\x02 \x01(\x02\x12\x1d\n\x03\x63tr\x18\x03 \x01(\x0b\x32\x10.vsg.CoordinatesB\x19\n\nvsg.shapesB\tVsgShapesP\x01\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0cshapes.proto\x12\x03vsg\"k\n\x05Shape\x12\x1d\n\x06square\x18\x01 \x01(\x0b\x32\x0b.vsg.SquareH\x00\x12\x1d\n\x06\x63ircle\x18\x02 \x01(\x0b\x32\x0b.vsg.CircleH\x00\x12\x1b\n\x05\x63ross\x18\x03 \x01(\x0b\x32\n.vsg.CrossH\x00\x42\x07\n\x05shape\"\x7f\n\x08Stimulus\x12\n\n\x02id\x18\x01 \x01(\r\x12\x19\n\x05shape\x18\x02 \x01(\x0b\x32\n.vsg.Shape\x12\x0e\n\x06hidden\x18\x03 \x01(\x08\x12\x19\n\x0f\x64uration_frames\x18\x04 \x01(\rH\x00\x12\x15\n\x0b\x64uration_ms\x18\x05 \x01(\x02H\x00\x42\n\n\x08lifetime\"#\n\x0b\x43oordinates\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\">\n\x05\x43olor\x12\t\n\x01r\x18\x01 \x01(\x02\x12\t\n\x01g\x18\x02 \x01(\x02\x12\t\n\x01\x62\x18\x03 \x01(\x02\x12\x0e\n\x01\x61\x18\x04 \x01(\x02H\x00\x88\x01\x01\x42\x04\n\x02_a\"P\n\x06Square\x12\x0c\n\x04size\x18\x01 \x01(\x02\x12\x1d\n\x03\x63tr\x18\x02 \x01(\x0b\x32\x10.vsg.Coordinates\x12\x19\n\x05\x63olor\x18\x03 \x01(\x0b\x32\n.vsg.Color\"R\n\x06\x43ircle\x12\x0e\n\x06radius\x18\x01 \x01(\x02\x12\x1d\n\x03\x63tr\x18\x02 \x01(\x0b\x32\x10.vsg.Coordinates\x12\x19\n\x05\x63olor\x18\x03 \x01(\x0b\x32\n.vsg.Color\"c\n\x05\x43ross\x12\x0c\n\x04size\x18\x01 \x01(\x02\x12\x12\n\nline_width\x18\x02 \x01(\x02\x12\x1d\n\x03\x63tr\x18\x03 \x01(\x0b\x32\x10.vsg.Coordinates\x12\x19\n\x05\x63olor\x18\x04 \x01(\x0b\x32\n.vsg.ColorB\x19\n\nvsg.shapesB\tVsgShapesP\x01\x62\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'shapes_pb2', globals())
@@ -23,12 +23,16 @@ if _descriptor._USE_C_DESCRIPTORS == False:
   DESCRIPTOR._serialized_options = b'\n\nvsg.shapesB\tVsgShapesP\001'
   _SHAPE._serialized_start=21
   _SHAPE._serialized_end=128
-  _COORDINATES._serialized_start=130
-  _COORDINATES._serialized_end=165
-  _SQUARE._serialized_start=167
-  _SQUARE._serialized_end=220
-  _CIRCLE._serialized_start=222
-  _CIRCLE._serialized_end=277
-  _CROSS._serialized_start=279
-  _CROSS._serialized_end=351
+  _STIMULUS._serialized_start=130
+  _STIMULUS._serialized_end=257
+  _COORDINATES._serialized_start=259
+  _COORDINATES._serialized_end=294
+  _COLOR._serialized_start=296
+  _COLOR._serialized_end=358
+  _SQUARE._serialized_start=360
+  _SQUARE._serialized_end=440
+  _CIRCLE._serialized_start=442
+  _CIRCLE._serialized_end=524
+  _CROSS._serialized_start=526
+  _CROSS._serialized_end=625
 # @@protoc_insertion_point(module_scope)
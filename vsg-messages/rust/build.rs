@@ -1,14 +1,23 @@
 use prost_build::Config;
 use std::io::Result;
+use std::path::PathBuf;
 
 fn main() -> Result<()> {
     let proto_path = "../proto";
     // Tell Cargo that if the given file changes, to rerun this build script.
     println!("cargo:rerun-if-changed={}", proto_path);
 
+    let descriptor_path = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("vsg.bin");
     Config::new()
         .out_dir("src/proto")
-        .include_file("mod.rs")
+        .file_descriptor_set_path(&descriptor_path)
         .compile_protos(&[format!("{}/message.proto", proto_path)], &[proto_path])?;
+
+    // serde implementations used by the json feature
+    let descriptor_set = std::fs::read(descriptor_path)?;
+    pbjson_build::Builder::new()
+        .out_dir("src/proto")
+        .register_descriptors(&descriptor_set)?
+        .build(&[".vsg"])?;
     Ok(())
 }
@@ -1,2 +1,45 @@
-mod proto;
+mod proto {
+    pub mod vsg {
+        include!("proto/vsg.rs");
+
+        // Generated by pbjson-build, which does not keep up with clippy.
+        #[cfg(feature = "json")]
+        #[allow(clippy::all)]
+        mod serde_impls {
+            use super::*;
+            include!("proto/vsg.serde.rs");
+        }
+    }
+}
 pub use proto::vsg::*;
+
+/// Protocol version of this copy of the messages, sent in [`Hello`].
+pub const PROTOCOL_VERSION: u32 = ProtocolVersion::Current as u32;
+
+impl RootResponse {
+    pub fn ok(sequence: u64) -> Self {
+        Self {
+            status: Status::Ok.into(),
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    /// An unsolicited message carrying `event`.
+    pub fn event(event: Event) -> Self {
+        Self {
+            event: Some(event),
+            ..Self::ok(0)
+        }
+    }
+
+    pub fn error(sequence: u64, code: ErrorCode, error: impl Into<String>) -> Self {
+        Self {
+            status: Status::Error.into(),
+            error_code: code.into(),
+            error: error.into(),
+            sequence,
+            ..Default::default()
+        }
+    }
+}
@@ -1,3 +0,0 @@
-pub mod vsg {
-    include!("vsg.rs");
-}
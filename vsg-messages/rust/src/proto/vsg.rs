@@ -17,6 +17,37 @@ pub mod shape {
         Cross(super::Cross),
     }
 }
+/// A shape kept in the scene under a client-chosen id.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Stimulus {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub shape: ::core::option::Option<Shape>,
+    #[prost(bool, tag = "3")]
+    pub hidden: bool,
+    /// Removed from the scene automatically after being presented this long.
+    /// Stays until removed when not set. Only allowed for stimuli that are
+    /// not hidden, since a hidden one would expire without being shown.
+    #[prost(oneof = "stimulus::Lifetime", tags = "4, 5")]
+    pub lifetime: ::core::option::Option<stimulus::Lifetime>,
+}
+/// Nested message and enum types in `Stimulus`.
+pub mod stimulus {
+    /// Removed from the scene automatically after being presented this long.
+    /// Stays until removed when not set. Only allowed for stimuli that are
+    /// not hidden, since a hidden one would expire without being shown.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Lifetime {
+        #[prost(uint32, tag = "4")]
+        DurationFrames(u32),
+        /// Rounded to the nearest number of frames, at least one.
+        #[prost(float, tag = "5")]
+        DurationMs(f32),
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Coordinates {
@@ -25,6 +56,20 @@ pub struct Coordinates {
     #[prost(float, tag = "2")]
     pub y: f32,
 }
+/// RGBA with every component in \[0, 1\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Color {
+    #[prost(float, tag = "1")]
+    pub r: f32,
+    #[prost(float, tag = "2")]
+    pub g: f32,
+    #[prost(float, tag = "3")]
+    pub b: f32,
+    /// Opaque when not set.
+    #[prost(float, optional, tag = "4")]
+    pub a: ::core::option::Option<f32>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Square {
@@ -32,6 +77,8 @@ pub struct Square {
     pub size: f32,
     #[prost(message, optional, tag = "2")]
     pub ctr: ::core::option::Option<Coordinates>,
+    #[prost(message, optional, tag = "3")]
+    pub color: ::core::option::Option<Color>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -40,6 +87,8 @@ pub struct Circle {
     pub radius: f32,
     #[prost(message, optional, tag = "2")]
     pub ctr: ::core::option::Option<Coordinates>,
+    #[prost(message, optional, tag = "3")]
+    pub color: ::core::option::Option<Color>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -50,11 +99,35 @@ pub struct Cross {
     pub line_width: f32,
     #[prost(message, optional, tag = "3")]
     pub ctr: ::core::option::Option<Coordinates>,
+    #[prost(message, optional, tag = "4")]
+    pub color: ::core::option::Option<Color>,
 }
+/// On stream connections every message is preceded by its length as a 4 byte
+/// big-endian integer, and so is every reply. A connection may open with the
+/// single byte 'J' to exchange both in the canonical protobuf JSON mapping
+/// instead, or 'P' for protobuf. The first message should then be a Hello.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RootMessage {
-    #[prost(oneof = "root_message::Command", tags = "1, 2, 3")]
+    /// Chosen by the client and echoed back in RootResponse.sequence. Over
+    /// UDP, a datagram whose sequence is not above the last one from the same
+    /// sender is dropped, except with sequence 0, which is always applied.
+    #[prost(uint64, tag = "32")]
+    pub sequence: u64,
+    /// Delay the reply until the frame containing this command has been
+    /// presented, and report its onset in RootResponse.onset. Without it, the
+    /// reply only says the command was queued, and a CommandFailed event
+    /// follows if applying it fails.
+    #[prost(bool, tag = "33")]
+    pub flip_ack: bool,
+    /// Hold the command until a given frame or time. Applied right away when
+    /// not set or already in the past.
+    #[prost(message, optional, tag = "34")]
+    pub schedule: ::core::option::Option<Schedule>,
+    #[prost(
+        oneof = "root_message::Command",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16"
+    )]
     pub command: ::core::option::Option<root_message::Command>,
 }
 /// Nested message and enum types in `RootMessage`.
@@ -62,25 +135,672 @@ pub mod root_message {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Command {
+        /// Replaces every stimulus in the scene with this shape, which has no
+        /// id: stimuli added later can use any id, and it stays until the
+        /// next SetShape or SetScene.
         #[prost(message, tag = "1")]
         SetShape(super::Shape),
         #[prost(message, tag = "2")]
         SetBgColor(super::BgColor),
+        /// Stores the texture and shows it instead of any other texture.
         #[prost(message, tag = "3")]
         SetTexture(super::Texture),
+        #[prost(message, tag = "4")]
+        AddStimulus(super::Stimulus),
+        #[prost(message, tag = "5")]
+        UpdateStimulus(super::UpdateStimulus),
+        #[prost(message, tag = "6")]
+        SetVisibility(super::SetVisibility),
+        #[prost(message, tag = "7")]
+        RemoveStimulus(super::RemoveStimulus),
+        #[prost(message, tag = "8")]
+        SetScene(super::SetScene),
+        /// Stores the texture without showing it.
+        #[prost(message, tag = "9")]
+        PreloadTexture(super::Texture),
+        #[prost(message, tag = "10")]
+        ShowTexture(super::TextureIndex),
+        #[prost(message, tag = "11")]
+        HideTexture(super::TextureIndex),
+        #[prost(message, tag = "12")]
+        DeleteTexture(super::TextureIndex),
+        /// Answered right away with RootResponse.display_status. Ignores
+        /// flip_ack and schedule.
+        #[prost(message, tag = "13")]
+        GetStatus(super::GetStatus),
+        /// Answered with RootResponse.hello, or with an error when the
+        /// versions differ, after which every other message is refused.
+        #[prost(message, tag = "14")]
+        Hello(super::Hello),
+        /// Choose which events are pushed to this connection.
+        #[prost(message, tag = "15")]
+        Subscribe(super::Subscribe),
+        /// Answered with RootResponse.capture and RootResponse.onset once the
+        /// frame the image shows has been presented.
+        #[prost(message, tag = "16")]
+        CaptureFrame(super::CaptureFrame),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Schedule {
+    #[prost(oneof = "schedule::Target", tags = "1, 2")]
+    pub target: ::core::option::Option<schedule::Target>,
+}
+/// Nested message and enum types in `Schedule`.
+pub mod schedule {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Target {
+        /// Frame number as counted in Onset.frame.
+        #[prost(uint64, tag = "1")]
+        Frame(u64),
+        /// Server clock as in Onset.timestamp_us. The command is applied on
+        /// the first frame boundary at or after this time.
+        #[prost(uint64, tag = "2")]
+        DeadlineUs(u64),
     }
 }
+/// Reply sent by the server for every RootMessage it receives.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RootResponse {
+    #[prost(enumeration = "Status", tag = "1")]
+    pub status: i32,
+    #[prost(enumeration = "ErrorCode", tag = "2")]
+    pub error_code: i32,
+    #[prost(string, tag = "3")]
+    pub error: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub sequence: u64,
+    /// Only set in reply to a RootMessage with flip_ack.
+    #[prost(message, optional, tag = "5")]
+    pub onset: ::core::option::Option<Onset>,
+    /// Server clock when the reply was sent, as in Onset.timestamp_us.
+    #[prost(uint64, tag = "6")]
+    pub server_time_us: u64,
+    /// Only set in reply to GetStatus.
+    #[prost(message, optional, tag = "7")]
+    pub display_status: ::core::option::Option<DisplayStatus>,
+    /// Only set in reply to Hello.
+    #[prost(message, optional, tag = "8")]
+    pub hello: ::core::option::Option<Hello>,
+    /// Pushed to subscribed connections outside of the request and reply
+    /// flow, with sequence 0.
+    #[prost(message, optional, tag = "9")]
+    pub event: ::core::option::Option<Event>,
+    /// Only set in reply to CaptureFrame.
+    #[prost(message, optional, tag = "10")]
+    pub capture: ::core::option::Option<Capture>,
+}
+/// When a frame was handed to the display for presentation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Onset {
+    /// Number of frames presented before this one since the server started.
+    #[prost(uint64, tag = "1")]
+    pub frame: u64,
+    /// Microseconds on the server's monotonic clock, counted from its start,
+    /// when presenting the frame returned. The frame is only queued then: the
+    /// display starts scanning it out at its next refresh, up to one refresh
+    /// interval (DisplayStatus.refresh_rate_hz) later, and a pixel lights up
+    /// later still depending on its row and the display's response time.
+    /// Measure the actual onset with a photodiode where it matters.
+    #[prost(uint64, tag = "2")]
+    pub timestamp_us: u64,
+    /// Stimuli with a lifetime that were added by the acknowledged command.
+    #[prost(message, repeated, tag = "3")]
+    pub offsets: ::prost::alloc::vec::Vec<StimulusOffset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StimulusOffset {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    /// First frame without the stimulus.
+    #[prost(uint64, tag = "2")]
+    pub frame: u64,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BgColor {
     #[prost(float, repeated, tag = "1")]
     pub color: ::prost::alloc::vec::Vec<f32>,
 }
+/// Replaces the shape of an existing stimulus, keeping its visibility.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateStimulus {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub shape: ::core::option::Option<Shape>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetVisibility {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(bool, tag = "2")]
+    pub visible: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveStimulus {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+}
+/// Image stored in the texture library under index. Without a source, or with
+/// empty data, it refers to the texture already stored under index.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Texture {
     #[prost(uint32, tag = "1")]
     pub index: u32,
-    #[prost(bytes = "vec", tag = "2")]
+    #[prost(oneof = "texture::Source", tags = "2, 3")]
+    pub source: ::core::option::Option<texture::Source>,
+}
+/// Nested message and enum types in `Texture`.
+pub mod texture {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        /// Encoded image file such as PNG or JPEG.
+        #[prost(bytes, tag = "2")]
+        Data(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "3")]
+        Raw(super::RawPixels),
+    }
+}
+/// Pixels uploaded as they are, without decoding. Rows run from top to bottom.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RawPixels {
+    #[prost(uint32, tag = "1")]
+    pub width: u32,
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+    #[prost(enumeration = "PixelFormat", tag = "3")]
+    pub format: i32,
+    /// Bytes from the start of one row to the next. 0 means tightly packed.
+    #[prost(uint32, tag = "4")]
+    pub stride: u32,
+    #[prost(bytes = "vec", tag = "5")]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextureIndex {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+}
+/// Replaces the whole display on a single frame.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetScene {
+    /// Become the only stimuli in the scene.
+    #[prost(message, repeated, tag = "1")]
+    pub stimuli: ::prost::alloc::vec::Vec<Stimulus>,
+    /// Become the only textures shown. Empty hides every texture.
+    #[prost(message, repeated, tag = "2")]
+    pub textures: ::prost::alloc::vec::Vec<Texture>,
+    /// Left unchanged when not set.
+    #[prost(message, optional, tag = "3")]
+    pub bg_color: ::core::option::Option<BgColor>,
+}
+/// Renders the stimuli, textures and background offscreen at the frame
+/// boundary, exactly as they appear on that frame.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CaptureFrame {
+    #[prost(enumeration = "CaptureFormat", tag = "1")]
+    pub format: i32,
+    /// Writes the image to this file on the server instead of returning it.
+    /// Only allowed when the server runs with --capture-dir, relative to that
+    /// directory, which it may not lead out of.
+    #[prost(string, tag = "2")]
+    pub path: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Capture {
+    #[prost(uint32, tag = "1")]
+    pub width: u32,
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+    #[prost(enumeration = "CaptureFormat", tag = "3")]
+    pub format: i32,
+    /// Empty when the image was written to a file.
+    #[prost(bytes = "vec", tag = "4")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub path: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStatus {}
+/// What the server is drawing to.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisplayStatus {
+    #[prost(string, tag = "1")]
+    pub adapter_name: ::prost::alloc::string::String,
+    /// Graphics API in use, such as "Vulkan" or "Metal".
+    #[prost(string, tag = "2")]
+    pub backend: ::prost::alloc::string::String,
+    /// Texture format of the window surface, such as "Bgra8UnormSrgb".
+    #[prost(string, tag = "3")]
+    pub surface_format: ::prost::alloc::string::String,
+    /// Window size in physical pixels.
+    #[prost(uint32, tag = "4")]
+    pub width: u32,
+    #[prost(uint32, tag = "5")]
+    pub height: u32,
+    /// Physical pixels per logical pixel.
+    #[prost(double, tag = "6")]
+    pub scale_factor: f64,
+    /// Measured from the intervals between presented frames.
+    #[prost(double, tag = "7")]
+    pub refresh_rate_hz: f64,
+    #[prost(message, repeated, tag = "8")]
+    pub monitors: ::prost::alloc::vec::Vec<Monitor>,
+    /// Same as the next Onset.frame.
+    #[prost(uint64, tag = "9")]
+    pub frames_presented: u64,
+    /// Frames that took noticeably longer than the refresh interval.
+    #[prost(uint64, tag = "10")]
+    pub frames_skipped: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Monitor {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Physical pixels.
+    #[prost(uint32, tag = "2")]
+    pub width: u32,
+    #[prost(uint32, tag = "3")]
+    pub height: u32,
+    /// Top-left corner on the desktop, in physical pixels.
+    #[prost(int32, tag = "4")]
+    pub x: i32,
+    #[prost(int32, tag = "5")]
+    pub y: i32,
+    #[prost(double, tag = "6")]
+    pub scale_factor: f64,
+    /// Nominal refresh rate reported by the system, 0 when unknown.
+    #[prost(uint32, tag = "7")]
+    pub refresh_rate_millihertz: u32,
+    /// Whether the window is on this monitor.
+    #[prost(bool, tag = "8")]
+    pub current: bool,
+}
+/// Exchanged once at the start of a connection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Hello {
+    /// PROTOCOL_VERSION_CURRENT of the sender's copy of this file.
+    #[prost(uint32, tag = "1")]
+    pub protocol_version: u32,
+    /// From the client, the features it relies on. From the server, every
+    /// feature it supports, such as "flip_ack" or "json".
+    #[prost(string, repeated, tag = "2")]
+    pub features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Free-form description of the sender, such as "vsg-core 0.1.0".
+    #[prost(string, tag = "3")]
+    pub agent: ::prost::alloc::string::String,
+}
+/// Replaces the kinds of events pushed to the connection. Empty unsubscribes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Subscribe {
+    #[prost(enumeration = "EventKind", repeated, tag = "1")]
+    pub kinds: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Event {
+    /// Server clock when the server learned about the event, as in
+    /// Onset.timestamp_us.
+    #[prost(uint64, tag = "1")]
+    pub timestamp_us: u64,
+    #[prost(oneof = "event::Kind", tags = "2, 3, 4, 5, 6, 7, 8")]
+    pub kind: ::core::option::Option<event::Kind>,
+}
+/// Nested message and enum types in `Event`.
+pub mod event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "2")]
+        Input(super::InputEvent),
+        #[prost(message, tag = "3")]
+        FrameDrop(super::FrameDrop),
+        #[prost(message, tag = "4")]
+        Onset(super::Onset),
+        #[prost(message, tag = "5")]
+        Offset(super::StimulusOffset),
+        #[prost(message, tag = "6")]
+        SurfaceLost(super::SurfaceLost),
+        #[prost(message, tag = "7")]
+        Resize(super::Resize),
+        /// Sent to the connection whose command failed, subscribed or not.
+        #[prost(message, tag = "8")]
+        CommandFailed(super::CommandFailed),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FrameDrop {
+    /// The late frame.
+    #[prost(uint64, tag = "1")]
+    pub frame: u64,
+    /// Time since the previous frame was presented.
+    #[prost(uint64, tag = "2")]
+    pub interval_us: u64,
+}
+/// The surface had to be configured again and the frame was not presented.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SurfaceLost {
+    /// Such as "Lost" or "Outdated".
+    #[prost(string, tag = "1")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// Applying a command sent without flip_ack failed after it had already been
+/// answered with STATUS_OK.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandFailed {
+    /// RootMessage.sequence of the command.
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(enumeration = "ErrorCode", tag = "2")]
+    pub error_code: i32,
+    #[prost(string, tag = "3")]
+    pub error: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Resize {
+    /// Surface size in physical pixels.
+    #[prost(uint32, tag = "1")]
+    pub width: u32,
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+    #[prost(double, tag = "3")]
+    pub scale_factor: f64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InputEvent {
+    /// Released when false.
+    #[prost(bool, tag = "1")]
+    pub pressed: bool,
+    /// Most recent frame on which a command took effect, and the time from
+    /// its onset to this event. Not set before the first such frame.
+    #[prost(uint64, optional, tag = "4")]
+    pub onset_frame: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "5")]
+    pub since_onset_us: ::core::option::Option<u64>,
+    #[prost(oneof = "input_event::Source", tags = "2, 3")]
+    pub source: ::core::option::Option<input_event::Source>,
+}
+/// Nested message and enum types in `InputEvent`.
+pub mod input_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        #[prost(message, tag = "2")]
+        Key(super::Key),
+        #[prost(message, tag = "3")]
+        MouseButton(super::MouseButton),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Key {
+    /// Such as "Space", "A" or "Left". Empty for keys without a name, which
+    /// can still be told apart by scancode.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub scancode: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MouseButton {
+    /// "Left", "Right", "Middle" or "Other" followed by a number.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Cursor position in the same coordinates as stimuli.
+    #[prost(message, optional, tag = "2")]
+    pub position: ::core::option::Option<Coordinates>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Status {
+    Ok = 0,
+    Error = 1,
+}
+impl Status {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Status::Ok => "STATUS_OK",
+            Status::Error => "STATUS_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STATUS_OK" => Some(Self::Ok),
+            "STATUS_ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ErrorCode {
+    None = 0,
+    /// The payload could not be decoded as a RootMessage.
+    Decode = 1,
+    /// The RootMessage did not carry a command this server understands.
+    UnknownCommand = 2,
+    /// The command was understood but its contents are not valid.
+    InvalidArgument = 3,
+    /// No stimulus with the requested id is in the scene.
+    NotFound = 4,
+    /// A stimulus with the requested id is already in the scene.
+    AlreadyExists = 5,
+    /// No texture is stored under the requested index.
+    TextureNotFound = 6,
+    /// Client and server speak different protocol versions.
+    VersionMismatch = 7,
+    /// The client relies on a feature this server does not have.
+    UnsupportedFeature = 8,
+    /// The message is longer than the server accepts. The connection is closed
+    /// after this reply, since the rest of the stream cannot be trusted.
+    MessageTooLarge = 9,
+    /// The server was not started with what the request needs, like
+    /// --capture-dir for CaptureFrame.path.
+    PermissionDenied = 10,
+}
+impl ErrorCode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ErrorCode::None => "ERROR_CODE_NONE",
+            ErrorCode::Decode => "ERROR_CODE_DECODE",
+            ErrorCode::UnknownCommand => "ERROR_CODE_UNKNOWN_COMMAND",
+            ErrorCode::InvalidArgument => "ERROR_CODE_INVALID_ARGUMENT",
+            ErrorCode::NotFound => "ERROR_CODE_NOT_FOUND",
+            ErrorCode::AlreadyExists => "ERROR_CODE_ALREADY_EXISTS",
+            ErrorCode::TextureNotFound => "ERROR_CODE_TEXTURE_NOT_FOUND",
+            ErrorCode::VersionMismatch => "ERROR_CODE_VERSION_MISMATCH",
+            ErrorCode::UnsupportedFeature => "ERROR_CODE_UNSUPPORTED_FEATURE",
+            ErrorCode::MessageTooLarge => "ERROR_CODE_MESSAGE_TOO_LARGE",
+            ErrorCode::PermissionDenied => "ERROR_CODE_PERMISSION_DENIED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ERROR_CODE_NONE" => Some(Self::None),
+            "ERROR_CODE_DECODE" => Some(Self::Decode),
+            "ERROR_CODE_UNKNOWN_COMMAND" => Some(Self::UnknownCommand),
+            "ERROR_CODE_INVALID_ARGUMENT" => Some(Self::InvalidArgument),
+            "ERROR_CODE_NOT_FOUND" => Some(Self::NotFound),
+            "ERROR_CODE_ALREADY_EXISTS" => Some(Self::AlreadyExists),
+            "ERROR_CODE_TEXTURE_NOT_FOUND" => Some(Self::TextureNotFound),
+            "ERROR_CODE_VERSION_MISMATCH" => Some(Self::VersionMismatch),
+            "ERROR_CODE_UNSUPPORTED_FEATURE" => Some(Self::UnsupportedFeature),
+            "ERROR_CODE_MESSAGE_TOO_LARGE" => Some(Self::MessageTooLarge),
+            "ERROR_CODE_PERMISSION_DENIED" => Some(Self::PermissionDenied),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PixelFormat {
+    /// sRGB encoded, like decoded image files.
+    Rgba8 = 0,
+    /// Single channel formats are shown as gray levels. They and RGBA32F are
+    /// linear intensities. Multi-byte values are little-endian.
+    R8 = 1,
+    /// Needs an adapter supporting 16 bit normalized textures.
+    R16 = 2,
+    Rgba32f = 3,
+}
+impl PixelFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PixelFormat::Rgba8 => "PIXEL_FORMAT_RGBA8",
+            PixelFormat::R8 => "PIXEL_FORMAT_R8",
+            PixelFormat::R16 => "PIXEL_FORMAT_R16",
+            PixelFormat::Rgba32f => "PIXEL_FORMAT_RGBA32F",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PIXEL_FORMAT_RGBA8" => Some(Self::Rgba8),
+            "PIXEL_FORMAT_R8" => Some(Self::R8),
+            "PIXEL_FORMAT_R16" => Some(Self::R16),
+            "PIXEL_FORMAT_RGBA32F" => Some(Self::Rgba32f),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CaptureFormat {
+    Png = 0,
+    /// 8 bits per channel, rows top to bottom without padding.
+    Rgba = 1,
+}
+impl CaptureFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CaptureFormat::Png => "CAPTURE_FORMAT_PNG",
+            CaptureFormat::Rgba => "CAPTURE_FORMAT_RGBA",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CAPTURE_FORMAT_PNG" => Some(Self::Png),
+            "CAPTURE_FORMAT_RGBA" => Some(Self::Rgba),
+            _ => None,
+        }
+    }
+}
+/// Bumped whenever messages change in a way older peers would misread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtocolVersion {
+    Unspecified = 0,
+    Current = 1,
+}
+impl ProtocolVersion {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ProtocolVersion::Unspecified => "PROTOCOL_VERSION_UNSPECIFIED",
+            ProtocolVersion::Current => "PROTOCOL_VERSION_CURRENT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROTOCOL_VERSION_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROTOCOL_VERSION_CURRENT" => Some(Self::Current),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EventKind {
+    Unspecified = 0,
+    /// Key presses and releases, and mouse buttons.
+    Input = 1,
+    /// Frames presented over 1.5 refresh intervals after the previous one, so
+    /// that at least one refresh was missed.
+    FrameDrop = 2,
+    /// Frames on which commands took effect, from any connection.
+    Onset = 3,
+    /// Stimuli removed at the end of their lifetime.
+    Offset = 4,
+    SurfaceLost = 5,
+    Resize = 6,
+}
+impl EventKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EventKind::Unspecified => "EVENT_KIND_UNSPECIFIED",
+            EventKind::Input => "EVENT_KIND_INPUT",
+            EventKind::FrameDrop => "EVENT_KIND_FRAME_DROP",
+            EventKind::Onset => "EVENT_KIND_ONSET",
+            EventKind::Offset => "EVENT_KIND_OFFSET",
+            EventKind::SurfaceLost => "EVENT_KIND_SURFACE_LOST",
+            EventKind::Resize => "EVENT_KIND_RESIZE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EVENT_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "EVENT_KIND_INPUT" => Some(Self::Input),
+            "EVENT_KIND_FRAME_DROP" => Some(Self::FrameDrop),
+            "EVENT_KIND_ONSET" => Some(Self::Onset),
+            "EVENT_KIND_OFFSET" => Some(Self::Offset),
+            "EVENT_KIND_SURFACE_LOST" => Some(Self::SurfaceLost),
+            "EVENT_KIND_RESIZE" => Some(Self::Resize),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,5431 @@
+impl serde::Serialize for BgColor {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.color.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.BgColor", len)?;
+        if !self.color.is_empty() {
+            struct_ser.serialize_field("color", &self.color)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BgColor {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "color",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Color,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "color" => Ok(GeneratedField::Color),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BgColor;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.BgColor")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BgColor, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut color__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Color => {
+                            if color__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("color"));
+                            }
+                            color__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                    }
+                }
+                Ok(BgColor {
+                    color: color__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.BgColor", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Capture {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.width != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.format != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.path.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Capture", len)?;
+        if self.width != 0 {
+            struct_ser.serialize_field("width", &self.width)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", &self.height)?;
+        }
+        if self.format != 0 {
+            let v = CaptureFormat::try_from(self.format)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        if !self.data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        }
+        if !self.path.is_empty() {
+            struct_ser.serialize_field("path", &self.path)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Capture {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "width",
+            "height",
+            "format",
+            "data",
+            "path",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Width,
+            Height,
+            Format,
+            Data,
+            Path,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "width" => Ok(GeneratedField::Width),
+                            "height" => Ok(GeneratedField::Height),
+                            "format" => Ok(GeneratedField::Format),
+                            "data" => Ok(GeneratedField::Data),
+                            "path" => Ok(GeneratedField::Path),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Capture;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Capture")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Capture, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut width__ = None;
+                let mut height__ = None;
+                let mut format__ = None;
+                let mut data__ = None;
+                let mut path__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Width => {
+                            if width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            width__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map_.next_value::<CaptureFormat>()? as i32);
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Path => {
+                            if path__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("path"));
+                            }
+                            path__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Capture {
+                    width: width__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    format: format__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    path: path__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Capture", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CaptureFormat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Png => "CAPTURE_FORMAT_PNG",
+            Self::Rgba => "CAPTURE_FORMAT_RGBA",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for CaptureFormat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "CAPTURE_FORMAT_PNG",
+            "CAPTURE_FORMAT_RGBA",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CaptureFormat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CAPTURE_FORMAT_PNG" => Ok(CaptureFormat::Png),
+                    "CAPTURE_FORMAT_RGBA" => Ok(CaptureFormat::Rgba),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CaptureFrame {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.format != 0 {
+            len += 1;
+        }
+        if !self.path.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.CaptureFrame", len)?;
+        if self.format != 0 {
+            let v = CaptureFormat::try_from(self.format)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        if !self.path.is_empty() {
+            struct_ser.serialize_field("path", &self.path)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CaptureFrame {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "format",
+            "path",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Format,
+            Path,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "format" => Ok(GeneratedField::Format),
+                            "path" => Ok(GeneratedField::Path),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CaptureFrame;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.CaptureFrame")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CaptureFrame, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut format__ = None;
+                let mut path__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map_.next_value::<CaptureFormat>()? as i32);
+                        }
+                        GeneratedField::Path => {
+                            if path__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("path"));
+                            }
+                            path__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CaptureFrame {
+                    format: format__.unwrap_or_default(),
+                    path: path__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.CaptureFrame", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Circle {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.radius != 0. {
+            len += 1;
+        }
+        if self.ctr.is_some() {
+            len += 1;
+        }
+        if self.color.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Circle", len)?;
+        if self.radius != 0. {
+            struct_ser.serialize_field("radius", &self.radius)?;
+        }
+        if let Some(v) = self.ctr.as_ref() {
+            struct_ser.serialize_field("ctr", v)?;
+        }
+        if let Some(v) = self.color.as_ref() {
+            struct_ser.serialize_field("color", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Circle {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "radius",
+            "ctr",
+            "color",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Radius,
+            Ctr,
+            Color,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "radius" => Ok(GeneratedField::Radius),
+                            "ctr" => Ok(GeneratedField::Ctr),
+                            "color" => Ok(GeneratedField::Color),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Circle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Circle")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Circle, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut radius__ = None;
+                let mut ctr__ = None;
+                let mut color__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Radius => {
+                            if radius__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("radius"));
+                            }
+                            radius__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Ctr => {
+                            if ctr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ctr"));
+                            }
+                            ctr__ = map_.next_value()?;
+                        }
+                        GeneratedField::Color => {
+                            if color__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("color"));
+                            }
+                            color__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Circle {
+                    radius: radius__.unwrap_or_default(),
+                    ctr: ctr__,
+                    color: color__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Circle", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Color {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.r != 0. {
+            len += 1;
+        }
+        if self.g != 0. {
+            len += 1;
+        }
+        if self.b != 0. {
+            len += 1;
+        }
+        if self.a.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Color", len)?;
+        if self.r != 0. {
+            struct_ser.serialize_field("r", &self.r)?;
+        }
+        if self.g != 0. {
+            struct_ser.serialize_field("g", &self.g)?;
+        }
+        if self.b != 0. {
+            struct_ser.serialize_field("b", &self.b)?;
+        }
+        if let Some(v) = self.a.as_ref() {
+            struct_ser.serialize_field("a", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Color {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "r",
+            "g",
+            "b",
+            "a",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            R,
+            G,
+            B,
+            A,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "r" => Ok(GeneratedField::R),
+                            "g" => Ok(GeneratedField::G),
+                            "b" => Ok(GeneratedField::B),
+                            "a" => Ok(GeneratedField::A),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Color;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Color")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Color, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r__ = None;
+                let mut g__ = None;
+                let mut b__ = None;
+                let mut a__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::R => {
+                            if r__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r"));
+                            }
+                            r__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::G => {
+                            if g__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("g"));
+                            }
+                            g__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::B => {
+                            if b__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("b"));
+                            }
+                            b__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::A => {
+                            if a__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("a"));
+                            }
+                            a__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Color {
+                    r: r__.unwrap_or_default(),
+                    g: g__.unwrap_or_default(),
+                    b: b__.unwrap_or_default(),
+                    a: a__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Color", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CommandFailed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.error_code != 0 {
+            len += 1;
+        }
+        if !self.error.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.CommandFailed", len)?;
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if self.error_code != 0 {
+            let v = ErrorCode::try_from(self.error_code)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.error_code)))?;
+            struct_ser.serialize_field("errorCode", &v)?;
+        }
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommandFailed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sequence",
+            "error_code",
+            "errorCode",
+            "error",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Sequence,
+            ErrorCode,
+            Error,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "errorCode" | "error_code" => Ok(GeneratedField::ErrorCode),
+                            "error" => Ok(GeneratedField::Error),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommandFailed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.CommandFailed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommandFailed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sequence__ = None;
+                let mut error_code__ = None;
+                let mut error__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ErrorCode => {
+                            if error_code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorCode"));
+                            }
+                            error_code__ = Some(map_.next_value::<ErrorCode>()? as i32);
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CommandFailed {
+                    sequence: sequence__.unwrap_or_default(),
+                    error_code: error_code__.unwrap_or_default(),
+                    error: error__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.CommandFailed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Coordinates {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.x != 0. {
+            len += 1;
+        }
+        if self.y != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Coordinates", len)?;
+        if self.x != 0. {
+            struct_ser.serialize_field("x", &self.x)?;
+        }
+        if self.y != 0. {
+            struct_ser.serialize_field("y", &self.y)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Coordinates {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "x",
+            "y",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            X,
+            Y,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "x" => Ok(GeneratedField::X),
+                            "y" => Ok(GeneratedField::Y),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Coordinates;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Coordinates")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Coordinates, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut x__ = None;
+                let mut y__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::X => {
+                            if x__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("x"));
+                            }
+                            x__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Y => {
+                            if y__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y"));
+                            }
+                            y__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Coordinates {
+                    x: x__.unwrap_or_default(),
+                    y: y__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Coordinates", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Cross {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.size != 0. {
+            len += 1;
+        }
+        if self.line_width != 0. {
+            len += 1;
+        }
+        if self.ctr.is_some() {
+            len += 1;
+        }
+        if self.color.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Cross", len)?;
+        if self.size != 0. {
+            struct_ser.serialize_field("size", &self.size)?;
+        }
+        if self.line_width != 0. {
+            struct_ser.serialize_field("lineWidth", &self.line_width)?;
+        }
+        if let Some(v) = self.ctr.as_ref() {
+            struct_ser.serialize_field("ctr", v)?;
+        }
+        if let Some(v) = self.color.as_ref() {
+            struct_ser.serialize_field("color", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Cross {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "size",
+            "line_width",
+            "lineWidth",
+            "ctr",
+            "color",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Size,
+            LineWidth,
+            Ctr,
+            Color,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "size" => Ok(GeneratedField::Size),
+                            "lineWidth" | "line_width" => Ok(GeneratedField::LineWidth),
+                            "ctr" => Ok(GeneratedField::Ctr),
+                            "color" => Ok(GeneratedField::Color),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Cross;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Cross")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Cross, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut size__ = None;
+                let mut line_width__ = None;
+                let mut ctr__ = None;
+                let mut color__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LineWidth => {
+                            if line_width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lineWidth"));
+                            }
+                            line_width__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Ctr => {
+                            if ctr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ctr"));
+                            }
+                            ctr__ = map_.next_value()?;
+                        }
+                        GeneratedField::Color => {
+                            if color__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("color"));
+                            }
+                            color__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Cross {
+                    size: size__.unwrap_or_default(),
+                    line_width: line_width__.unwrap_or_default(),
+                    ctr: ctr__,
+                    color: color__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Cross", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DisplayStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.adapter_name.is_empty() {
+            len += 1;
+        }
+        if !self.backend.is_empty() {
+            len += 1;
+        }
+        if !self.surface_format.is_empty() {
+            len += 1;
+        }
+        if self.width != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.scale_factor != 0. {
+            len += 1;
+        }
+        if self.refresh_rate_hz != 0. {
+            len += 1;
+        }
+        if !self.monitors.is_empty() {
+            len += 1;
+        }
+        if self.frames_presented != 0 {
+            len += 1;
+        }
+        if self.frames_skipped != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.DisplayStatus", len)?;
+        if !self.adapter_name.is_empty() {
+            struct_ser.serialize_field("adapterName", &self.adapter_name)?;
+        }
+        if !self.backend.is_empty() {
+            struct_ser.serialize_field("backend", &self.backend)?;
+        }
+        if !self.surface_format.is_empty() {
+            struct_ser.serialize_field("surfaceFormat", &self.surface_format)?;
+        }
+        if self.width != 0 {
+            struct_ser.serialize_field("width", &self.width)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", &self.height)?;
+        }
+        if self.scale_factor != 0. {
+            struct_ser.serialize_field("scaleFactor", &self.scale_factor)?;
+        }
+        if self.refresh_rate_hz != 0. {
+            struct_ser.serialize_field("refreshRateHz", &self.refresh_rate_hz)?;
+        }
+        if !self.monitors.is_empty() {
+            struct_ser.serialize_field("monitors", &self.monitors)?;
+        }
+        if self.frames_presented != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("framesPresented", ToString::to_string(&self.frames_presented).as_str())?;
+        }
+        if self.frames_skipped != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("framesSkipped", ToString::to_string(&self.frames_skipped).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DisplayStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "adapter_name",
+            "adapterName",
+            "backend",
+            "surface_format",
+            "surfaceFormat",
+            "width",
+            "height",
+            "scale_factor",
+            "scaleFactor",
+            "refresh_rate_hz",
+            "refreshRateHz",
+            "monitors",
+            "frames_presented",
+            "framesPresented",
+            "frames_skipped",
+            "framesSkipped",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AdapterName,
+            Backend,
+            SurfaceFormat,
+            Width,
+            Height,
+            ScaleFactor,
+            RefreshRateHz,
+            Monitors,
+            FramesPresented,
+            FramesSkipped,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "adapterName" | "adapter_name" => Ok(GeneratedField::AdapterName),
+                            "backend" => Ok(GeneratedField::Backend),
+                            "surfaceFormat" | "surface_format" => Ok(GeneratedField::SurfaceFormat),
+                            "width" => Ok(GeneratedField::Width),
+                            "height" => Ok(GeneratedField::Height),
+                            "scaleFactor" | "scale_factor" => Ok(GeneratedField::ScaleFactor),
+                            "refreshRateHz" | "refresh_rate_hz" => Ok(GeneratedField::RefreshRateHz),
+                            "monitors" => Ok(GeneratedField::Monitors),
+                            "framesPresented" | "frames_presented" => Ok(GeneratedField::FramesPresented),
+                            "framesSkipped" | "frames_skipped" => Ok(GeneratedField::FramesSkipped),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DisplayStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.DisplayStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DisplayStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut adapter_name__ = None;
+                let mut backend__ = None;
+                let mut surface_format__ = None;
+                let mut width__ = None;
+                let mut height__ = None;
+                let mut scale_factor__ = None;
+                let mut refresh_rate_hz__ = None;
+                let mut monitors__ = None;
+                let mut frames_presented__ = None;
+                let mut frames_skipped__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AdapterName => {
+                            if adapter_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adapterName"));
+                            }
+                            adapter_name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Backend => {
+                            if backend__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backend"));
+                            }
+                            backend__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SurfaceFormat => {
+                            if surface_format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("surfaceFormat"));
+                            }
+                            surface_format__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Width => {
+                            if width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            width__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScaleFactor => {
+                            if scale_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scaleFactor"));
+                            }
+                            scale_factor__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RefreshRateHz => {
+                            if refresh_rate_hz__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refreshRateHz"));
+                            }
+                            refresh_rate_hz__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Monitors => {
+                            if monitors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("monitors"));
+                            }
+                            monitors__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FramesPresented => {
+                            if frames_presented__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("framesPresented"));
+                            }
+                            frames_presented__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FramesSkipped => {
+                            if frames_skipped__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("framesSkipped"));
+                            }
+                            frames_skipped__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(DisplayStatus {
+                    adapter_name: adapter_name__.unwrap_or_default(),
+                    backend: backend__.unwrap_or_default(),
+                    surface_format: surface_format__.unwrap_or_default(),
+                    width: width__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    scale_factor: scale_factor__.unwrap_or_default(),
+                    refresh_rate_hz: refresh_rate_hz__.unwrap_or_default(),
+                    monitors: monitors__.unwrap_or_default(),
+                    frames_presented: frames_presented__.unwrap_or_default(),
+                    frames_skipped: frames_skipped__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.DisplayStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ErrorCode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::None => "ERROR_CODE_NONE",
+            Self::Decode => "ERROR_CODE_DECODE",
+            Self::UnknownCommand => "ERROR_CODE_UNKNOWN_COMMAND",
+            Self::InvalidArgument => "ERROR_CODE_INVALID_ARGUMENT",
+            Self::NotFound => "ERROR_CODE_NOT_FOUND",
+            Self::AlreadyExists => "ERROR_CODE_ALREADY_EXISTS",
+            Self::TextureNotFound => "ERROR_CODE_TEXTURE_NOT_FOUND",
+            Self::VersionMismatch => "ERROR_CODE_VERSION_MISMATCH",
+            Self::UnsupportedFeature => "ERROR_CODE_UNSUPPORTED_FEATURE",
+            Self::MessageTooLarge => "ERROR_CODE_MESSAGE_TOO_LARGE",
+            Self::PermissionDenied => "ERROR_CODE_PERMISSION_DENIED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ErrorCode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ERROR_CODE_NONE",
+            "ERROR_CODE_DECODE",
+            "ERROR_CODE_UNKNOWN_COMMAND",
+            "ERROR_CODE_INVALID_ARGUMENT",
+            "ERROR_CODE_NOT_FOUND",
+            "ERROR_CODE_ALREADY_EXISTS",
+            "ERROR_CODE_TEXTURE_NOT_FOUND",
+            "ERROR_CODE_VERSION_MISMATCH",
+            "ERROR_CODE_UNSUPPORTED_FEATURE",
+            "ERROR_CODE_MESSAGE_TOO_LARGE",
+            "ERROR_CODE_PERMISSION_DENIED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ErrorCode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ERROR_CODE_NONE" => Ok(ErrorCode::None),
+                    "ERROR_CODE_DECODE" => Ok(ErrorCode::Decode),
+                    "ERROR_CODE_UNKNOWN_COMMAND" => Ok(ErrorCode::UnknownCommand),
+                    "ERROR_CODE_INVALID_ARGUMENT" => Ok(ErrorCode::InvalidArgument),
+                    "ERROR_CODE_NOT_FOUND" => Ok(ErrorCode::NotFound),
+                    "ERROR_CODE_ALREADY_EXISTS" => Ok(ErrorCode::AlreadyExists),
+                    "ERROR_CODE_TEXTURE_NOT_FOUND" => Ok(ErrorCode::TextureNotFound),
+                    "ERROR_CODE_VERSION_MISMATCH" => Ok(ErrorCode::VersionMismatch),
+                    "ERROR_CODE_UNSUPPORTED_FEATURE" => Ok(ErrorCode::UnsupportedFeature),
+                    "ERROR_CODE_MESSAGE_TOO_LARGE" => Ok(ErrorCode::MessageTooLarge),
+                    "ERROR_CODE_PERMISSION_DENIED" => Ok(ErrorCode::PermissionDenied),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Event {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.timestamp_us != 0 {
+            len += 1;
+        }
+        if self.kind.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Event", len)?;
+        if self.timestamp_us != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestampUs", ToString::to_string(&self.timestamp_us).as_str())?;
+        }
+        if let Some(v) = self.kind.as_ref() {
+            match v {
+                event::Kind::Input(v) => {
+                    struct_ser.serialize_field("input", v)?;
+                }
+                event::Kind::FrameDrop(v) => {
+                    struct_ser.serialize_field("frameDrop", v)?;
+                }
+                event::Kind::Onset(v) => {
+                    struct_ser.serialize_field("onset", v)?;
+                }
+                event::Kind::Offset(v) => {
+                    struct_ser.serialize_field("offset", v)?;
+                }
+                event::Kind::SurfaceLost(v) => {
+                    struct_ser.serialize_field("surfaceLost", v)?;
+                }
+                event::Kind::Resize(v) => {
+                    struct_ser.serialize_field("resize", v)?;
+                }
+                event::Kind::CommandFailed(v) => {
+                    struct_ser.serialize_field("commandFailed", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Event {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timestamp_us",
+            "timestampUs",
+            "input",
+            "frame_drop",
+            "frameDrop",
+            "onset",
+            "offset",
+            "surface_lost",
+            "surfaceLost",
+            "resize",
+            "command_failed",
+            "commandFailed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TimestampUs,
+            Input,
+            FrameDrop,
+            Onset,
+            Offset,
+            SurfaceLost,
+            Resize,
+            CommandFailed,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timestampUs" | "timestamp_us" => Ok(GeneratedField::TimestampUs),
+                            "input" => Ok(GeneratedField::Input),
+                            "frameDrop" | "frame_drop" => Ok(GeneratedField::FrameDrop),
+                            "onset" => Ok(GeneratedField::Onset),
+                            "offset" => Ok(GeneratedField::Offset),
+                            "surfaceLost" | "surface_lost" => Ok(GeneratedField::SurfaceLost),
+                            "resize" => Ok(GeneratedField::Resize),
+                            "commandFailed" | "command_failed" => Ok(GeneratedField::CommandFailed),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Event;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Event")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Event, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timestamp_us__ = None;
+                let mut kind__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TimestampUs => {
+                            if timestamp_us__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestampUs"));
+                            }
+                            timestamp_us__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Input => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::Input)
+;
+                        }
+                        GeneratedField::FrameDrop => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frameDrop"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::FrameDrop)
+;
+                        }
+                        GeneratedField::Onset => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("onset"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::Onset)
+;
+                        }
+                        GeneratedField::Offset => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("offset"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::Offset)
+;
+                        }
+                        GeneratedField::SurfaceLost => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("surfaceLost"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::SurfaceLost)
+;
+                        }
+                        GeneratedField::Resize => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resize"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::Resize)
+;
+                        }
+                        GeneratedField::CommandFailed => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commandFailed"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(event::Kind::CommandFailed)
+;
+                        }
+                    }
+                }
+                Ok(Event {
+                    timestamp_us: timestamp_us__.unwrap_or_default(),
+                    kind: kind__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Event", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "EVENT_KIND_UNSPECIFIED",
+            Self::Input => "EVENT_KIND_INPUT",
+            Self::FrameDrop => "EVENT_KIND_FRAME_DROP",
+            Self::Onset => "EVENT_KIND_ONSET",
+            Self::Offset => "EVENT_KIND_OFFSET",
+            Self::SurfaceLost => "EVENT_KIND_SURFACE_LOST",
+            Self::Resize => "EVENT_KIND_RESIZE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "EVENT_KIND_UNSPECIFIED",
+            "EVENT_KIND_INPUT",
+            "EVENT_KIND_FRAME_DROP",
+            "EVENT_KIND_ONSET",
+            "EVENT_KIND_OFFSET",
+            "EVENT_KIND_SURFACE_LOST",
+            "EVENT_KIND_RESIZE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "EVENT_KIND_UNSPECIFIED" => Ok(EventKind::Unspecified),
+                    "EVENT_KIND_INPUT" => Ok(EventKind::Input),
+                    "EVENT_KIND_FRAME_DROP" => Ok(EventKind::FrameDrop),
+                    "EVENT_KIND_ONSET" => Ok(EventKind::Onset),
+                    "EVENT_KIND_OFFSET" => Ok(EventKind::Offset),
+                    "EVENT_KIND_SURFACE_LOST" => Ok(EventKind::SurfaceLost),
+                    "EVENT_KIND_RESIZE" => Ok(EventKind::Resize),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FrameDrop {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.frame != 0 {
+            len += 1;
+        }
+        if self.interval_us != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.FrameDrop", len)?;
+        if self.frame != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("frame", ToString::to_string(&self.frame).as_str())?;
+        }
+        if self.interval_us != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("intervalUs", ToString::to_string(&self.interval_us).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FrameDrop {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "frame",
+            "interval_us",
+            "intervalUs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Frame,
+            IntervalUs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "frame" => Ok(GeneratedField::Frame),
+                            "intervalUs" | "interval_us" => Ok(GeneratedField::IntervalUs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FrameDrop;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.FrameDrop")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FrameDrop, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut frame__ = None;
+                let mut interval_us__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Frame => {
+                            if frame__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frame"));
+                            }
+                            frame__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IntervalUs => {
+                            if interval_us__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("intervalUs"));
+                            }
+                            interval_us__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(FrameDrop {
+                    frame: frame__.unwrap_or_default(),
+                    interval_us: interval_us__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.FrameDrop", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vsg.GetStatus", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.GetStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(GetStatus {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.GetStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Hello {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.protocol_version != 0 {
+            len += 1;
+        }
+        if !self.features.is_empty() {
+            len += 1;
+        }
+        if !self.agent.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Hello", len)?;
+        if self.protocol_version != 0 {
+            struct_ser.serialize_field("protocolVersion", &self.protocol_version)?;
+        }
+        if !self.features.is_empty() {
+            struct_ser.serialize_field("features", &self.features)?;
+        }
+        if !self.agent.is_empty() {
+            struct_ser.serialize_field("agent", &self.agent)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Hello {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "protocol_version",
+            "protocolVersion",
+            "features",
+            "agent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProtocolVersion,
+            Features,
+            Agent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "protocolVersion" | "protocol_version" => Ok(GeneratedField::ProtocolVersion),
+                            "features" => Ok(GeneratedField::Features),
+                            "agent" => Ok(GeneratedField::Agent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Hello;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Hello")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Hello, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut protocol_version__ = None;
+                let mut features__ = None;
+                let mut agent__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProtocolVersion => {
+                            if protocol_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolVersion"));
+                            }
+                            protocol_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Features => {
+                            if features__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("features"));
+                            }
+                            features__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Agent => {
+                            if agent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("agent"));
+                            }
+                            agent__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Hello {
+                    protocol_version: protocol_version__.unwrap_or_default(),
+                    features: features__.unwrap_or_default(),
+                    agent: agent__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Hello", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InputEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pressed {
+            len += 1;
+        }
+        if self.onset_frame.is_some() {
+            len += 1;
+        }
+        if self.since_onset_us.is_some() {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.InputEvent", len)?;
+        if self.pressed {
+            struct_ser.serialize_field("pressed", &self.pressed)?;
+        }
+        if let Some(v) = self.onset_frame.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("onsetFrame", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.since_onset_us.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sinceOnsetUs", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            match v {
+                input_event::Source::Key(v) => {
+                    struct_ser.serialize_field("key", v)?;
+                }
+                input_event::Source::MouseButton(v) => {
+                    struct_ser.serialize_field("mouseButton", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InputEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pressed",
+            "onset_frame",
+            "onsetFrame",
+            "since_onset_us",
+            "sinceOnsetUs",
+            "key",
+            "mouse_button",
+            "mouseButton",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pressed,
+            OnsetFrame,
+            SinceOnsetUs,
+            Key,
+            MouseButton,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pressed" => Ok(GeneratedField::Pressed),
+                            "onsetFrame" | "onset_frame" => Ok(GeneratedField::OnsetFrame),
+                            "sinceOnsetUs" | "since_onset_us" => Ok(GeneratedField::SinceOnsetUs),
+                            "key" => Ok(GeneratedField::Key),
+                            "mouseButton" | "mouse_button" => Ok(GeneratedField::MouseButton),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InputEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.InputEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InputEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pressed__ = None;
+                let mut onset_frame__ = None;
+                let mut since_onset_us__ = None;
+                let mut source__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pressed => {
+                            if pressed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pressed"));
+                            }
+                            pressed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OnsetFrame => {
+                            if onset_frame__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("onsetFrame"));
+                            }
+                            onset_frame__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::SinceOnsetUs => {
+                            if since_onset_us__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sinceOnsetUs"));
+                            }
+                            since_onset_us__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Key => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            source__ = map_.next_value::<::std::option::Option<_>>()?.map(input_event::Source::Key)
+;
+                        }
+                        GeneratedField::MouseButton => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mouseButton"));
+                            }
+                            source__ = map_.next_value::<::std::option::Option<_>>()?.map(input_event::Source::MouseButton)
+;
+                        }
+                    }
+                }
+                Ok(InputEvent {
+                    pressed: pressed__.unwrap_or_default(),
+                    onset_frame: onset_frame__,
+                    since_onset_us: since_onset_us__,
+                    source: source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.InputEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Key {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.scancode != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Key", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.scancode != 0 {
+            struct_ser.serialize_field("scancode", &self.scancode)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Key {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "scancode",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Scancode,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "scancode" => Ok(GeneratedField::Scancode),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Key;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Key")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Key, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut scancode__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Scancode => {
+                            if scancode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scancode"));
+                            }
+                            scancode__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Key {
+                    name: name__.unwrap_or_default(),
+                    scancode: scancode__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Key", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Monitor {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.width != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.x != 0 {
+            len += 1;
+        }
+        if self.y != 0 {
+            len += 1;
+        }
+        if self.scale_factor != 0. {
+            len += 1;
+        }
+        if self.refresh_rate_millihertz != 0 {
+            len += 1;
+        }
+        if self.current {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Monitor", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.width != 0 {
+            struct_ser.serialize_field("width", &self.width)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", &self.height)?;
+        }
+        if self.x != 0 {
+            struct_ser.serialize_field("x", &self.x)?;
+        }
+        if self.y != 0 {
+            struct_ser.serialize_field("y", &self.y)?;
+        }
+        if self.scale_factor != 0. {
+            struct_ser.serialize_field("scaleFactor", &self.scale_factor)?;
+        }
+        if self.refresh_rate_millihertz != 0 {
+            struct_ser.serialize_field("refreshRateMillihertz", &self.refresh_rate_millihertz)?;
+        }
+        if self.current {
+            struct_ser.serialize_field("current", &self.current)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Monitor {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "width",
+            "height",
+            "x",
+            "y",
+            "scale_factor",
+            "scaleFactor",
+            "refresh_rate_millihertz",
+            "refreshRateMillihertz",
+            "current",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Width,
+            Height,
+            X,
+            Y,
+            ScaleFactor,
+            RefreshRateMillihertz,
+            Current,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "width" => Ok(GeneratedField::Width),
+                            "height" => Ok(GeneratedField::Height),
+                            "x" => Ok(GeneratedField::X),
+                            "y" => Ok(GeneratedField::Y),
+                            "scaleFactor" | "scale_factor" => Ok(GeneratedField::ScaleFactor),
+                            "refreshRateMillihertz" | "refresh_rate_millihertz" => Ok(GeneratedField::RefreshRateMillihertz),
+                            "current" => Ok(GeneratedField::Current),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Monitor;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Monitor")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Monitor, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut width__ = None;
+                let mut height__ = None;
+                let mut x__ = None;
+                let mut y__ = None;
+                let mut scale_factor__ = None;
+                let mut refresh_rate_millihertz__ = None;
+                let mut current__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Width => {
+                            if width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            width__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::X => {
+                            if x__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("x"));
+                            }
+                            x__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Y => {
+                            if y__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y"));
+                            }
+                            y__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScaleFactor => {
+                            if scale_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scaleFactor"));
+                            }
+                            scale_factor__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RefreshRateMillihertz => {
+                            if refresh_rate_millihertz__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refreshRateMillihertz"));
+                            }
+                            refresh_rate_millihertz__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Current => {
+                            if current__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("current"));
+                            }
+                            current__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Monitor {
+                    name: name__.unwrap_or_default(),
+                    width: width__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    x: x__.unwrap_or_default(),
+                    y: y__.unwrap_or_default(),
+                    scale_factor: scale_factor__.unwrap_or_default(),
+                    refresh_rate_millihertz: refresh_rate_millihertz__.unwrap_or_default(),
+                    current: current__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Monitor", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MouseButton {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.MouseButton", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MouseButton {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "position",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Position,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "position" => Ok(GeneratedField::Position),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MouseButton;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.MouseButton")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MouseButton, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(MouseButton {
+                    name: name__.unwrap_or_default(),
+                    position: position__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.MouseButton", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Onset {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.frame != 0 {
+            len += 1;
+        }
+        if self.timestamp_us != 0 {
+            len += 1;
+        }
+        if !self.offsets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Onset", len)?;
+        if self.frame != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("frame", ToString::to_string(&self.frame).as_str())?;
+        }
+        if self.timestamp_us != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestampUs", ToString::to_string(&self.timestamp_us).as_str())?;
+        }
+        if !self.offsets.is_empty() {
+            struct_ser.serialize_field("offsets", &self.offsets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Onset {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "frame",
+            "timestamp_us",
+            "timestampUs",
+            "offsets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Frame,
+            TimestampUs,
+            Offsets,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "frame" => Ok(GeneratedField::Frame),
+                            "timestampUs" | "timestamp_us" => Ok(GeneratedField::TimestampUs),
+                            "offsets" => Ok(GeneratedField::Offsets),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Onset;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Onset")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Onset, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut frame__ = None;
+                let mut timestamp_us__ = None;
+                let mut offsets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Frame => {
+                            if frame__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frame"));
+                            }
+                            frame__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TimestampUs => {
+                            if timestamp_us__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestampUs"));
+                            }
+                            timestamp_us__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Offsets => {
+                            if offsets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("offsets"));
+                            }
+                            offsets__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Onset {
+                    frame: frame__.unwrap_or_default(),
+                    timestamp_us: timestamp_us__.unwrap_or_default(),
+                    offsets: offsets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Onset", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PixelFormat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Rgba8 => "PIXEL_FORMAT_RGBA8",
+            Self::R8 => "PIXEL_FORMAT_R8",
+            Self::R16 => "PIXEL_FORMAT_R16",
+            Self::Rgba32f => "PIXEL_FORMAT_RGBA32F",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for PixelFormat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "PIXEL_FORMAT_RGBA8",
+            "PIXEL_FORMAT_R8",
+            "PIXEL_FORMAT_R16",
+            "PIXEL_FORMAT_RGBA32F",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PixelFormat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "PIXEL_FORMAT_RGBA8" => Ok(PixelFormat::Rgba8),
+                    "PIXEL_FORMAT_R8" => Ok(PixelFormat::R8),
+                    "PIXEL_FORMAT_R16" => Ok(PixelFormat::R16),
+                    "PIXEL_FORMAT_RGBA32F" => Ok(PixelFormat::Rgba32f),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolVersion {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "PROTOCOL_VERSION_UNSPECIFIED",
+            Self::Current => "PROTOCOL_VERSION_CURRENT",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolVersion {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "PROTOCOL_VERSION_UNSPECIFIED",
+            "PROTOCOL_VERSION_CURRENT",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolVersion;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "PROTOCOL_VERSION_UNSPECIFIED" => Ok(ProtocolVersion::Unspecified),
+                    "PROTOCOL_VERSION_CURRENT" => Ok(ProtocolVersion::Current),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RawPixels {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.width != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.format != 0 {
+            len += 1;
+        }
+        if self.stride != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.RawPixels", len)?;
+        if self.width != 0 {
+            struct_ser.serialize_field("width", &self.width)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", &self.height)?;
+        }
+        if self.format != 0 {
+            let v = PixelFormat::try_from(self.format)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        if self.stride != 0 {
+            struct_ser.serialize_field("stride", &self.stride)?;
+        }
+        if !self.data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RawPixels {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "width",
+            "height",
+            "format",
+            "stride",
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Width,
+            Height,
+            Format,
+            Stride,
+            Data,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "width" => Ok(GeneratedField::Width),
+                            "height" => Ok(GeneratedField::Height),
+                            "format" => Ok(GeneratedField::Format),
+                            "stride" => Ok(GeneratedField::Stride),
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RawPixels;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.RawPixels")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RawPixels, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut width__ = None;
+                let mut height__ = None;
+                let mut format__ = None;
+                let mut stride__ = None;
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Width => {
+                            if width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            width__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map_.next_value::<PixelFormat>()? as i32);
+                        }
+                        GeneratedField::Stride => {
+                            if stride__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stride"));
+                            }
+                            stride__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RawPixels {
+                    width: width__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    format: format__.unwrap_or_default(),
+                    stride: stride__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.RawPixels", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RemoveStimulus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.RemoveStimulus", len)?;
+        if self.id != 0 {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RemoveStimulus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RemoveStimulus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.RemoveStimulus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RemoveStimulus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RemoveStimulus {
+                    id: id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.RemoveStimulus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Resize {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.width != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.scale_factor != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Resize", len)?;
+        if self.width != 0 {
+            struct_ser.serialize_field("width", &self.width)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", &self.height)?;
+        }
+        if self.scale_factor != 0. {
+            struct_ser.serialize_field("scaleFactor", &self.scale_factor)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Resize {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "width",
+            "height",
+            "scale_factor",
+            "scaleFactor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Width,
+            Height,
+            ScaleFactor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "width" => Ok(GeneratedField::Width),
+                            "height" => Ok(GeneratedField::Height),
+                            "scaleFactor" | "scale_factor" => Ok(GeneratedField::ScaleFactor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Resize;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Resize")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Resize, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut width__ = None;
+                let mut height__ = None;
+                let mut scale_factor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Width => {
+                            if width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            width__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScaleFactor => {
+                            if scale_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scaleFactor"));
+                            }
+                            scale_factor__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Resize {
+                    width: width__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    scale_factor: scale_factor__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Resize", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RootMessage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.flip_ack {
+            len += 1;
+        }
+        if self.schedule.is_some() {
+            len += 1;
+        }
+        if self.command.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.RootMessage", len)?;
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if self.flip_ack {
+            struct_ser.serialize_field("flipAck", &self.flip_ack)?;
+        }
+        if let Some(v) = self.schedule.as_ref() {
+            struct_ser.serialize_field("schedule", v)?;
+        }
+        if let Some(v) = self.command.as_ref() {
+            match v {
+                root_message::Command::SetShape(v) => {
+                    struct_ser.serialize_field("setShape", v)?;
+                }
+                root_message::Command::SetBgColor(v) => {
+                    struct_ser.serialize_field("setBgColor", v)?;
+                }
+                root_message::Command::SetTexture(v) => {
+                    struct_ser.serialize_field("setTexture", v)?;
+                }
+                root_message::Command::AddStimulus(v) => {
+                    struct_ser.serialize_field("addStimulus", v)?;
+                }
+                root_message::Command::UpdateStimulus(v) => {
+                    struct_ser.serialize_field("updateStimulus", v)?;
+                }
+                root_message::Command::SetVisibility(v) => {
+                    struct_ser.serialize_field("setVisibility", v)?;
+                }
+                root_message::Command::RemoveStimulus(v) => {
+                    struct_ser.serialize_field("removeStimulus", v)?;
+                }
+                root_message::Command::SetScene(v) => {
+                    struct_ser.serialize_field("setScene", v)?;
+                }
+                root_message::Command::PreloadTexture(v) => {
+                    struct_ser.serialize_field("preloadTexture", v)?;
+                }
+                root_message::Command::ShowTexture(v) => {
+                    struct_ser.serialize_field("showTexture", v)?;
+                }
+                root_message::Command::HideTexture(v) => {
+                    struct_ser.serialize_field("hideTexture", v)?;
+                }
+                root_message::Command::DeleteTexture(v) => {
+                    struct_ser.serialize_field("deleteTexture", v)?;
+                }
+                root_message::Command::GetStatus(v) => {
+                    struct_ser.serialize_field("getStatus", v)?;
+                }
+                root_message::Command::Hello(v) => {
+                    struct_ser.serialize_field("hello", v)?;
+                }
+                root_message::Command::Subscribe(v) => {
+                    struct_ser.serialize_field("subscribe", v)?;
+                }
+                root_message::Command::CaptureFrame(v) => {
+                    struct_ser.serialize_field("captureFrame", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RootMessage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sequence",
+            "flip_ack",
+            "flipAck",
+            "schedule",
+            "set_shape",
+            "setShape",
+            "set_bg_color",
+            "setBgColor",
+            "set_texture",
+            "setTexture",
+            "add_stimulus",
+            "addStimulus",
+            "update_stimulus",
+            "updateStimulus",
+            "set_visibility",
+            "setVisibility",
+            "remove_stimulus",
+            "removeStimulus",
+            "set_scene",
+            "setScene",
+            "preload_texture",
+            "preloadTexture",
+            "show_texture",
+            "showTexture",
+            "hide_texture",
+            "hideTexture",
+            "delete_texture",
+            "deleteTexture",
+            "get_status",
+            "getStatus",
+            "hello",
+            "subscribe",
+            "capture_frame",
+            "captureFrame",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Sequence,
+            FlipAck,
+            Schedule,
+            SetShape,
+            SetBgColor,
+            SetTexture,
+            AddStimulus,
+            UpdateStimulus,
+            SetVisibility,
+            RemoveStimulus,
+            SetScene,
+            PreloadTexture,
+            ShowTexture,
+            HideTexture,
+            DeleteTexture,
+            GetStatus,
+            Hello,
+            Subscribe,
+            CaptureFrame,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "flipAck" | "flip_ack" => Ok(GeneratedField::FlipAck),
+                            "schedule" => Ok(GeneratedField::Schedule),
+                            "setShape" | "set_shape" => Ok(GeneratedField::SetShape),
+                            "setBgColor" | "set_bg_color" => Ok(GeneratedField::SetBgColor),
+                            "setTexture" | "set_texture" => Ok(GeneratedField::SetTexture),
+                            "addStimulus" | "add_stimulus" => Ok(GeneratedField::AddStimulus),
+                            "updateStimulus" | "update_stimulus" => Ok(GeneratedField::UpdateStimulus),
+                            "setVisibility" | "set_visibility" => Ok(GeneratedField::SetVisibility),
+                            "removeStimulus" | "remove_stimulus" => Ok(GeneratedField::RemoveStimulus),
+                            "setScene" | "set_scene" => Ok(GeneratedField::SetScene),
+                            "preloadTexture" | "preload_texture" => Ok(GeneratedField::PreloadTexture),
+                            "showTexture" | "show_texture" => Ok(GeneratedField::ShowTexture),
+                            "hideTexture" | "hide_texture" => Ok(GeneratedField::HideTexture),
+                            "deleteTexture" | "delete_texture" => Ok(GeneratedField::DeleteTexture),
+                            "getStatus" | "get_status" => Ok(GeneratedField::GetStatus),
+                            "hello" => Ok(GeneratedField::Hello),
+                            "subscribe" => Ok(GeneratedField::Subscribe),
+                            "captureFrame" | "capture_frame" => Ok(GeneratedField::CaptureFrame),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RootMessage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.RootMessage")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RootMessage, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sequence__ = None;
+                let mut flip_ack__ = None;
+                let mut schedule__ = None;
+                let mut command__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FlipAck => {
+                            if flip_ack__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flipAck"));
+                            }
+                            flip_ack__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Schedule => {
+                            if schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schedule"));
+                            }
+                            schedule__ = map_.next_value()?;
+                        }
+                        GeneratedField::SetShape => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setShape"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::SetShape)
+;
+                        }
+                        GeneratedField::SetBgColor => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setBgColor"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::SetBgColor)
+;
+                        }
+                        GeneratedField::SetTexture => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setTexture"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::SetTexture)
+;
+                        }
+                        GeneratedField::AddStimulus => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addStimulus"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::AddStimulus)
+;
+                        }
+                        GeneratedField::UpdateStimulus => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateStimulus"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::UpdateStimulus)
+;
+                        }
+                        GeneratedField::SetVisibility => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setVisibility"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::SetVisibility)
+;
+                        }
+                        GeneratedField::RemoveStimulus => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removeStimulus"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::RemoveStimulus)
+;
+                        }
+                        GeneratedField::SetScene => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setScene"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::SetScene)
+;
+                        }
+                        GeneratedField::PreloadTexture => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preloadTexture"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::PreloadTexture)
+;
+                        }
+                        GeneratedField::ShowTexture => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("showTexture"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::ShowTexture)
+;
+                        }
+                        GeneratedField::HideTexture => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hideTexture"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::HideTexture)
+;
+                        }
+                        GeneratedField::DeleteTexture => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deleteTexture"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::DeleteTexture)
+;
+                        }
+                        GeneratedField::GetStatus => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("getStatus"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::GetStatus)
+;
+                        }
+                        GeneratedField::Hello => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hello"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::Hello)
+;
+                        }
+                        GeneratedField::Subscribe => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subscribe"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::Subscribe)
+;
+                        }
+                        GeneratedField::CaptureFrame => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("captureFrame"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(root_message::Command::CaptureFrame)
+;
+                        }
+                    }
+                }
+                Ok(RootMessage {
+                    sequence: sequence__.unwrap_or_default(),
+                    flip_ack: flip_ack__.unwrap_or_default(),
+                    schedule: schedule__,
+                    command: command__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.RootMessage", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RootResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.error_code != 0 {
+            len += 1;
+        }
+        if !self.error.is_empty() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.onset.is_some() {
+            len += 1;
+        }
+        if self.server_time_us != 0 {
+            len += 1;
+        }
+        if self.display_status.is_some() {
+            len += 1;
+        }
+        if self.hello.is_some() {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        if self.capture.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.RootResponse", len)?;
+        if self.status != 0 {
+            let v = Status::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.error_code != 0 {
+            let v = ErrorCode::try_from(self.error_code)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.error_code)))?;
+            struct_ser.serialize_field("errorCode", &v)?;
+        }
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if let Some(v) = self.onset.as_ref() {
+            struct_ser.serialize_field("onset", v)?;
+        }
+        if self.server_time_us != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("serverTimeUs", ToString::to_string(&self.server_time_us).as_str())?;
+        }
+        if let Some(v) = self.display_status.as_ref() {
+            struct_ser.serialize_field("displayStatus", v)?;
+        }
+        if let Some(v) = self.hello.as_ref() {
+            struct_ser.serialize_field("hello", v)?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            struct_ser.serialize_field("event", v)?;
+        }
+        if let Some(v) = self.capture.as_ref() {
+            struct_ser.serialize_field("capture", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RootResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "status",
+            "error_code",
+            "errorCode",
+            "error",
+            "sequence",
+            "onset",
+            "server_time_us",
+            "serverTimeUs",
+            "display_status",
+            "displayStatus",
+            "hello",
+            "event",
+            "capture",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Status,
+            ErrorCode,
+            Error,
+            Sequence,
+            Onset,
+            ServerTimeUs,
+            DisplayStatus,
+            Hello,
+            Event,
+            Capture,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "status" => Ok(GeneratedField::Status),
+                            "errorCode" | "error_code" => Ok(GeneratedField::ErrorCode),
+                            "error" => Ok(GeneratedField::Error),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "onset" => Ok(GeneratedField::Onset),
+                            "serverTimeUs" | "server_time_us" => Ok(GeneratedField::ServerTimeUs),
+                            "displayStatus" | "display_status" => Ok(GeneratedField::DisplayStatus),
+                            "hello" => Ok(GeneratedField::Hello),
+                            "event" => Ok(GeneratedField::Event),
+                            "capture" => Ok(GeneratedField::Capture),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RootResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.RootResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RootResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut status__ = None;
+                let mut error_code__ = None;
+                let mut error__ = None;
+                let mut sequence__ = None;
+                let mut onset__ = None;
+                let mut server_time_us__ = None;
+                let mut display_status__ = None;
+                let mut hello__ = None;
+                let mut event__ = None;
+                let mut capture__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<Status>()? as i32);
+                        }
+                        GeneratedField::ErrorCode => {
+                            if error_code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errorCode"));
+                            }
+                            error_code__ = Some(map_.next_value::<ErrorCode>()? as i32);
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Onset => {
+                            if onset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("onset"));
+                            }
+                            onset__ = map_.next_value()?;
+                        }
+                        GeneratedField::ServerTimeUs => {
+                            if server_time_us__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("serverTimeUs"));
+                            }
+                            server_time_us__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DisplayStatus => {
+                            if display_status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("displayStatus"));
+                            }
+                            display_status__ = map_.next_value()?;
+                        }
+                        GeneratedField::Hello => {
+                            if hello__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hello"));
+                            }
+                            hello__ = map_.next_value()?;
+                        }
+                        GeneratedField::Event => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("event"));
+                            }
+                            event__ = map_.next_value()?;
+                        }
+                        GeneratedField::Capture => {
+                            if capture__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("capture"));
+                            }
+                            capture__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RootResponse {
+                    status: status__.unwrap_or_default(),
+                    error_code: error_code__.unwrap_or_default(),
+                    error: error__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                    onset: onset__,
+                    server_time_us: server_time_us__.unwrap_or_default(),
+                    display_status: display_status__,
+                    hello: hello__,
+                    event: event__,
+                    capture: capture__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.RootResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Schedule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.target.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Schedule", len)?;
+        if let Some(v) = self.target.as_ref() {
+            match v {
+                schedule::Target::Frame(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("frame", ToString::to_string(&v).as_str())?;
+                }
+                schedule::Target::DeadlineUs(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("deadlineUs", ToString::to_string(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Schedule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "frame",
+            "deadline_us",
+            "deadlineUs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Frame,
+            DeadlineUs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "frame" => Ok(GeneratedField::Frame),
+                            "deadlineUs" | "deadline_us" => Ok(GeneratedField::DeadlineUs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Schedule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Schedule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Schedule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut target__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Frame => {
+                            if target__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frame"));
+                            }
+                            target__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| schedule::Target::Frame(x.0));
+                        }
+                        GeneratedField::DeadlineUs => {
+                            if target__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deadlineUs"));
+                            }
+                            target__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| schedule::Target::DeadlineUs(x.0));
+                        }
+                    }
+                }
+                Ok(Schedule {
+                    target: target__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Schedule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetScene {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.stimuli.is_empty() {
+            len += 1;
+        }
+        if !self.textures.is_empty() {
+            len += 1;
+        }
+        if self.bg_color.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.SetScene", len)?;
+        if !self.stimuli.is_empty() {
+            struct_ser.serialize_field("stimuli", &self.stimuli)?;
+        }
+        if !self.textures.is_empty() {
+            struct_ser.serialize_field("textures", &self.textures)?;
+        }
+        if let Some(v) = self.bg_color.as_ref() {
+            struct_ser.serialize_field("bgColor", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetScene {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "stimuli",
+            "textures",
+            "bg_color",
+            "bgColor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Stimuli,
+            Textures,
+            BgColor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "stimuli" => Ok(GeneratedField::Stimuli),
+                            "textures" => Ok(GeneratedField::Textures),
+                            "bgColor" | "bg_color" => Ok(GeneratedField::BgColor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetScene;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.SetScene")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetScene, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut stimuli__ = None;
+                let mut textures__ = None;
+                let mut bg_color__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Stimuli => {
+                            if stimuli__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stimuli"));
+                            }
+                            stimuli__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Textures => {
+                            if textures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("textures"));
+                            }
+                            textures__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BgColor => {
+                            if bg_color__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bgColor"));
+                            }
+                            bg_color__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SetScene {
+                    stimuli: stimuli__.unwrap_or_default(),
+                    textures: textures__.unwrap_or_default(),
+                    bg_color: bg_color__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.SetScene", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetVisibility {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.visible {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.SetVisibility", len)?;
+        if self.id != 0 {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.visible {
+            struct_ser.serialize_field("visible", &self.visible)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetVisibility {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "visible",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Visible,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "visible" => Ok(GeneratedField::Visible),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetVisibility;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.SetVisibility")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetVisibility, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut visible__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Visible => {
+                            if visible__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visible"));
+                            }
+                            visible__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SetVisibility {
+                    id: id__.unwrap_or_default(),
+                    visible: visible__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.SetVisibility", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Shape {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.shape.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Shape", len)?;
+        if let Some(v) = self.shape.as_ref() {
+            match v {
+                shape::Shape::Square(v) => {
+                    struct_ser.serialize_field("square", v)?;
+                }
+                shape::Shape::Circle(v) => {
+                    struct_ser.serialize_field("circle", v)?;
+                }
+                shape::Shape::Cross(v) => {
+                    struct_ser.serialize_field("cross", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Shape {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "square",
+            "circle",
+            "cross",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Square,
+            Circle,
+            Cross,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "square" => Ok(GeneratedField::Square),
+                            "circle" => Ok(GeneratedField::Circle),
+                            "cross" => Ok(GeneratedField::Cross),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Shape;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Shape")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Shape, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut shape__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Square => {
+                            if shape__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("square"));
+                            }
+                            shape__ = map_.next_value::<::std::option::Option<_>>()?.map(shape::Shape::Square)
+;
+                        }
+                        GeneratedField::Circle => {
+                            if shape__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("circle"));
+                            }
+                            shape__ = map_.next_value::<::std::option::Option<_>>()?.map(shape::Shape::Circle)
+;
+                        }
+                        GeneratedField::Cross => {
+                            if shape__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cross"));
+                            }
+                            shape__ = map_.next_value::<::std::option::Option<_>>()?.map(shape::Shape::Cross)
+;
+                        }
+                    }
+                }
+                Ok(Shape {
+                    shape: shape__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Shape", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Square {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.size != 0. {
+            len += 1;
+        }
+        if self.ctr.is_some() {
+            len += 1;
+        }
+        if self.color.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Square", len)?;
+        if self.size != 0. {
+            struct_ser.serialize_field("size", &self.size)?;
+        }
+        if let Some(v) = self.ctr.as_ref() {
+            struct_ser.serialize_field("ctr", v)?;
+        }
+        if let Some(v) = self.color.as_ref() {
+            struct_ser.serialize_field("color", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Square {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "size",
+            "ctr",
+            "color",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Size,
+            Ctr,
+            Color,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "size" => Ok(GeneratedField::Size),
+                            "ctr" => Ok(GeneratedField::Ctr),
+                            "color" => Ok(GeneratedField::Color),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Square;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Square")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Square, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut size__ = None;
+                let mut ctr__ = None;
+                let mut color__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Ctr => {
+                            if ctr__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ctr"));
+                            }
+                            ctr__ = map_.next_value()?;
+                        }
+                        GeneratedField::Color => {
+                            if color__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("color"));
+                            }
+                            color__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Square {
+                    size: size__.unwrap_or_default(),
+                    ctr: ctr__,
+                    color: color__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Square", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Status {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Ok => "STATUS_OK",
+            Self::Error => "STATUS_ERROR",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Status {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_OK",
+            "STATUS_ERROR",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Status;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_OK" => Ok(Status::Ok),
+                    "STATUS_ERROR" => Ok(Status::Error),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Stimulus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.shape.is_some() {
+            len += 1;
+        }
+        if self.hidden {
+            len += 1;
+        }
+        if self.lifetime.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Stimulus", len)?;
+        if self.id != 0 {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if let Some(v) = self.shape.as_ref() {
+            struct_ser.serialize_field("shape", v)?;
+        }
+        if self.hidden {
+            struct_ser.serialize_field("hidden", &self.hidden)?;
+        }
+        if let Some(v) = self.lifetime.as_ref() {
+            match v {
+                stimulus::Lifetime::DurationFrames(v) => {
+                    struct_ser.serialize_field("durationFrames", v)?;
+                }
+                stimulus::Lifetime::DurationMs(v) => {
+                    struct_ser.serialize_field("durationMs", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Stimulus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "shape",
+            "hidden",
+            "duration_frames",
+            "durationFrames",
+            "duration_ms",
+            "durationMs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Shape,
+            Hidden,
+            DurationFrames,
+            DurationMs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "shape" => Ok(GeneratedField::Shape),
+                            "hidden" => Ok(GeneratedField::Hidden),
+                            "durationFrames" | "duration_frames" => Ok(GeneratedField::DurationFrames),
+                            "durationMs" | "duration_ms" => Ok(GeneratedField::DurationMs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Stimulus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Stimulus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Stimulus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut shape__ = None;
+                let mut hidden__ = None;
+                let mut lifetime__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Shape => {
+                            if shape__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shape"));
+                            }
+                            shape__ = map_.next_value()?;
+                        }
+                        GeneratedField::Hidden => {
+                            if hidden__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hidden"));
+                            }
+                            hidden__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DurationFrames => {
+                            if lifetime__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("durationFrames"));
+                            }
+                            lifetime__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| stimulus::Lifetime::DurationFrames(x.0));
+                        }
+                        GeneratedField::DurationMs => {
+                            if lifetime__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("durationMs"));
+                            }
+                            lifetime__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| stimulus::Lifetime::DurationMs(x.0));
+                        }
+                    }
+                }
+                Ok(Stimulus {
+                    id: id__.unwrap_or_default(),
+                    shape: shape__,
+                    hidden: hidden__.unwrap_or_default(),
+                    lifetime: lifetime__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Stimulus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StimulusOffset {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.frame != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.StimulusOffset", len)?;
+        if self.id != 0 {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.frame != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("frame", ToString::to_string(&self.frame).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StimulusOffset {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "frame",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Frame,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "frame" => Ok(GeneratedField::Frame),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StimulusOffset;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.StimulusOffset")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StimulusOffset, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut frame__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Frame => {
+                            if frame__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frame"));
+                            }
+                            frame__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(StimulusOffset {
+                    id: id__.unwrap_or_default(),
+                    frame: frame__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.StimulusOffset", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Subscribe {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.kinds.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Subscribe", len)?;
+        if !self.kinds.is_empty() {
+            let v = self.kinds.iter().cloned().map(|v| {
+                EventKind::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("kinds", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Subscribe {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "kinds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Kinds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "kinds" => Ok(GeneratedField::Kinds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Subscribe;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Subscribe")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Subscribe, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut kinds__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Kinds => {
+                            if kinds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kinds"));
+                            }
+                            kinds__ = Some(map_.next_value::<Vec<EventKind>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                    }
+                }
+                Ok(Subscribe {
+                    kinds: kinds__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Subscribe", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SurfaceLost {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.SurfaceLost", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SurfaceLost {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SurfaceLost;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.SurfaceLost")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SurfaceLost, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SurfaceLost {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.SurfaceLost", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Texture {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.index != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.Texture", len)?;
+        if self.index != 0 {
+            struct_ser.serialize_field("index", &self.index)?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            match v {
+                texture::Source::Data(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("data", pbjson::private::base64::encode(&v).as_str())?;
+                }
+                texture::Source::Raw(v) => {
+                    struct_ser.serialize_field("raw", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Texture {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "index",
+            "data",
+            "raw",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Index,
+            Data,
+            Raw,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "index" => Ok(GeneratedField::Index),
+                            "data" => Ok(GeneratedField::Data),
+                            "raw" => Ok(GeneratedField::Raw),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Texture;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.Texture")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Texture, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut index__ = None;
+                let mut source__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Data => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            source__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| texture::Source::Data(x.0));
+                        }
+                        GeneratedField::Raw => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("raw"));
+                            }
+                            source__ = map_.next_value::<::std::option::Option<_>>()?.map(texture::Source::Raw)
+;
+                        }
+                    }
+                }
+                Ok(Texture {
+                    index: index__.unwrap_or_default(),
+                    source: source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.Texture", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TextureIndex {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.TextureIndex", len)?;
+        if self.index != 0 {
+            struct_ser.serialize_field("index", &self.index)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TextureIndex {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "index",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Index,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "index" => Ok(GeneratedField::Index),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TextureIndex;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.TextureIndex")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TextureIndex, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Index => {
+                            if index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("index"));
+                            }
+                            index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TextureIndex {
+                    index: index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.TextureIndex", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateStimulus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.shape.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vsg.UpdateStimulus", len)?;
+        if self.id != 0 {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if let Some(v) = self.shape.as_ref() {
+            struct_ser.serialize_field("shape", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateStimulus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "shape",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Shape,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "shape" => Ok(GeneratedField::Shape),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateStimulus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vsg.UpdateStimulus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateStimulus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut shape__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Shape => {
+                            if shape__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shape"));
+                            }
+                            shape__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateStimulus {
+                    id: id__.unwrap_or_default(),
+                    shape: shape__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vsg.UpdateStimulus", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,865 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.Capture}
+ */
+public final class Capture extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Capture)
+    CaptureOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Capture.newBuilder() to construct.
+  private Capture(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Capture() {
+    format_ = 0;
+    data_ = com.google.protobuf.ByteString.EMPTY;
+    path_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Capture();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Capture(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            width_ = input.readUInt32();
+            break;
+          }
+          case 16: {
+
+            height_ = input.readUInt32();
+            break;
+          }
+          case 24: {
+            int rawValue = input.readEnum();
+
+            format_ = rawValue;
+            break;
+          }
+          case 34: {
+
+            data_ = input.readBytes();
+            break;
+          }
+          case 42: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            path_ = s;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_Capture_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_Capture_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.Capture.class, vsg.Capture.Builder.class);
+  }
+
+  public static final int WIDTH_FIELD_NUMBER = 1;
+  private int width_;
+  /**
+   * <code>uint32 width = 1;</code>
+   * @return The width.
+   */
+  @java.lang.Override
+  public int getWidth() {
+    return width_;
+  }
+
+  public static final int HEIGHT_FIELD_NUMBER = 2;
+  private int height_;
+  /**
+   * <code>uint32 height = 2;</code>
+   * @return The height.
+   */
+  @java.lang.Override
+  public int getHeight() {
+    return height_;
+  }
+
+  public static final int FORMAT_FIELD_NUMBER = 3;
+  private int format_;
+  /**
+   * <code>.vsg.CaptureFormat format = 3;</code>
+   * @return The enum numeric value on the wire for format.
+   */
+  @java.lang.Override public int getFormatValue() {
+    return format_;
+  }
+  /**
+   * <code>.vsg.CaptureFormat format = 3;</code>
+   * @return The format.
+   */
+  @java.lang.Override public vsg.CaptureFormat getFormat() {
+    @SuppressWarnings("deprecation")
+    vsg.CaptureFormat result = vsg.CaptureFormat.valueOf(format_);
+    return result == null ? vsg.CaptureFormat.UNRECOGNIZED : result;
+  }
+
+  public static final int DATA_FIELD_NUMBER = 4;
+  private com.google.protobuf.ByteString data_;
+  /**
+   * <pre>
+   * Empty when the image was written to a file.
+   * </pre>
+   *
+   * <code>bytes data = 4;</code>
+   * @return The data.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString getData() {
+    return data_;
+  }
+
+  public static final int PATH_FIELD_NUMBER = 5;
+  private volatile java.lang.Object path_;
+  /**
+   * <code>string path = 5;</code>
+   * @return The path.
+   */
+  @java.lang.Override
+  public java.lang.String getPath() {
+    java.lang.Object ref = path_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      path_ = s;
+      return s;
+    }
+  }
+  /**
+   * <code>string path = 5;</code>
+   * @return The bytes for path.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getPathBytes() {
+    java.lang.Object ref = path_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      path_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (width_ != 0) {
+      output.writeUInt32(1, width_);
+    }
+    if (height_ != 0) {
+      output.writeUInt32(2, height_);
+    }
+    if (format_ != vsg.CaptureFormat.CAPTURE_FORMAT_PNG.getNumber()) {
+      output.writeEnum(3, format_);
+    }
+    if (!data_.isEmpty()) {
+      output.writeBytes(4, data_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(path_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 5, path_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (width_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(1, width_);
+    }
+    if (height_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(2, height_);
+    }
+    if (format_ != vsg.CaptureFormat.CAPTURE_FORMAT_PNG.getNumber()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeEnumSize(3, format_);
+    }
+    if (!data_.isEmpty()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeBytesSize(4, data_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(path_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(5, path_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.Capture)) {
+      return super.equals(obj);
+    }
+    vsg.Capture other = (vsg.Capture) obj;
+
+    if (getWidth()
+        != other.getWidth()) return false;
+    if (getHeight()
+        != other.getHeight()) return false;
+    if (format_ != other.format_) return false;
+    if (!getData()
+        .equals(other.getData())) return false;
+    if (!getPath()
+        .equals(other.getPath())) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + WIDTH_FIELD_NUMBER;
+    hash = (53 * hash) + getWidth();
+    hash = (37 * hash) + HEIGHT_FIELD_NUMBER;
+    hash = (53 * hash) + getHeight();
+    hash = (37 * hash) + FORMAT_FIELD_NUMBER;
+    hash = (53 * hash) + format_;
+    hash = (37 * hash) + DATA_FIELD_NUMBER;
+    hash = (53 * hash) + getData().hashCode();
+    hash = (37 * hash) + PATH_FIELD_NUMBER;
+    hash = (53 * hash) + getPath().hashCode();
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.Capture parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Capture parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Capture parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Capture parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Capture parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Capture parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Capture parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Capture parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Capture parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.Capture parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Capture parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Capture parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.Capture prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.Capture}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Capture)
+      vsg.CaptureOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_Capture_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_Capture_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.Capture.class, vsg.Capture.Builder.class);
+    }
+
+    // Construct using vsg.Capture.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      width_ = 0;
+
+      height_ = 0;
+
+      format_ = 0;
+
+      data_ = com.google.protobuf.ByteString.EMPTY;
+
+      path_ = "";
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_Capture_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.Capture getDefaultInstanceForType() {
+      return vsg.Capture.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.Capture build() {
+      vsg.Capture result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.Capture buildPartial() {
+      vsg.Capture result = new vsg.Capture(this);
+      result.width_ = width_;
+      result.height_ = height_;
+      result.format_ = format_;
+      result.data_ = data_;
+      result.path_ = path_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.Capture) {
+        return mergeFrom((vsg.Capture)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.Capture other) {
+      if (other == vsg.Capture.getDefaultInstance()) return this;
+      if (other.getWidth() != 0) {
+        setWidth(other.getWidth());
+      }
+      if (other.getHeight() != 0) {
+        setHeight(other.getHeight());
+      }
+      if (other.format_ != 0) {
+        setFormatValue(other.getFormatValue());
+      }
+      if (other.getData() != com.google.protobuf.ByteString.EMPTY) {
+        setData(other.getData());
+      }
+      if (!other.getPath().isEmpty()) {
+        path_ = other.path_;
+        onChanged();
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.Capture parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.Capture) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private int width_ ;
+    /**
+     * <code>uint32 width = 1;</code>
+     * @return The width.
+     */
+    @java.lang.Override
+    public int getWidth() {
+      return width_;
+    }
+    /**
+     * <code>uint32 width = 1;</code>
+     * @param value The width to set.
+     * @return This builder for chaining.
+     */
+    public Builder setWidth(int value) {
+      
+      width_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 width = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearWidth() {
+      
+      width_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int height_ ;
+    /**
+     * <code>uint32 height = 2;</code>
+     * @return The height.
+     */
+    @java.lang.Override
+    public int getHeight() {
+      return height_;
+    }
+    /**
+     * <code>uint32 height = 2;</code>
+     * @param value The height to set.
+     * @return This builder for chaining.
+     */
+    public Builder setHeight(int value) {
+      
+      height_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 height = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearHeight() {
+      
+      height_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int format_ = 0;
+    /**
+     * <code>.vsg.CaptureFormat format = 3;</code>
+     * @return The enum numeric value on the wire for format.
+     */
+    @java.lang.Override public int getFormatValue() {
+      return format_;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 3;</code>
+     * @param value The enum numeric value on the wire for format to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFormatValue(int value) {
+      
+      format_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 3;</code>
+     * @return The format.
+     */
+    @java.lang.Override
+    public vsg.CaptureFormat getFormat() {
+      @SuppressWarnings("deprecation")
+      vsg.CaptureFormat result = vsg.CaptureFormat.valueOf(format_);
+      return result == null ? vsg.CaptureFormat.UNRECOGNIZED : result;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 3;</code>
+     * @param value The format to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFormat(vsg.CaptureFormat value) {
+      if (value == null) {
+        throw new NullPointerException();
+      }
+      
+      format_ = value.getNumber();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFormat() {
+      
+      format_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private com.google.protobuf.ByteString data_ = com.google.protobuf.ByteString.EMPTY;
+    /**
+     * <pre>
+     * Empty when the image was written to a file.
+     * </pre>
+     *
+     * <code>bytes data = 4;</code>
+     * @return The data.
+     */
+    @java.lang.Override
+    public com.google.protobuf.ByteString getData() {
+      return data_;
+    }
+    /**
+     * <pre>
+     * Empty when the image was written to a file.
+     * </pre>
+     *
+     * <code>bytes data = 4;</code>
+     * @param value The data to set.
+     * @return This builder for chaining.
+     */
+    public Builder setData(com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      data_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Empty when the image was written to a file.
+     * </pre>
+     *
+     * <code>bytes data = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearData() {
+      
+      data_ = getDefaultInstance().getData();
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object path_ = "";
+    /**
+     * <code>string path = 5;</code>
+     * @return The path.
+     */
+    public java.lang.String getPath() {
+      java.lang.Object ref = path_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        path_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <code>string path = 5;</code>
+     * @return The bytes for path.
+     */
+    public com.google.protobuf.ByteString
+        getPathBytes() {
+      java.lang.Object ref = path_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        path_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <code>string path = 5;</code>
+     * @param value The path to set.
+     * @return This builder for chaining.
+     */
+    public Builder setPath(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      path_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string path = 5;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearPath() {
+      
+      path_ = getDefaultInstance().getPath();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string path = 5;</code>
+     * @param value The bytes for path to set.
+     * @return This builder for chaining.
+     */
+    public Builder setPathBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      path_ = value;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Capture)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Capture)
+  private static final vsg.Capture DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.Capture();
+  }
+
+  public static vsg.Capture getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Capture>
+      PARSER = new com.google.protobuf.AbstractParser<Capture>() {
+    @java.lang.Override
+    public Capture parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Capture(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Capture> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Capture> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.Capture getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,121 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf enum {@code vsg.CaptureFormat}
+ */
+public enum CaptureFormat
+    implements com.google.protobuf.ProtocolMessageEnum {
+  /**
+   * <code>CAPTURE_FORMAT_PNG = 0;</code>
+   */
+  CAPTURE_FORMAT_PNG(0),
+  /**
+   * <pre>
+   * 8 bits per channel, rows top to bottom without padding.
+   * </pre>
+   *
+   * <code>CAPTURE_FORMAT_RGBA = 1;</code>
+   */
+  CAPTURE_FORMAT_RGBA(1),
+  UNRECOGNIZED(-1),
+  ;
+
+  /**
+   * <code>CAPTURE_FORMAT_PNG = 0;</code>
+   */
+  public static final int CAPTURE_FORMAT_PNG_VALUE = 0;
+  /**
+   * <pre>
+   * 8 bits per channel, rows top to bottom without padding.
+   * </pre>
+   *
+   * <code>CAPTURE_FORMAT_RGBA = 1;</code>
+   */
+  public static final int CAPTURE_FORMAT_RGBA_VALUE = 1;
+
+
+  public final int getNumber() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalArgumentException(
+          "Can't get the number of an unknown enum value.");
+    }
+    return value;
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   * @deprecated Use {@link #forNumber(int)} instead.
+   */
+  @java.lang.Deprecated
+  public static CaptureFormat valueOf(int value) {
+    return forNumber(value);
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   */
+  public static CaptureFormat forNumber(int value) {
+    switch (value) {
+      case 0: return CAPTURE_FORMAT_PNG;
+      case 1: return CAPTURE_FORMAT_RGBA;
+      default: return null;
+    }
+  }
+
+  public static com.google.protobuf.Internal.EnumLiteMap<CaptureFormat>
+      internalGetValueMap() {
+    return internalValueMap;
+  }
+  private static final com.google.protobuf.Internal.EnumLiteMap<
+      CaptureFormat> internalValueMap =
+        new com.google.protobuf.Internal.EnumLiteMap<CaptureFormat>() {
+          public CaptureFormat findValueByNumber(int number) {
+            return CaptureFormat.forNumber(number);
+          }
+        };
+
+  public final com.google.protobuf.Descriptors.EnumValueDescriptor
+      getValueDescriptor() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalStateException(
+          "Can't get the descriptor of an unrecognized enum value.");
+    }
+    return getDescriptor().getValues().get(ordinal());
+  }
+  public final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptorForType() {
+    return getDescriptor();
+  }
+  public static final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptor() {
+    return vsg.Vsg.getDescriptor().getEnumTypes().get(3);
+  }
+
+  private static final CaptureFormat[] VALUES = values();
+
+  public static CaptureFormat valueOf(
+      com.google.protobuf.Descriptors.EnumValueDescriptor desc) {
+    if (desc.getType() != getDescriptor()) {
+      throw new java.lang.IllegalArgumentException(
+        "EnumValueDescriptor is not for this type.");
+    }
+    if (desc.getIndex() == -1) {
+      return UNRECOGNIZED;
+    }
+    return VALUES[desc.getIndex()];
+  }
+
+  private final int value;
+
+  private CaptureFormat(int value) {
+    this.value = value;
+  }
+
+  // @@protoc_insertion_point(enum_scope:vsg.CaptureFormat)
+}
+
@@ -0,0 +1,705 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Renders the stimuli, textures and background offscreen at the frame
+ * boundary, exactly as they appear on that frame.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.CaptureFrame}
+ */
+public final class CaptureFrame extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.CaptureFrame)
+    CaptureFrameOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use CaptureFrame.newBuilder() to construct.
+  private CaptureFrame(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private CaptureFrame() {
+    format_ = 0;
+    path_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new CaptureFrame();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private CaptureFrame(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+            int rawValue = input.readEnum();
+
+            format_ = rawValue;
+            break;
+          }
+          case 18: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            path_ = s;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_CaptureFrame_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_CaptureFrame_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.CaptureFrame.class, vsg.CaptureFrame.Builder.class);
+  }
+
+  public static final int FORMAT_FIELD_NUMBER = 1;
+  private int format_;
+  /**
+   * <code>.vsg.CaptureFormat format = 1;</code>
+   * @return The enum numeric value on the wire for format.
+   */
+  @java.lang.Override public int getFormatValue() {
+    return format_;
+  }
+  /**
+   * <code>.vsg.CaptureFormat format = 1;</code>
+   * @return The format.
+   */
+  @java.lang.Override public vsg.CaptureFormat getFormat() {
+    @SuppressWarnings("deprecation")
+    vsg.CaptureFormat result = vsg.CaptureFormat.valueOf(format_);
+    return result == null ? vsg.CaptureFormat.UNRECOGNIZED : result;
+  }
+
+  public static final int PATH_FIELD_NUMBER = 2;
+  private volatile java.lang.Object path_;
+  /**
+   * <pre>
+   * Writes the image to this file on the server instead of returning it.
+   * Only allowed when the server runs with --capture-dir, relative to that
+   * directory, which it may not lead out of.
+   * </pre>
+   *
+   * <code>string path = 2;</code>
+   * @return The path.
+   */
+  @java.lang.Override
+  public java.lang.String getPath() {
+    java.lang.Object ref = path_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      path_ = s;
+      return s;
+    }
+  }
+  /**
+   * <pre>
+   * Writes the image to this file on the server instead of returning it.
+   * Only allowed when the server runs with --capture-dir, relative to that
+   * directory, which it may not lead out of.
+   * </pre>
+   *
+   * <code>string path = 2;</code>
+   * @return The bytes for path.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getPathBytes() {
+    java.lang.Object ref = path_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      path_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (format_ != vsg.CaptureFormat.CAPTURE_FORMAT_PNG.getNumber()) {
+      output.writeEnum(1, format_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(path_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 2, path_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (format_ != vsg.CaptureFormat.CAPTURE_FORMAT_PNG.getNumber()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeEnumSize(1, format_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(path_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(2, path_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.CaptureFrame)) {
+      return super.equals(obj);
+    }
+    vsg.CaptureFrame other = (vsg.CaptureFrame) obj;
+
+    if (format_ != other.format_) return false;
+    if (!getPath()
+        .equals(other.getPath())) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + FORMAT_FIELD_NUMBER;
+    hash = (53 * hash) + format_;
+    hash = (37 * hash) + PATH_FIELD_NUMBER;
+    hash = (53 * hash) + getPath().hashCode();
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.CaptureFrame parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.CaptureFrame parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.CaptureFrame parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.CaptureFrame parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.CaptureFrame parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.CaptureFrame parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.CaptureFrame prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Renders the stimuli, textures and background offscreen at the frame
+   * boundary, exactly as they appear on that frame.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.CaptureFrame}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.CaptureFrame)
+      vsg.CaptureFrameOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_CaptureFrame_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_CaptureFrame_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.CaptureFrame.class, vsg.CaptureFrame.Builder.class);
+    }
+
+    // Construct using vsg.CaptureFrame.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      format_ = 0;
+
+      path_ = "";
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_CaptureFrame_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.CaptureFrame getDefaultInstanceForType() {
+      return vsg.CaptureFrame.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.CaptureFrame build() {
+      vsg.CaptureFrame result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.CaptureFrame buildPartial() {
+      vsg.CaptureFrame result = new vsg.CaptureFrame(this);
+      result.format_ = format_;
+      result.path_ = path_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.CaptureFrame) {
+        return mergeFrom((vsg.CaptureFrame)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.CaptureFrame other) {
+      if (other == vsg.CaptureFrame.getDefaultInstance()) return this;
+      if (other.format_ != 0) {
+        setFormatValue(other.getFormatValue());
+      }
+      if (!other.getPath().isEmpty()) {
+        path_ = other.path_;
+        onChanged();
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.CaptureFrame parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.CaptureFrame) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private int format_ = 0;
+    /**
+     * <code>.vsg.CaptureFormat format = 1;</code>
+     * @return The enum numeric value on the wire for format.
+     */
+    @java.lang.Override public int getFormatValue() {
+      return format_;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 1;</code>
+     * @param value The enum numeric value on the wire for format to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFormatValue(int value) {
+      
+      format_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 1;</code>
+     * @return The format.
+     */
+    @java.lang.Override
+    public vsg.CaptureFormat getFormat() {
+      @SuppressWarnings("deprecation")
+      vsg.CaptureFormat result = vsg.CaptureFormat.valueOf(format_);
+      return result == null ? vsg.CaptureFormat.UNRECOGNIZED : result;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 1;</code>
+     * @param value The format to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFormat(vsg.CaptureFormat value) {
+      if (value == null) {
+        throw new NullPointerException();
+      }
+      
+      format_ = value.getNumber();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.CaptureFormat format = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFormat() {
+      
+      format_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object path_ = "";
+    /**
+     * <pre>
+     * Writes the image to this file on the server instead of returning it.
+     * Only allowed when the server runs with --capture-dir, relative to that
+     * directory, which it may not lead out of.
+     * </pre>
+     *
+     * <code>string path = 2;</code>
+     * @return The path.
+     */
+    public java.lang.String getPath() {
+      java.lang.Object ref = path_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        path_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Writes the image to this file on the server instead of returning it.
+     * Only allowed when the server runs with --capture-dir, relative to that
+     * directory, which it may not lead out of.
+     * </pre>
+     *
+     * <code>string path = 2;</code>
+     * @return The bytes for path.
+     */
+    public com.google.protobuf.ByteString
+        getPathBytes() {
+      java.lang.Object ref = path_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        path_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Writes the image to this file on the server instead of returning it.
+     * Only allowed when the server runs with --capture-dir, relative to that
+     * directory, which it may not lead out of.
+     * </pre>
+     *
+     * <code>string path = 2;</code>
+     * @param value The path to set.
+     * @return This builder for chaining.
+     */
+    public Builder setPath(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      path_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Writes the image to this file on the server instead of returning it.
+     * Only allowed when the server runs with --capture-dir, relative to that
+     * directory, which it may not lead out of.
+     * </pre>
+     *
+     * <code>string path = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearPath() {
+      
+      path_ = getDefaultInstance().getPath();
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Writes the image to this file on the server instead of returning it.
+     * Only allowed when the server runs with --capture-dir, relative to that
+     * directory, which it may not lead out of.
+     * </pre>
+     *
+     * <code>string path = 2;</code>
+     * @param value The bytes for path to set.
+     * @return This builder for chaining.
+     */
+    public Builder setPathBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      path_ = value;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.CaptureFrame)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.CaptureFrame)
+  private static final vsg.CaptureFrame DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.CaptureFrame();
+  }
+
+  public static vsg.CaptureFrame getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<CaptureFrame>
+      PARSER = new com.google.protobuf.AbstractParser<CaptureFrame>() {
+    @java.lang.Override
+    public CaptureFrame parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new CaptureFrame(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<CaptureFrame> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<CaptureFrame> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.CaptureFrame getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,44 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface CaptureFrameOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.CaptureFrame)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>.vsg.CaptureFormat format = 1;</code>
+   * @return The enum numeric value on the wire for format.
+   */
+  int getFormatValue();
+  /**
+   * <code>.vsg.CaptureFormat format = 1;</code>
+   * @return The format.
+   */
+  vsg.CaptureFormat getFormat();
+
+  /**
+   * <pre>
+   * Writes the image to this file on the server instead of returning it.
+   * Only allowed when the server runs with --capture-dir, relative to that
+   * directory, which it may not lead out of.
+   * </pre>
+   *
+   * <code>string path = 2;</code>
+   * @return The path.
+   */
+  java.lang.String getPath();
+  /**
+   * <pre>
+   * Writes the image to this file on the server instead of returning it.
+   * Only allowed when the server runs with --capture-dir, relative to that
+   * directory, which it may not lead out of.
+   * </pre>
+   *
+   * <code>string path = 2;</code>
+   * @return The bytes for path.
+   */
+  com.google.protobuf.ByteString
+      getPathBytes();
+}
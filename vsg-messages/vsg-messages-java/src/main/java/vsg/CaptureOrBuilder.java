@@ -0,0 +1,54 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface CaptureOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Capture)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 width = 1;</code>
+   * @return The width.
+   */
+  int getWidth();
+
+  /**
+   * <code>uint32 height = 2;</code>
+   * @return The height.
+   */
+  int getHeight();
+
+  /**
+   * <code>.vsg.CaptureFormat format = 3;</code>
+   * @return The enum numeric value on the wire for format.
+   */
+  int getFormatValue();
+  /**
+   * <code>.vsg.CaptureFormat format = 3;</code>
+   * @return The format.
+   */
+  vsg.CaptureFormat getFormat();
+
+  /**
+   * <pre>
+   * Empty when the image was written to a file.
+   * </pre>
+   *
+   * <code>bytes data = 4;</code>
+   * @return The data.
+   */
+  com.google.protobuf.ByteString getData();
+
+  /**
+   * <code>string path = 5;</code>
+   * @return The path.
+   */
+  java.lang.String getPath();
+  /**
+   * <code>string path = 5;</code>
+   * @return The bytes for path.
+   */
+  com.google.protobuf.ByteString
+      getPathBytes();
+}
@@ -0,0 +1,744 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Applying a command sent without flip_ack failed after it had already been
+ * answered with STATUS_OK.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.CommandFailed}
+ */
+public final class CommandFailed extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.CommandFailed)
+    CommandFailedOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use CommandFailed.newBuilder() to construct.
+  private CommandFailed(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private CommandFailed() {
+    errorCode_ = 0;
+    error_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new CommandFailed();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private CommandFailed(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            sequence_ = input.readUInt64();
+            break;
+          }
+          case 16: {
+            int rawValue = input.readEnum();
+
+            errorCode_ = rawValue;
+            break;
+          }
+          case 26: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            error_ = s;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_CommandFailed_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_CommandFailed_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.CommandFailed.class, vsg.CommandFailed.Builder.class);
+  }
+
+  public static final int SEQUENCE_FIELD_NUMBER = 1;
+  private long sequence_;
+  /**
+   * <pre>
+   * RootMessage.sequence of the command.
+   * </pre>
+   *
+   * <code>uint64 sequence = 1;</code>
+   * @return The sequence.
+   */
+  @java.lang.Override
+  public long getSequence() {
+    return sequence_;
+  }
+
+  public static final int ERROR_CODE_FIELD_NUMBER = 2;
+  private int errorCode_;
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The enum numeric value on the wire for errorCode.
+   */
+  @java.lang.Override public int getErrorCodeValue() {
+    return errorCode_;
+  }
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The errorCode.
+   */
+  @java.lang.Override public vsg.ErrorCode getErrorCode() {
+    @SuppressWarnings("deprecation")
+    vsg.ErrorCode result = vsg.ErrorCode.valueOf(errorCode_);
+    return result == null ? vsg.ErrorCode.UNRECOGNIZED : result;
+  }
+
+  public static final int ERROR_FIELD_NUMBER = 3;
+  private volatile java.lang.Object error_;
+  /**
+   * <code>string error = 3;</code>
+   * @return The error.
+   */
+  @java.lang.Override
+  public java.lang.String getError() {
+    java.lang.Object ref = error_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      error_ = s;
+      return s;
+    }
+  }
+  /**
+   * <code>string error = 3;</code>
+   * @return The bytes for error.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getErrorBytes() {
+    java.lang.Object ref = error_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      error_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (sequence_ != 0L) {
+      output.writeUInt64(1, sequence_);
+    }
+    if (errorCode_ != vsg.ErrorCode.ERROR_CODE_NONE.getNumber()) {
+      output.writeEnum(2, errorCode_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(error_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 3, error_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (sequence_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(1, sequence_);
+    }
+    if (errorCode_ != vsg.ErrorCode.ERROR_CODE_NONE.getNumber()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeEnumSize(2, errorCode_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(error_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(3, error_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.CommandFailed)) {
+      return super.equals(obj);
+    }
+    vsg.CommandFailed other = (vsg.CommandFailed) obj;
+
+    if (getSequence()
+        != other.getSequence()) return false;
+    if (errorCode_ != other.errorCode_) return false;
+    if (!getError()
+        .equals(other.getError())) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + SEQUENCE_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getSequence());
+    hash = (37 * hash) + ERROR_CODE_FIELD_NUMBER;
+    hash = (53 * hash) + errorCode_;
+    hash = (37 * hash) + ERROR_FIELD_NUMBER;
+    hash = (53 * hash) + getError().hashCode();
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.CommandFailed parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.CommandFailed parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.CommandFailed parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.CommandFailed parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.CommandFailed parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.CommandFailed parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.CommandFailed parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.CommandFailed parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.CommandFailed parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.CommandFailed parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.CommandFailed parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.CommandFailed parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.CommandFailed prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Applying a command sent without flip_ack failed after it had already been
+   * answered with STATUS_OK.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.CommandFailed}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.CommandFailed)
+      vsg.CommandFailedOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_CommandFailed_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_CommandFailed_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.CommandFailed.class, vsg.CommandFailed.Builder.class);
+    }
+
+    // Construct using vsg.CommandFailed.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      sequence_ = 0L;
+
+      errorCode_ = 0;
+
+      error_ = "";
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_CommandFailed_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.CommandFailed getDefaultInstanceForType() {
+      return vsg.CommandFailed.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.CommandFailed build() {
+      vsg.CommandFailed result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.CommandFailed buildPartial() {
+      vsg.CommandFailed result = new vsg.CommandFailed(this);
+      result.sequence_ = sequence_;
+      result.errorCode_ = errorCode_;
+      result.error_ = error_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.CommandFailed) {
+        return mergeFrom((vsg.CommandFailed)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.CommandFailed other) {
+      if (other == vsg.CommandFailed.getDefaultInstance()) return this;
+      if (other.getSequence() != 0L) {
+        setSequence(other.getSequence());
+      }
+      if (other.errorCode_ != 0) {
+        setErrorCodeValue(other.getErrorCodeValue());
+      }
+      if (!other.getError().isEmpty()) {
+        error_ = other.error_;
+        onChanged();
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.CommandFailed parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.CommandFailed) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private long sequence_ ;
+    /**
+     * <pre>
+     * RootMessage.sequence of the command.
+     * </pre>
+     *
+     * <code>uint64 sequence = 1;</code>
+     * @return The sequence.
+     */
+    @java.lang.Override
+    public long getSequence() {
+      return sequence_;
+    }
+    /**
+     * <pre>
+     * RootMessage.sequence of the command.
+     * </pre>
+     *
+     * <code>uint64 sequence = 1;</code>
+     * @param value The sequence to set.
+     * @return This builder for chaining.
+     */
+    public Builder setSequence(long value) {
+      
+      sequence_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * RootMessage.sequence of the command.
+     * </pre>
+     *
+     * <code>uint64 sequence = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearSequence() {
+      
+      sequence_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private int errorCode_ = 0;
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @return The enum numeric value on the wire for errorCode.
+     */
+    @java.lang.Override public int getErrorCodeValue() {
+      return errorCode_;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @param value The enum numeric value on the wire for errorCode to set.
+     * @return This builder for chaining.
+     */
+    public Builder setErrorCodeValue(int value) {
+      
+      errorCode_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @return The errorCode.
+     */
+    @java.lang.Override
+    public vsg.ErrorCode getErrorCode() {
+      @SuppressWarnings("deprecation")
+      vsg.ErrorCode result = vsg.ErrorCode.valueOf(errorCode_);
+      return result == null ? vsg.ErrorCode.UNRECOGNIZED : result;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @param value The errorCode to set.
+     * @return This builder for chaining.
+     */
+    public Builder setErrorCode(vsg.ErrorCode value) {
+      if (value == null) {
+        throw new NullPointerException();
+      }
+      
+      errorCode_ = value.getNumber();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearErrorCode() {
+      
+      errorCode_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object error_ = "";
+    /**
+     * <code>string error = 3;</code>
+     * @return The error.
+     */
+    public java.lang.String getError() {
+      java.lang.Object ref = error_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        error_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @return The bytes for error.
+     */
+    public com.google.protobuf.ByteString
+        getErrorBytes() {
+      java.lang.Object ref = error_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        error_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @param value The error to set.
+     * @return This builder for chaining.
+     */
+    public Builder setError(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      error_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearError() {
+      
+      error_ = getDefaultInstance().getError();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @param value The bytes for error to set.
+     * @return This builder for chaining.
+     */
+    public Builder setErrorBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      error_ = value;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.CommandFailed)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.CommandFailed)
+  private static final vsg.CommandFailed DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.CommandFailed();
+  }
+
+  public static vsg.CommandFailed getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<CommandFailed>
+      PARSER = new com.google.protobuf.AbstractParser<CommandFailed>() {
+    @java.lang.Override
+    public CommandFailed parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new CommandFailed(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<CommandFailed> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<CommandFailed> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.CommandFailed getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
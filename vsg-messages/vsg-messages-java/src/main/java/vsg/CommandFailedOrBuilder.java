@@ -0,0 +1,42 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface CommandFailedOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.CommandFailed)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * RootMessage.sequence of the command.
+   * </pre>
+   *
+   * <code>uint64 sequence = 1;</code>
+   * @return The sequence.
+   */
+  long getSequence();
+
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The enum numeric value on the wire for errorCode.
+   */
+  int getErrorCodeValue();
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The errorCode.
+   */
+  vsg.ErrorCode getErrorCode();
+
+  /**
+   * <code>string error = 3;</code>
+   * @return The error.
+   */
+  java.lang.String getError();
+  /**
+   * <code>string error = 3;</code>
+   * @return The bytes for error.
+   */
+  com.google.protobuf.ByteString
+      getErrorBytes();
+}
@@ -0,0 +1,1718 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * What the server is drawing to.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.DisplayStatus}
+ */
+public final class DisplayStatus extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.DisplayStatus)
+    DisplayStatusOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use DisplayStatus.newBuilder() to construct.
+  private DisplayStatus(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private DisplayStatus() {
+    adapterName_ = "";
+    backend_ = "";
+    surfaceFormat_ = "";
+    monitors_ = java.util.Collections.emptyList();
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new DisplayStatus();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private DisplayStatus(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    int mutable_bitField0_ = 0;
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 10: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            adapterName_ = s;
+            break;
+          }
+          case 18: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            backend_ = s;
+            break;
+          }
+          case 26: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            surfaceFormat_ = s;
+            break;
+          }
+          case 32: {
+
+            width_ = input.readUInt32();
+            break;
+          }
+          case 40: {
+
+            height_ = input.readUInt32();
+            break;
+          }
+          case 49: {
+
+            scaleFactor_ = input.readDouble();
+            break;
+          }
+          case 57: {
+
+            refreshRateHz_ = input.readDouble();
+            break;
+          }
+          case 66: {
+            if (!((mutable_bitField0_ & 0x00000001) != 0)) {
+              monitors_ = new java.util.ArrayList<vsg.Monitor>();
+              mutable_bitField0_ |= 0x00000001;
+            }
+            monitors_.add(
+                input.readMessage(vsg.Monitor.parser(), extensionRegistry));
+            break;
+          }
+          case 72: {
+
+            framesPresented_ = input.readUInt64();
+            break;
+          }
+          case 80: {
+
+            framesSkipped_ = input.readUInt64();
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      if (((mutable_bitField0_ & 0x00000001) != 0)) {
+        monitors_ = java.util.Collections.unmodifiableList(monitors_);
+      }
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_DisplayStatus_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_DisplayStatus_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.DisplayStatus.class, vsg.DisplayStatus.Builder.class);
+  }
+
+  public static final int ADAPTER_NAME_FIELD_NUMBER = 1;
+  private volatile java.lang.Object adapterName_;
+  /**
+   * <code>string adapter_name = 1;</code>
+   * @return The adapterName.
+   */
+  @java.lang.Override
+  public java.lang.String getAdapterName() {
+    java.lang.Object ref = adapterName_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      adapterName_ = s;
+      return s;
+    }
+  }
+  /**
+   * <code>string adapter_name = 1;</code>
+   * @return The bytes for adapterName.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getAdapterNameBytes() {
+    java.lang.Object ref = adapterName_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      adapterName_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  public static final int BACKEND_FIELD_NUMBER = 2;
+  private volatile java.lang.Object backend_;
+  /**
+   * <pre>
+   * Graphics API in use, such as "Vulkan" or "Metal".
+   * </pre>
+   *
+   * <code>string backend = 2;</code>
+   * @return The backend.
+   */
+  @java.lang.Override
+  public java.lang.String getBackend() {
+    java.lang.Object ref = backend_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      backend_ = s;
+      return s;
+    }
+  }
+  /**
+   * <pre>
+   * Graphics API in use, such as "Vulkan" or "Metal".
+   * </pre>
+   *
+   * <code>string backend = 2;</code>
+   * @return The bytes for backend.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getBackendBytes() {
+    java.lang.Object ref = backend_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      backend_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  public static final int SURFACE_FORMAT_FIELD_NUMBER = 3;
+  private volatile java.lang.Object surfaceFormat_;
+  /**
+   * <pre>
+   * Texture format of the window surface, such as "Bgra8UnormSrgb".
+   * </pre>
+   *
+   * <code>string surface_format = 3;</code>
+   * @return The surfaceFormat.
+   */
+  @java.lang.Override
+  public java.lang.String getSurfaceFormat() {
+    java.lang.Object ref = surfaceFormat_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      surfaceFormat_ = s;
+      return s;
+    }
+  }
+  /**
+   * <pre>
+   * Texture format of the window surface, such as "Bgra8UnormSrgb".
+   * </pre>
+   *
+   * <code>string surface_format = 3;</code>
+   * @return The bytes for surfaceFormat.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getSurfaceFormatBytes() {
+    java.lang.Object ref = surfaceFormat_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      surfaceFormat_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  public static final int WIDTH_FIELD_NUMBER = 4;
+  private int width_;
+  /**
+   * <pre>
+   * Window size in physical pixels.
+   * </pre>
+   *
+   * <code>uint32 width = 4;</code>
+   * @return The width.
+   */
+  @java.lang.Override
+  public int getWidth() {
+    return width_;
+  }
+
+  public static final int HEIGHT_FIELD_NUMBER = 5;
+  private int height_;
+  /**
+   * <code>uint32 height = 5;</code>
+   * @return The height.
+   */
+  @java.lang.Override
+  public int getHeight() {
+    return height_;
+  }
+
+  public static final int SCALE_FACTOR_FIELD_NUMBER = 6;
+  private double scaleFactor_;
+  /**
+   * <pre>
+   * Physical pixels per logical pixel.
+   * </pre>
+   *
+   * <code>double scale_factor = 6;</code>
+   * @return The scaleFactor.
+   */
+  @java.lang.Override
+  public double getScaleFactor() {
+    return scaleFactor_;
+  }
+
+  public static final int REFRESH_RATE_HZ_FIELD_NUMBER = 7;
+  private double refreshRateHz_;
+  /**
+   * <pre>
+   * Measured from the intervals between presented frames.
+   * </pre>
+   *
+   * <code>double refresh_rate_hz = 7;</code>
+   * @return The refreshRateHz.
+   */
+  @java.lang.Override
+  public double getRefreshRateHz() {
+    return refreshRateHz_;
+  }
+
+  public static final int MONITORS_FIELD_NUMBER = 8;
+  private java.util.List<vsg.Monitor> monitors_;
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  @java.lang.Override
+  public java.util.List<vsg.Monitor> getMonitorsList() {
+    return monitors_;
+  }
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  @java.lang.Override
+  public java.util.List<? extends vsg.MonitorOrBuilder> 
+      getMonitorsOrBuilderList() {
+    return monitors_;
+  }
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  @java.lang.Override
+  public int getMonitorsCount() {
+    return monitors_.size();
+  }
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  @java.lang.Override
+  public vsg.Monitor getMonitors(int index) {
+    return monitors_.get(index);
+  }
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  @java.lang.Override
+  public vsg.MonitorOrBuilder getMonitorsOrBuilder(
+      int index) {
+    return monitors_.get(index);
+  }
+
+  public static final int FRAMES_PRESENTED_FIELD_NUMBER = 9;
+  private long framesPresented_;
+  /**
+   * <pre>
+   * Same as the next Onset.frame.
+   * </pre>
+   *
+   * <code>uint64 frames_presented = 9;</code>
+   * @return The framesPresented.
+   */
+  @java.lang.Override
+  public long getFramesPresented() {
+    return framesPresented_;
+  }
+
+  public static final int FRAMES_SKIPPED_FIELD_NUMBER = 10;
+  private long framesSkipped_;
+  /**
+   * <pre>
+   * Frames that took noticeably longer than the refresh interval.
+   * </pre>
+   *
+   * <code>uint64 frames_skipped = 10;</code>
+   * @return The framesSkipped.
+   */
+  @java.lang.Override
+  public long getFramesSkipped() {
+    return framesSkipped_;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(adapterName_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 1, adapterName_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(backend_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 2, backend_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(surfaceFormat_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 3, surfaceFormat_);
+    }
+    if (width_ != 0) {
+      output.writeUInt32(4, width_);
+    }
+    if (height_ != 0) {
+      output.writeUInt32(5, height_);
+    }
+    if (java.lang.Double.doubleToRawLongBits(scaleFactor_) != 0) {
+      output.writeDouble(6, scaleFactor_);
+    }
+    if (java.lang.Double.doubleToRawLongBits(refreshRateHz_) != 0) {
+      output.writeDouble(7, refreshRateHz_);
+    }
+    for (int i = 0; i < monitors_.size(); i++) {
+      output.writeMessage(8, monitors_.get(i));
+    }
+    if (framesPresented_ != 0L) {
+      output.writeUInt64(9, framesPresented_);
+    }
+    if (framesSkipped_ != 0L) {
+      output.writeUInt64(10, framesSkipped_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(adapterName_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(1, adapterName_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(backend_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(2, backend_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(surfaceFormat_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(3, surfaceFormat_);
+    }
+    if (width_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(4, width_);
+    }
+    if (height_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(5, height_);
+    }
+    if (java.lang.Double.doubleToRawLongBits(scaleFactor_) != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeDoubleSize(6, scaleFactor_);
+    }
+    if (java.lang.Double.doubleToRawLongBits(refreshRateHz_) != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeDoubleSize(7, refreshRateHz_);
+    }
+    for (int i = 0; i < monitors_.size(); i++) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(8, monitors_.get(i));
+    }
+    if (framesPresented_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(9, framesPresented_);
+    }
+    if (framesSkipped_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(10, framesSkipped_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.DisplayStatus)) {
+      return super.equals(obj);
+    }
+    vsg.DisplayStatus other = (vsg.DisplayStatus) obj;
+
+    if (!getAdapterName()
+        .equals(other.getAdapterName())) return false;
+    if (!getBackend()
+        .equals(other.getBackend())) return false;
+    if (!getSurfaceFormat()
+        .equals(other.getSurfaceFormat())) return false;
+    if (getWidth()
+        != other.getWidth()) return false;
+    if (getHeight()
+        != other.getHeight()) return false;
+    if (java.lang.Double.doubleToLongBits(getScaleFactor())
+        != java.lang.Double.doubleToLongBits(
+            other.getScaleFactor())) return false;
+    if (java.lang.Double.doubleToLongBits(getRefreshRateHz())
+        != java.lang.Double.doubleToLongBits(
+            other.getRefreshRateHz())) return false;
+    if (!getMonitorsList()
+        .equals(other.getMonitorsList())) return false;
+    if (getFramesPresented()
+        != other.getFramesPresented()) return false;
+    if (getFramesSkipped()
+        != other.getFramesSkipped()) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + ADAPTER_NAME_FIELD_NUMBER;
+    hash = (53 * hash) + getAdapterName().hashCode();
+    hash = (37 * hash) + BACKEND_FIELD_NUMBER;
+    hash = (53 * hash) + getBackend().hashCode();
+    hash = (37 * hash) + SURFACE_FORMAT_FIELD_NUMBER;
+    hash = (53 * hash) + getSurfaceFormat().hashCode();
+    hash = (37 * hash) + WIDTH_FIELD_NUMBER;
+    hash = (53 * hash) + getWidth();
+    hash = (37 * hash) + HEIGHT_FIELD_NUMBER;
+    hash = (53 * hash) + getHeight();
+    hash = (37 * hash) + SCALE_FACTOR_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        java.lang.Double.doubleToLongBits(getScaleFactor()));
+    hash = (37 * hash) + REFRESH_RATE_HZ_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        java.lang.Double.doubleToLongBits(getRefreshRateHz()));
+    if (getMonitorsCount() > 0) {
+      hash = (37 * hash) + MONITORS_FIELD_NUMBER;
+      hash = (53 * hash) + getMonitorsList().hashCode();
+    }
+    hash = (37 * hash) + FRAMES_PRESENTED_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getFramesPresented());
+    hash = (37 * hash) + FRAMES_SKIPPED_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getFramesSkipped());
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.DisplayStatus parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.DisplayStatus parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.DisplayStatus parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.DisplayStatus parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.DisplayStatus parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.DisplayStatus parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.DisplayStatus prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * What the server is drawing to.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.DisplayStatus}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.DisplayStatus)
+      vsg.DisplayStatusOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_DisplayStatus_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_DisplayStatus_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.DisplayStatus.class, vsg.DisplayStatus.Builder.class);
+    }
+
+    // Construct using vsg.DisplayStatus.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+        getMonitorsFieldBuilder();
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      adapterName_ = "";
+
+      backend_ = "";
+
+      surfaceFormat_ = "";
+
+      width_ = 0;
+
+      height_ = 0;
+
+      scaleFactor_ = 0D;
+
+      refreshRateHz_ = 0D;
+
+      if (monitorsBuilder_ == null) {
+        monitors_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000001);
+      } else {
+        monitorsBuilder_.clear();
+      }
+      framesPresented_ = 0L;
+
+      framesSkipped_ = 0L;
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_DisplayStatus_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.DisplayStatus getDefaultInstanceForType() {
+      return vsg.DisplayStatus.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.DisplayStatus build() {
+      vsg.DisplayStatus result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.DisplayStatus buildPartial() {
+      vsg.DisplayStatus result = new vsg.DisplayStatus(this);
+      int from_bitField0_ = bitField0_;
+      result.adapterName_ = adapterName_;
+      result.backend_ = backend_;
+      result.surfaceFormat_ = surfaceFormat_;
+      result.width_ = width_;
+      result.height_ = height_;
+      result.scaleFactor_ = scaleFactor_;
+      result.refreshRateHz_ = refreshRateHz_;
+      if (monitorsBuilder_ == null) {
+        if (((bitField0_ & 0x00000001) != 0)) {
+          monitors_ = java.util.Collections.unmodifiableList(monitors_);
+          bitField0_ = (bitField0_ & ~0x00000001);
+        }
+        result.monitors_ = monitors_;
+      } else {
+        result.monitors_ = monitorsBuilder_.build();
+      }
+      result.framesPresented_ = framesPresented_;
+      result.framesSkipped_ = framesSkipped_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.DisplayStatus) {
+        return mergeFrom((vsg.DisplayStatus)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.DisplayStatus other) {
+      if (other == vsg.DisplayStatus.getDefaultInstance()) return this;
+      if (!other.getAdapterName().isEmpty()) {
+        adapterName_ = other.adapterName_;
+        onChanged();
+      }
+      if (!other.getBackend().isEmpty()) {
+        backend_ = other.backend_;
+        onChanged();
+      }
+      if (!other.getSurfaceFormat().isEmpty()) {
+        surfaceFormat_ = other.surfaceFormat_;
+        onChanged();
+      }
+      if (other.getWidth() != 0) {
+        setWidth(other.getWidth());
+      }
+      if (other.getHeight() != 0) {
+        setHeight(other.getHeight());
+      }
+      if (other.getScaleFactor() != 0D) {
+        setScaleFactor(other.getScaleFactor());
+      }
+      if (other.getRefreshRateHz() != 0D) {
+        setRefreshRateHz(other.getRefreshRateHz());
+      }
+      if (monitorsBuilder_ == null) {
+        if (!other.monitors_.isEmpty()) {
+          if (monitors_.isEmpty()) {
+            monitors_ = other.monitors_;
+            bitField0_ = (bitField0_ & ~0x00000001);
+          } else {
+            ensureMonitorsIsMutable();
+            monitors_.addAll(other.monitors_);
+          }
+          onChanged();
+        }
+      } else {
+        if (!other.monitors_.isEmpty()) {
+          if (monitorsBuilder_.isEmpty()) {
+            monitorsBuilder_.dispose();
+            monitorsBuilder_ = null;
+            monitors_ = other.monitors_;
+            bitField0_ = (bitField0_ & ~0x00000001);
+            monitorsBuilder_ = 
+              com.google.protobuf.GeneratedMessageV3.alwaysUseFieldBuilders ?
+                 getMonitorsFieldBuilder() : null;
+          } else {
+            monitorsBuilder_.addAllMessages(other.monitors_);
+          }
+        }
+      }
+      if (other.getFramesPresented() != 0L) {
+        setFramesPresented(other.getFramesPresented());
+      }
+      if (other.getFramesSkipped() != 0L) {
+        setFramesSkipped(other.getFramesSkipped());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.DisplayStatus parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.DisplayStatus) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int bitField0_;
+
+    private java.lang.Object adapterName_ = "";
+    /**
+     * <code>string adapter_name = 1;</code>
+     * @return The adapterName.
+     */
+    public java.lang.String getAdapterName() {
+      java.lang.Object ref = adapterName_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        adapterName_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <code>string adapter_name = 1;</code>
+     * @return The bytes for adapterName.
+     */
+    public com.google.protobuf.ByteString
+        getAdapterNameBytes() {
+      java.lang.Object ref = adapterName_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        adapterName_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <code>string adapter_name = 1;</code>
+     * @param value The adapterName to set.
+     * @return This builder for chaining.
+     */
+    public Builder setAdapterName(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      adapterName_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string adapter_name = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearAdapterName() {
+      
+      adapterName_ = getDefaultInstance().getAdapterName();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string adapter_name = 1;</code>
+     * @param value The bytes for adapterName to set.
+     * @return This builder for chaining.
+     */
+    public Builder setAdapterNameBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      adapterName_ = value;
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object backend_ = "";
+    /**
+     * <pre>
+     * Graphics API in use, such as "Vulkan" or "Metal".
+     * </pre>
+     *
+     * <code>string backend = 2;</code>
+     * @return The backend.
+     */
+    public java.lang.String getBackend() {
+      java.lang.Object ref = backend_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        backend_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Graphics API in use, such as "Vulkan" or "Metal".
+     * </pre>
+     *
+     * <code>string backend = 2;</code>
+     * @return The bytes for backend.
+     */
+    public com.google.protobuf.ByteString
+        getBackendBytes() {
+      java.lang.Object ref = backend_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        backend_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Graphics API in use, such as "Vulkan" or "Metal".
+     * </pre>
+     *
+     * <code>string backend = 2;</code>
+     * @param value The backend to set.
+     * @return This builder for chaining.
+     */
+    public Builder setBackend(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      backend_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Graphics API in use, such as "Vulkan" or "Metal".
+     * </pre>
+     *
+     * <code>string backend = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearBackend() {
+      
+      backend_ = getDefaultInstance().getBackend();
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Graphics API in use, such as "Vulkan" or "Metal".
+     * </pre>
+     *
+     * <code>string backend = 2;</code>
+     * @param value The bytes for backend to set.
+     * @return This builder for chaining.
+     */
+    public Builder setBackendBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      backend_ = value;
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object surfaceFormat_ = "";
+    /**
+     * <pre>
+     * Texture format of the window surface, such as "Bgra8UnormSrgb".
+     * </pre>
+     *
+     * <code>string surface_format = 3;</code>
+     * @return The surfaceFormat.
+     */
+    public java.lang.String getSurfaceFormat() {
+      java.lang.Object ref = surfaceFormat_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        surfaceFormat_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Texture format of the window surface, such as "Bgra8UnormSrgb".
+     * </pre>
+     *
+     * <code>string surface_format = 3;</code>
+     * @return The bytes for surfaceFormat.
+     */
+    public com.google.protobuf.ByteString
+        getSurfaceFormatBytes() {
+      java.lang.Object ref = surfaceFormat_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        surfaceFormat_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Texture format of the window surface, such as "Bgra8UnormSrgb".
+     * </pre>
+     *
+     * <code>string surface_format = 3;</code>
+     * @param value The surfaceFormat to set.
+     * @return This builder for chaining.
+     */
+    public Builder setSurfaceFormat(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      surfaceFormat_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Texture format of the window surface, such as "Bgra8UnormSrgb".
+     * </pre>
+     *
+     * <code>string surface_format = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearSurfaceFormat() {
+      
+      surfaceFormat_ = getDefaultInstance().getSurfaceFormat();
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Texture format of the window surface, such as "Bgra8UnormSrgb".
+     * </pre>
+     *
+     * <code>string surface_format = 3;</code>
+     * @param value The bytes for surfaceFormat to set.
+     * @return This builder for chaining.
+     */
+    public Builder setSurfaceFormatBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      surfaceFormat_ = value;
+      onChanged();
+      return this;
+    }
+
+    private int width_ ;
+    /**
+     * <pre>
+     * Window size in physical pixels.
+     * </pre>
+     *
+     * <code>uint32 width = 4;</code>
+     * @return The width.
+     */
+    @java.lang.Override
+    public int getWidth() {
+      return width_;
+    }
+    /**
+     * <pre>
+     * Window size in physical pixels.
+     * </pre>
+     *
+     * <code>uint32 width = 4;</code>
+     * @param value The width to set.
+     * @return This builder for chaining.
+     */
+    public Builder setWidth(int value) {
+      
+      width_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Window size in physical pixels.
+     * </pre>
+     *
+     * <code>uint32 width = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearWidth() {
+      
+      width_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int height_ ;
+    /**
+     * <code>uint32 height = 5;</code>
+     * @return The height.
+     */
+    @java.lang.Override
+    public int getHeight() {
+      return height_;
+    }
+    /**
+     * <code>uint32 height = 5;</code>
+     * @param value The height to set.
+     * @return This builder for chaining.
+     */
+    public Builder setHeight(int value) {
+      
+      height_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 height = 5;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearHeight() {
+      
+      height_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private double scaleFactor_ ;
+    /**
+     * <pre>
+     * Physical pixels per logical pixel.
+     * </pre>
+     *
+     * <code>double scale_factor = 6;</code>
+     * @return The scaleFactor.
+     */
+    @java.lang.Override
+    public double getScaleFactor() {
+      return scaleFactor_;
+    }
+    /**
+     * <pre>
+     * Physical pixels per logical pixel.
+     * </pre>
+     *
+     * <code>double scale_factor = 6;</code>
+     * @param value The scaleFactor to set.
+     * @return This builder for chaining.
+     */
+    public Builder setScaleFactor(double value) {
+      
+      scaleFactor_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Physical pixels per logical pixel.
+     * </pre>
+     *
+     * <code>double scale_factor = 6;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearScaleFactor() {
+      
+      scaleFactor_ = 0D;
+      onChanged();
+      return this;
+    }
+
+    private double refreshRateHz_ ;
+    /**
+     * <pre>
+     * Measured from the intervals between presented frames.
+     * </pre>
+     *
+     * <code>double refresh_rate_hz = 7;</code>
+     * @return The refreshRateHz.
+     */
+    @java.lang.Override
+    public double getRefreshRateHz() {
+      return refreshRateHz_;
+    }
+    /**
+     * <pre>
+     * Measured from the intervals between presented frames.
+     * </pre>
+     *
+     * <code>double refresh_rate_hz = 7;</code>
+     * @param value The refreshRateHz to set.
+     * @return This builder for chaining.
+     */
+    public Builder setRefreshRateHz(double value) {
+      
+      refreshRateHz_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Measured from the intervals between presented frames.
+     * </pre>
+     *
+     * <code>double refresh_rate_hz = 7;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearRefreshRateHz() {
+      
+      refreshRateHz_ = 0D;
+      onChanged();
+      return this;
+    }
+
+    private java.util.List<vsg.Monitor> monitors_ =
+      java.util.Collections.emptyList();
+    private void ensureMonitorsIsMutable() {
+      if (!((bitField0_ & 0x00000001) != 0)) {
+        monitors_ = new java.util.ArrayList<vsg.Monitor>(monitors_);
+        bitField0_ |= 0x00000001;
+       }
+    }
+
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.Monitor, vsg.Monitor.Builder, vsg.MonitorOrBuilder> monitorsBuilder_;
+
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public java.util.List<vsg.Monitor> getMonitorsList() {
+      if (monitorsBuilder_ == null) {
+        return java.util.Collections.unmodifiableList(monitors_);
+      } else {
+        return monitorsBuilder_.getMessageList();
+      }
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public int getMonitorsCount() {
+      if (monitorsBuilder_ == null) {
+        return monitors_.size();
+      } else {
+        return monitorsBuilder_.getCount();
+      }
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public vsg.Monitor getMonitors(int index) {
+      if (monitorsBuilder_ == null) {
+        return monitors_.get(index);
+      } else {
+        return monitorsBuilder_.getMessage(index);
+      }
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder setMonitors(
+        int index, vsg.Monitor value) {
+      if (monitorsBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureMonitorsIsMutable();
+        monitors_.set(index, value);
+        onChanged();
+      } else {
+        monitorsBuilder_.setMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder setMonitors(
+        int index, vsg.Monitor.Builder builderForValue) {
+      if (monitorsBuilder_ == null) {
+        ensureMonitorsIsMutable();
+        monitors_.set(index, builderForValue.build());
+        onChanged();
+      } else {
+        monitorsBuilder_.setMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder addMonitors(vsg.Monitor value) {
+      if (monitorsBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureMonitorsIsMutable();
+        monitors_.add(value);
+        onChanged();
+      } else {
+        monitorsBuilder_.addMessage(value);
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder addMonitors(
+        int index, vsg.Monitor value) {
+      if (monitorsBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureMonitorsIsMutable();
+        monitors_.add(index, value);
+        onChanged();
+      } else {
+        monitorsBuilder_.addMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder addMonitors(
+        vsg.Monitor.Builder builderForValue) {
+      if (monitorsBuilder_ == null) {
+        ensureMonitorsIsMutable();
+        monitors_.add(builderForValue.build());
+        onChanged();
+      } else {
+        monitorsBuilder_.addMessage(builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder addMonitors(
+        int index, vsg.Monitor.Builder builderForValue) {
+      if (monitorsBuilder_ == null) {
+        ensureMonitorsIsMutable();
+        monitors_.add(index, builderForValue.build());
+        onChanged();
+      } else {
+        monitorsBuilder_.addMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder addAllMonitors(
+        java.lang.Iterable<? extends vsg.Monitor> values) {
+      if (monitorsBuilder_ == null) {
+        ensureMonitorsIsMutable();
+        com.google.protobuf.AbstractMessageLite.Builder.addAll(
+            values, monitors_);
+        onChanged();
+      } else {
+        monitorsBuilder_.addAllMessages(values);
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder clearMonitors() {
+      if (monitorsBuilder_ == null) {
+        monitors_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000001);
+        onChanged();
+      } else {
+        monitorsBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public Builder removeMonitors(int index) {
+      if (monitorsBuilder_ == null) {
+        ensureMonitorsIsMutable();
+        monitors_.remove(index);
+        onChanged();
+      } else {
+        monitorsBuilder_.remove(index);
+      }
+      return this;
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public vsg.Monitor.Builder getMonitorsBuilder(
+        int index) {
+      return getMonitorsFieldBuilder().getBuilder(index);
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public vsg.MonitorOrBuilder getMonitorsOrBuilder(
+        int index) {
+      if (monitorsBuilder_ == null) {
+        return monitors_.get(index);  } else {
+        return monitorsBuilder_.getMessageOrBuilder(index);
+      }
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public java.util.List<? extends vsg.MonitorOrBuilder> 
+         getMonitorsOrBuilderList() {
+      if (monitorsBuilder_ != null) {
+        return monitorsBuilder_.getMessageOrBuilderList();
+      } else {
+        return java.util.Collections.unmodifiableList(monitors_);
+      }
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public vsg.Monitor.Builder addMonitorsBuilder() {
+      return getMonitorsFieldBuilder().addBuilder(
+          vsg.Monitor.getDefaultInstance());
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public vsg.Monitor.Builder addMonitorsBuilder(
+        int index) {
+      return getMonitorsFieldBuilder().addBuilder(
+          index, vsg.Monitor.getDefaultInstance());
+    }
+    /**
+     * <code>repeated .vsg.Monitor monitors = 8;</code>
+     */
+    public java.util.List<vsg.Monitor.Builder> 
+         getMonitorsBuilderList() {
+      return getMonitorsFieldBuilder().getBuilderList();
+    }
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.Monitor, vsg.Monitor.Builder, vsg.MonitorOrBuilder> 
+        getMonitorsFieldBuilder() {
+      if (monitorsBuilder_ == null) {
+        monitorsBuilder_ = new com.google.protobuf.RepeatedFieldBuilderV3<
+            vsg.Monitor, vsg.Monitor.Builder, vsg.MonitorOrBuilder>(
+                monitors_,
+                ((bitField0_ & 0x00000001) != 0),
+                getParentForChildren(),
+                isClean());
+        monitors_ = null;
+      }
+      return monitorsBuilder_;
+    }
+
+    private long framesPresented_ ;
+    /**
+     * <pre>
+     * Same as the next Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frames_presented = 9;</code>
+     * @return The framesPresented.
+     */
+    @java.lang.Override
+    public long getFramesPresented() {
+      return framesPresented_;
+    }
+    /**
+     * <pre>
+     * Same as the next Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frames_presented = 9;</code>
+     * @param value The framesPresented to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFramesPresented(long value) {
+      
+      framesPresented_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Same as the next Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frames_presented = 9;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFramesPresented() {
+      
+      framesPresented_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private long framesSkipped_ ;
+    /**
+     * <pre>
+     * Frames that took noticeably longer than the refresh interval.
+     * </pre>
+     *
+     * <code>uint64 frames_skipped = 10;</code>
+     * @return The framesSkipped.
+     */
+    @java.lang.Override
+    public long getFramesSkipped() {
+      return framesSkipped_;
+    }
+    /**
+     * <pre>
+     * Frames that took noticeably longer than the refresh interval.
+     * </pre>
+     *
+     * <code>uint64 frames_skipped = 10;</code>
+     * @param value The framesSkipped to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFramesSkipped(long value) {
+      
+      framesSkipped_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Frames that took noticeably longer than the refresh interval.
+     * </pre>
+     *
+     * <code>uint64 frames_skipped = 10;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFramesSkipped() {
+      
+      framesSkipped_ = 0L;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.DisplayStatus)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.DisplayStatus)
+  private static final vsg.DisplayStatus DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.DisplayStatus();
+  }
+
+  public static vsg.DisplayStatus getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<DisplayStatus>
+      PARSER = new com.google.protobuf.AbstractParser<DisplayStatus>() {
+    @java.lang.Override
+    public DisplayStatus parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new DisplayStatus(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<DisplayStatus> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<DisplayStatus> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.DisplayStatus getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,141 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface DisplayStatusOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.DisplayStatus)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>string adapter_name = 1;</code>
+   * @return The adapterName.
+   */
+  java.lang.String getAdapterName();
+  /**
+   * <code>string adapter_name = 1;</code>
+   * @return The bytes for adapterName.
+   */
+  com.google.protobuf.ByteString
+      getAdapterNameBytes();
+
+  /**
+   * <pre>
+   * Graphics API in use, such as "Vulkan" or "Metal".
+   * </pre>
+   *
+   * <code>string backend = 2;</code>
+   * @return The backend.
+   */
+  java.lang.String getBackend();
+  /**
+   * <pre>
+   * Graphics API in use, such as "Vulkan" or "Metal".
+   * </pre>
+   *
+   * <code>string backend = 2;</code>
+   * @return The bytes for backend.
+   */
+  com.google.protobuf.ByteString
+      getBackendBytes();
+
+  /**
+   * <pre>
+   * Texture format of the window surface, such as "Bgra8UnormSrgb".
+   * </pre>
+   *
+   * <code>string surface_format = 3;</code>
+   * @return The surfaceFormat.
+   */
+  java.lang.String getSurfaceFormat();
+  /**
+   * <pre>
+   * Texture format of the window surface, such as "Bgra8UnormSrgb".
+   * </pre>
+   *
+   * <code>string surface_format = 3;</code>
+   * @return The bytes for surfaceFormat.
+   */
+  com.google.protobuf.ByteString
+      getSurfaceFormatBytes();
+
+  /**
+   * <pre>
+   * Window size in physical pixels.
+   * </pre>
+   *
+   * <code>uint32 width = 4;</code>
+   * @return The width.
+   */
+  int getWidth();
+
+  /**
+   * <code>uint32 height = 5;</code>
+   * @return The height.
+   */
+  int getHeight();
+
+  /**
+   * <pre>
+   * Physical pixels per logical pixel.
+   * </pre>
+   *
+   * <code>double scale_factor = 6;</code>
+   * @return The scaleFactor.
+   */
+  double getScaleFactor();
+
+  /**
+   * <pre>
+   * Measured from the intervals between presented frames.
+   * </pre>
+   *
+   * <code>double refresh_rate_hz = 7;</code>
+   * @return The refreshRateHz.
+   */
+  double getRefreshRateHz();
+
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  java.util.List<vsg.Monitor> 
+      getMonitorsList();
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  vsg.Monitor getMonitors(int index);
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  int getMonitorsCount();
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  java.util.List<? extends vsg.MonitorOrBuilder> 
+      getMonitorsOrBuilderList();
+  /**
+   * <code>repeated .vsg.Monitor monitors = 8;</code>
+   */
+  vsg.MonitorOrBuilder getMonitorsOrBuilder(
+      int index);
+
+  /**
+   * <pre>
+   * Same as the next Onset.frame.
+   * </pre>
+   *
+   * <code>uint64 frames_presented = 9;</code>
+   * @return The framesPresented.
+   */
+  long getFramesPresented();
+
+  /**
+   * <pre>
+   * Frames that took noticeably longer than the refresh interval.
+   * </pre>
+   *
+   * <code>uint64 frames_skipped = 10;</code>
+   * @return The framesSkipped.
+   */
+  long getFramesSkipped();
+}
@@ -0,0 +1,278 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf enum {@code vsg.ErrorCode}
+ */
+public enum ErrorCode
+    implements com.google.protobuf.ProtocolMessageEnum {
+  /**
+   * <code>ERROR_CODE_NONE = 0;</code>
+   */
+  ERROR_CODE_NONE(0),
+  /**
+   * <pre>
+   * The payload could not be decoded as a RootMessage.
+   * </pre>
+   *
+   * <code>ERROR_CODE_DECODE = 1;</code>
+   */
+  ERROR_CODE_DECODE(1),
+  /**
+   * <pre>
+   * The RootMessage did not carry a command this server understands.
+   * </pre>
+   *
+   * <code>ERROR_CODE_UNKNOWN_COMMAND = 2;</code>
+   */
+  ERROR_CODE_UNKNOWN_COMMAND(2),
+  /**
+   * <pre>
+   * The command was understood but its contents are not valid.
+   * </pre>
+   *
+   * <code>ERROR_CODE_INVALID_ARGUMENT = 3;</code>
+   */
+  ERROR_CODE_INVALID_ARGUMENT(3),
+  /**
+   * <pre>
+   * No stimulus with the requested id is in the scene.
+   * </pre>
+   *
+   * <code>ERROR_CODE_NOT_FOUND = 4;</code>
+   */
+  ERROR_CODE_NOT_FOUND(4),
+  /**
+   * <pre>
+   * A stimulus with the requested id is already in the scene.
+   * </pre>
+   *
+   * <code>ERROR_CODE_ALREADY_EXISTS = 5;</code>
+   */
+  ERROR_CODE_ALREADY_EXISTS(5),
+  /**
+   * <pre>
+   * No texture is stored under the requested index.
+   * </pre>
+   *
+   * <code>ERROR_CODE_TEXTURE_NOT_FOUND = 6;</code>
+   */
+  ERROR_CODE_TEXTURE_NOT_FOUND(6),
+  /**
+   * <pre>
+   * Client and server speak different protocol versions.
+   * </pre>
+   *
+   * <code>ERROR_CODE_VERSION_MISMATCH = 7;</code>
+   */
+  ERROR_CODE_VERSION_MISMATCH(7),
+  /**
+   * <pre>
+   * The client relies on a feature this server does not have.
+   * </pre>
+   *
+   * <code>ERROR_CODE_UNSUPPORTED_FEATURE = 8;</code>
+   */
+  ERROR_CODE_UNSUPPORTED_FEATURE(8),
+  /**
+   * <pre>
+   * The message is longer than the server accepts. The connection is closed
+   * after this reply, since the rest of the stream cannot be trusted.
+   * </pre>
+   *
+   * <code>ERROR_CODE_MESSAGE_TOO_LARGE = 9;</code>
+   */
+  ERROR_CODE_MESSAGE_TOO_LARGE(9),
+  /**
+   * <pre>
+   * The server was not started with what the request needs, like
+   * --capture-dir for CaptureFrame.path.
+   * </pre>
+   *
+   * <code>ERROR_CODE_PERMISSION_DENIED = 10;</code>
+   */
+  ERROR_CODE_PERMISSION_DENIED(10),
+  UNRECOGNIZED(-1),
+  ;
+
+  /**
+   * <code>ERROR_CODE_NONE = 0;</code>
+   */
+  public static final int ERROR_CODE_NONE_VALUE = 0;
+  /**
+   * <pre>
+   * The payload could not be decoded as a RootMessage.
+   * </pre>
+   *
+   * <code>ERROR_CODE_DECODE = 1;</code>
+   */
+  public static final int ERROR_CODE_DECODE_VALUE = 1;
+  /**
+   * <pre>
+   * The RootMessage did not carry a command this server understands.
+   * </pre>
+   *
+   * <code>ERROR_CODE_UNKNOWN_COMMAND = 2;</code>
+   */
+  public static final int ERROR_CODE_UNKNOWN_COMMAND_VALUE = 2;
+  /**
+   * <pre>
+   * The command was understood but its contents are not valid.
+   * </pre>
+   *
+   * <code>ERROR_CODE_INVALID_ARGUMENT = 3;</code>
+   */
+  public static final int ERROR_CODE_INVALID_ARGUMENT_VALUE = 3;
+  /**
+   * <pre>
+   * No stimulus with the requested id is in the scene.
+   * </pre>
+   *
+   * <code>ERROR_CODE_NOT_FOUND = 4;</code>
+   */
+  public static final int ERROR_CODE_NOT_FOUND_VALUE = 4;
+  /**
+   * <pre>
+   * A stimulus with the requested id is already in the scene.
+   * </pre>
+   *
+   * <code>ERROR_CODE_ALREADY_EXISTS = 5;</code>
+   */
+  public static final int ERROR_CODE_ALREADY_EXISTS_VALUE = 5;
+  /**
+   * <pre>
+   * No texture is stored under the requested index.
+   * </pre>
+   *
+   * <code>ERROR_CODE_TEXTURE_NOT_FOUND = 6;</code>
+   */
+  public static final int ERROR_CODE_TEXTURE_NOT_FOUND_VALUE = 6;
+  /**
+   * <pre>
+   * Client and server speak different protocol versions.
+   * </pre>
+   *
+   * <code>ERROR_CODE_VERSION_MISMATCH = 7;</code>
+   */
+  public static final int ERROR_CODE_VERSION_MISMATCH_VALUE = 7;
+  /**
+   * <pre>
+   * The client relies on a feature this server does not have.
+   * </pre>
+   *
+   * <code>ERROR_CODE_UNSUPPORTED_FEATURE = 8;</code>
+   */
+  public static final int ERROR_CODE_UNSUPPORTED_FEATURE_VALUE = 8;
+  /**
+   * <pre>
+   * The message is longer than the server accepts. The connection is closed
+   * after this reply, since the rest of the stream cannot be trusted.
+   * </pre>
+   *
+   * <code>ERROR_CODE_MESSAGE_TOO_LARGE = 9;</code>
+   */
+  public static final int ERROR_CODE_MESSAGE_TOO_LARGE_VALUE = 9;
+  /**
+   * <pre>
+   * The server was not started with what the request needs, like
+   * --capture-dir for CaptureFrame.path.
+   * </pre>
+   *
+   * <code>ERROR_CODE_PERMISSION_DENIED = 10;</code>
+   */
+  public static final int ERROR_CODE_PERMISSION_DENIED_VALUE = 10;
+
+
+  public final int getNumber() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalArgumentException(
+          "Can't get the number of an unknown enum value.");
+    }
+    return value;
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   * @deprecated Use {@link #forNumber(int)} instead.
+   */
+  @java.lang.Deprecated
+  public static ErrorCode valueOf(int value) {
+    return forNumber(value);
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   */
+  public static ErrorCode forNumber(int value) {
+    switch (value) {
+      case 0: return ERROR_CODE_NONE;
+      case 1: return ERROR_CODE_DECODE;
+      case 2: return ERROR_CODE_UNKNOWN_COMMAND;
+      case 3: return ERROR_CODE_INVALID_ARGUMENT;
+      case 4: return ERROR_CODE_NOT_FOUND;
+      case 5: return ERROR_CODE_ALREADY_EXISTS;
+      case 6: return ERROR_CODE_TEXTURE_NOT_FOUND;
+      case 7: return ERROR_CODE_VERSION_MISMATCH;
+      case 8: return ERROR_CODE_UNSUPPORTED_FEATURE;
+      case 9: return ERROR_CODE_MESSAGE_TOO_LARGE;
+      case 10: return ERROR_CODE_PERMISSION_DENIED;
+      default: return null;
+    }
+  }
+
+  public static com.google.protobuf.Internal.EnumLiteMap<ErrorCode>
+      internalGetValueMap() {
+    return internalValueMap;
+  }
+  private static final com.google.protobuf.Internal.EnumLiteMap<
+      ErrorCode> internalValueMap =
+        new com.google.protobuf.Internal.EnumLiteMap<ErrorCode>() {
+          public ErrorCode findValueByNumber(int number) {
+            return ErrorCode.forNumber(number);
+          }
+        };
+
+  public final com.google.protobuf.Descriptors.EnumValueDescriptor
+      getValueDescriptor() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalStateException(
+          "Can't get the descriptor of an unrecognized enum value.");
+    }
+    return getDescriptor().getValues().get(ordinal());
+  }
+  public final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptorForType() {
+    return getDescriptor();
+  }
+  public static final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptor() {
+    return vsg.Vsg.getDescriptor().getEnumTypes().get(1);
+  }
+
+  private static final ErrorCode[] VALUES = values();
+
+  public static ErrorCode valueOf(
+      com.google.protobuf.Descriptors.EnumValueDescriptor desc) {
+    if (desc.getType() != getDescriptor()) {
+      throw new java.lang.IllegalArgumentException(
+        "EnumValueDescriptor is not for this type.");
+    }
+    if (desc.getIndex() == -1) {
+      return UNRECOGNIZED;
+    }
+    return VALUES[desc.getIndex()];
+  }
+
+  private final int value;
+
+  private ErrorCode(int value) {
+    this.value = value;
+  }
+
+  // @@protoc_insertion_point(enum_scope:vsg.ErrorCode)
+}
+
@@ -0,0 +1,2119 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.Event}
+ */
+public final class Event extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Event)
+    EventOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Event.newBuilder() to construct.
+  private Event(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Event() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Event();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Event(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            timestampUs_ = input.readUInt64();
+            break;
+          }
+          case 18: {
+            vsg.InputEvent.Builder subBuilder = null;
+            if (kindCase_ == 2) {
+              subBuilder = ((vsg.InputEvent) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.InputEvent.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.InputEvent) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 2;
+            break;
+          }
+          case 26: {
+            vsg.FrameDrop.Builder subBuilder = null;
+            if (kindCase_ == 3) {
+              subBuilder = ((vsg.FrameDrop) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.FrameDrop.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.FrameDrop) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 3;
+            break;
+          }
+          case 34: {
+            vsg.Onset.Builder subBuilder = null;
+            if (kindCase_ == 4) {
+              subBuilder = ((vsg.Onset) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.Onset.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.Onset) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 4;
+            break;
+          }
+          case 42: {
+            vsg.StimulusOffset.Builder subBuilder = null;
+            if (kindCase_ == 5) {
+              subBuilder = ((vsg.StimulusOffset) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.StimulusOffset.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.StimulusOffset) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 5;
+            break;
+          }
+          case 50: {
+            vsg.SurfaceLost.Builder subBuilder = null;
+            if (kindCase_ == 6) {
+              subBuilder = ((vsg.SurfaceLost) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.SurfaceLost.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.SurfaceLost) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 6;
+            break;
+          }
+          case 58: {
+            vsg.Resize.Builder subBuilder = null;
+            if (kindCase_ == 7) {
+              subBuilder = ((vsg.Resize) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.Resize.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.Resize) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 7;
+            break;
+          }
+          case 66: {
+            vsg.CommandFailed.Builder subBuilder = null;
+            if (kindCase_ == 8) {
+              subBuilder = ((vsg.CommandFailed) kind_).toBuilder();
+            }
+            kind_ =
+                input.readMessage(vsg.CommandFailed.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.CommandFailed) kind_);
+              kind_ = subBuilder.buildPartial();
+            }
+            kindCase_ = 8;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_Event_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_Event_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.Event.class, vsg.Event.Builder.class);
+  }
+
+  private int kindCase_ = 0;
+  private java.lang.Object kind_;
+  public enum KindCase
+      implements com.google.protobuf.Internal.EnumLite,
+          com.google.protobuf.AbstractMessage.InternalOneOfEnum {
+    INPUT(2),
+    FRAME_DROP(3),
+    ONSET(4),
+    OFFSET(5),
+    SURFACE_LOST(6),
+    RESIZE(7),
+    COMMAND_FAILED(8),
+    KIND_NOT_SET(0);
+    private final int value;
+    private KindCase(int value) {
+      this.value = value;
+    }
+    /**
+     * @param value The number of the enum to look for.
+     * @return The enum associated with the given number.
+     * @deprecated Use {@link #forNumber(int)} instead.
+     */
+    @java.lang.Deprecated
+    public static KindCase valueOf(int value) {
+      return forNumber(value);
+    }
+
+    public static KindCase forNumber(int value) {
+      switch (value) {
+        case 2: return INPUT;
+        case 3: return FRAME_DROP;
+        case 4: return ONSET;
+        case 5: return OFFSET;
+        case 6: return SURFACE_LOST;
+        case 7: return RESIZE;
+        case 8: return COMMAND_FAILED;
+        case 0: return KIND_NOT_SET;
+        default: return null;
+      }
+    }
+    public int getNumber() {
+      return this.value;
+    }
+  };
+
+  public KindCase
+  getKindCase() {
+    return KindCase.forNumber(
+        kindCase_);
+  }
+
+  public static final int TIMESTAMP_US_FIELD_NUMBER = 1;
+  private long timestampUs_;
+  /**
+   * <pre>
+   * Server clock when the server learned about the event, as in
+   * Onset.timestamp_us.
+   * </pre>
+   *
+   * <code>uint64 timestamp_us = 1;</code>
+   * @return The timestampUs.
+   */
+  @java.lang.Override
+  public long getTimestampUs() {
+    return timestampUs_;
+  }
+
+  public static final int INPUT_FIELD_NUMBER = 2;
+  /**
+   * <code>.vsg.InputEvent input = 2;</code>
+   * @return Whether the input field is set.
+   */
+  @java.lang.Override
+  public boolean hasInput() {
+    return kindCase_ == 2;
+  }
+  /**
+   * <code>.vsg.InputEvent input = 2;</code>
+   * @return The input.
+   */
+  @java.lang.Override
+  public vsg.InputEvent getInput() {
+    if (kindCase_ == 2) {
+       return (vsg.InputEvent) kind_;
+    }
+    return vsg.InputEvent.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.InputEvent input = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.InputEventOrBuilder getInputOrBuilder() {
+    if (kindCase_ == 2) {
+       return (vsg.InputEvent) kind_;
+    }
+    return vsg.InputEvent.getDefaultInstance();
+  }
+
+  public static final int FRAME_DROP_FIELD_NUMBER = 3;
+  /**
+   * <code>.vsg.FrameDrop frame_drop = 3;</code>
+   * @return Whether the frameDrop field is set.
+   */
+  @java.lang.Override
+  public boolean hasFrameDrop() {
+    return kindCase_ == 3;
+  }
+  /**
+   * <code>.vsg.FrameDrop frame_drop = 3;</code>
+   * @return The frameDrop.
+   */
+  @java.lang.Override
+  public vsg.FrameDrop getFrameDrop() {
+    if (kindCase_ == 3) {
+       return (vsg.FrameDrop) kind_;
+    }
+    return vsg.FrameDrop.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.FrameDrop frame_drop = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.FrameDropOrBuilder getFrameDropOrBuilder() {
+    if (kindCase_ == 3) {
+       return (vsg.FrameDrop) kind_;
+    }
+    return vsg.FrameDrop.getDefaultInstance();
+  }
+
+  public static final int ONSET_FIELD_NUMBER = 4;
+  /**
+   * <code>.vsg.Onset onset = 4;</code>
+   * @return Whether the onset field is set.
+   */
+  @java.lang.Override
+  public boolean hasOnset() {
+    return kindCase_ == 4;
+  }
+  /**
+   * <code>.vsg.Onset onset = 4;</code>
+   * @return The onset.
+   */
+  @java.lang.Override
+  public vsg.Onset getOnset() {
+    if (kindCase_ == 4) {
+       return (vsg.Onset) kind_;
+    }
+    return vsg.Onset.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.Onset onset = 4;</code>
+   */
+  @java.lang.Override
+  public vsg.OnsetOrBuilder getOnsetOrBuilder() {
+    if (kindCase_ == 4) {
+       return (vsg.Onset) kind_;
+    }
+    return vsg.Onset.getDefaultInstance();
+  }
+
+  public static final int OFFSET_FIELD_NUMBER = 5;
+  /**
+   * <code>.vsg.StimulusOffset offset = 5;</code>
+   * @return Whether the offset field is set.
+   */
+  @java.lang.Override
+  public boolean hasOffset() {
+    return kindCase_ == 5;
+  }
+  /**
+   * <code>.vsg.StimulusOffset offset = 5;</code>
+   * @return The offset.
+   */
+  @java.lang.Override
+  public vsg.StimulusOffset getOffset() {
+    if (kindCase_ == 5) {
+       return (vsg.StimulusOffset) kind_;
+    }
+    return vsg.StimulusOffset.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.StimulusOffset offset = 5;</code>
+   */
+  @java.lang.Override
+  public vsg.StimulusOffsetOrBuilder getOffsetOrBuilder() {
+    if (kindCase_ == 5) {
+       return (vsg.StimulusOffset) kind_;
+    }
+    return vsg.StimulusOffset.getDefaultInstance();
+  }
+
+  public static final int SURFACE_LOST_FIELD_NUMBER = 6;
+  /**
+   * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+   * @return Whether the surfaceLost field is set.
+   */
+  @java.lang.Override
+  public boolean hasSurfaceLost() {
+    return kindCase_ == 6;
+  }
+  /**
+   * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+   * @return The surfaceLost.
+   */
+  @java.lang.Override
+  public vsg.SurfaceLost getSurfaceLost() {
+    if (kindCase_ == 6) {
+       return (vsg.SurfaceLost) kind_;
+    }
+    return vsg.SurfaceLost.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+   */
+  @java.lang.Override
+  public vsg.SurfaceLostOrBuilder getSurfaceLostOrBuilder() {
+    if (kindCase_ == 6) {
+       return (vsg.SurfaceLost) kind_;
+    }
+    return vsg.SurfaceLost.getDefaultInstance();
+  }
+
+  public static final int RESIZE_FIELD_NUMBER = 7;
+  /**
+   * <code>.vsg.Resize resize = 7;</code>
+   * @return Whether the resize field is set.
+   */
+  @java.lang.Override
+  public boolean hasResize() {
+    return kindCase_ == 7;
+  }
+  /**
+   * <code>.vsg.Resize resize = 7;</code>
+   * @return The resize.
+   */
+  @java.lang.Override
+  public vsg.Resize getResize() {
+    if (kindCase_ == 7) {
+       return (vsg.Resize) kind_;
+    }
+    return vsg.Resize.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.Resize resize = 7;</code>
+   */
+  @java.lang.Override
+  public vsg.ResizeOrBuilder getResizeOrBuilder() {
+    if (kindCase_ == 7) {
+       return (vsg.Resize) kind_;
+    }
+    return vsg.Resize.getDefaultInstance();
+  }
+
+  public static final int COMMAND_FAILED_FIELD_NUMBER = 8;
+  /**
+   * <pre>
+   * Sent to the connection whose command failed, subscribed or not.
+   * </pre>
+   *
+   * <code>.vsg.CommandFailed command_failed = 8;</code>
+   * @return Whether the commandFailed field is set.
+   */
+  @java.lang.Override
+  public boolean hasCommandFailed() {
+    return kindCase_ == 8;
+  }
+  /**
+   * <pre>
+   * Sent to the connection whose command failed, subscribed or not.
+   * </pre>
+   *
+   * <code>.vsg.CommandFailed command_failed = 8;</code>
+   * @return The commandFailed.
+   */
+  @java.lang.Override
+  public vsg.CommandFailed getCommandFailed() {
+    if (kindCase_ == 8) {
+       return (vsg.CommandFailed) kind_;
+    }
+    return vsg.CommandFailed.getDefaultInstance();
+  }
+  /**
+   * <pre>
+   * Sent to the connection whose command failed, subscribed or not.
+   * </pre>
+   *
+   * <code>.vsg.CommandFailed command_failed = 8;</code>
+   */
+  @java.lang.Override
+  public vsg.CommandFailedOrBuilder getCommandFailedOrBuilder() {
+    if (kindCase_ == 8) {
+       return (vsg.CommandFailed) kind_;
+    }
+    return vsg.CommandFailed.getDefaultInstance();
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (timestampUs_ != 0L) {
+      output.writeUInt64(1, timestampUs_);
+    }
+    if (kindCase_ == 2) {
+      output.writeMessage(2, (vsg.InputEvent) kind_);
+    }
+    if (kindCase_ == 3) {
+      output.writeMessage(3, (vsg.FrameDrop) kind_);
+    }
+    if (kindCase_ == 4) {
+      output.writeMessage(4, (vsg.Onset) kind_);
+    }
+    if (kindCase_ == 5) {
+      output.writeMessage(5, (vsg.StimulusOffset) kind_);
+    }
+    if (kindCase_ == 6) {
+      output.writeMessage(6, (vsg.SurfaceLost) kind_);
+    }
+    if (kindCase_ == 7) {
+      output.writeMessage(7, (vsg.Resize) kind_);
+    }
+    if (kindCase_ == 8) {
+      output.writeMessage(8, (vsg.CommandFailed) kind_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (timestampUs_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(1, timestampUs_);
+    }
+    if (kindCase_ == 2) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, (vsg.InputEvent) kind_);
+    }
+    if (kindCase_ == 3) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, (vsg.FrameDrop) kind_);
+    }
+    if (kindCase_ == 4) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(4, (vsg.Onset) kind_);
+    }
+    if (kindCase_ == 5) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(5, (vsg.StimulusOffset) kind_);
+    }
+    if (kindCase_ == 6) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(6, (vsg.SurfaceLost) kind_);
+    }
+    if (kindCase_ == 7) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(7, (vsg.Resize) kind_);
+    }
+    if (kindCase_ == 8) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(8, (vsg.CommandFailed) kind_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.Event)) {
+      return super.equals(obj);
+    }
+    vsg.Event other = (vsg.Event) obj;
+
+    if (getTimestampUs()
+        != other.getTimestampUs()) return false;
+    if (!getKindCase().equals(other.getKindCase())) return false;
+    switch (kindCase_) {
+      case 2:
+        if (!getInput()
+            .equals(other.getInput())) return false;
+        break;
+      case 3:
+        if (!getFrameDrop()
+            .equals(other.getFrameDrop())) return false;
+        break;
+      case 4:
+        if (!getOnset()
+            .equals(other.getOnset())) return false;
+        break;
+      case 5:
+        if (!getOffset()
+            .equals(other.getOffset())) return false;
+        break;
+      case 6:
+        if (!getSurfaceLost()
+            .equals(other.getSurfaceLost())) return false;
+        break;
+      case 7:
+        if (!getResize()
+            .equals(other.getResize())) return false;
+        break;
+      case 8:
+        if (!getCommandFailed()
+            .equals(other.getCommandFailed())) return false;
+        break;
+      case 0:
+      default:
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + TIMESTAMP_US_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getTimestampUs());
+    switch (kindCase_) {
+      case 2:
+        hash = (37 * hash) + INPUT_FIELD_NUMBER;
+        hash = (53 * hash) + getInput().hashCode();
+        break;
+      case 3:
+        hash = (37 * hash) + FRAME_DROP_FIELD_NUMBER;
+        hash = (53 * hash) + getFrameDrop().hashCode();
+        break;
+      case 4:
+        hash = (37 * hash) + ONSET_FIELD_NUMBER;
+        hash = (53 * hash) + getOnset().hashCode();
+        break;
+      case 5:
+        hash = (37 * hash) + OFFSET_FIELD_NUMBER;
+        hash = (53 * hash) + getOffset().hashCode();
+        break;
+      case 6:
+        hash = (37 * hash) + SURFACE_LOST_FIELD_NUMBER;
+        hash = (53 * hash) + getSurfaceLost().hashCode();
+        break;
+      case 7:
+        hash = (37 * hash) + RESIZE_FIELD_NUMBER;
+        hash = (53 * hash) + getResize().hashCode();
+        break;
+      case 8:
+        hash = (37 * hash) + COMMAND_FAILED_FIELD_NUMBER;
+        hash = (53 * hash) + getCommandFailed().hashCode();
+        break;
+      case 0:
+      default:
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.Event parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Event parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Event parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Event parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Event parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Event parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Event parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Event parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Event parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.Event parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Event parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Event parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.Event prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.Event}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Event)
+      vsg.EventOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_Event_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_Event_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.Event.class, vsg.Event.Builder.class);
+    }
+
+    // Construct using vsg.Event.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      timestampUs_ = 0L;
+
+      kindCase_ = 0;
+      kind_ = null;
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_Event_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.Event getDefaultInstanceForType() {
+      return vsg.Event.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.Event build() {
+      vsg.Event result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.Event buildPartial() {
+      vsg.Event result = new vsg.Event(this);
+      result.timestampUs_ = timestampUs_;
+      if (kindCase_ == 2) {
+        if (inputBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = inputBuilder_.build();
+        }
+      }
+      if (kindCase_ == 3) {
+        if (frameDropBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = frameDropBuilder_.build();
+        }
+      }
+      if (kindCase_ == 4) {
+        if (onsetBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = onsetBuilder_.build();
+        }
+      }
+      if (kindCase_ == 5) {
+        if (offsetBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = offsetBuilder_.build();
+        }
+      }
+      if (kindCase_ == 6) {
+        if (surfaceLostBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = surfaceLostBuilder_.build();
+        }
+      }
+      if (kindCase_ == 7) {
+        if (resizeBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = resizeBuilder_.build();
+        }
+      }
+      if (kindCase_ == 8) {
+        if (commandFailedBuilder_ == null) {
+          result.kind_ = kind_;
+        } else {
+          result.kind_ = commandFailedBuilder_.build();
+        }
+      }
+      result.kindCase_ = kindCase_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.Event) {
+        return mergeFrom((vsg.Event)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.Event other) {
+      if (other == vsg.Event.getDefaultInstance()) return this;
+      if (other.getTimestampUs() != 0L) {
+        setTimestampUs(other.getTimestampUs());
+      }
+      switch (other.getKindCase()) {
+        case INPUT: {
+          mergeInput(other.getInput());
+          break;
+        }
+        case FRAME_DROP: {
+          mergeFrameDrop(other.getFrameDrop());
+          break;
+        }
+        case ONSET: {
+          mergeOnset(other.getOnset());
+          break;
+        }
+        case OFFSET: {
+          mergeOffset(other.getOffset());
+          break;
+        }
+        case SURFACE_LOST: {
+          mergeSurfaceLost(other.getSurfaceLost());
+          break;
+        }
+        case RESIZE: {
+          mergeResize(other.getResize());
+          break;
+        }
+        case COMMAND_FAILED: {
+          mergeCommandFailed(other.getCommandFailed());
+          break;
+        }
+        case KIND_NOT_SET: {
+          break;
+        }
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.Event parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.Event) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int kindCase_ = 0;
+    private java.lang.Object kind_;
+    public KindCase
+        getKindCase() {
+      return KindCase.forNumber(
+          kindCase_);
+    }
+
+    public Builder clearKind() {
+      kindCase_ = 0;
+      kind_ = null;
+      onChanged();
+      return this;
+    }
+
+
+    private long timestampUs_ ;
+    /**
+     * <pre>
+     * Server clock when the server learned about the event, as in
+     * Onset.timestamp_us.
+     * </pre>
+     *
+     * <code>uint64 timestamp_us = 1;</code>
+     * @return The timestampUs.
+     */
+    @java.lang.Override
+    public long getTimestampUs() {
+      return timestampUs_;
+    }
+    /**
+     * <pre>
+     * Server clock when the server learned about the event, as in
+     * Onset.timestamp_us.
+     * </pre>
+     *
+     * <code>uint64 timestamp_us = 1;</code>
+     * @param value The timestampUs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setTimestampUs(long value) {
+      
+      timestampUs_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Server clock when the server learned about the event, as in
+     * Onset.timestamp_us.
+     * </pre>
+     *
+     * <code>uint64 timestamp_us = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearTimestampUs() {
+      
+      timestampUs_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.InputEvent, vsg.InputEvent.Builder, vsg.InputEventOrBuilder> inputBuilder_;
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     * @return Whether the input field is set.
+     */
+    @java.lang.Override
+    public boolean hasInput() {
+      return kindCase_ == 2;
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     * @return The input.
+     */
+    @java.lang.Override
+    public vsg.InputEvent getInput() {
+      if (inputBuilder_ == null) {
+        if (kindCase_ == 2) {
+          return (vsg.InputEvent) kind_;
+        }
+        return vsg.InputEvent.getDefaultInstance();
+      } else {
+        if (kindCase_ == 2) {
+          return inputBuilder_.getMessage();
+        }
+        return vsg.InputEvent.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    public Builder setInput(vsg.InputEvent value) {
+      if (inputBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        inputBuilder_.setMessage(value);
+      }
+      kindCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    public Builder setInput(
+        vsg.InputEvent.Builder builderForValue) {
+      if (inputBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        inputBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    public Builder mergeInput(vsg.InputEvent value) {
+      if (inputBuilder_ == null) {
+        if (kindCase_ == 2 &&
+            kind_ != vsg.InputEvent.getDefaultInstance()) {
+          kind_ = vsg.InputEvent.newBuilder((vsg.InputEvent) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 2) {
+          inputBuilder_.mergeFrom(value);
+        }
+        inputBuilder_.setMessage(value);
+      }
+      kindCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    public Builder clearInput() {
+      if (inputBuilder_ == null) {
+        if (kindCase_ == 2) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 2) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        inputBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    public vsg.InputEvent.Builder getInputBuilder() {
+      return getInputFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    @java.lang.Override
+    public vsg.InputEventOrBuilder getInputOrBuilder() {
+      if ((kindCase_ == 2) && (inputBuilder_ != null)) {
+        return inputBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 2) {
+          return (vsg.InputEvent) kind_;
+        }
+        return vsg.InputEvent.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.InputEvent input = 2;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.InputEvent, vsg.InputEvent.Builder, vsg.InputEventOrBuilder> 
+        getInputFieldBuilder() {
+      if (inputBuilder_ == null) {
+        if (!(kindCase_ == 2)) {
+          kind_ = vsg.InputEvent.getDefaultInstance();
+        }
+        inputBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.InputEvent, vsg.InputEvent.Builder, vsg.InputEventOrBuilder>(
+                (vsg.InputEvent) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 2;
+      onChanged();;
+      return inputBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.FrameDrop, vsg.FrameDrop.Builder, vsg.FrameDropOrBuilder> frameDropBuilder_;
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     * @return Whether the frameDrop field is set.
+     */
+    @java.lang.Override
+    public boolean hasFrameDrop() {
+      return kindCase_ == 3;
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     * @return The frameDrop.
+     */
+    @java.lang.Override
+    public vsg.FrameDrop getFrameDrop() {
+      if (frameDropBuilder_ == null) {
+        if (kindCase_ == 3) {
+          return (vsg.FrameDrop) kind_;
+        }
+        return vsg.FrameDrop.getDefaultInstance();
+      } else {
+        if (kindCase_ == 3) {
+          return frameDropBuilder_.getMessage();
+        }
+        return vsg.FrameDrop.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    public Builder setFrameDrop(vsg.FrameDrop value) {
+      if (frameDropBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        frameDropBuilder_.setMessage(value);
+      }
+      kindCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    public Builder setFrameDrop(
+        vsg.FrameDrop.Builder builderForValue) {
+      if (frameDropBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        frameDropBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    public Builder mergeFrameDrop(vsg.FrameDrop value) {
+      if (frameDropBuilder_ == null) {
+        if (kindCase_ == 3 &&
+            kind_ != vsg.FrameDrop.getDefaultInstance()) {
+          kind_ = vsg.FrameDrop.newBuilder((vsg.FrameDrop) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 3) {
+          frameDropBuilder_.mergeFrom(value);
+        }
+        frameDropBuilder_.setMessage(value);
+      }
+      kindCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    public Builder clearFrameDrop() {
+      if (frameDropBuilder_ == null) {
+        if (kindCase_ == 3) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 3) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        frameDropBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    public vsg.FrameDrop.Builder getFrameDropBuilder() {
+      return getFrameDropFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    @java.lang.Override
+    public vsg.FrameDropOrBuilder getFrameDropOrBuilder() {
+      if ((kindCase_ == 3) && (frameDropBuilder_ != null)) {
+        return frameDropBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 3) {
+          return (vsg.FrameDrop) kind_;
+        }
+        return vsg.FrameDrop.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.FrameDrop frame_drop = 3;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.FrameDrop, vsg.FrameDrop.Builder, vsg.FrameDropOrBuilder> 
+        getFrameDropFieldBuilder() {
+      if (frameDropBuilder_ == null) {
+        if (!(kindCase_ == 3)) {
+          kind_ = vsg.FrameDrop.getDefaultInstance();
+        }
+        frameDropBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.FrameDrop, vsg.FrameDrop.Builder, vsg.FrameDropOrBuilder>(
+                (vsg.FrameDrop) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 3;
+      onChanged();;
+      return frameDropBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Onset, vsg.Onset.Builder, vsg.OnsetOrBuilder> onsetBuilder_;
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     * @return Whether the onset field is set.
+     */
+    @java.lang.Override
+    public boolean hasOnset() {
+      return kindCase_ == 4;
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     * @return The onset.
+     */
+    @java.lang.Override
+    public vsg.Onset getOnset() {
+      if (onsetBuilder_ == null) {
+        if (kindCase_ == 4) {
+          return (vsg.Onset) kind_;
+        }
+        return vsg.Onset.getDefaultInstance();
+      } else {
+        if (kindCase_ == 4) {
+          return onsetBuilder_.getMessage();
+        }
+        return vsg.Onset.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    public Builder setOnset(vsg.Onset value) {
+      if (onsetBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        onsetBuilder_.setMessage(value);
+      }
+      kindCase_ = 4;
+      return this;
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    public Builder setOnset(
+        vsg.Onset.Builder builderForValue) {
+      if (onsetBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        onsetBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 4;
+      return this;
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    public Builder mergeOnset(vsg.Onset value) {
+      if (onsetBuilder_ == null) {
+        if (kindCase_ == 4 &&
+            kind_ != vsg.Onset.getDefaultInstance()) {
+          kind_ = vsg.Onset.newBuilder((vsg.Onset) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 4) {
+          onsetBuilder_.mergeFrom(value);
+        }
+        onsetBuilder_.setMessage(value);
+      }
+      kindCase_ = 4;
+      return this;
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    public Builder clearOnset() {
+      if (onsetBuilder_ == null) {
+        if (kindCase_ == 4) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 4) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        onsetBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    public vsg.Onset.Builder getOnsetBuilder() {
+      return getOnsetFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    @java.lang.Override
+    public vsg.OnsetOrBuilder getOnsetOrBuilder() {
+      if ((kindCase_ == 4) && (onsetBuilder_ != null)) {
+        return onsetBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 4) {
+          return (vsg.Onset) kind_;
+        }
+        return vsg.Onset.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Onset onset = 4;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Onset, vsg.Onset.Builder, vsg.OnsetOrBuilder> 
+        getOnsetFieldBuilder() {
+      if (onsetBuilder_ == null) {
+        if (!(kindCase_ == 4)) {
+          kind_ = vsg.Onset.getDefaultInstance();
+        }
+        onsetBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Onset, vsg.Onset.Builder, vsg.OnsetOrBuilder>(
+                (vsg.Onset) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 4;
+      onChanged();;
+      return onsetBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.StimulusOffset, vsg.StimulusOffset.Builder, vsg.StimulusOffsetOrBuilder> offsetBuilder_;
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     * @return Whether the offset field is set.
+     */
+    @java.lang.Override
+    public boolean hasOffset() {
+      return kindCase_ == 5;
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     * @return The offset.
+     */
+    @java.lang.Override
+    public vsg.StimulusOffset getOffset() {
+      if (offsetBuilder_ == null) {
+        if (kindCase_ == 5) {
+          return (vsg.StimulusOffset) kind_;
+        }
+        return vsg.StimulusOffset.getDefaultInstance();
+      } else {
+        if (kindCase_ == 5) {
+          return offsetBuilder_.getMessage();
+        }
+        return vsg.StimulusOffset.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    public Builder setOffset(vsg.StimulusOffset value) {
+      if (offsetBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        offsetBuilder_.setMessage(value);
+      }
+      kindCase_ = 5;
+      return this;
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    public Builder setOffset(
+        vsg.StimulusOffset.Builder builderForValue) {
+      if (offsetBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        offsetBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 5;
+      return this;
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    public Builder mergeOffset(vsg.StimulusOffset value) {
+      if (offsetBuilder_ == null) {
+        if (kindCase_ == 5 &&
+            kind_ != vsg.StimulusOffset.getDefaultInstance()) {
+          kind_ = vsg.StimulusOffset.newBuilder((vsg.StimulusOffset) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 5) {
+          offsetBuilder_.mergeFrom(value);
+        }
+        offsetBuilder_.setMessage(value);
+      }
+      kindCase_ = 5;
+      return this;
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    public Builder clearOffset() {
+      if (offsetBuilder_ == null) {
+        if (kindCase_ == 5) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 5) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        offsetBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    public vsg.StimulusOffset.Builder getOffsetBuilder() {
+      return getOffsetFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    @java.lang.Override
+    public vsg.StimulusOffsetOrBuilder getOffsetOrBuilder() {
+      if ((kindCase_ == 5) && (offsetBuilder_ != null)) {
+        return offsetBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 5) {
+          return (vsg.StimulusOffset) kind_;
+        }
+        return vsg.StimulusOffset.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.StimulusOffset offset = 5;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.StimulusOffset, vsg.StimulusOffset.Builder, vsg.StimulusOffsetOrBuilder> 
+        getOffsetFieldBuilder() {
+      if (offsetBuilder_ == null) {
+        if (!(kindCase_ == 5)) {
+          kind_ = vsg.StimulusOffset.getDefaultInstance();
+        }
+        offsetBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.StimulusOffset, vsg.StimulusOffset.Builder, vsg.StimulusOffsetOrBuilder>(
+                (vsg.StimulusOffset) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 5;
+      onChanged();;
+      return offsetBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.SurfaceLost, vsg.SurfaceLost.Builder, vsg.SurfaceLostOrBuilder> surfaceLostBuilder_;
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     * @return Whether the surfaceLost field is set.
+     */
+    @java.lang.Override
+    public boolean hasSurfaceLost() {
+      return kindCase_ == 6;
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     * @return The surfaceLost.
+     */
+    @java.lang.Override
+    public vsg.SurfaceLost getSurfaceLost() {
+      if (surfaceLostBuilder_ == null) {
+        if (kindCase_ == 6) {
+          return (vsg.SurfaceLost) kind_;
+        }
+        return vsg.SurfaceLost.getDefaultInstance();
+      } else {
+        if (kindCase_ == 6) {
+          return surfaceLostBuilder_.getMessage();
+        }
+        return vsg.SurfaceLost.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    public Builder setSurfaceLost(vsg.SurfaceLost value) {
+      if (surfaceLostBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        surfaceLostBuilder_.setMessage(value);
+      }
+      kindCase_ = 6;
+      return this;
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    public Builder setSurfaceLost(
+        vsg.SurfaceLost.Builder builderForValue) {
+      if (surfaceLostBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        surfaceLostBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 6;
+      return this;
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    public Builder mergeSurfaceLost(vsg.SurfaceLost value) {
+      if (surfaceLostBuilder_ == null) {
+        if (kindCase_ == 6 &&
+            kind_ != vsg.SurfaceLost.getDefaultInstance()) {
+          kind_ = vsg.SurfaceLost.newBuilder((vsg.SurfaceLost) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 6) {
+          surfaceLostBuilder_.mergeFrom(value);
+        }
+        surfaceLostBuilder_.setMessage(value);
+      }
+      kindCase_ = 6;
+      return this;
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    public Builder clearSurfaceLost() {
+      if (surfaceLostBuilder_ == null) {
+        if (kindCase_ == 6) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 6) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        surfaceLostBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    public vsg.SurfaceLost.Builder getSurfaceLostBuilder() {
+      return getSurfaceLostFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    @java.lang.Override
+    public vsg.SurfaceLostOrBuilder getSurfaceLostOrBuilder() {
+      if ((kindCase_ == 6) && (surfaceLostBuilder_ != null)) {
+        return surfaceLostBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 6) {
+          return (vsg.SurfaceLost) kind_;
+        }
+        return vsg.SurfaceLost.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.SurfaceLost, vsg.SurfaceLost.Builder, vsg.SurfaceLostOrBuilder> 
+        getSurfaceLostFieldBuilder() {
+      if (surfaceLostBuilder_ == null) {
+        if (!(kindCase_ == 6)) {
+          kind_ = vsg.SurfaceLost.getDefaultInstance();
+        }
+        surfaceLostBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.SurfaceLost, vsg.SurfaceLost.Builder, vsg.SurfaceLostOrBuilder>(
+                (vsg.SurfaceLost) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 6;
+      onChanged();;
+      return surfaceLostBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Resize, vsg.Resize.Builder, vsg.ResizeOrBuilder> resizeBuilder_;
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     * @return Whether the resize field is set.
+     */
+    @java.lang.Override
+    public boolean hasResize() {
+      return kindCase_ == 7;
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     * @return The resize.
+     */
+    @java.lang.Override
+    public vsg.Resize getResize() {
+      if (resizeBuilder_ == null) {
+        if (kindCase_ == 7) {
+          return (vsg.Resize) kind_;
+        }
+        return vsg.Resize.getDefaultInstance();
+      } else {
+        if (kindCase_ == 7) {
+          return resizeBuilder_.getMessage();
+        }
+        return vsg.Resize.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    public Builder setResize(vsg.Resize value) {
+      if (resizeBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        resizeBuilder_.setMessage(value);
+      }
+      kindCase_ = 7;
+      return this;
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    public Builder setResize(
+        vsg.Resize.Builder builderForValue) {
+      if (resizeBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        resizeBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 7;
+      return this;
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    public Builder mergeResize(vsg.Resize value) {
+      if (resizeBuilder_ == null) {
+        if (kindCase_ == 7 &&
+            kind_ != vsg.Resize.getDefaultInstance()) {
+          kind_ = vsg.Resize.newBuilder((vsg.Resize) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 7) {
+          resizeBuilder_.mergeFrom(value);
+        }
+        resizeBuilder_.setMessage(value);
+      }
+      kindCase_ = 7;
+      return this;
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    public Builder clearResize() {
+      if (resizeBuilder_ == null) {
+        if (kindCase_ == 7) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 7) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        resizeBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    public vsg.Resize.Builder getResizeBuilder() {
+      return getResizeFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    @java.lang.Override
+    public vsg.ResizeOrBuilder getResizeOrBuilder() {
+      if ((kindCase_ == 7) && (resizeBuilder_ != null)) {
+        return resizeBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 7) {
+          return (vsg.Resize) kind_;
+        }
+        return vsg.Resize.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Resize resize = 7;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Resize, vsg.Resize.Builder, vsg.ResizeOrBuilder> 
+        getResizeFieldBuilder() {
+      if (resizeBuilder_ == null) {
+        if (!(kindCase_ == 7)) {
+          kind_ = vsg.Resize.getDefaultInstance();
+        }
+        resizeBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Resize, vsg.Resize.Builder, vsg.ResizeOrBuilder>(
+                (vsg.Resize) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 7;
+      onChanged();;
+      return resizeBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.CommandFailed, vsg.CommandFailed.Builder, vsg.CommandFailedOrBuilder> commandFailedBuilder_;
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     * @return Whether the commandFailed field is set.
+     */
+    @java.lang.Override
+    public boolean hasCommandFailed() {
+      return kindCase_ == 8;
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     * @return The commandFailed.
+     */
+    @java.lang.Override
+    public vsg.CommandFailed getCommandFailed() {
+      if (commandFailedBuilder_ == null) {
+        if (kindCase_ == 8) {
+          return (vsg.CommandFailed) kind_;
+        }
+        return vsg.CommandFailed.getDefaultInstance();
+      } else {
+        if (kindCase_ == 8) {
+          return commandFailedBuilder_.getMessage();
+        }
+        return vsg.CommandFailed.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    public Builder setCommandFailed(vsg.CommandFailed value) {
+      if (commandFailedBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        kind_ = value;
+        onChanged();
+      } else {
+        commandFailedBuilder_.setMessage(value);
+      }
+      kindCase_ = 8;
+      return this;
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    public Builder setCommandFailed(
+        vsg.CommandFailed.Builder builderForValue) {
+      if (commandFailedBuilder_ == null) {
+        kind_ = builderForValue.build();
+        onChanged();
+      } else {
+        commandFailedBuilder_.setMessage(builderForValue.build());
+      }
+      kindCase_ = 8;
+      return this;
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    public Builder mergeCommandFailed(vsg.CommandFailed value) {
+      if (commandFailedBuilder_ == null) {
+        if (kindCase_ == 8 &&
+            kind_ != vsg.CommandFailed.getDefaultInstance()) {
+          kind_ = vsg.CommandFailed.newBuilder((vsg.CommandFailed) kind_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          kind_ = value;
+        }
+        onChanged();
+      } else {
+        if (kindCase_ == 8) {
+          commandFailedBuilder_.mergeFrom(value);
+        }
+        commandFailedBuilder_.setMessage(value);
+      }
+      kindCase_ = 8;
+      return this;
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    public Builder clearCommandFailed() {
+      if (commandFailedBuilder_ == null) {
+        if (kindCase_ == 8) {
+          kindCase_ = 0;
+          kind_ = null;
+          onChanged();
+        }
+      } else {
+        if (kindCase_ == 8) {
+          kindCase_ = 0;
+          kind_ = null;
+        }
+        commandFailedBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    public vsg.CommandFailed.Builder getCommandFailedBuilder() {
+      return getCommandFailedFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    @java.lang.Override
+    public vsg.CommandFailedOrBuilder getCommandFailedOrBuilder() {
+      if ((kindCase_ == 8) && (commandFailedBuilder_ != null)) {
+        return commandFailedBuilder_.getMessageOrBuilder();
+      } else {
+        if (kindCase_ == 8) {
+          return (vsg.CommandFailed) kind_;
+        }
+        return vsg.CommandFailed.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Sent to the connection whose command failed, subscribed or not.
+     * </pre>
+     *
+     * <code>.vsg.CommandFailed command_failed = 8;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.CommandFailed, vsg.CommandFailed.Builder, vsg.CommandFailedOrBuilder> 
+        getCommandFailedFieldBuilder() {
+      if (commandFailedBuilder_ == null) {
+        if (!(kindCase_ == 8)) {
+          kind_ = vsg.CommandFailed.getDefaultInstance();
+        }
+        commandFailedBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.CommandFailed, vsg.CommandFailed.Builder, vsg.CommandFailedOrBuilder>(
+                (vsg.CommandFailed) kind_,
+                getParentForChildren(),
+                isClean());
+        kind_ = null;
+      }
+      kindCase_ = 8;
+      onChanged();;
+      return commandFailedBuilder_;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Event)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Event)
+  private static final vsg.Event DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.Event();
+  }
+
+  public static vsg.Event getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Event>
+      PARSER = new com.google.protobuf.AbstractParser<Event>() {
+    @java.lang.Override
+    public Event parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Event(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Event> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Event> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.Event getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,192 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf enum {@code vsg.EventKind}
+ */
+public enum EventKind
+    implements com.google.protobuf.ProtocolMessageEnum {
+  /**
+   * <code>EVENT_KIND_UNSPECIFIED = 0;</code>
+   */
+  EVENT_KIND_UNSPECIFIED(0),
+  /**
+   * <pre>
+   * Key presses and releases, and mouse buttons.
+   * </pre>
+   *
+   * <code>EVENT_KIND_INPUT = 1;</code>
+   */
+  EVENT_KIND_INPUT(1),
+  /**
+   * <pre>
+   * Frames presented over 1.5 refresh intervals after the previous one, so
+   * that at least one refresh was missed.
+   * </pre>
+   *
+   * <code>EVENT_KIND_FRAME_DROP = 2;</code>
+   */
+  EVENT_KIND_FRAME_DROP(2),
+  /**
+   * <pre>
+   * Frames on which commands took effect, from any connection.
+   * </pre>
+   *
+   * <code>EVENT_KIND_ONSET = 3;</code>
+   */
+  EVENT_KIND_ONSET(3),
+  /**
+   * <pre>
+   * Stimuli removed at the end of their lifetime.
+   * </pre>
+   *
+   * <code>EVENT_KIND_OFFSET = 4;</code>
+   */
+  EVENT_KIND_OFFSET(4),
+  /**
+   * <code>EVENT_KIND_SURFACE_LOST = 5;</code>
+   */
+  EVENT_KIND_SURFACE_LOST(5),
+  /**
+   * <code>EVENT_KIND_RESIZE = 6;</code>
+   */
+  EVENT_KIND_RESIZE(6),
+  UNRECOGNIZED(-1),
+  ;
+
+  /**
+   * <code>EVENT_KIND_UNSPECIFIED = 0;</code>
+   */
+  public static final int EVENT_KIND_UNSPECIFIED_VALUE = 0;
+  /**
+   * <pre>
+   * Key presses and releases, and mouse buttons.
+   * </pre>
+   *
+   * <code>EVENT_KIND_INPUT = 1;</code>
+   */
+  public static final int EVENT_KIND_INPUT_VALUE = 1;
+  /**
+   * <pre>
+   * Frames presented over 1.5 refresh intervals after the previous one, so
+   * that at least one refresh was missed.
+   * </pre>
+   *
+   * <code>EVENT_KIND_FRAME_DROP = 2;</code>
+   */
+  public static final int EVENT_KIND_FRAME_DROP_VALUE = 2;
+  /**
+   * <pre>
+   * Frames on which commands took effect, from any connection.
+   * </pre>
+   *
+   * <code>EVENT_KIND_ONSET = 3;</code>
+   */
+  public static final int EVENT_KIND_ONSET_VALUE = 3;
+  /**
+   * <pre>
+   * Stimuli removed at the end of their lifetime.
+   * </pre>
+   *
+   * <code>EVENT_KIND_OFFSET = 4;</code>
+   */
+  public static final int EVENT_KIND_OFFSET_VALUE = 4;
+  /**
+   * <code>EVENT_KIND_SURFACE_LOST = 5;</code>
+   */
+  public static final int EVENT_KIND_SURFACE_LOST_VALUE = 5;
+  /**
+   * <code>EVENT_KIND_RESIZE = 6;</code>
+   */
+  public static final int EVENT_KIND_RESIZE_VALUE = 6;
+
+
+  public final int getNumber() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalArgumentException(
+          "Can't get the number of an unknown enum value.");
+    }
+    return value;
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   * @deprecated Use {@link #forNumber(int)} instead.
+   */
+  @java.lang.Deprecated
+  public static EventKind valueOf(int value) {
+    return forNumber(value);
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   */
+  public static EventKind forNumber(int value) {
+    switch (value) {
+      case 0: return EVENT_KIND_UNSPECIFIED;
+      case 1: return EVENT_KIND_INPUT;
+      case 2: return EVENT_KIND_FRAME_DROP;
+      case 3: return EVENT_KIND_ONSET;
+      case 4: return EVENT_KIND_OFFSET;
+      case 5: return EVENT_KIND_SURFACE_LOST;
+      case 6: return EVENT_KIND_RESIZE;
+      default: return null;
+    }
+  }
+
+  public static com.google.protobuf.Internal.EnumLiteMap<EventKind>
+      internalGetValueMap() {
+    return internalValueMap;
+  }
+  private static final com.google.protobuf.Internal.EnumLiteMap<
+      EventKind> internalValueMap =
+        new com.google.protobuf.Internal.EnumLiteMap<EventKind>() {
+          public EventKind findValueByNumber(int number) {
+            return EventKind.forNumber(number);
+          }
+        };
+
+  public final com.google.protobuf.Descriptors.EnumValueDescriptor
+      getValueDescriptor() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalStateException(
+          "Can't get the descriptor of an unrecognized enum value.");
+    }
+    return getDescriptor().getValues().get(ordinal());
+  }
+  public final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptorForType() {
+    return getDescriptor();
+  }
+  public static final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptor() {
+    return vsg.Vsg.getDescriptor().getEnumTypes().get(5);
+  }
+
+  private static final EventKind[] VALUES = values();
+
+  public static EventKind valueOf(
+      com.google.protobuf.Descriptors.EnumValueDescriptor desc) {
+    if (desc.getType() != getDescriptor()) {
+      throw new java.lang.IllegalArgumentException(
+        "EnumValueDescriptor is not for this type.");
+    }
+    if (desc.getIndex() == -1) {
+      return UNRECOGNIZED;
+    }
+    return VALUES[desc.getIndex()];
+  }
+
+  private final int value;
+
+  private EventKind(int value) {
+    this.value = value;
+  }
+
+  // @@protoc_insertion_point(enum_scope:vsg.EventKind)
+}
+
@@ -0,0 +1,139 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface EventOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Event)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Server clock when the server learned about the event, as in
+   * Onset.timestamp_us.
+   * </pre>
+   *
+   * <code>uint64 timestamp_us = 1;</code>
+   * @return The timestampUs.
+   */
+  long getTimestampUs();
+
+  /**
+   * <code>.vsg.InputEvent input = 2;</code>
+   * @return Whether the input field is set.
+   */
+  boolean hasInput();
+  /**
+   * <code>.vsg.InputEvent input = 2;</code>
+   * @return The input.
+   */
+  vsg.InputEvent getInput();
+  /**
+   * <code>.vsg.InputEvent input = 2;</code>
+   */
+  vsg.InputEventOrBuilder getInputOrBuilder();
+
+  /**
+   * <code>.vsg.FrameDrop frame_drop = 3;</code>
+   * @return Whether the frameDrop field is set.
+   */
+  boolean hasFrameDrop();
+  /**
+   * <code>.vsg.FrameDrop frame_drop = 3;</code>
+   * @return The frameDrop.
+   */
+  vsg.FrameDrop getFrameDrop();
+  /**
+   * <code>.vsg.FrameDrop frame_drop = 3;</code>
+   */
+  vsg.FrameDropOrBuilder getFrameDropOrBuilder();
+
+  /**
+   * <code>.vsg.Onset onset = 4;</code>
+   * @return Whether the onset field is set.
+   */
+  boolean hasOnset();
+  /**
+   * <code>.vsg.Onset onset = 4;</code>
+   * @return The onset.
+   */
+  vsg.Onset getOnset();
+  /**
+   * <code>.vsg.Onset onset = 4;</code>
+   */
+  vsg.OnsetOrBuilder getOnsetOrBuilder();
+
+  /**
+   * <code>.vsg.StimulusOffset offset = 5;</code>
+   * @return Whether the offset field is set.
+   */
+  boolean hasOffset();
+  /**
+   * <code>.vsg.StimulusOffset offset = 5;</code>
+   * @return The offset.
+   */
+  vsg.StimulusOffset getOffset();
+  /**
+   * <code>.vsg.StimulusOffset offset = 5;</code>
+   */
+  vsg.StimulusOffsetOrBuilder getOffsetOrBuilder();
+
+  /**
+   * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+   * @return Whether the surfaceLost field is set.
+   */
+  boolean hasSurfaceLost();
+  /**
+   * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+   * @return The surfaceLost.
+   */
+  vsg.SurfaceLost getSurfaceLost();
+  /**
+   * <code>.vsg.SurfaceLost surface_lost = 6;</code>
+   */
+  vsg.SurfaceLostOrBuilder getSurfaceLostOrBuilder();
+
+  /**
+   * <code>.vsg.Resize resize = 7;</code>
+   * @return Whether the resize field is set.
+   */
+  boolean hasResize();
+  /**
+   * <code>.vsg.Resize resize = 7;</code>
+   * @return The resize.
+   */
+  vsg.Resize getResize();
+  /**
+   * <code>.vsg.Resize resize = 7;</code>
+   */
+  vsg.ResizeOrBuilder getResizeOrBuilder();
+
+  /**
+   * <pre>
+   * Sent to the connection whose command failed, subscribed or not.
+   * </pre>
+   *
+   * <code>.vsg.CommandFailed command_failed = 8;</code>
+   * @return Whether the commandFailed field is set.
+   */
+  boolean hasCommandFailed();
+  /**
+   * <pre>
+   * Sent to the connection whose command failed, subscribed or not.
+   * </pre>
+   *
+   * <code>.vsg.CommandFailed command_failed = 8;</code>
+   * @return The commandFailed.
+   */
+  vsg.CommandFailed getCommandFailed();
+  /**
+   * <pre>
+   * Sent to the connection whose command failed, subscribed or not.
+   * </pre>
+   *
+   * <code>.vsg.CommandFailed command_failed = 8;</code>
+   */
+  vsg.CommandFailedOrBuilder getCommandFailedOrBuilder();
+
+  public vsg.Event.KindCase getKindCase();
+}
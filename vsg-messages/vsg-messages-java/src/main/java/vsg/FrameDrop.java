@@ -0,0 +1,581 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.FrameDrop}
+ */
+public final class FrameDrop extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.FrameDrop)
+    FrameDropOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use FrameDrop.newBuilder() to construct.
+  private FrameDrop(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private FrameDrop() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new FrameDrop();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private FrameDrop(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            frame_ = input.readUInt64();
+            break;
+          }
+          case 16: {
+
+            intervalUs_ = input.readUInt64();
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_FrameDrop_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_FrameDrop_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.FrameDrop.class, vsg.FrameDrop.Builder.class);
+  }
+
+  public static final int FRAME_FIELD_NUMBER = 1;
+  private long frame_;
+  /**
+   * <pre>
+   * The late frame.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return The frame.
+   */
+  @java.lang.Override
+  public long getFrame() {
+    return frame_;
+  }
+
+  public static final int INTERVAL_US_FIELD_NUMBER = 2;
+  private long intervalUs_;
+  /**
+   * <pre>
+   * Time since the previous frame was presented.
+   * </pre>
+   *
+   * <code>uint64 interval_us = 2;</code>
+   * @return The intervalUs.
+   */
+  @java.lang.Override
+  public long getIntervalUs() {
+    return intervalUs_;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (frame_ != 0L) {
+      output.writeUInt64(1, frame_);
+    }
+    if (intervalUs_ != 0L) {
+      output.writeUInt64(2, intervalUs_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (frame_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(1, frame_);
+    }
+    if (intervalUs_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(2, intervalUs_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.FrameDrop)) {
+      return super.equals(obj);
+    }
+    vsg.FrameDrop other = (vsg.FrameDrop) obj;
+
+    if (getFrame()
+        != other.getFrame()) return false;
+    if (getIntervalUs()
+        != other.getIntervalUs()) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + FRAME_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getFrame());
+    hash = (37 * hash) + INTERVAL_US_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getIntervalUs());
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.FrameDrop parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.FrameDrop parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.FrameDrop parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.FrameDrop parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.FrameDrop parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.FrameDrop parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.FrameDrop parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.FrameDrop parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.FrameDrop parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.FrameDrop parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.FrameDrop parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.FrameDrop parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.FrameDrop prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.FrameDrop}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.FrameDrop)
+      vsg.FrameDropOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_FrameDrop_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_FrameDrop_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.FrameDrop.class, vsg.FrameDrop.Builder.class);
+    }
+
+    // Construct using vsg.FrameDrop.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      frame_ = 0L;
+
+      intervalUs_ = 0L;
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_FrameDrop_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.FrameDrop getDefaultInstanceForType() {
+      return vsg.FrameDrop.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.FrameDrop build() {
+      vsg.FrameDrop result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.FrameDrop buildPartial() {
+      vsg.FrameDrop result = new vsg.FrameDrop(this);
+      result.frame_ = frame_;
+      result.intervalUs_ = intervalUs_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.FrameDrop) {
+        return mergeFrom((vsg.FrameDrop)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.FrameDrop other) {
+      if (other == vsg.FrameDrop.getDefaultInstance()) return this;
+      if (other.getFrame() != 0L) {
+        setFrame(other.getFrame());
+      }
+      if (other.getIntervalUs() != 0L) {
+        setIntervalUs(other.getIntervalUs());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.FrameDrop parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.FrameDrop) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private long frame_ ;
+    /**
+     * <pre>
+     * The late frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return The frame.
+     */
+    @java.lang.Override
+    public long getFrame() {
+      return frame_;
+    }
+    /**
+     * <pre>
+     * The late frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @param value The frame to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFrame(long value) {
+      
+      frame_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * The late frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFrame() {
+      
+      frame_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private long intervalUs_ ;
+    /**
+     * <pre>
+     * Time since the previous frame was presented.
+     * </pre>
+     *
+     * <code>uint64 interval_us = 2;</code>
+     * @return The intervalUs.
+     */
+    @java.lang.Override
+    public long getIntervalUs() {
+      return intervalUs_;
+    }
+    /**
+     * <pre>
+     * Time since the previous frame was presented.
+     * </pre>
+     *
+     * <code>uint64 interval_us = 2;</code>
+     * @param value The intervalUs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setIntervalUs(long value) {
+      
+      intervalUs_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Time since the previous frame was presented.
+     * </pre>
+     *
+     * <code>uint64 interval_us = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearIntervalUs() {
+      
+      intervalUs_ = 0L;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.FrameDrop)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.FrameDrop)
+  private static final vsg.FrameDrop DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.FrameDrop();
+  }
+
+  public static vsg.FrameDrop getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<FrameDrop>
+      PARSER = new com.google.protobuf.AbstractParser<FrameDrop>() {
+    @java.lang.Override
+    public FrameDrop parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new FrameDrop(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<FrameDrop> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<FrameDrop> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.FrameDrop getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
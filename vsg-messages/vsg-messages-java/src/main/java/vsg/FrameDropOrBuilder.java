@@ -0,0 +1,29 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface FrameDropOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.FrameDrop)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * The late frame.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return The frame.
+   */
+  long getFrame();
+
+  /**
+   * <pre>
+   * Time since the previous frame was presented.
+   * </pre>
+   *
+   * <code>uint64 interval_us = 2;</code>
+   * @return The intervalUs.
+   */
+  long getIntervalUs();
+}
@@ -0,0 +1,9 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface GetStatusOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.GetStatus)
+    com.google.protobuf.MessageOrBuilder {
+}
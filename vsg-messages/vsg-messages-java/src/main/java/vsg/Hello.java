@@ -0,0 +1,933 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Exchanged once at the start of a connection.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.Hello}
+ */
+public final class Hello extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Hello)
+    HelloOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Hello.newBuilder() to construct.
+  private Hello(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Hello() {
+    features_ = com.google.protobuf.LazyStringArrayList.EMPTY;
+    agent_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Hello();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Hello(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    int mutable_bitField0_ = 0;
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            protocolVersion_ = input.readUInt32();
+            break;
+          }
+          case 18: {
+            java.lang.String s = input.readStringRequireUtf8();
+            if (!((mutable_bitField0_ & 0x00000001) != 0)) {
+              features_ = new com.google.protobuf.LazyStringArrayList();
+              mutable_bitField0_ |= 0x00000001;
+            }
+            features_.add(s);
+            break;
+          }
+          case 26: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            agent_ = s;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      if (((mutable_bitField0_ & 0x00000001) != 0)) {
+        features_ = features_.getUnmodifiableView();
+      }
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_Hello_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_Hello_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.Hello.class, vsg.Hello.Builder.class);
+  }
+
+  public static final int PROTOCOL_VERSION_FIELD_NUMBER = 1;
+  private int protocolVersion_;
+  /**
+   * <pre>
+   * PROTOCOL_VERSION_CURRENT of the sender's copy of this file.
+   * </pre>
+   *
+   * <code>uint32 protocol_version = 1;</code>
+   * @return The protocolVersion.
+   */
+  @java.lang.Override
+  public int getProtocolVersion() {
+    return protocolVersion_;
+  }
+
+  public static final int FEATURES_FIELD_NUMBER = 2;
+  private com.google.protobuf.LazyStringList features_;
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @return A list containing the features.
+   */
+  public com.google.protobuf.ProtocolStringList
+      getFeaturesList() {
+    return features_;
+  }
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @return The count of features.
+   */
+  public int getFeaturesCount() {
+    return features_.size();
+  }
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @param index The index of the element to return.
+   * @return The features at the given index.
+   */
+  public java.lang.String getFeatures(int index) {
+    return features_.get(index);
+  }
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @param index The index of the value to return.
+   * @return The bytes of the features at the given index.
+   */
+  public com.google.protobuf.ByteString
+      getFeaturesBytes(int index) {
+    return features_.getByteString(index);
+  }
+
+  public static final int AGENT_FIELD_NUMBER = 3;
+  private volatile java.lang.Object agent_;
+  /**
+   * <pre>
+   * Free-form description of the sender, such as "vsg-core 0.1.0".
+   * </pre>
+   *
+   * <code>string agent = 3;</code>
+   * @return The agent.
+   */
+  @java.lang.Override
+  public java.lang.String getAgent() {
+    java.lang.Object ref = agent_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      agent_ = s;
+      return s;
+    }
+  }
+  /**
+   * <pre>
+   * Free-form description of the sender, such as "vsg-core 0.1.0".
+   * </pre>
+   *
+   * <code>string agent = 3;</code>
+   * @return The bytes for agent.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getAgentBytes() {
+    java.lang.Object ref = agent_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      agent_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (protocolVersion_ != 0) {
+      output.writeUInt32(1, protocolVersion_);
+    }
+    for (int i = 0; i < features_.size(); i++) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 2, features_.getRaw(i));
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(agent_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 3, agent_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (protocolVersion_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(1, protocolVersion_);
+    }
+    {
+      int dataSize = 0;
+      for (int i = 0; i < features_.size(); i++) {
+        dataSize += computeStringSizeNoTag(features_.getRaw(i));
+      }
+      size += dataSize;
+      size += 1 * getFeaturesList().size();
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(agent_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(3, agent_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.Hello)) {
+      return super.equals(obj);
+    }
+    vsg.Hello other = (vsg.Hello) obj;
+
+    if (getProtocolVersion()
+        != other.getProtocolVersion()) return false;
+    if (!getFeaturesList()
+        .equals(other.getFeaturesList())) return false;
+    if (!getAgent()
+        .equals(other.getAgent())) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + PROTOCOL_VERSION_FIELD_NUMBER;
+    hash = (53 * hash) + getProtocolVersion();
+    if (getFeaturesCount() > 0) {
+      hash = (37 * hash) + FEATURES_FIELD_NUMBER;
+      hash = (53 * hash) + getFeaturesList().hashCode();
+    }
+    hash = (37 * hash) + AGENT_FIELD_NUMBER;
+    hash = (53 * hash) + getAgent().hashCode();
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.Hello parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Hello parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Hello parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Hello parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Hello parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Hello parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Hello parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Hello parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Hello parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.Hello parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Hello parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Hello parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.Hello prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Exchanged once at the start of a connection.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.Hello}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Hello)
+      vsg.HelloOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_Hello_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_Hello_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.Hello.class, vsg.Hello.Builder.class);
+    }
+
+    // Construct using vsg.Hello.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      protocolVersion_ = 0;
+
+      features_ = com.google.protobuf.LazyStringArrayList.EMPTY;
+      bitField0_ = (bitField0_ & ~0x00000001);
+      agent_ = "";
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_Hello_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.Hello getDefaultInstanceForType() {
+      return vsg.Hello.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.Hello build() {
+      vsg.Hello result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.Hello buildPartial() {
+      vsg.Hello result = new vsg.Hello(this);
+      int from_bitField0_ = bitField0_;
+      result.protocolVersion_ = protocolVersion_;
+      if (((bitField0_ & 0x00000001) != 0)) {
+        features_ = features_.getUnmodifiableView();
+        bitField0_ = (bitField0_ & ~0x00000001);
+      }
+      result.features_ = features_;
+      result.agent_ = agent_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.Hello) {
+        return mergeFrom((vsg.Hello)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.Hello other) {
+      if (other == vsg.Hello.getDefaultInstance()) return this;
+      if (other.getProtocolVersion() != 0) {
+        setProtocolVersion(other.getProtocolVersion());
+      }
+      if (!other.features_.isEmpty()) {
+        if (features_.isEmpty()) {
+          features_ = other.features_;
+          bitField0_ = (bitField0_ & ~0x00000001);
+        } else {
+          ensureFeaturesIsMutable();
+          features_.addAll(other.features_);
+        }
+        onChanged();
+      }
+      if (!other.getAgent().isEmpty()) {
+        agent_ = other.agent_;
+        onChanged();
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.Hello parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.Hello) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int bitField0_;
+
+    private int protocolVersion_ ;
+    /**
+     * <pre>
+     * PROTOCOL_VERSION_CURRENT of the sender's copy of this file.
+     * </pre>
+     *
+     * <code>uint32 protocol_version = 1;</code>
+     * @return The protocolVersion.
+     */
+    @java.lang.Override
+    public int getProtocolVersion() {
+      return protocolVersion_;
+    }
+    /**
+     * <pre>
+     * PROTOCOL_VERSION_CURRENT of the sender's copy of this file.
+     * </pre>
+     *
+     * <code>uint32 protocol_version = 1;</code>
+     * @param value The protocolVersion to set.
+     * @return This builder for chaining.
+     */
+    public Builder setProtocolVersion(int value) {
+      
+      protocolVersion_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * PROTOCOL_VERSION_CURRENT of the sender's copy of this file.
+     * </pre>
+     *
+     * <code>uint32 protocol_version = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearProtocolVersion() {
+      
+      protocolVersion_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private com.google.protobuf.LazyStringList features_ = com.google.protobuf.LazyStringArrayList.EMPTY;
+    private void ensureFeaturesIsMutable() {
+      if (!((bitField0_ & 0x00000001) != 0)) {
+        features_ = new com.google.protobuf.LazyStringArrayList(features_);
+        bitField0_ |= 0x00000001;
+       }
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @return A list containing the features.
+     */
+    public com.google.protobuf.ProtocolStringList
+        getFeaturesList() {
+      return features_.getUnmodifiableView();
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @return The count of features.
+     */
+    public int getFeaturesCount() {
+      return features_.size();
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @param index The index of the element to return.
+     * @return The features at the given index.
+     */
+    public java.lang.String getFeatures(int index) {
+      return features_.get(index);
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @param index The index of the value to return.
+     * @return The bytes of the features at the given index.
+     */
+    public com.google.protobuf.ByteString
+        getFeaturesBytes(int index) {
+      return features_.getByteString(index);
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @param index The index to set the value at.
+     * @param value The features to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFeatures(
+        int index, java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  ensureFeaturesIsMutable();
+      features_.set(index, value);
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @param value The features to add.
+     * @return This builder for chaining.
+     */
+    public Builder addFeatures(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  ensureFeaturesIsMutable();
+      features_.add(value);
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @param values The features to add.
+     * @return This builder for chaining.
+     */
+    public Builder addAllFeatures(
+        java.lang.Iterable<java.lang.String> values) {
+      ensureFeaturesIsMutable();
+      com.google.protobuf.AbstractMessageLite.Builder.addAll(
+          values, features_);
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFeatures() {
+      features_ = com.google.protobuf.LazyStringArrayList.EMPTY;
+      bitField0_ = (bitField0_ & ~0x00000001);
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * From the client, the features it relies on. From the server, every
+     * feature it supports, such as "flip_ack" or "json".
+     * </pre>
+     *
+     * <code>repeated string features = 2;</code>
+     * @param value The bytes of the features to add.
+     * @return This builder for chaining.
+     */
+    public Builder addFeaturesBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      ensureFeaturesIsMutable();
+      features_.add(value);
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object agent_ = "";
+    /**
+     * <pre>
+     * Free-form description of the sender, such as "vsg-core 0.1.0".
+     * </pre>
+     *
+     * <code>string agent = 3;</code>
+     * @return The agent.
+     */
+    public java.lang.String getAgent() {
+      java.lang.Object ref = agent_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        agent_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Free-form description of the sender, such as "vsg-core 0.1.0".
+     * </pre>
+     *
+     * <code>string agent = 3;</code>
+     * @return The bytes for agent.
+     */
+    public com.google.protobuf.ByteString
+        getAgentBytes() {
+      java.lang.Object ref = agent_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        agent_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <pre>
+     * Free-form description of the sender, such as "vsg-core 0.1.0".
+     * </pre>
+     *
+     * <code>string agent = 3;</code>
+     * @param value The agent to set.
+     * @return This builder for chaining.
+     */
+    public Builder setAgent(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      agent_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Free-form description of the sender, such as "vsg-core 0.1.0".
+     * </pre>
+     *
+     * <code>string agent = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearAgent() {
+      
+      agent_ = getDefaultInstance().getAgent();
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Free-form description of the sender, such as "vsg-core 0.1.0".
+     * </pre>
+     *
+     * <code>string agent = 3;</code>
+     * @param value The bytes for agent to set.
+     * @return This builder for chaining.
+     */
+    public Builder setAgentBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      agent_ = value;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Hello)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Hello)
+  private static final vsg.Hello DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.Hello();
+  }
+
+  public static vsg.Hello getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Hello>
+      PARSER = new com.google.protobuf.AbstractParser<Hello>() {
+    @java.lang.Override
+    public Hello parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Hello(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Hello> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Hello> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.Hello getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
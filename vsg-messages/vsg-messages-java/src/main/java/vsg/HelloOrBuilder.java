@@ -0,0 +1,84 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface HelloOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Hello)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * PROTOCOL_VERSION_CURRENT of the sender's copy of this file.
+   * </pre>
+   *
+   * <code>uint32 protocol_version = 1;</code>
+   * @return The protocolVersion.
+   */
+  int getProtocolVersion();
+
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @return A list containing the features.
+   */
+  java.util.List<java.lang.String>
+      getFeaturesList();
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @return The count of features.
+   */
+  int getFeaturesCount();
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @param index The index of the element to return.
+   * @return The features at the given index.
+   */
+  java.lang.String getFeatures(int index);
+  /**
+   * <pre>
+   * From the client, the features it relies on. From the server, every
+   * feature it supports, such as "flip_ack" or "json".
+   * </pre>
+   *
+   * <code>repeated string features = 2;</code>
+   * @param index The index of the value to return.
+   * @return The bytes of the features at the given index.
+   */
+  com.google.protobuf.ByteString
+      getFeaturesBytes(int index);
+
+  /**
+   * <pre>
+   * Free-form description of the sender, such as "vsg-core 0.1.0".
+   * </pre>
+   *
+   * <code>string agent = 3;</code>
+   * @return The agent.
+   */
+  java.lang.String getAgent();
+  /**
+   * <pre>
+   * Free-form description of the sender, such as "vsg-core 0.1.0".
+   * </pre>
+   *
+   * <code>string agent = 3;</code>
+   * @return The bytes for agent.
+   */
+  com.google.protobuf.ByteString
+      getAgentBytes();
+}
@@ -0,0 +1,1211 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.InputEvent}
+ */
+public final class InputEvent extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.InputEvent)
+    InputEventOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use InputEvent.newBuilder() to construct.
+  private InputEvent(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private InputEvent() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new InputEvent();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private InputEvent(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    int mutable_bitField0_ = 0;
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            pressed_ = input.readBool();
+            break;
+          }
+          case 18: {
+            vsg.Key.Builder subBuilder = null;
+            if (sourceCase_ == 2) {
+              subBuilder = ((vsg.Key) source_).toBuilder();
+            }
+            source_ =
+                input.readMessage(vsg.Key.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.Key) source_);
+              source_ = subBuilder.buildPartial();
+            }
+            sourceCase_ = 2;
+            break;
+          }
+          case 26: {
+            vsg.MouseButton.Builder subBuilder = null;
+            if (sourceCase_ == 3) {
+              subBuilder = ((vsg.MouseButton) source_).toBuilder();
+            }
+            source_ =
+                input.readMessage(vsg.MouseButton.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.MouseButton) source_);
+              source_ = subBuilder.buildPartial();
+            }
+            sourceCase_ = 3;
+            break;
+          }
+          case 32: {
+            bitField0_ |= 0x00000001;
+            onsetFrame_ = input.readUInt64();
+            break;
+          }
+          case 40: {
+            bitField0_ |= 0x00000002;
+            sinceOnsetUs_ = input.readUInt64();
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_InputEvent_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_InputEvent_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.InputEvent.class, vsg.InputEvent.Builder.class);
+  }
+
+  private int bitField0_;
+  private int sourceCase_ = 0;
+  private java.lang.Object source_;
+  public enum SourceCase
+      implements com.google.protobuf.Internal.EnumLite,
+          com.google.protobuf.AbstractMessage.InternalOneOfEnum {
+    KEY(2),
+    MOUSE_BUTTON(3),
+    SOURCE_NOT_SET(0);
+    private final int value;
+    private SourceCase(int value) {
+      this.value = value;
+    }
+    /**
+     * @param value The number of the enum to look for.
+     * @return The enum associated with the given number.
+     * @deprecated Use {@link #forNumber(int)} instead.
+     */
+    @java.lang.Deprecated
+    public static SourceCase valueOf(int value) {
+      return forNumber(value);
+    }
+
+    public static SourceCase forNumber(int value) {
+      switch (value) {
+        case 2: return KEY;
+        case 3: return MOUSE_BUTTON;
+        case 0: return SOURCE_NOT_SET;
+        default: return null;
+      }
+    }
+    public int getNumber() {
+      return this.value;
+    }
+  };
+
+  public SourceCase
+  getSourceCase() {
+    return SourceCase.forNumber(
+        sourceCase_);
+  }
+
+  public static final int PRESSED_FIELD_NUMBER = 1;
+  private boolean pressed_;
+  /**
+   * <pre>
+   * Released when false.
+   * </pre>
+   *
+   * <code>bool pressed = 1;</code>
+   * @return The pressed.
+   */
+  @java.lang.Override
+  public boolean getPressed() {
+    return pressed_;
+  }
+
+  public static final int KEY_FIELD_NUMBER = 2;
+  /**
+   * <code>.vsg.Key key = 2;</code>
+   * @return Whether the key field is set.
+   */
+  @java.lang.Override
+  public boolean hasKey() {
+    return sourceCase_ == 2;
+  }
+  /**
+   * <code>.vsg.Key key = 2;</code>
+   * @return The key.
+   */
+  @java.lang.Override
+  public vsg.Key getKey() {
+    if (sourceCase_ == 2) {
+       return (vsg.Key) source_;
+    }
+    return vsg.Key.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.Key key = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.KeyOrBuilder getKeyOrBuilder() {
+    if (sourceCase_ == 2) {
+       return (vsg.Key) source_;
+    }
+    return vsg.Key.getDefaultInstance();
+  }
+
+  public static final int MOUSE_BUTTON_FIELD_NUMBER = 3;
+  /**
+   * <code>.vsg.MouseButton mouse_button = 3;</code>
+   * @return Whether the mouseButton field is set.
+   */
+  @java.lang.Override
+  public boolean hasMouseButton() {
+    return sourceCase_ == 3;
+  }
+  /**
+   * <code>.vsg.MouseButton mouse_button = 3;</code>
+   * @return The mouseButton.
+   */
+  @java.lang.Override
+  public vsg.MouseButton getMouseButton() {
+    if (sourceCase_ == 3) {
+       return (vsg.MouseButton) source_;
+    }
+    return vsg.MouseButton.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.MouseButton mouse_button = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.MouseButtonOrBuilder getMouseButtonOrBuilder() {
+    if (sourceCase_ == 3) {
+       return (vsg.MouseButton) source_;
+    }
+    return vsg.MouseButton.getDefaultInstance();
+  }
+
+  public static final int ONSET_FRAME_FIELD_NUMBER = 4;
+  private long onsetFrame_;
+  /**
+   * <pre>
+   * Most recent frame on which a command took effect, and the time from
+   * its onset to this event. Not set before the first such frame.
+   * </pre>
+   *
+   * <code>optional uint64 onset_frame = 4;</code>
+   * @return Whether the onsetFrame field is set.
+   */
+  @java.lang.Override
+  public boolean hasOnsetFrame() {
+    return ((bitField0_ & 0x00000001) != 0);
+  }
+  /**
+   * <pre>
+   * Most recent frame on which a command took effect, and the time from
+   * its onset to this event. Not set before the first such frame.
+   * </pre>
+   *
+   * <code>optional uint64 onset_frame = 4;</code>
+   * @return The onsetFrame.
+   */
+  @java.lang.Override
+  public long getOnsetFrame() {
+    return onsetFrame_;
+  }
+
+  public static final int SINCE_ONSET_US_FIELD_NUMBER = 5;
+  private long sinceOnsetUs_;
+  /**
+   * <code>optional uint64 since_onset_us = 5;</code>
+   * @return Whether the sinceOnsetUs field is set.
+   */
+  @java.lang.Override
+  public boolean hasSinceOnsetUs() {
+    return ((bitField0_ & 0x00000002) != 0);
+  }
+  /**
+   * <code>optional uint64 since_onset_us = 5;</code>
+   * @return The sinceOnsetUs.
+   */
+  @java.lang.Override
+  public long getSinceOnsetUs() {
+    return sinceOnsetUs_;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (pressed_ != false) {
+      output.writeBool(1, pressed_);
+    }
+    if (sourceCase_ == 2) {
+      output.writeMessage(2, (vsg.Key) source_);
+    }
+    if (sourceCase_ == 3) {
+      output.writeMessage(3, (vsg.MouseButton) source_);
+    }
+    if (((bitField0_ & 0x00000001) != 0)) {
+      output.writeUInt64(4, onsetFrame_);
+    }
+    if (((bitField0_ & 0x00000002) != 0)) {
+      output.writeUInt64(5, sinceOnsetUs_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (pressed_ != false) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeBoolSize(1, pressed_);
+    }
+    if (sourceCase_ == 2) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, (vsg.Key) source_);
+    }
+    if (sourceCase_ == 3) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, (vsg.MouseButton) source_);
+    }
+    if (((bitField0_ & 0x00000001) != 0)) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(4, onsetFrame_);
+    }
+    if (((bitField0_ & 0x00000002) != 0)) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(5, sinceOnsetUs_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.InputEvent)) {
+      return super.equals(obj);
+    }
+    vsg.InputEvent other = (vsg.InputEvent) obj;
+
+    if (getPressed()
+        != other.getPressed()) return false;
+    if (hasOnsetFrame() != other.hasOnsetFrame()) return false;
+    if (hasOnsetFrame()) {
+      if (getOnsetFrame()
+          != other.getOnsetFrame()) return false;
+    }
+    if (hasSinceOnsetUs() != other.hasSinceOnsetUs()) return false;
+    if (hasSinceOnsetUs()) {
+      if (getSinceOnsetUs()
+          != other.getSinceOnsetUs()) return false;
+    }
+    if (!getSourceCase().equals(other.getSourceCase())) return false;
+    switch (sourceCase_) {
+      case 2:
+        if (!getKey()
+            .equals(other.getKey())) return false;
+        break;
+      case 3:
+        if (!getMouseButton()
+            .equals(other.getMouseButton())) return false;
+        break;
+      case 0:
+      default:
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + PRESSED_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashBoolean(
+        getPressed());
+    if (hasOnsetFrame()) {
+      hash = (37 * hash) + ONSET_FRAME_FIELD_NUMBER;
+      hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+          getOnsetFrame());
+    }
+    if (hasSinceOnsetUs()) {
+      hash = (37 * hash) + SINCE_ONSET_US_FIELD_NUMBER;
+      hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+          getSinceOnsetUs());
+    }
+    switch (sourceCase_) {
+      case 2:
+        hash = (37 * hash) + KEY_FIELD_NUMBER;
+        hash = (53 * hash) + getKey().hashCode();
+        break;
+      case 3:
+        hash = (37 * hash) + MOUSE_BUTTON_FIELD_NUMBER;
+        hash = (53 * hash) + getMouseButton().hashCode();
+        break;
+      case 0:
+      default:
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.InputEvent parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.InputEvent parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.InputEvent parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.InputEvent parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.InputEvent parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.InputEvent parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.InputEvent parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.InputEvent parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.InputEvent parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.InputEvent parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.InputEvent parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.InputEvent parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.InputEvent prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.InputEvent}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.InputEvent)
+      vsg.InputEventOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_InputEvent_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_InputEvent_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.InputEvent.class, vsg.InputEvent.Builder.class);
+    }
+
+    // Construct using vsg.InputEvent.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      pressed_ = false;
+
+      onsetFrame_ = 0L;
+      bitField0_ = (bitField0_ & ~0x00000001);
+      sinceOnsetUs_ = 0L;
+      bitField0_ = (bitField0_ & ~0x00000002);
+      sourceCase_ = 0;
+      source_ = null;
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_InputEvent_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.InputEvent getDefaultInstanceForType() {
+      return vsg.InputEvent.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.InputEvent build() {
+      vsg.InputEvent result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.InputEvent buildPartial() {
+      vsg.InputEvent result = new vsg.InputEvent(this);
+      int from_bitField0_ = bitField0_;
+      int to_bitField0_ = 0;
+      result.pressed_ = pressed_;
+      if (sourceCase_ == 2) {
+        if (keyBuilder_ == null) {
+          result.source_ = source_;
+        } else {
+          result.source_ = keyBuilder_.build();
+        }
+      }
+      if (sourceCase_ == 3) {
+        if (mouseButtonBuilder_ == null) {
+          result.source_ = source_;
+        } else {
+          result.source_ = mouseButtonBuilder_.build();
+        }
+      }
+      if (((from_bitField0_ & 0x00000001) != 0)) {
+        result.onsetFrame_ = onsetFrame_;
+        to_bitField0_ |= 0x00000001;
+      }
+      if (((from_bitField0_ & 0x00000002) != 0)) {
+        result.sinceOnsetUs_ = sinceOnsetUs_;
+        to_bitField0_ |= 0x00000002;
+      }
+      result.bitField0_ = to_bitField0_;
+      result.sourceCase_ = sourceCase_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.InputEvent) {
+        return mergeFrom((vsg.InputEvent)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.InputEvent other) {
+      if (other == vsg.InputEvent.getDefaultInstance()) return this;
+      if (other.getPressed() != false) {
+        setPressed(other.getPressed());
+      }
+      if (other.hasOnsetFrame()) {
+        setOnsetFrame(other.getOnsetFrame());
+      }
+      if (other.hasSinceOnsetUs()) {
+        setSinceOnsetUs(other.getSinceOnsetUs());
+      }
+      switch (other.getSourceCase()) {
+        case KEY: {
+          mergeKey(other.getKey());
+          break;
+        }
+        case MOUSE_BUTTON: {
+          mergeMouseButton(other.getMouseButton());
+          break;
+        }
+        case SOURCE_NOT_SET: {
+          break;
+        }
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.InputEvent parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.InputEvent) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int sourceCase_ = 0;
+    private java.lang.Object source_;
+    public SourceCase
+        getSourceCase() {
+      return SourceCase.forNumber(
+          sourceCase_);
+    }
+
+    public Builder clearSource() {
+      sourceCase_ = 0;
+      source_ = null;
+      onChanged();
+      return this;
+    }
+
+    private int bitField0_;
+
+    private boolean pressed_ ;
+    /**
+     * <pre>
+     * Released when false.
+     * </pre>
+     *
+     * <code>bool pressed = 1;</code>
+     * @return The pressed.
+     */
+    @java.lang.Override
+    public boolean getPressed() {
+      return pressed_;
+    }
+    /**
+     * <pre>
+     * Released when false.
+     * </pre>
+     *
+     * <code>bool pressed = 1;</code>
+     * @param value The pressed to set.
+     * @return This builder for chaining.
+     */
+    public Builder setPressed(boolean value) {
+      
+      pressed_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Released when false.
+     * </pre>
+     *
+     * <code>bool pressed = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearPressed() {
+      
+      pressed_ = false;
+      onChanged();
+      return this;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Key, vsg.Key.Builder, vsg.KeyOrBuilder> keyBuilder_;
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     * @return Whether the key field is set.
+     */
+    @java.lang.Override
+    public boolean hasKey() {
+      return sourceCase_ == 2;
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     * @return The key.
+     */
+    @java.lang.Override
+    public vsg.Key getKey() {
+      if (keyBuilder_ == null) {
+        if (sourceCase_ == 2) {
+          return (vsg.Key) source_;
+        }
+        return vsg.Key.getDefaultInstance();
+      } else {
+        if (sourceCase_ == 2) {
+          return keyBuilder_.getMessage();
+        }
+        return vsg.Key.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    public Builder setKey(vsg.Key value) {
+      if (keyBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        source_ = value;
+        onChanged();
+      } else {
+        keyBuilder_.setMessage(value);
+      }
+      sourceCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    public Builder setKey(
+        vsg.Key.Builder builderForValue) {
+      if (keyBuilder_ == null) {
+        source_ = builderForValue.build();
+        onChanged();
+      } else {
+        keyBuilder_.setMessage(builderForValue.build());
+      }
+      sourceCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    public Builder mergeKey(vsg.Key value) {
+      if (keyBuilder_ == null) {
+        if (sourceCase_ == 2 &&
+            source_ != vsg.Key.getDefaultInstance()) {
+          source_ = vsg.Key.newBuilder((vsg.Key) source_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          source_ = value;
+        }
+        onChanged();
+      } else {
+        if (sourceCase_ == 2) {
+          keyBuilder_.mergeFrom(value);
+        }
+        keyBuilder_.setMessage(value);
+      }
+      sourceCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    public Builder clearKey() {
+      if (keyBuilder_ == null) {
+        if (sourceCase_ == 2) {
+          sourceCase_ = 0;
+          source_ = null;
+          onChanged();
+        }
+      } else {
+        if (sourceCase_ == 2) {
+          sourceCase_ = 0;
+          source_ = null;
+        }
+        keyBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    public vsg.Key.Builder getKeyBuilder() {
+      return getKeyFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    @java.lang.Override
+    public vsg.KeyOrBuilder getKeyOrBuilder() {
+      if ((sourceCase_ == 2) && (keyBuilder_ != null)) {
+        return keyBuilder_.getMessageOrBuilder();
+      } else {
+        if (sourceCase_ == 2) {
+          return (vsg.Key) source_;
+        }
+        return vsg.Key.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Key key = 2;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Key, vsg.Key.Builder, vsg.KeyOrBuilder> 
+        getKeyFieldBuilder() {
+      if (keyBuilder_ == null) {
+        if (!(sourceCase_ == 2)) {
+          source_ = vsg.Key.getDefaultInstance();
+        }
+        keyBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Key, vsg.Key.Builder, vsg.KeyOrBuilder>(
+                (vsg.Key) source_,
+                getParentForChildren(),
+                isClean());
+        source_ = null;
+      }
+      sourceCase_ = 2;
+      onChanged();;
+      return keyBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.MouseButton, vsg.MouseButton.Builder, vsg.MouseButtonOrBuilder> mouseButtonBuilder_;
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     * @return Whether the mouseButton field is set.
+     */
+    @java.lang.Override
+    public boolean hasMouseButton() {
+      return sourceCase_ == 3;
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     * @return The mouseButton.
+     */
+    @java.lang.Override
+    public vsg.MouseButton getMouseButton() {
+      if (mouseButtonBuilder_ == null) {
+        if (sourceCase_ == 3) {
+          return (vsg.MouseButton) source_;
+        }
+        return vsg.MouseButton.getDefaultInstance();
+      } else {
+        if (sourceCase_ == 3) {
+          return mouseButtonBuilder_.getMessage();
+        }
+        return vsg.MouseButton.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    public Builder setMouseButton(vsg.MouseButton value) {
+      if (mouseButtonBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        source_ = value;
+        onChanged();
+      } else {
+        mouseButtonBuilder_.setMessage(value);
+      }
+      sourceCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    public Builder setMouseButton(
+        vsg.MouseButton.Builder builderForValue) {
+      if (mouseButtonBuilder_ == null) {
+        source_ = builderForValue.build();
+        onChanged();
+      } else {
+        mouseButtonBuilder_.setMessage(builderForValue.build());
+      }
+      sourceCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    public Builder mergeMouseButton(vsg.MouseButton value) {
+      if (mouseButtonBuilder_ == null) {
+        if (sourceCase_ == 3 &&
+            source_ != vsg.MouseButton.getDefaultInstance()) {
+          source_ = vsg.MouseButton.newBuilder((vsg.MouseButton) source_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          source_ = value;
+        }
+        onChanged();
+      } else {
+        if (sourceCase_ == 3) {
+          mouseButtonBuilder_.mergeFrom(value);
+        }
+        mouseButtonBuilder_.setMessage(value);
+      }
+      sourceCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    public Builder clearMouseButton() {
+      if (mouseButtonBuilder_ == null) {
+        if (sourceCase_ == 3) {
+          sourceCase_ = 0;
+          source_ = null;
+          onChanged();
+        }
+      } else {
+        if (sourceCase_ == 3) {
+          sourceCase_ = 0;
+          source_ = null;
+        }
+        mouseButtonBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    public vsg.MouseButton.Builder getMouseButtonBuilder() {
+      return getMouseButtonFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    @java.lang.Override
+    public vsg.MouseButtonOrBuilder getMouseButtonOrBuilder() {
+      if ((sourceCase_ == 3) && (mouseButtonBuilder_ != null)) {
+        return mouseButtonBuilder_.getMessageOrBuilder();
+      } else {
+        if (sourceCase_ == 3) {
+          return (vsg.MouseButton) source_;
+        }
+        return vsg.MouseButton.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.MouseButton mouse_button = 3;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.MouseButton, vsg.MouseButton.Builder, vsg.MouseButtonOrBuilder> 
+        getMouseButtonFieldBuilder() {
+      if (mouseButtonBuilder_ == null) {
+        if (!(sourceCase_ == 3)) {
+          source_ = vsg.MouseButton.getDefaultInstance();
+        }
+        mouseButtonBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.MouseButton, vsg.MouseButton.Builder, vsg.MouseButtonOrBuilder>(
+                (vsg.MouseButton) source_,
+                getParentForChildren(),
+                isClean());
+        source_ = null;
+      }
+      sourceCase_ = 3;
+      onChanged();;
+      return mouseButtonBuilder_;
+    }
+
+    private long onsetFrame_ ;
+    /**
+     * <pre>
+     * Most recent frame on which a command took effect, and the time from
+     * its onset to this event. Not set before the first such frame.
+     * </pre>
+     *
+     * <code>optional uint64 onset_frame = 4;</code>
+     * @return Whether the onsetFrame field is set.
+     */
+    @java.lang.Override
+    public boolean hasOnsetFrame() {
+      return ((bitField0_ & 0x00000001) != 0);
+    }
+    /**
+     * <pre>
+     * Most recent frame on which a command took effect, and the time from
+     * its onset to this event. Not set before the first such frame.
+     * </pre>
+     *
+     * <code>optional uint64 onset_frame = 4;</code>
+     * @return The onsetFrame.
+     */
+    @java.lang.Override
+    public long getOnsetFrame() {
+      return onsetFrame_;
+    }
+    /**
+     * <pre>
+     * Most recent frame on which a command took effect, and the time from
+     * its onset to this event. Not set before the first such frame.
+     * </pre>
+     *
+     * <code>optional uint64 onset_frame = 4;</code>
+     * @param value The onsetFrame to set.
+     * @return This builder for chaining.
+     */
+    public Builder setOnsetFrame(long value) {
+      bitField0_ |= 0x00000001;
+      onsetFrame_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Most recent frame on which a command took effect, and the time from
+     * its onset to this event. Not set before the first such frame.
+     * </pre>
+     *
+     * <code>optional uint64 onset_frame = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearOnsetFrame() {
+      bitField0_ = (bitField0_ & ~0x00000001);
+      onsetFrame_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private long sinceOnsetUs_ ;
+    /**
+     * <code>optional uint64 since_onset_us = 5;</code>
+     * @return Whether the sinceOnsetUs field is set.
+     */
+    @java.lang.Override
+    public boolean hasSinceOnsetUs() {
+      return ((bitField0_ & 0x00000002) != 0);
+    }
+    /**
+     * <code>optional uint64 since_onset_us = 5;</code>
+     * @return The sinceOnsetUs.
+     */
+    @java.lang.Override
+    public long getSinceOnsetUs() {
+      return sinceOnsetUs_;
+    }
+    /**
+     * <code>optional uint64 since_onset_us = 5;</code>
+     * @param value The sinceOnsetUs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setSinceOnsetUs(long value) {
+      bitField0_ |= 0x00000002;
+      sinceOnsetUs_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>optional uint64 since_onset_us = 5;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearSinceOnsetUs() {
+      bitField0_ = (bitField0_ & ~0x00000002);
+      sinceOnsetUs_ = 0L;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.InputEvent)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.InputEvent)
+  private static final vsg.InputEvent DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.InputEvent();
+  }
+
+  public static vsg.InputEvent getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<InputEvent>
+      PARSER = new com.google.protobuf.AbstractParser<InputEvent>() {
+    @java.lang.Override
+    public InputEvent parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new InputEvent(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<InputEvent> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<InputEvent> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.InputEvent getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
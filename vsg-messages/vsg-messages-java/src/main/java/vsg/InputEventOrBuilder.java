@@ -0,0 +1,83 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface InputEventOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.InputEvent)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Released when false.
+   * </pre>
+   *
+   * <code>bool pressed = 1;</code>
+   * @return The pressed.
+   */
+  boolean getPressed();
+
+  /**
+   * <code>.vsg.Key key = 2;</code>
+   * @return Whether the key field is set.
+   */
+  boolean hasKey();
+  /**
+   * <code>.vsg.Key key = 2;</code>
+   * @return The key.
+   */
+  vsg.Key getKey();
+  /**
+   * <code>.vsg.Key key = 2;</code>
+   */
+  vsg.KeyOrBuilder getKeyOrBuilder();
+
+  /**
+   * <code>.vsg.MouseButton mouse_button = 3;</code>
+   * @return Whether the mouseButton field is set.
+   */
+  boolean hasMouseButton();
+  /**
+   * <code>.vsg.MouseButton mouse_button = 3;</code>
+   * @return The mouseButton.
+   */
+  vsg.MouseButton getMouseButton();
+  /**
+   * <code>.vsg.MouseButton mouse_button = 3;</code>
+   */
+  vsg.MouseButtonOrBuilder getMouseButtonOrBuilder();
+
+  /**
+   * <pre>
+   * Most recent frame on which a command took effect, and the time from
+   * its onset to this event. Not set before the first such frame.
+   * </pre>
+   *
+   * <code>optional uint64 onset_frame = 4;</code>
+   * @return Whether the onsetFrame field is set.
+   */
+  boolean hasOnsetFrame();
+  /**
+   * <pre>
+   * Most recent frame on which a command took effect, and the time from
+   * its onset to this event. Not set before the first such frame.
+   * </pre>
+   *
+   * <code>optional uint64 onset_frame = 4;</code>
+   * @return The onsetFrame.
+   */
+  long getOnsetFrame();
+
+  /**
+   * <code>optional uint64 since_onset_us = 5;</code>
+   * @return Whether the sinceOnsetUs field is set.
+   */
+  boolean hasSinceOnsetUs();
+  /**
+   * <code>optional uint64 since_onset_us = 5;</code>
+   * @return The sinceOnsetUs.
+   */
+  long getSinceOnsetUs();
+
+  public vsg.InputEvent.SourceCase getSourceCase();
+}
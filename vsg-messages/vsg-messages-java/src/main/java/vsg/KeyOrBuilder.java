@@ -0,0 +1,37 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface KeyOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Key)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Such as "Space", "A" or "Left". Empty for keys without a name, which
+   * can still be told apart by scancode.
+   * </pre>
+   *
+   * <code>string name = 1;</code>
+   * @return The name.
+   */
+  java.lang.String getName();
+  /**
+   * <pre>
+   * Such as "Space", "A" or "Left". Empty for keys without a name, which
+   * can still be told apart by scancode.
+   * </pre>
+   *
+   * <code>string name = 1;</code>
+   * @return The bytes for name.
+   */
+  com.google.protobuf.ByteString
+      getNameBytes();
+
+  /**
+   * <code>uint32 scancode = 2;</code>
+   * @return The scancode.
+   */
+  int getScancode();
+}
@@ -0,0 +1,1072 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.Monitor}
+ */
+public final class Monitor extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Monitor)
+    MonitorOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Monitor.newBuilder() to construct.
+  private Monitor(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Monitor() {
+    name_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Monitor();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Monitor(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 10: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            name_ = s;
+            break;
+          }
+          case 16: {
+
+            width_ = input.readUInt32();
+            break;
+          }
+          case 24: {
+
+            height_ = input.readUInt32();
+            break;
+          }
+          case 32: {
+
+            x_ = input.readInt32();
+            break;
+          }
+          case 40: {
+
+            y_ = input.readInt32();
+            break;
+          }
+          case 49: {
+
+            scaleFactor_ = input.readDouble();
+            break;
+          }
+          case 56: {
+
+            refreshRateMillihertz_ = input.readUInt32();
+            break;
+          }
+          case 64: {
+
+            current_ = input.readBool();
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_Monitor_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_Monitor_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.Monitor.class, vsg.Monitor.Builder.class);
+  }
+
+  public static final int NAME_FIELD_NUMBER = 1;
+  private volatile java.lang.Object name_;
+  /**
+   * <code>string name = 1;</code>
+   * @return The name.
+   */
+  @java.lang.Override
+  public java.lang.String getName() {
+    java.lang.Object ref = name_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      name_ = s;
+      return s;
+    }
+  }
+  /**
+   * <code>string name = 1;</code>
+   * @return The bytes for name.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getNameBytes() {
+    java.lang.Object ref = name_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      name_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  public static final int WIDTH_FIELD_NUMBER = 2;
+  private int width_;
+  /**
+   * <pre>
+   * Physical pixels.
+   * </pre>
+   *
+   * <code>uint32 width = 2;</code>
+   * @return The width.
+   */
+  @java.lang.Override
+  public int getWidth() {
+    return width_;
+  }
+
+  public static final int HEIGHT_FIELD_NUMBER = 3;
+  private int height_;
+  /**
+   * <code>uint32 height = 3;</code>
+   * @return The height.
+   */
+  @java.lang.Override
+  public int getHeight() {
+    return height_;
+  }
+
+  public static final int X_FIELD_NUMBER = 4;
+  private int x_;
+  /**
+   * <pre>
+   * Top-left corner on the desktop, in physical pixels.
+   * </pre>
+   *
+   * <code>int32 x = 4;</code>
+   * @return The x.
+   */
+  @java.lang.Override
+  public int getX() {
+    return x_;
+  }
+
+  public static final int Y_FIELD_NUMBER = 5;
+  private int y_;
+  /**
+   * <code>int32 y = 5;</code>
+   * @return The y.
+   */
+  @java.lang.Override
+  public int getY() {
+    return y_;
+  }
+
+  public static final int SCALE_FACTOR_FIELD_NUMBER = 6;
+  private double scaleFactor_;
+  /**
+   * <code>double scale_factor = 6;</code>
+   * @return The scaleFactor.
+   */
+  @java.lang.Override
+  public double getScaleFactor() {
+    return scaleFactor_;
+  }
+
+  public static final int REFRESH_RATE_MILLIHERTZ_FIELD_NUMBER = 7;
+  private int refreshRateMillihertz_;
+  /**
+   * <pre>
+   * Nominal refresh rate reported by the system, 0 when unknown.
+   * </pre>
+   *
+   * <code>uint32 refresh_rate_millihertz = 7;</code>
+   * @return The refreshRateMillihertz.
+   */
+  @java.lang.Override
+  public int getRefreshRateMillihertz() {
+    return refreshRateMillihertz_;
+  }
+
+  public static final int CURRENT_FIELD_NUMBER = 8;
+  private boolean current_;
+  /**
+   * <pre>
+   * Whether the window is on this monitor.
+   * </pre>
+   *
+   * <code>bool current = 8;</code>
+   * @return The current.
+   */
+  @java.lang.Override
+  public boolean getCurrent() {
+    return current_;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(name_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 1, name_);
+    }
+    if (width_ != 0) {
+      output.writeUInt32(2, width_);
+    }
+    if (height_ != 0) {
+      output.writeUInt32(3, height_);
+    }
+    if (x_ != 0) {
+      output.writeInt32(4, x_);
+    }
+    if (y_ != 0) {
+      output.writeInt32(5, y_);
+    }
+    if (java.lang.Double.doubleToRawLongBits(scaleFactor_) != 0) {
+      output.writeDouble(6, scaleFactor_);
+    }
+    if (refreshRateMillihertz_ != 0) {
+      output.writeUInt32(7, refreshRateMillihertz_);
+    }
+    if (current_ != false) {
+      output.writeBool(8, current_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(name_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(1, name_);
+    }
+    if (width_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(2, width_);
+    }
+    if (height_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(3, height_);
+    }
+    if (x_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeInt32Size(4, x_);
+    }
+    if (y_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeInt32Size(5, y_);
+    }
+    if (java.lang.Double.doubleToRawLongBits(scaleFactor_) != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeDoubleSize(6, scaleFactor_);
+    }
+    if (refreshRateMillihertz_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(7, refreshRateMillihertz_);
+    }
+    if (current_ != false) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeBoolSize(8, current_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.Monitor)) {
+      return super.equals(obj);
+    }
+    vsg.Monitor other = (vsg.Monitor) obj;
+
+    if (!getName()
+        .equals(other.getName())) return false;
+    if (getWidth()
+        != other.getWidth()) return false;
+    if (getHeight()
+        != other.getHeight()) return false;
+    if (getX()
+        != other.getX()) return false;
+    if (getY()
+        != other.getY()) return false;
+    if (java.lang.Double.doubleToLongBits(getScaleFactor())
+        != java.lang.Double.doubleToLongBits(
+            other.getScaleFactor())) return false;
+    if (getRefreshRateMillihertz()
+        != other.getRefreshRateMillihertz()) return false;
+    if (getCurrent()
+        != other.getCurrent()) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + NAME_FIELD_NUMBER;
+    hash = (53 * hash) + getName().hashCode();
+    hash = (37 * hash) + WIDTH_FIELD_NUMBER;
+    hash = (53 * hash) + getWidth();
+    hash = (37 * hash) + HEIGHT_FIELD_NUMBER;
+    hash = (53 * hash) + getHeight();
+    hash = (37 * hash) + X_FIELD_NUMBER;
+    hash = (53 * hash) + getX();
+    hash = (37 * hash) + Y_FIELD_NUMBER;
+    hash = (53 * hash) + getY();
+    hash = (37 * hash) + SCALE_FACTOR_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        java.lang.Double.doubleToLongBits(getScaleFactor()));
+    hash = (37 * hash) + REFRESH_RATE_MILLIHERTZ_FIELD_NUMBER;
+    hash = (53 * hash) + getRefreshRateMillihertz();
+    hash = (37 * hash) + CURRENT_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashBoolean(
+        getCurrent());
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.Monitor parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Monitor parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Monitor parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Monitor parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Monitor parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Monitor parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Monitor parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Monitor parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Monitor parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.Monitor parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Monitor parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Monitor parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.Monitor prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.Monitor}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Monitor)
+      vsg.MonitorOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_Monitor_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_Monitor_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.Monitor.class, vsg.Monitor.Builder.class);
+    }
+
+    // Construct using vsg.Monitor.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      name_ = "";
+
+      width_ = 0;
+
+      height_ = 0;
+
+      x_ = 0;
+
+      y_ = 0;
+
+      scaleFactor_ = 0D;
+
+      refreshRateMillihertz_ = 0;
+
+      current_ = false;
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_Monitor_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.Monitor getDefaultInstanceForType() {
+      return vsg.Monitor.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.Monitor build() {
+      vsg.Monitor result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.Monitor buildPartial() {
+      vsg.Monitor result = new vsg.Monitor(this);
+      result.name_ = name_;
+      result.width_ = width_;
+      result.height_ = height_;
+      result.x_ = x_;
+      result.y_ = y_;
+      result.scaleFactor_ = scaleFactor_;
+      result.refreshRateMillihertz_ = refreshRateMillihertz_;
+      result.current_ = current_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.Monitor) {
+        return mergeFrom((vsg.Monitor)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.Monitor other) {
+      if (other == vsg.Monitor.getDefaultInstance()) return this;
+      if (!other.getName().isEmpty()) {
+        name_ = other.name_;
+        onChanged();
+      }
+      if (other.getWidth() != 0) {
+        setWidth(other.getWidth());
+      }
+      if (other.getHeight() != 0) {
+        setHeight(other.getHeight());
+      }
+      if (other.getX() != 0) {
+        setX(other.getX());
+      }
+      if (other.getY() != 0) {
+        setY(other.getY());
+      }
+      if (other.getScaleFactor() != 0D) {
+        setScaleFactor(other.getScaleFactor());
+      }
+      if (other.getRefreshRateMillihertz() != 0) {
+        setRefreshRateMillihertz(other.getRefreshRateMillihertz());
+      }
+      if (other.getCurrent() != false) {
+        setCurrent(other.getCurrent());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.Monitor parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.Monitor) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private java.lang.Object name_ = "";
+    /**
+     * <code>string name = 1;</code>
+     * @return The name.
+     */
+    public java.lang.String getName() {
+      java.lang.Object ref = name_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        name_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <code>string name = 1;</code>
+     * @return The bytes for name.
+     */
+    public com.google.protobuf.ByteString
+        getNameBytes() {
+      java.lang.Object ref = name_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        name_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <code>string name = 1;</code>
+     * @param value The name to set.
+     * @return This builder for chaining.
+     */
+    public Builder setName(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      name_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string name = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearName() {
+      
+      name_ = getDefaultInstance().getName();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string name = 1;</code>
+     * @param value The bytes for name to set.
+     * @return This builder for chaining.
+     */
+    public Builder setNameBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      name_ = value;
+      onChanged();
+      return this;
+    }
+
+    private int width_ ;
+    /**
+     * <pre>
+     * Physical pixels.
+     * </pre>
+     *
+     * <code>uint32 width = 2;</code>
+     * @return The width.
+     */
+    @java.lang.Override
+    public int getWidth() {
+      return width_;
+    }
+    /**
+     * <pre>
+     * Physical pixels.
+     * </pre>
+     *
+     * <code>uint32 width = 2;</code>
+     * @param value The width to set.
+     * @return This builder for chaining.
+     */
+    public Builder setWidth(int value) {
+      
+      width_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Physical pixels.
+     * </pre>
+     *
+     * <code>uint32 width = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearWidth() {
+      
+      width_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int height_ ;
+    /**
+     * <code>uint32 height = 3;</code>
+     * @return The height.
+     */
+    @java.lang.Override
+    public int getHeight() {
+      return height_;
+    }
+    /**
+     * <code>uint32 height = 3;</code>
+     * @param value The height to set.
+     * @return This builder for chaining.
+     */
+    public Builder setHeight(int value) {
+      
+      height_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 height = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearHeight() {
+      
+      height_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int x_ ;
+    /**
+     * <pre>
+     * Top-left corner on the desktop, in physical pixels.
+     * </pre>
+     *
+     * <code>int32 x = 4;</code>
+     * @return The x.
+     */
+    @java.lang.Override
+    public int getX() {
+      return x_;
+    }
+    /**
+     * <pre>
+     * Top-left corner on the desktop, in physical pixels.
+     * </pre>
+     *
+     * <code>int32 x = 4;</code>
+     * @param value The x to set.
+     * @return This builder for chaining.
+     */
+    public Builder setX(int value) {
+      
+      x_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Top-left corner on the desktop, in physical pixels.
+     * </pre>
+     *
+     * <code>int32 x = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearX() {
+      
+      x_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int y_ ;
+    /**
+     * <code>int32 y = 5;</code>
+     * @return The y.
+     */
+    @java.lang.Override
+    public int getY() {
+      return y_;
+    }
+    /**
+     * <code>int32 y = 5;</code>
+     * @param value The y to set.
+     * @return This builder for chaining.
+     */
+    public Builder setY(int value) {
+      
+      y_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>int32 y = 5;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearY() {
+      
+      y_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private double scaleFactor_ ;
+    /**
+     * <code>double scale_factor = 6;</code>
+     * @return The scaleFactor.
+     */
+    @java.lang.Override
+    public double getScaleFactor() {
+      return scaleFactor_;
+    }
+    /**
+     * <code>double scale_factor = 6;</code>
+     * @param value The scaleFactor to set.
+     * @return This builder for chaining.
+     */
+    public Builder setScaleFactor(double value) {
+      
+      scaleFactor_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>double scale_factor = 6;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearScaleFactor() {
+      
+      scaleFactor_ = 0D;
+      onChanged();
+      return this;
+    }
+
+    private int refreshRateMillihertz_ ;
+    /**
+     * <pre>
+     * Nominal refresh rate reported by the system, 0 when unknown.
+     * </pre>
+     *
+     * <code>uint32 refresh_rate_millihertz = 7;</code>
+     * @return The refreshRateMillihertz.
+     */
+    @java.lang.Override
+    public int getRefreshRateMillihertz() {
+      return refreshRateMillihertz_;
+    }
+    /**
+     * <pre>
+     * Nominal refresh rate reported by the system, 0 when unknown.
+     * </pre>
+     *
+     * <code>uint32 refresh_rate_millihertz = 7;</code>
+     * @param value The refreshRateMillihertz to set.
+     * @return This builder for chaining.
+     */
+    public Builder setRefreshRateMillihertz(int value) {
+      
+      refreshRateMillihertz_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Nominal refresh rate reported by the system, 0 when unknown.
+     * </pre>
+     *
+     * <code>uint32 refresh_rate_millihertz = 7;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearRefreshRateMillihertz() {
+      
+      refreshRateMillihertz_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private boolean current_ ;
+    /**
+     * <pre>
+     * Whether the window is on this monitor.
+     * </pre>
+     *
+     * <code>bool current = 8;</code>
+     * @return The current.
+     */
+    @java.lang.Override
+    public boolean getCurrent() {
+      return current_;
+    }
+    /**
+     * <pre>
+     * Whether the window is on this monitor.
+     * </pre>
+     *
+     * <code>bool current = 8;</code>
+     * @param value The current to set.
+     * @return This builder for chaining.
+     */
+    public Builder setCurrent(boolean value) {
+      
+      current_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Whether the window is on this monitor.
+     * </pre>
+     *
+     * <code>bool current = 8;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearCurrent() {
+      
+      current_ = false;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Monitor)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Monitor)
+  private static final vsg.Monitor DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.Monitor();
+  }
+
+  public static vsg.Monitor getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Monitor>
+      PARSER = new com.google.protobuf.AbstractParser<Monitor>() {
+    @java.lang.Override
+    public Monitor parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Monitor(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Monitor> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Monitor> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.Monitor getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
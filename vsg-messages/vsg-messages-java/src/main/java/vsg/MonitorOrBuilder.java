@@ -0,0 +1,79 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface MonitorOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Monitor)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>string name = 1;</code>
+   * @return The name.
+   */
+  java.lang.String getName();
+  /**
+   * <code>string name = 1;</code>
+   * @return The bytes for name.
+   */
+  com.google.protobuf.ByteString
+      getNameBytes();
+
+  /**
+   * <pre>
+   * Physical pixels.
+   * </pre>
+   *
+   * <code>uint32 width = 2;</code>
+   * @return The width.
+   */
+  int getWidth();
+
+  /**
+   * <code>uint32 height = 3;</code>
+   * @return The height.
+   */
+  int getHeight();
+
+  /**
+   * <pre>
+   * Top-left corner on the desktop, in physical pixels.
+   * </pre>
+   *
+   * <code>int32 x = 4;</code>
+   * @return The x.
+   */
+  int getX();
+
+  /**
+   * <code>int32 y = 5;</code>
+   * @return The y.
+   */
+  int getY();
+
+  /**
+   * <code>double scale_factor = 6;</code>
+   * @return The scaleFactor.
+   */
+  double getScaleFactor();
+
+  /**
+   * <pre>
+   * Nominal refresh rate reported by the system, 0 when unknown.
+   * </pre>
+   *
+   * <code>uint32 refresh_rate_millihertz = 7;</code>
+   * @return The refreshRateMillihertz.
+   */
+  int getRefreshRateMillihertz();
+
+  /**
+   * <pre>
+   * Whether the window is on this monitor.
+   * </pre>
+   *
+   * <code>bool current = 8;</code>
+   * @return The current.
+   */
+  boolean getCurrent();
+}
@@ -0,0 +1,821 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.MouseButton}
+ */
+public final class MouseButton extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.MouseButton)
+    MouseButtonOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use MouseButton.newBuilder() to construct.
+  private MouseButton(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private MouseButton() {
+    name_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new MouseButton();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private MouseButton(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 10: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            name_ = s;
+            break;
+          }
+          case 18: {
+            vsg.shapes.Coordinates.Builder subBuilder = null;
+            if (position_ != null) {
+              subBuilder = position_.toBuilder();
+            }
+            position_ = input.readMessage(vsg.shapes.Coordinates.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(position_);
+              position_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_MouseButton_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_MouseButton_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.MouseButton.class, vsg.MouseButton.Builder.class);
+  }
+
+  public static final int NAME_FIELD_NUMBER = 1;
+  private volatile java.lang.Object name_;
+  /**
+   * <pre>
+   * "Left", "Right", "Middle" or "Other" followed by a number.
+   * </pre>
+   *
+   * <code>string name = 1;</code>
+   * @return The name.
+   */
+  @java.lang.Override
+  public java.lang.String getName() {
+    java.lang.Object ref = name_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      name_ = s;
+      return s;
+    }
+  }
+  /**
+   * <pre>
+   * "Left", "Right", "Middle" or "Other" followed by a number.
+   * </pre>
+   *
+   * <code>string name = 1;</code>
+   * @return The bytes for name.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getNameBytes() {
+    java.lang.Object ref = name_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      name_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  public static final int POSITION_FIELD_NUMBER = 2;
+  private vsg.shapes.Coordinates position_;
+  /**
+   * <pre>
+   * Cursor position in the same coordinates as stimuli.
+   * </pre>
+   *
+   * <code>.vsg.Coordinates position = 2;</code>
+   * @return Whether the position field is set.
+   */
+  @java.lang.Override
+  public boolean hasPosition() {
+    return position_ != null;
+  }
+  /**
+   * <pre>
+   * Cursor position in the same coordinates as stimuli.
+   * </pre>
+   *
+   * <code>.vsg.Coordinates position = 2;</code>
+   * @return The position.
+   */
+  @java.lang.Override
+  public vsg.shapes.Coordinates getPosition() {
+    return position_ == null ? vsg.shapes.Coordinates.getDefaultInstance() : position_;
+  }
+  /**
+   * <pre>
+   * Cursor position in the same coordinates as stimuli.
+   * </pre>
+   *
+   * <code>.vsg.Coordinates position = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.CoordinatesOrBuilder getPositionOrBuilder() {
+    return getPosition();
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(name_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 1, name_);
+    }
+    if (position_ != null) {
+      output.writeMessage(2, getPosition());
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(name_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(1, name_);
+    }
+    if (position_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, getPosition());
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.MouseButton)) {
+      return super.equals(obj);
+    }
+    vsg.MouseButton other = (vsg.MouseButton) obj;
+
+    if (!getName()
+        .equals(other.getName())) return false;
+    if (hasPosition() != other.hasPosition()) return false;
+    if (hasPosition()) {
+      if (!getPosition()
+          .equals(other.getPosition())) return false;
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + NAME_FIELD_NUMBER;
+    hash = (53 * hash) + getName().hashCode();
+    if (hasPosition()) {
+      hash = (37 * hash) + POSITION_FIELD_NUMBER;
+      hash = (53 * hash) + getPosition().hashCode();
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.MouseButton parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.MouseButton parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.MouseButton parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.MouseButton parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.MouseButton parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.MouseButton parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.MouseButton parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.MouseButton parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.MouseButton parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.MouseButton parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.MouseButton parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.MouseButton parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.MouseButton prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.MouseButton}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.MouseButton)
+      vsg.MouseButtonOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_MouseButton_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_MouseButton_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.MouseButton.class, vsg.MouseButton.Builder.class);
+    }
+
+    // Construct using vsg.MouseButton.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      name_ = "";
+
+      if (positionBuilder_ == null) {
+        position_ = null;
+      } else {
+        position_ = null;
+        positionBuilder_ = null;
+      }
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_MouseButton_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.MouseButton getDefaultInstanceForType() {
+      return vsg.MouseButton.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.MouseButton build() {
+      vsg.MouseButton result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.MouseButton buildPartial() {
+      vsg.MouseButton result = new vsg.MouseButton(this);
+      result.name_ = name_;
+      if (positionBuilder_ == null) {
+        result.position_ = position_;
+      } else {
+        result.position_ = positionBuilder_.build();
+      }
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.MouseButton) {
+        return mergeFrom((vsg.MouseButton)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.MouseButton other) {
+      if (other == vsg.MouseButton.getDefaultInstance()) return this;
+      if (!other.getName().isEmpty()) {
+        name_ = other.name_;
+        onChanged();
+      }
+      if (other.hasPosition()) {
+        mergePosition(other.getPosition());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.MouseButton parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.MouseButton) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private java.lang.Object name_ = "";
+    /**
+     * <pre>
+     * "Left", "Right", "Middle" or "Other" followed by a number.
+     * </pre>
+     *
+     * <code>string name = 1;</code>
+     * @return The name.
+     */
+    public java.lang.String getName() {
+      java.lang.Object ref = name_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        name_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <pre>
+     * "Left", "Right", "Middle" or "Other" followed by a number.
+     * </pre>
+     *
+     * <code>string name = 1;</code>
+     * @return The bytes for name.
+     */
+    public com.google.protobuf.ByteString
+        getNameBytes() {
+      java.lang.Object ref = name_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        name_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <pre>
+     * "Left", "Right", "Middle" or "Other" followed by a number.
+     * </pre>
+     *
+     * <code>string name = 1;</code>
+     * @param value The name to set.
+     * @return This builder for chaining.
+     */
+    public Builder setName(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      name_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * "Left", "Right", "Middle" or "Other" followed by a number.
+     * </pre>
+     *
+     * <code>string name = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearName() {
+      
+      name_ = getDefaultInstance().getName();
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * "Left", "Right", "Middle" or "Other" followed by a number.
+     * </pre>
+     *
+     * <code>string name = 1;</code>
+     * @param value The bytes for name to set.
+     * @return This builder for chaining.
+     */
+    public Builder setNameBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      name_ = value;
+      onChanged();
+      return this;
+    }
+
+    private vsg.shapes.Coordinates position_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Coordinates, vsg.shapes.Coordinates.Builder, vsg.shapes.CoordinatesOrBuilder> positionBuilder_;
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     * @return Whether the position field is set.
+     */
+    public boolean hasPosition() {
+      return positionBuilder_ != null || position_ != null;
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     * @return The position.
+     */
+    public vsg.shapes.Coordinates getPosition() {
+      if (positionBuilder_ == null) {
+        return position_ == null ? vsg.shapes.Coordinates.getDefaultInstance() : position_;
+      } else {
+        return positionBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    public Builder setPosition(vsg.shapes.Coordinates value) {
+      if (positionBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        position_ = value;
+        onChanged();
+      } else {
+        positionBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    public Builder setPosition(
+        vsg.shapes.Coordinates.Builder builderForValue) {
+      if (positionBuilder_ == null) {
+        position_ = builderForValue.build();
+        onChanged();
+      } else {
+        positionBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    public Builder mergePosition(vsg.shapes.Coordinates value) {
+      if (positionBuilder_ == null) {
+        if (position_ != null) {
+          position_ =
+            vsg.shapes.Coordinates.newBuilder(position_).mergeFrom(value).buildPartial();
+        } else {
+          position_ = value;
+        }
+        onChanged();
+      } else {
+        positionBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    public Builder clearPosition() {
+      if (positionBuilder_ == null) {
+        position_ = null;
+        onChanged();
+      } else {
+        position_ = null;
+        positionBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    public vsg.shapes.Coordinates.Builder getPositionBuilder() {
+      
+      onChanged();
+      return getPositionFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    public vsg.shapes.CoordinatesOrBuilder getPositionOrBuilder() {
+      if (positionBuilder_ != null) {
+        return positionBuilder_.getMessageOrBuilder();
+      } else {
+        return position_ == null ?
+            vsg.shapes.Coordinates.getDefaultInstance() : position_;
+      }
+    }
+    /**
+     * <pre>
+     * Cursor position in the same coordinates as stimuli.
+     * </pre>
+     *
+     * <code>.vsg.Coordinates position = 2;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Coordinates, vsg.shapes.Coordinates.Builder, vsg.shapes.CoordinatesOrBuilder> 
+        getPositionFieldBuilder() {
+      if (positionBuilder_ == null) {
+        positionBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.shapes.Coordinates, vsg.shapes.Coordinates.Builder, vsg.shapes.CoordinatesOrBuilder>(
+                getPosition(),
+                getParentForChildren(),
+                isClean());
+        position_ = null;
+      }
+      return positionBuilder_;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.MouseButton)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.MouseButton)
+  private static final vsg.MouseButton DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.MouseButton();
+  }
+
+  public static vsg.MouseButton getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<MouseButton>
+      PARSER = new com.google.protobuf.AbstractParser<MouseButton>() {
+    @java.lang.Override
+    public MouseButton parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new MouseButton(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<MouseButton> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<MouseButton> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.MouseButton getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,56 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface MouseButtonOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.MouseButton)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * "Left", "Right", "Middle" or "Other" followed by a number.
+   * </pre>
+   *
+   * <code>string name = 1;</code>
+   * @return The name.
+   */
+  java.lang.String getName();
+  /**
+   * <pre>
+   * "Left", "Right", "Middle" or "Other" followed by a number.
+   * </pre>
+   *
+   * <code>string name = 1;</code>
+   * @return The bytes for name.
+   */
+  com.google.protobuf.ByteString
+      getNameBytes();
+
+  /**
+   * <pre>
+   * Cursor position in the same coordinates as stimuli.
+   * </pre>
+   *
+   * <code>.vsg.Coordinates position = 2;</code>
+   * @return Whether the position field is set.
+   */
+  boolean hasPosition();
+  /**
+   * <pre>
+   * Cursor position in the same coordinates as stimuli.
+   * </pre>
+   *
+   * <code>.vsg.Coordinates position = 2;</code>
+   * @return The position.
+   */
+  vsg.shapes.Coordinates getPosition();
+  /**
+   * <pre>
+   * Cursor position in the same coordinates as stimuli.
+   * </pre>
+   *
+   * <code>.vsg.Coordinates position = 2;</code>
+   */
+  vsg.shapes.CoordinatesOrBuilder getPositionOrBuilder();
+}
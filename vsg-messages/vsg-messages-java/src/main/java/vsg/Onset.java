@@ -0,0 +1,1052 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * When a frame was handed to the display for presentation.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.Onset}
+ */
+public final class Onset extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Onset)
+    OnsetOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Onset.newBuilder() to construct.
+  private Onset(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Onset() {
+    offsets_ = java.util.Collections.emptyList();
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Onset();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Onset(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    int mutable_bitField0_ = 0;
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            frame_ = input.readUInt64();
+            break;
+          }
+          case 16: {
+
+            timestampUs_ = input.readUInt64();
+            break;
+          }
+          case 26: {
+            if (!((mutable_bitField0_ & 0x00000001) != 0)) {
+              offsets_ = new java.util.ArrayList<vsg.StimulusOffset>();
+              mutable_bitField0_ |= 0x00000001;
+            }
+            offsets_.add(
+                input.readMessage(vsg.StimulusOffset.parser(), extensionRegistry));
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      if (((mutable_bitField0_ & 0x00000001) != 0)) {
+        offsets_ = java.util.Collections.unmodifiableList(offsets_);
+      }
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_Onset_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_Onset_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.Onset.class, vsg.Onset.Builder.class);
+  }
+
+  public static final int FRAME_FIELD_NUMBER = 1;
+  private long frame_;
+  /**
+   * <pre>
+   * Number of frames presented before this one since the server started.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return The frame.
+   */
+  @java.lang.Override
+  public long getFrame() {
+    return frame_;
+  }
+
+  public static final int TIMESTAMP_US_FIELD_NUMBER = 2;
+  private long timestampUs_;
+  /**
+   * <pre>
+   * Microseconds on the server's monotonic clock, counted from its start,
+   * when presenting the frame returned. The frame is only queued then: the
+   * display starts scanning it out at its next refresh, up to one refresh
+   * interval (DisplayStatus.refresh_rate_hz) later, and a pixel lights up
+   * later still depending on its row and the display's response time.
+   * Measure the actual onset with a photodiode where it matters.
+   * </pre>
+   *
+   * <code>uint64 timestamp_us = 2;</code>
+   * @return The timestampUs.
+   */
+  @java.lang.Override
+  public long getTimestampUs() {
+    return timestampUs_;
+  }
+
+  public static final int OFFSETS_FIELD_NUMBER = 3;
+  private java.util.List<vsg.StimulusOffset> offsets_;
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  @java.lang.Override
+  public java.util.List<vsg.StimulusOffset> getOffsetsList() {
+    return offsets_;
+  }
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  @java.lang.Override
+  public java.util.List<? extends vsg.StimulusOffsetOrBuilder> 
+      getOffsetsOrBuilderList() {
+    return offsets_;
+  }
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  @java.lang.Override
+  public int getOffsetsCount() {
+    return offsets_.size();
+  }
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.StimulusOffset getOffsets(int index) {
+    return offsets_.get(index);
+  }
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.StimulusOffsetOrBuilder getOffsetsOrBuilder(
+      int index) {
+    return offsets_.get(index);
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (frame_ != 0L) {
+      output.writeUInt64(1, frame_);
+    }
+    if (timestampUs_ != 0L) {
+      output.writeUInt64(2, timestampUs_);
+    }
+    for (int i = 0; i < offsets_.size(); i++) {
+      output.writeMessage(3, offsets_.get(i));
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (frame_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(1, frame_);
+    }
+    if (timestampUs_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(2, timestampUs_);
+    }
+    for (int i = 0; i < offsets_.size(); i++) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, offsets_.get(i));
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.Onset)) {
+      return super.equals(obj);
+    }
+    vsg.Onset other = (vsg.Onset) obj;
+
+    if (getFrame()
+        != other.getFrame()) return false;
+    if (getTimestampUs()
+        != other.getTimestampUs()) return false;
+    if (!getOffsetsList()
+        .equals(other.getOffsetsList())) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + FRAME_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getFrame());
+    hash = (37 * hash) + TIMESTAMP_US_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getTimestampUs());
+    if (getOffsetsCount() > 0) {
+      hash = (37 * hash) + OFFSETS_FIELD_NUMBER;
+      hash = (53 * hash) + getOffsetsList().hashCode();
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.Onset parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Onset parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Onset parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Onset parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Onset parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Onset parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Onset parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Onset parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Onset parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.Onset parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Onset parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Onset parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.Onset prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * When a frame was handed to the display for presentation.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.Onset}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Onset)
+      vsg.OnsetOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_Onset_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_Onset_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.Onset.class, vsg.Onset.Builder.class);
+    }
+
+    // Construct using vsg.Onset.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+        getOffsetsFieldBuilder();
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      frame_ = 0L;
+
+      timestampUs_ = 0L;
+
+      if (offsetsBuilder_ == null) {
+        offsets_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000001);
+      } else {
+        offsetsBuilder_.clear();
+      }
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_Onset_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.Onset getDefaultInstanceForType() {
+      return vsg.Onset.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.Onset build() {
+      vsg.Onset result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.Onset buildPartial() {
+      vsg.Onset result = new vsg.Onset(this);
+      int from_bitField0_ = bitField0_;
+      result.frame_ = frame_;
+      result.timestampUs_ = timestampUs_;
+      if (offsetsBuilder_ == null) {
+        if (((bitField0_ & 0x00000001) != 0)) {
+          offsets_ = java.util.Collections.unmodifiableList(offsets_);
+          bitField0_ = (bitField0_ & ~0x00000001);
+        }
+        result.offsets_ = offsets_;
+      } else {
+        result.offsets_ = offsetsBuilder_.build();
+      }
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.Onset) {
+        return mergeFrom((vsg.Onset)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.Onset other) {
+      if (other == vsg.Onset.getDefaultInstance()) return this;
+      if (other.getFrame() != 0L) {
+        setFrame(other.getFrame());
+      }
+      if (other.getTimestampUs() != 0L) {
+        setTimestampUs(other.getTimestampUs());
+      }
+      if (offsetsBuilder_ == null) {
+        if (!other.offsets_.isEmpty()) {
+          if (offsets_.isEmpty()) {
+            offsets_ = other.offsets_;
+            bitField0_ = (bitField0_ & ~0x00000001);
+          } else {
+            ensureOffsetsIsMutable();
+            offsets_.addAll(other.offsets_);
+          }
+          onChanged();
+        }
+      } else {
+        if (!other.offsets_.isEmpty()) {
+          if (offsetsBuilder_.isEmpty()) {
+            offsetsBuilder_.dispose();
+            offsetsBuilder_ = null;
+            offsets_ = other.offsets_;
+            bitField0_ = (bitField0_ & ~0x00000001);
+            offsetsBuilder_ = 
+              com.google.protobuf.GeneratedMessageV3.alwaysUseFieldBuilders ?
+                 getOffsetsFieldBuilder() : null;
+          } else {
+            offsetsBuilder_.addAllMessages(other.offsets_);
+          }
+        }
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.Onset parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.Onset) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int bitField0_;
+
+    private long frame_ ;
+    /**
+     * <pre>
+     * Number of frames presented before this one since the server started.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return The frame.
+     */
+    @java.lang.Override
+    public long getFrame() {
+      return frame_;
+    }
+    /**
+     * <pre>
+     * Number of frames presented before this one since the server started.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @param value The frame to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFrame(long value) {
+      
+      frame_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Number of frames presented before this one since the server started.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFrame() {
+      
+      frame_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private long timestampUs_ ;
+    /**
+     * <pre>
+     * Microseconds on the server's monotonic clock, counted from its start,
+     * when presenting the frame returned. The frame is only queued then: the
+     * display starts scanning it out at its next refresh, up to one refresh
+     * interval (DisplayStatus.refresh_rate_hz) later, and a pixel lights up
+     * later still depending on its row and the display's response time.
+     * Measure the actual onset with a photodiode where it matters.
+     * </pre>
+     *
+     * <code>uint64 timestamp_us = 2;</code>
+     * @return The timestampUs.
+     */
+    @java.lang.Override
+    public long getTimestampUs() {
+      return timestampUs_;
+    }
+    /**
+     * <pre>
+     * Microseconds on the server's monotonic clock, counted from its start,
+     * when presenting the frame returned. The frame is only queued then: the
+     * display starts scanning it out at its next refresh, up to one refresh
+     * interval (DisplayStatus.refresh_rate_hz) later, and a pixel lights up
+     * later still depending on its row and the display's response time.
+     * Measure the actual onset with a photodiode where it matters.
+     * </pre>
+     *
+     * <code>uint64 timestamp_us = 2;</code>
+     * @param value The timestampUs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setTimestampUs(long value) {
+      
+      timestampUs_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Microseconds on the server's monotonic clock, counted from its start,
+     * when presenting the frame returned. The frame is only queued then: the
+     * display starts scanning it out at its next refresh, up to one refresh
+     * interval (DisplayStatus.refresh_rate_hz) later, and a pixel lights up
+     * later still depending on its row and the display's response time.
+     * Measure the actual onset with a photodiode where it matters.
+     * </pre>
+     *
+     * <code>uint64 timestamp_us = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearTimestampUs() {
+      
+      timestampUs_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private java.util.List<vsg.StimulusOffset> offsets_ =
+      java.util.Collections.emptyList();
+    private void ensureOffsetsIsMutable() {
+      if (!((bitField0_ & 0x00000001) != 0)) {
+        offsets_ = new java.util.ArrayList<vsg.StimulusOffset>(offsets_);
+        bitField0_ |= 0x00000001;
+       }
+    }
+
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.StimulusOffset, vsg.StimulusOffset.Builder, vsg.StimulusOffsetOrBuilder> offsetsBuilder_;
+
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public java.util.List<vsg.StimulusOffset> getOffsetsList() {
+      if (offsetsBuilder_ == null) {
+        return java.util.Collections.unmodifiableList(offsets_);
+      } else {
+        return offsetsBuilder_.getMessageList();
+      }
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public int getOffsetsCount() {
+      if (offsetsBuilder_ == null) {
+        return offsets_.size();
+      } else {
+        return offsetsBuilder_.getCount();
+      }
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public vsg.StimulusOffset getOffsets(int index) {
+      if (offsetsBuilder_ == null) {
+        return offsets_.get(index);
+      } else {
+        return offsetsBuilder_.getMessage(index);
+      }
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder setOffsets(
+        int index, vsg.StimulusOffset value) {
+      if (offsetsBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureOffsetsIsMutable();
+        offsets_.set(index, value);
+        onChanged();
+      } else {
+        offsetsBuilder_.setMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder setOffsets(
+        int index, vsg.StimulusOffset.Builder builderForValue) {
+      if (offsetsBuilder_ == null) {
+        ensureOffsetsIsMutable();
+        offsets_.set(index, builderForValue.build());
+        onChanged();
+      } else {
+        offsetsBuilder_.setMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder addOffsets(vsg.StimulusOffset value) {
+      if (offsetsBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureOffsetsIsMutable();
+        offsets_.add(value);
+        onChanged();
+      } else {
+        offsetsBuilder_.addMessage(value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder addOffsets(
+        int index, vsg.StimulusOffset value) {
+      if (offsetsBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureOffsetsIsMutable();
+        offsets_.add(index, value);
+        onChanged();
+      } else {
+        offsetsBuilder_.addMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder addOffsets(
+        vsg.StimulusOffset.Builder builderForValue) {
+      if (offsetsBuilder_ == null) {
+        ensureOffsetsIsMutable();
+        offsets_.add(builderForValue.build());
+        onChanged();
+      } else {
+        offsetsBuilder_.addMessage(builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder addOffsets(
+        int index, vsg.StimulusOffset.Builder builderForValue) {
+      if (offsetsBuilder_ == null) {
+        ensureOffsetsIsMutable();
+        offsets_.add(index, builderForValue.build());
+        onChanged();
+      } else {
+        offsetsBuilder_.addMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder addAllOffsets(
+        java.lang.Iterable<? extends vsg.StimulusOffset> values) {
+      if (offsetsBuilder_ == null) {
+        ensureOffsetsIsMutable();
+        com.google.protobuf.AbstractMessageLite.Builder.addAll(
+            values, offsets_);
+        onChanged();
+      } else {
+        offsetsBuilder_.addAllMessages(values);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder clearOffsets() {
+      if (offsetsBuilder_ == null) {
+        offsets_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000001);
+        onChanged();
+      } else {
+        offsetsBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public Builder removeOffsets(int index) {
+      if (offsetsBuilder_ == null) {
+        ensureOffsetsIsMutable();
+        offsets_.remove(index);
+        onChanged();
+      } else {
+        offsetsBuilder_.remove(index);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public vsg.StimulusOffset.Builder getOffsetsBuilder(
+        int index) {
+      return getOffsetsFieldBuilder().getBuilder(index);
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public vsg.StimulusOffsetOrBuilder getOffsetsOrBuilder(
+        int index) {
+      if (offsetsBuilder_ == null) {
+        return offsets_.get(index);  } else {
+        return offsetsBuilder_.getMessageOrBuilder(index);
+      }
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public java.util.List<? extends vsg.StimulusOffsetOrBuilder> 
+         getOffsetsOrBuilderList() {
+      if (offsetsBuilder_ != null) {
+        return offsetsBuilder_.getMessageOrBuilderList();
+      } else {
+        return java.util.Collections.unmodifiableList(offsets_);
+      }
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public vsg.StimulusOffset.Builder addOffsetsBuilder() {
+      return getOffsetsFieldBuilder().addBuilder(
+          vsg.StimulusOffset.getDefaultInstance());
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public vsg.StimulusOffset.Builder addOffsetsBuilder(
+        int index) {
+      return getOffsetsFieldBuilder().addBuilder(
+          index, vsg.StimulusOffset.getDefaultInstance());
+    }
+    /**
+     * <pre>
+     * Stimuli with a lifetime that were added by the acknowledged command.
+     * </pre>
+     *
+     * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+     */
+    public java.util.List<vsg.StimulusOffset.Builder> 
+         getOffsetsBuilderList() {
+      return getOffsetsFieldBuilder().getBuilderList();
+    }
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.StimulusOffset, vsg.StimulusOffset.Builder, vsg.StimulusOffsetOrBuilder> 
+        getOffsetsFieldBuilder() {
+      if (offsetsBuilder_ == null) {
+        offsetsBuilder_ = new com.google.protobuf.RepeatedFieldBuilderV3<
+            vsg.StimulusOffset, vsg.StimulusOffset.Builder, vsg.StimulusOffsetOrBuilder>(
+                offsets_,
+                ((bitField0_ & 0x00000001) != 0),
+                getParentForChildren(),
+                isClean());
+        offsets_ = null;
+      }
+      return offsetsBuilder_;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Onset)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Onset)
+  private static final vsg.Onset DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.Onset();
+  }
+
+  public static vsg.Onset getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Onset>
+      PARSER = new com.google.protobuf.AbstractParser<Onset>() {
+    @java.lang.Override
+    public Onset parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Onset(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Onset> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Onset> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.Onset getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
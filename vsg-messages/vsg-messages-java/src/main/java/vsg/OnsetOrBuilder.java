@@ -0,0 +1,78 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface OnsetOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Onset)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Number of frames presented before this one since the server started.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return The frame.
+   */
+  long getFrame();
+
+  /**
+   * <pre>
+   * Microseconds on the server's monotonic clock, counted from its start,
+   * when presenting the frame returned. The frame is only queued then: the
+   * display starts scanning it out at its next refresh, up to one refresh
+   * interval (DisplayStatus.refresh_rate_hz) later, and a pixel lights up
+   * later still depending on its row and the display's response time.
+   * Measure the actual onset with a photodiode where it matters.
+   * </pre>
+   *
+   * <code>uint64 timestamp_us = 2;</code>
+   * @return The timestampUs.
+   */
+  long getTimestampUs();
+
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  java.util.List<vsg.StimulusOffset> 
+      getOffsetsList();
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  vsg.StimulusOffset getOffsets(int index);
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  int getOffsetsCount();
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  java.util.List<? extends vsg.StimulusOffsetOrBuilder> 
+      getOffsetsOrBuilderList();
+  /**
+   * <pre>
+   * Stimuli with a lifetime that were added by the acknowledged command.
+   * </pre>
+   *
+   * <code>repeated .vsg.StimulusOffset offsets = 3;</code>
+   */
+  vsg.StimulusOffsetOrBuilder getOffsetsOrBuilder(
+      int index);
+}
@@ -0,0 +1,157 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf enum {@code vsg.PixelFormat}
+ */
+public enum PixelFormat
+    implements com.google.protobuf.ProtocolMessageEnum {
+  /**
+   * <pre>
+   * sRGB encoded, like decoded image files.
+   * </pre>
+   *
+   * <code>PIXEL_FORMAT_RGBA8 = 0;</code>
+   */
+  PIXEL_FORMAT_RGBA8(0),
+  /**
+   * <pre>
+   * Single channel formats are shown as gray levels. They and RGBA32F are
+   * linear intensities. Multi-byte values are little-endian.
+   * </pre>
+   *
+   * <code>PIXEL_FORMAT_R8 = 1;</code>
+   */
+  PIXEL_FORMAT_R8(1),
+  /**
+   * <pre>
+   * Needs an adapter supporting 16 bit normalized textures.
+   * </pre>
+   *
+   * <code>PIXEL_FORMAT_R16 = 2;</code>
+   */
+  PIXEL_FORMAT_R16(2),
+  /**
+   * <code>PIXEL_FORMAT_RGBA32F = 3;</code>
+   */
+  PIXEL_FORMAT_RGBA32F(3),
+  UNRECOGNIZED(-1),
+  ;
+
+  /**
+   * <pre>
+   * sRGB encoded, like decoded image files.
+   * </pre>
+   *
+   * <code>PIXEL_FORMAT_RGBA8 = 0;</code>
+   */
+  public static final int PIXEL_FORMAT_RGBA8_VALUE = 0;
+  /**
+   * <pre>
+   * Single channel formats are shown as gray levels. They and RGBA32F are
+   * linear intensities. Multi-byte values are little-endian.
+   * </pre>
+   *
+   * <code>PIXEL_FORMAT_R8 = 1;</code>
+   */
+  public static final int PIXEL_FORMAT_R8_VALUE = 1;
+  /**
+   * <pre>
+   * Needs an adapter supporting 16 bit normalized textures.
+   * </pre>
+   *
+   * <code>PIXEL_FORMAT_R16 = 2;</code>
+   */
+  public static final int PIXEL_FORMAT_R16_VALUE = 2;
+  /**
+   * <code>PIXEL_FORMAT_RGBA32F = 3;</code>
+   */
+  public static final int PIXEL_FORMAT_RGBA32F_VALUE = 3;
+
+
+  public final int getNumber() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalArgumentException(
+          "Can't get the number of an unknown enum value.");
+    }
+    return value;
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   * @deprecated Use {@link #forNumber(int)} instead.
+   */
+  @java.lang.Deprecated
+  public static PixelFormat valueOf(int value) {
+    return forNumber(value);
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   */
+  public static PixelFormat forNumber(int value) {
+    switch (value) {
+      case 0: return PIXEL_FORMAT_RGBA8;
+      case 1: return PIXEL_FORMAT_R8;
+      case 2: return PIXEL_FORMAT_R16;
+      case 3: return PIXEL_FORMAT_RGBA32F;
+      default: return null;
+    }
+  }
+
+  public static com.google.protobuf.Internal.EnumLiteMap<PixelFormat>
+      internalGetValueMap() {
+    return internalValueMap;
+  }
+  private static final com.google.protobuf.Internal.EnumLiteMap<
+      PixelFormat> internalValueMap =
+        new com.google.protobuf.Internal.EnumLiteMap<PixelFormat>() {
+          public PixelFormat findValueByNumber(int number) {
+            return PixelFormat.forNumber(number);
+          }
+        };
+
+  public final com.google.protobuf.Descriptors.EnumValueDescriptor
+      getValueDescriptor() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalStateException(
+          "Can't get the descriptor of an unrecognized enum value.");
+    }
+    return getDescriptor().getValues().get(ordinal());
+  }
+  public final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptorForType() {
+    return getDescriptor();
+  }
+  public static final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptor() {
+    return vsg.Vsg.getDescriptor().getEnumTypes().get(2);
+  }
+
+  private static final PixelFormat[] VALUES = values();
+
+  public static PixelFormat valueOf(
+      com.google.protobuf.Descriptors.EnumValueDescriptor desc) {
+    if (desc.getType() != getDescriptor()) {
+      throw new java.lang.IllegalArgumentException(
+        "EnumValueDescriptor is not for this type.");
+    }
+    if (desc.getIndex() == -1) {
+      return UNRECOGNIZED;
+    }
+    return VALUES[desc.getIndex()];
+  }
+
+  private final int value;
+
+  private PixelFormat(int value) {
+    this.value = value;
+  }
+
+  // @@protoc_insertion_point(enum_scope:vsg.PixelFormat)
+}
+
@@ -0,0 +1,117 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Bumped whenever messages change in a way older peers would misread.
+ * </pre>
+ *
+ * Protobuf enum {@code vsg.ProtocolVersion}
+ */
+public enum ProtocolVersion
+    implements com.google.protobuf.ProtocolMessageEnum {
+  /**
+   * <code>PROTOCOL_VERSION_UNSPECIFIED = 0;</code>
+   */
+  PROTOCOL_VERSION_UNSPECIFIED(0),
+  /**
+   * <code>PROTOCOL_VERSION_CURRENT = 1;</code>
+   */
+  PROTOCOL_VERSION_CURRENT(1),
+  UNRECOGNIZED(-1),
+  ;
+
+  /**
+   * <code>PROTOCOL_VERSION_UNSPECIFIED = 0;</code>
+   */
+  public static final int PROTOCOL_VERSION_UNSPECIFIED_VALUE = 0;
+  /**
+   * <code>PROTOCOL_VERSION_CURRENT = 1;</code>
+   */
+  public static final int PROTOCOL_VERSION_CURRENT_VALUE = 1;
+
+
+  public final int getNumber() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalArgumentException(
+          "Can't get the number of an unknown enum value.");
+    }
+    return value;
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   * @deprecated Use {@link #forNumber(int)} instead.
+   */
+  @java.lang.Deprecated
+  public static ProtocolVersion valueOf(int value) {
+    return forNumber(value);
+  }
+
+  /**
+   * @param value The numeric wire value of the corresponding enum entry.
+   * @return The enum associated with the given numeric wire value.
+   */
+  public static ProtocolVersion forNumber(int value) {
+    switch (value) {
+      case 0: return PROTOCOL_VERSION_UNSPECIFIED;
+      case 1: return PROTOCOL_VERSION_CURRENT;
+      default: return null;
+    }
+  }
+
+  public static com.google.protobuf.Internal.EnumLiteMap<ProtocolVersion>
+      internalGetValueMap() {
+    return internalValueMap;
+  }
+  private static final com.google.protobuf.Internal.EnumLiteMap<
+      ProtocolVersion> internalValueMap =
+        new com.google.protobuf.Internal.EnumLiteMap<ProtocolVersion>() {
+          public ProtocolVersion findValueByNumber(int number) {
+            return ProtocolVersion.forNumber(number);
+          }
+        };
+
+  public final com.google.protobuf.Descriptors.EnumValueDescriptor
+      getValueDescriptor() {
+    if (this == UNRECOGNIZED) {
+      throw new java.lang.IllegalStateException(
+          "Can't get the descriptor of an unrecognized enum value.");
+    }
+    return getDescriptor().getValues().get(ordinal());
+  }
+  public final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptorForType() {
+    return getDescriptor();
+  }
+  public static final com.google.protobuf.Descriptors.EnumDescriptor
+      getDescriptor() {
+    return vsg.Vsg.getDescriptor().getEnumTypes().get(4);
+  }
+
+  private static final ProtocolVersion[] VALUES = values();
+
+  public static ProtocolVersion valueOf(
+      com.google.protobuf.Descriptors.EnumValueDescriptor desc) {
+    if (desc.getType() != getDescriptor()) {
+      throw new java.lang.IllegalArgumentException(
+        "EnumValueDescriptor is not for this type.");
+    }
+    if (desc.getIndex() == -1) {
+      return UNRECOGNIZED;
+    }
+    return VALUES[desc.getIndex()];
+  }
+
+  private final int value;
+
+  private ProtocolVersion(int value) {
+    this.value = value;
+  }
+
+  // @@protoc_insertion_point(enum_scope:vsg.ProtocolVersion)
+}
+
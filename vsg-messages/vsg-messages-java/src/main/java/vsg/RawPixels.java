@@ -0,0 +1,799 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Pixels uploaded as they are, without decoding. Rows run from top to bottom.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.RawPixels}
+ */
+public final class RawPixels extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.RawPixels)
+    RawPixelsOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use RawPixels.newBuilder() to construct.
+  private RawPixels(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private RawPixels() {
+    format_ = 0;
+    data_ = com.google.protobuf.ByteString.EMPTY;
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new RawPixels();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private RawPixels(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            width_ = input.readUInt32();
+            break;
+          }
+          case 16: {
+
+            height_ = input.readUInt32();
+            break;
+          }
+          case 24: {
+            int rawValue = input.readEnum();
+
+            format_ = rawValue;
+            break;
+          }
+          case 32: {
+
+            stride_ = input.readUInt32();
+            break;
+          }
+          case 42: {
+
+            data_ = input.readBytes();
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_RawPixels_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_RawPixels_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.RawPixels.class, vsg.RawPixels.Builder.class);
+  }
+
+  public static final int WIDTH_FIELD_NUMBER = 1;
+  private int width_;
+  /**
+   * <code>uint32 width = 1;</code>
+   * @return The width.
+   */
+  @java.lang.Override
+  public int getWidth() {
+    return width_;
+  }
+
+  public static final int HEIGHT_FIELD_NUMBER = 2;
+  private int height_;
+  /**
+   * <code>uint32 height = 2;</code>
+   * @return The height.
+   */
+  @java.lang.Override
+  public int getHeight() {
+    return height_;
+  }
+
+  public static final int FORMAT_FIELD_NUMBER = 3;
+  private int format_;
+  /**
+   * <code>.vsg.PixelFormat format = 3;</code>
+   * @return The enum numeric value on the wire for format.
+   */
+  @java.lang.Override public int getFormatValue() {
+    return format_;
+  }
+  /**
+   * <code>.vsg.PixelFormat format = 3;</code>
+   * @return The format.
+   */
+  @java.lang.Override public vsg.PixelFormat getFormat() {
+    @SuppressWarnings("deprecation")
+    vsg.PixelFormat result = vsg.PixelFormat.valueOf(format_);
+    return result == null ? vsg.PixelFormat.UNRECOGNIZED : result;
+  }
+
+  public static final int STRIDE_FIELD_NUMBER = 4;
+  private int stride_;
+  /**
+   * <pre>
+   * Bytes from the start of one row to the next. 0 means tightly packed.
+   * </pre>
+   *
+   * <code>uint32 stride = 4;</code>
+   * @return The stride.
+   */
+  @java.lang.Override
+  public int getStride() {
+    return stride_;
+  }
+
+  public static final int DATA_FIELD_NUMBER = 5;
+  private com.google.protobuf.ByteString data_;
+  /**
+   * <code>bytes data = 5;</code>
+   * @return The data.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString getData() {
+    return data_;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (width_ != 0) {
+      output.writeUInt32(1, width_);
+    }
+    if (height_ != 0) {
+      output.writeUInt32(2, height_);
+    }
+    if (format_ != vsg.PixelFormat.PIXEL_FORMAT_RGBA8.getNumber()) {
+      output.writeEnum(3, format_);
+    }
+    if (stride_ != 0) {
+      output.writeUInt32(4, stride_);
+    }
+    if (!data_.isEmpty()) {
+      output.writeBytes(5, data_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (width_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(1, width_);
+    }
+    if (height_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(2, height_);
+    }
+    if (format_ != vsg.PixelFormat.PIXEL_FORMAT_RGBA8.getNumber()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeEnumSize(3, format_);
+    }
+    if (stride_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(4, stride_);
+    }
+    if (!data_.isEmpty()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeBytesSize(5, data_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.RawPixels)) {
+      return super.equals(obj);
+    }
+    vsg.RawPixels other = (vsg.RawPixels) obj;
+
+    if (getWidth()
+        != other.getWidth()) return false;
+    if (getHeight()
+        != other.getHeight()) return false;
+    if (format_ != other.format_) return false;
+    if (getStride()
+        != other.getStride()) return false;
+    if (!getData()
+        .equals(other.getData())) return false;
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + WIDTH_FIELD_NUMBER;
+    hash = (53 * hash) + getWidth();
+    hash = (37 * hash) + HEIGHT_FIELD_NUMBER;
+    hash = (53 * hash) + getHeight();
+    hash = (37 * hash) + FORMAT_FIELD_NUMBER;
+    hash = (53 * hash) + format_;
+    hash = (37 * hash) + STRIDE_FIELD_NUMBER;
+    hash = (53 * hash) + getStride();
+    hash = (37 * hash) + DATA_FIELD_NUMBER;
+    hash = (53 * hash) + getData().hashCode();
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.RawPixels parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RawPixels parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RawPixels parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RawPixels parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RawPixels parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RawPixels parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RawPixels parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.RawPixels parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.RawPixels parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.RawPixels parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.RawPixels parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.RawPixels parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.RawPixels prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Pixels uploaded as they are, without decoding. Rows run from top to bottom.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.RawPixels}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.RawPixels)
+      vsg.RawPixelsOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_RawPixels_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_RawPixels_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.RawPixels.class, vsg.RawPixels.Builder.class);
+    }
+
+    // Construct using vsg.RawPixels.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      width_ = 0;
+
+      height_ = 0;
+
+      format_ = 0;
+
+      stride_ = 0;
+
+      data_ = com.google.protobuf.ByteString.EMPTY;
+
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_RawPixels_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.RawPixels getDefaultInstanceForType() {
+      return vsg.RawPixels.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.RawPixels build() {
+      vsg.RawPixels result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.RawPixels buildPartial() {
+      vsg.RawPixels result = new vsg.RawPixels(this);
+      result.width_ = width_;
+      result.height_ = height_;
+      result.format_ = format_;
+      result.stride_ = stride_;
+      result.data_ = data_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.RawPixels) {
+        return mergeFrom((vsg.RawPixels)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.RawPixels other) {
+      if (other == vsg.RawPixels.getDefaultInstance()) return this;
+      if (other.getWidth() != 0) {
+        setWidth(other.getWidth());
+      }
+      if (other.getHeight() != 0) {
+        setHeight(other.getHeight());
+      }
+      if (other.format_ != 0) {
+        setFormatValue(other.getFormatValue());
+      }
+      if (other.getStride() != 0) {
+        setStride(other.getStride());
+      }
+      if (other.getData() != com.google.protobuf.ByteString.EMPTY) {
+        setData(other.getData());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.RawPixels parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.RawPixels) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private int width_ ;
+    /**
+     * <code>uint32 width = 1;</code>
+     * @return The width.
+     */
+    @java.lang.Override
+    public int getWidth() {
+      return width_;
+    }
+    /**
+     * <code>uint32 width = 1;</code>
+     * @param value The width to set.
+     * @return This builder for chaining.
+     */
+    public Builder setWidth(int value) {
+      
+      width_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 width = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearWidth() {
+      
+      width_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int height_ ;
+    /**
+     * <code>uint32 height = 2;</code>
+     * @return The height.
+     */
+    @java.lang.Override
+    public int getHeight() {
+      return height_;
+    }
+    /**
+     * <code>uint32 height = 2;</code>
+     * @param value The height to set.
+     * @return This builder for chaining.
+     */
+    public Builder setHeight(int value) {
+      
+      height_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 height = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearHeight() {
+      
+      height_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int format_ = 0;
+    /**
+     * <code>.vsg.PixelFormat format = 3;</code>
+     * @return The enum numeric value on the wire for format.
+     */
+    @java.lang.Override public int getFormatValue() {
+      return format_;
+    }
+    /**
+     * <code>.vsg.PixelFormat format = 3;</code>
+     * @param value The enum numeric value on the wire for format to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFormatValue(int value) {
+      
+      format_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.PixelFormat format = 3;</code>
+     * @return The format.
+     */
+    @java.lang.Override
+    public vsg.PixelFormat getFormat() {
+      @SuppressWarnings("deprecation")
+      vsg.PixelFormat result = vsg.PixelFormat.valueOf(format_);
+      return result == null ? vsg.PixelFormat.UNRECOGNIZED : result;
+    }
+    /**
+     * <code>.vsg.PixelFormat format = 3;</code>
+     * @param value The format to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFormat(vsg.PixelFormat value) {
+      if (value == null) {
+        throw new NullPointerException();
+      }
+      
+      format_ = value.getNumber();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.PixelFormat format = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFormat() {
+      
+      format_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int stride_ ;
+    /**
+     * <pre>
+     * Bytes from the start of one row to the next. 0 means tightly packed.
+     * </pre>
+     *
+     * <code>uint32 stride = 4;</code>
+     * @return The stride.
+     */
+    @java.lang.Override
+    public int getStride() {
+      return stride_;
+    }
+    /**
+     * <pre>
+     * Bytes from the start of one row to the next. 0 means tightly packed.
+     * </pre>
+     *
+     * <code>uint32 stride = 4;</code>
+     * @param value The stride to set.
+     * @return This builder for chaining.
+     */
+    public Builder setStride(int value) {
+      
+      stride_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Bytes from the start of one row to the next. 0 means tightly packed.
+     * </pre>
+     *
+     * <code>uint32 stride = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearStride() {
+      
+      stride_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private com.google.protobuf.ByteString data_ = com.google.protobuf.ByteString.EMPTY;
+    /**
+     * <code>bytes data = 5;</code>
+     * @return The data.
+     */
+    @java.lang.Override
+    public com.google.protobuf.ByteString getData() {
+      return data_;
+    }
+    /**
+     * <code>bytes data = 5;</code>
+     * @param value The data to set.
+     * @return This builder for chaining.
+     */
+    public Builder setData(com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      data_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>bytes data = 5;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearData() {
+      
+      data_ = getDefaultInstance().getData();
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.RawPixels)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.RawPixels)
+  private static final vsg.RawPixels DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.RawPixels();
+  }
+
+  public static vsg.RawPixels getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<RawPixels>
+      PARSER = new com.google.protobuf.AbstractParser<RawPixels>() {
+    @java.lang.Override
+    public RawPixels parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new RawPixels(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<RawPixels> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<RawPixels> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.RawPixels getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,48 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface RawPixelsOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.RawPixels)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 width = 1;</code>
+   * @return The width.
+   */
+  int getWidth();
+
+  /**
+   * <code>uint32 height = 2;</code>
+   * @return The height.
+   */
+  int getHeight();
+
+  /**
+   * <code>.vsg.PixelFormat format = 3;</code>
+   * @return The enum numeric value on the wire for format.
+   */
+  int getFormatValue();
+  /**
+   * <code>.vsg.PixelFormat format = 3;</code>
+   * @return The format.
+   */
+  vsg.PixelFormat getFormat();
+
+  /**
+   * <pre>
+   * Bytes from the start of one row to the next. 0 means tightly packed.
+   * </pre>
+   *
+   * <code>uint32 stride = 4;</code>
+   * @return The stride.
+   */
+  int getStride();
+
+  /**
+   * <code>bytes data = 5;</code>
+   * @return The data.
+   */
+  com.google.protobuf.ByteString getData();
+}
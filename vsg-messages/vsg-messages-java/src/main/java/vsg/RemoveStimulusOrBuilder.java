@@ -0,0 +1,15 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface RemoveStimulusOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.RemoveStimulus)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  int getId();
+}
@@ -0,0 +1,31 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface ResizeOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Resize)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Surface size in physical pixels.
+   * </pre>
+   *
+   * <code>uint32 width = 1;</code>
+   * @return The width.
+   */
+  int getWidth();
+
+  /**
+   * <code>uint32 height = 2;</code>
+   * @return The height.
+   */
+  int getHeight();
+
+  /**
+   * <code>double scale_factor = 3;</code>
+   * @return The scaleFactor.
+   */
+  double getScaleFactor();
+}
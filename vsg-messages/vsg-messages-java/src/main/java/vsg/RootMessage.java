@@ -4,6 +4,13 @@
 package vsg;
 
 /**
+ * <pre>
+ * On stream connections every message is preceded by its length as a 4 byte
+ * big-endian integer, and so is every reply. A connection may open with the
+ * single byte 'J' to exchange both in the canonical protobuf JSON mapping
+ * instead, or 'P' for protobuf. The first message should then be a Hello.
+ * </pre>
+ *
  * Protobuf type {@code vsg.RootMessage}
  */
 public final class RootMessage extends
@@ -90,6 +97,211 @@ private static final long serialVersionUID = 0L;
             commandCase_ = 3;
             break;
           }
+          case 34: {
+            vsg.shapes.Stimulus.Builder subBuilder = null;
+            if (commandCase_ == 4) {
+              subBuilder = ((vsg.shapes.Stimulus) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.shapes.Stimulus.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.shapes.Stimulus) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 4;
+            break;
+          }
+          case 42: {
+            vsg.UpdateStimulus.Builder subBuilder = null;
+            if (commandCase_ == 5) {
+              subBuilder = ((vsg.UpdateStimulus) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.UpdateStimulus.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.UpdateStimulus) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 5;
+            break;
+          }
+          case 50: {
+            vsg.SetVisibility.Builder subBuilder = null;
+            if (commandCase_ == 6) {
+              subBuilder = ((vsg.SetVisibility) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.SetVisibility.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.SetVisibility) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 6;
+            break;
+          }
+          case 58: {
+            vsg.RemoveStimulus.Builder subBuilder = null;
+            if (commandCase_ == 7) {
+              subBuilder = ((vsg.RemoveStimulus) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.RemoveStimulus.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.RemoveStimulus) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 7;
+            break;
+          }
+          case 66: {
+            vsg.SetScene.Builder subBuilder = null;
+            if (commandCase_ == 8) {
+              subBuilder = ((vsg.SetScene) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.SetScene.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.SetScene) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 8;
+            break;
+          }
+          case 74: {
+            vsg.Texture.Builder subBuilder = null;
+            if (commandCase_ == 9) {
+              subBuilder = ((vsg.Texture) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.Texture.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.Texture) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 9;
+            break;
+          }
+          case 82: {
+            vsg.TextureIndex.Builder subBuilder = null;
+            if (commandCase_ == 10) {
+              subBuilder = ((vsg.TextureIndex) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.TextureIndex.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.TextureIndex) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 10;
+            break;
+          }
+          case 90: {
+            vsg.TextureIndex.Builder subBuilder = null;
+            if (commandCase_ == 11) {
+              subBuilder = ((vsg.TextureIndex) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.TextureIndex.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.TextureIndex) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 11;
+            break;
+          }
+          case 98: {
+            vsg.TextureIndex.Builder subBuilder = null;
+            if (commandCase_ == 12) {
+              subBuilder = ((vsg.TextureIndex) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.TextureIndex.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.TextureIndex) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 12;
+            break;
+          }
+          case 106: {
+            vsg.GetStatus.Builder subBuilder = null;
+            if (commandCase_ == 13) {
+              subBuilder = ((vsg.GetStatus) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.GetStatus.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.GetStatus) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 13;
+            break;
+          }
+          case 114: {
+            vsg.Hello.Builder subBuilder = null;
+            if (commandCase_ == 14) {
+              subBuilder = ((vsg.Hello) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.Hello.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.Hello) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 14;
+            break;
+          }
+          case 122: {
+            vsg.Subscribe.Builder subBuilder = null;
+            if (commandCase_ == 15) {
+              subBuilder = ((vsg.Subscribe) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.Subscribe.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.Subscribe) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 15;
+            break;
+          }
+          case 130: {
+            vsg.CaptureFrame.Builder subBuilder = null;
+            if (commandCase_ == 16) {
+              subBuilder = ((vsg.CaptureFrame) command_).toBuilder();
+            }
+            command_ =
+                input.readMessage(vsg.CaptureFrame.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.CaptureFrame) command_);
+              command_ = subBuilder.buildPartial();
+            }
+            commandCase_ = 16;
+            break;
+          }
+          case 256: {
+
+            sequence_ = input.readUInt64();
+            break;
+          }
+          case 264: {
+
+            flipAck_ = input.readBool();
+            break;
+          }
+          case 274: {
+            vsg.Schedule.Builder subBuilder = null;
+            if (schedule_ != null) {
+              subBuilder = schedule_.toBuilder();
+            }
+            schedule_ = input.readMessage(vsg.Schedule.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(schedule_);
+              schedule_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
           default: {
             if (!parseUnknownField(
                 input, unknownFields, extensionRegistry, tag)) {
@@ -130,6 +342,19 @@ private static final long serialVersionUID = 0L;
     SET_SHAPE(1),
     SET_BG_COLOR(2),
     SET_TEXTURE(3),
+    ADD_STIMULUS(4),
+    UPDATE_STIMULUS(5),
+    SET_VISIBILITY(6),
+    REMOVE_STIMULUS(7),
+    SET_SCENE(8),
+    PRELOAD_TEXTURE(9),
+    SHOW_TEXTURE(10),
+    HIDE_TEXTURE(11),
+    DELETE_TEXTURE(12),
+    GET_STATUS(13),
+    HELLO(14),
+    SUBSCRIBE(15),
+    CAPTURE_FRAME(16),
     COMMAND_NOT_SET(0);
     private final int value;
     private CommandCase(int value) {
@@ -150,6 +375,19 @@ private static final long serialVersionUID = 0L;
         case 1: return SET_SHAPE;
         case 2: return SET_BG_COLOR;
         case 3: return SET_TEXTURE;
+        case 4: return ADD_STIMULUS;
+        case 5: return UPDATE_STIMULUS;
+        case 6: return SET_VISIBILITY;
+        case 7: return REMOVE_STIMULUS;
+        case 8: return SET_SCENE;
+        case 9: return PRELOAD_TEXTURE;
+        case 10: return SHOW_TEXTURE;
+        case 11: return HIDE_TEXTURE;
+        case 12: return DELETE_TEXTURE;
+        case 13: return GET_STATUS;
+        case 14: return HELLO;
+        case 15: return SUBSCRIBE;
+        case 16: return CAPTURE_FRAME;
         case 0: return COMMAND_NOT_SET;
         default: return null;
       }
@@ -167,6 +405,12 @@ private static final long serialVersionUID = 0L;
 
   public static final int SET_SHAPE_FIELD_NUMBER = 1;
   /**
+   * <pre>
+   * Replaces every stimulus in the scene with this shape, which has no
+   * id: stimuli added later can use any id, and it stays until the
+   * next SetShape or SetScene.
+   * </pre>
+   *
    * <code>.vsg.Shape set_shape = 1;</code>
    * @return Whether the setShape field is set.
    */
@@ -175,6 +419,12 @@ private static final long serialVersionUID = 0L;
     return commandCase_ == 1;
   }
   /**
+   * <pre>
+   * Replaces every stimulus in the scene with this shape, which has no
+   * id: stimuli added later can use any id, and it stays until the
+   * next SetShape or SetScene.
+   * </pre>
+   *
    * <code>.vsg.Shape set_shape = 1;</code>
    * @return The setShape.
    */
@@ -186,6 +436,12 @@ private static final long serialVersionUID = 0L;
     return vsg.shapes.Shape.getDefaultInstance();
   }
   /**
+   * <pre>
+   * Replaces every stimulus in the scene with this shape, which has no
+   * id: stimuli added later can use any id, and it stays until the
+   * next SetShape or SetScene.
+   * </pre>
+   *
    * <code>.vsg.Shape set_shape = 1;</code>
    */
   @java.lang.Override
@@ -229,6 +485,10 @@ private static final long serialVersionUID = 0L;
 
   public static final int SET_TEXTURE_FIELD_NUMBER = 3;
   /**
+   * <pre>
+   * Stores the texture and shows it instead of any other texture.
+   * </pre>
+   *
    * <code>.vsg.Texture set_texture = 3;</code>
    * @return Whether the setTexture field is set.
    */
@@ -237,6 +497,10 @@ private static final long serialVersionUID = 0L;
     return commandCase_ == 3;
   }
   /**
+   * <pre>
+   * Stores the texture and shows it instead of any other texture.
+   * </pre>
+   *
    * <code>.vsg.Texture set_texture = 3;</code>
    * @return The setTexture.
    */
@@ -248,6 +512,10 @@ private static final long serialVersionUID = 0L;
     return vsg.Texture.getDefaultInstance();
   }
   /**
+   * <pre>
+   * Stores the texture and shows it instead of any other texture.
+   * </pre>
+   *
    * <code>.vsg.Texture set_texture = 3;</code>
    */
   @java.lang.Override
@@ -258,822 +526,4176 @@ private static final long serialVersionUID = 0L;
     return vsg.Texture.getDefaultInstance();
   }
 
-  private byte memoizedIsInitialized = -1;
+  public static final int ADD_STIMULUS_FIELD_NUMBER = 4;
+  /**
+   * <code>.vsg.Stimulus add_stimulus = 4;</code>
+   * @return Whether the addStimulus field is set.
+   */
   @java.lang.Override
-  public final boolean isInitialized() {
-    byte isInitialized = memoizedIsInitialized;
-    if (isInitialized == 1) return true;
-    if (isInitialized == 0) return false;
-
-    memoizedIsInitialized = 1;
-    return true;
+  public boolean hasAddStimulus() {
+    return commandCase_ == 4;
   }
-
+  /**
+   * <code>.vsg.Stimulus add_stimulus = 4;</code>
+   * @return The addStimulus.
+   */
   @java.lang.Override
-  public void writeTo(com.google.protobuf.CodedOutputStream output)
-                      throws java.io.IOException {
-    if (commandCase_ == 1) {
-      output.writeMessage(1, (vsg.shapes.Shape) command_);
+  public vsg.shapes.Stimulus getAddStimulus() {
+    if (commandCase_ == 4) {
+       return (vsg.shapes.Stimulus) command_;
     }
-    if (commandCase_ == 2) {
-      output.writeMessage(2, (vsg.BgColor) command_);
-    }
-    if (commandCase_ == 3) {
-      output.writeMessage(3, (vsg.Texture) command_);
+    return vsg.shapes.Stimulus.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.Stimulus add_stimulus = 4;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.StimulusOrBuilder getAddStimulusOrBuilder() {
+    if (commandCase_ == 4) {
+       return (vsg.shapes.Stimulus) command_;
     }
-    unknownFields.writeTo(output);
+    return vsg.shapes.Stimulus.getDefaultInstance();
   }
 
+  public static final int UPDATE_STIMULUS_FIELD_NUMBER = 5;
+  /**
+   * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+   * @return Whether the updateStimulus field is set.
+   */
   @java.lang.Override
-  public int getSerializedSize() {
-    int size = memoizedSize;
-    if (size != -1) return size;
-
-    size = 0;
-    if (commandCase_ == 1) {
-      size += com.google.protobuf.CodedOutputStream
-        .computeMessageSize(1, (vsg.shapes.Shape) command_);
-    }
-    if (commandCase_ == 2) {
-      size += com.google.protobuf.CodedOutputStream
-        .computeMessageSize(2, (vsg.BgColor) command_);
+  public boolean hasUpdateStimulus() {
+    return commandCase_ == 5;
+  }
+  /**
+   * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+   * @return The updateStimulus.
+   */
+  @java.lang.Override
+  public vsg.UpdateStimulus getUpdateStimulus() {
+    if (commandCase_ == 5) {
+       return (vsg.UpdateStimulus) command_;
     }
-    if (commandCase_ == 3) {
-      size += com.google.protobuf.CodedOutputStream
-        .computeMessageSize(3, (vsg.Texture) command_);
+    return vsg.UpdateStimulus.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+   */
+  @java.lang.Override
+  public vsg.UpdateStimulusOrBuilder getUpdateStimulusOrBuilder() {
+    if (commandCase_ == 5) {
+       return (vsg.UpdateStimulus) command_;
     }
-    size += unknownFields.getSerializedSize();
-    memoizedSize = size;
-    return size;
+    return vsg.UpdateStimulus.getDefaultInstance();
   }
 
+  public static final int SET_VISIBILITY_FIELD_NUMBER = 6;
+  /**
+   * <code>.vsg.SetVisibility set_visibility = 6;</code>
+   * @return Whether the setVisibility field is set.
+   */
   @java.lang.Override
-  public boolean equals(final java.lang.Object obj) {
-    if (obj == this) {
-     return true;
-    }
-    if (!(obj instanceof vsg.RootMessage)) {
-      return super.equals(obj);
+  public boolean hasSetVisibility() {
+    return commandCase_ == 6;
+  }
+  /**
+   * <code>.vsg.SetVisibility set_visibility = 6;</code>
+   * @return The setVisibility.
+   */
+  @java.lang.Override
+  public vsg.SetVisibility getSetVisibility() {
+    if (commandCase_ == 6) {
+       return (vsg.SetVisibility) command_;
     }
-    vsg.RootMessage other = (vsg.RootMessage) obj;
-
-    if (!getCommandCase().equals(other.getCommandCase())) return false;
-    switch (commandCase_) {
-      case 1:
-        if (!getSetShape()
-            .equals(other.getSetShape())) return false;
-        break;
-      case 2:
-        if (!getSetBgColor()
-            .equals(other.getSetBgColor())) return false;
-        break;
-      case 3:
-        if (!getSetTexture()
-            .equals(other.getSetTexture())) return false;
-        break;
-      case 0:
-      default:
+    return vsg.SetVisibility.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.SetVisibility set_visibility = 6;</code>
+   */
+  @java.lang.Override
+  public vsg.SetVisibilityOrBuilder getSetVisibilityOrBuilder() {
+    if (commandCase_ == 6) {
+       return (vsg.SetVisibility) command_;
     }
-    if (!unknownFields.equals(other.unknownFields)) return false;
-    return true;
+    return vsg.SetVisibility.getDefaultInstance();
   }
 
+  public static final int REMOVE_STIMULUS_FIELD_NUMBER = 7;
+  /**
+   * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+   * @return Whether the removeStimulus field is set.
+   */
   @java.lang.Override
-  public int hashCode() {
-    if (memoizedHashCode != 0) {
-      return memoizedHashCode;
+  public boolean hasRemoveStimulus() {
+    return commandCase_ == 7;
+  }
+  /**
+   * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+   * @return The removeStimulus.
+   */
+  @java.lang.Override
+  public vsg.RemoveStimulus getRemoveStimulus() {
+    if (commandCase_ == 7) {
+       return (vsg.RemoveStimulus) command_;
     }
-    int hash = 41;
-    hash = (19 * hash) + getDescriptor().hashCode();
-    switch (commandCase_) {
-      case 1:
-        hash = (37 * hash) + SET_SHAPE_FIELD_NUMBER;
-        hash = (53 * hash) + getSetShape().hashCode();
-        break;
-      case 2:
-        hash = (37 * hash) + SET_BG_COLOR_FIELD_NUMBER;
-        hash = (53 * hash) + getSetBgColor().hashCode();
-        break;
-      case 3:
-        hash = (37 * hash) + SET_TEXTURE_FIELD_NUMBER;
-        hash = (53 * hash) + getSetTexture().hashCode();
-        break;
-      case 0:
-      default:
+    return vsg.RemoveStimulus.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+   */
+  @java.lang.Override
+  public vsg.RemoveStimulusOrBuilder getRemoveStimulusOrBuilder() {
+    if (commandCase_ == 7) {
+       return (vsg.RemoveStimulus) command_;
     }
-    hash = (29 * hash) + unknownFields.hashCode();
-    memoizedHashCode = hash;
-    return hash;
+    return vsg.RemoveStimulus.getDefaultInstance();
   }
 
-  public static vsg.RootMessage parseFrom(
-      java.nio.ByteBuffer data)
-      throws com.google.protobuf.InvalidProtocolBufferException {
-    return PARSER.parseFrom(data);
+  public static final int SET_SCENE_FIELD_NUMBER = 8;
+  /**
+   * <code>.vsg.SetScene set_scene = 8;</code>
+   * @return Whether the setScene field is set.
+   */
+  @java.lang.Override
+  public boolean hasSetScene() {
+    return commandCase_ == 8;
   }
-  public static vsg.RootMessage parseFrom(
-      java.nio.ByteBuffer data,
-      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-      throws com.google.protobuf.InvalidProtocolBufferException {
-    return PARSER.parseFrom(data, extensionRegistry);
+  /**
+   * <code>.vsg.SetScene set_scene = 8;</code>
+   * @return The setScene.
+   */
+  @java.lang.Override
+  public vsg.SetScene getSetScene() {
+    if (commandCase_ == 8) {
+       return (vsg.SetScene) command_;
+    }
+    return vsg.SetScene.getDefaultInstance();
   }
-  public static vsg.RootMessage parseFrom(
-      com.google.protobuf.ByteString data)
-      throws com.google.protobuf.InvalidProtocolBufferException {
-    return PARSER.parseFrom(data);
+  /**
+   * <code>.vsg.SetScene set_scene = 8;</code>
+   */
+  @java.lang.Override
+  public vsg.SetSceneOrBuilder getSetSceneOrBuilder() {
+    if (commandCase_ == 8) {
+       return (vsg.SetScene) command_;
+    }
+    return vsg.SetScene.getDefaultInstance();
   }
-  public static vsg.RootMessage parseFrom(
-      com.google.protobuf.ByteString data,
-      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-      throws com.google.protobuf.InvalidProtocolBufferException {
-    return PARSER.parseFrom(data, extensionRegistry);
+
+  public static final int PRELOAD_TEXTURE_FIELD_NUMBER = 9;
+  /**
+   * <pre>
+   * Stores the texture without showing it.
+   * </pre>
+   *
+   * <code>.vsg.Texture preload_texture = 9;</code>
+   * @return Whether the preloadTexture field is set.
+   */
+  @java.lang.Override
+  public boolean hasPreloadTexture() {
+    return commandCase_ == 9;
   }
-  public static vsg.RootMessage parseFrom(byte[] data)
-      throws com.google.protobuf.InvalidProtocolBufferException {
-    return PARSER.parseFrom(data);
+  /**
+   * <pre>
+   * Stores the texture without showing it.
+   * </pre>
+   *
+   * <code>.vsg.Texture preload_texture = 9;</code>
+   * @return The preloadTexture.
+   */
+  @java.lang.Override
+  public vsg.Texture getPreloadTexture() {
+    if (commandCase_ == 9) {
+       return (vsg.Texture) command_;
+    }
+    return vsg.Texture.getDefaultInstance();
   }
-  public static vsg.RootMessage parseFrom(
-      byte[] data,
-      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-      throws com.google.protobuf.InvalidProtocolBufferException {
-    return PARSER.parseFrom(data, extensionRegistry);
+  /**
+   * <pre>
+   * Stores the texture without showing it.
+   * </pre>
+   *
+   * <code>.vsg.Texture preload_texture = 9;</code>
+   */
+  @java.lang.Override
+  public vsg.TextureOrBuilder getPreloadTextureOrBuilder() {
+    if (commandCase_ == 9) {
+       return (vsg.Texture) command_;
+    }
+    return vsg.Texture.getDefaultInstance();
   }
-  public static vsg.RootMessage parseFrom(java.io.InputStream input)
-      throws java.io.IOException {
-    return com.google.protobuf.GeneratedMessageV3
-        .parseWithIOException(PARSER, input);
+
+  public static final int SHOW_TEXTURE_FIELD_NUMBER = 10;
+  /**
+   * <code>.vsg.TextureIndex show_texture = 10;</code>
+   * @return Whether the showTexture field is set.
+   */
+  @java.lang.Override
+  public boolean hasShowTexture() {
+    return commandCase_ == 10;
   }
-  public static vsg.RootMessage parseFrom(
-      java.io.InputStream input,
-      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-      throws java.io.IOException {
-    return com.google.protobuf.GeneratedMessageV3
-        .parseWithIOException(PARSER, input, extensionRegistry);
+  /**
+   * <code>.vsg.TextureIndex show_texture = 10;</code>
+   * @return The showTexture.
+   */
+  @java.lang.Override
+  public vsg.TextureIndex getShowTexture() {
+    if (commandCase_ == 10) {
+       return (vsg.TextureIndex) command_;
+    }
+    return vsg.TextureIndex.getDefaultInstance();
   }
-  public static vsg.RootMessage parseDelimitedFrom(java.io.InputStream input)
-      throws java.io.IOException {
-    return com.google.protobuf.GeneratedMessageV3
-        .parseDelimitedWithIOException(PARSER, input);
+  /**
+   * <code>.vsg.TextureIndex show_texture = 10;</code>
+   */
+  @java.lang.Override
+  public vsg.TextureIndexOrBuilder getShowTextureOrBuilder() {
+    if (commandCase_ == 10) {
+       return (vsg.TextureIndex) command_;
+    }
+    return vsg.TextureIndex.getDefaultInstance();
   }
-  public static vsg.RootMessage parseDelimitedFrom(
-      java.io.InputStream input,
-      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-      throws java.io.IOException {
-    return com.google.protobuf.GeneratedMessageV3
-        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+
+  public static final int HIDE_TEXTURE_FIELD_NUMBER = 11;
+  /**
+   * <code>.vsg.TextureIndex hide_texture = 11;</code>
+   * @return Whether the hideTexture field is set.
+   */
+  @java.lang.Override
+  public boolean hasHideTexture() {
+    return commandCase_ == 11;
   }
-  public static vsg.RootMessage parseFrom(
-      com.google.protobuf.CodedInputStream input)
-      throws java.io.IOException {
-    return com.google.protobuf.GeneratedMessageV3
-        .parseWithIOException(PARSER, input);
+  /**
+   * <code>.vsg.TextureIndex hide_texture = 11;</code>
+   * @return The hideTexture.
+   */
+  @java.lang.Override
+  public vsg.TextureIndex getHideTexture() {
+    if (commandCase_ == 11) {
+       return (vsg.TextureIndex) command_;
+    }
+    return vsg.TextureIndex.getDefaultInstance();
   }
-  public static vsg.RootMessage parseFrom(
-      com.google.protobuf.CodedInputStream input,
-      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-      throws java.io.IOException {
-    return com.google.protobuf.GeneratedMessageV3
-        .parseWithIOException(PARSER, input, extensionRegistry);
+  /**
+   * <code>.vsg.TextureIndex hide_texture = 11;</code>
+   */
+  @java.lang.Override
+  public vsg.TextureIndexOrBuilder getHideTextureOrBuilder() {
+    if (commandCase_ == 11) {
+       return (vsg.TextureIndex) command_;
+    }
+    return vsg.TextureIndex.getDefaultInstance();
   }
 
+  public static final int DELETE_TEXTURE_FIELD_NUMBER = 12;
+  /**
+   * <code>.vsg.TextureIndex delete_texture = 12;</code>
+   * @return Whether the deleteTexture field is set.
+   */
   @java.lang.Override
-  public Builder newBuilderForType() { return newBuilder(); }
-  public static Builder newBuilder() {
-    return DEFAULT_INSTANCE.toBuilder();
+  public boolean hasDeleteTexture() {
+    return commandCase_ == 12;
   }
-  public static Builder newBuilder(vsg.RootMessage prototype) {
-    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  /**
+   * <code>.vsg.TextureIndex delete_texture = 12;</code>
+   * @return The deleteTexture.
+   */
+  @java.lang.Override
+  public vsg.TextureIndex getDeleteTexture() {
+    if (commandCase_ == 12) {
+       return (vsg.TextureIndex) command_;
+    }
+    return vsg.TextureIndex.getDefaultInstance();
   }
+  /**
+   * <code>.vsg.TextureIndex delete_texture = 12;</code>
+   */
   @java.lang.Override
-  public Builder toBuilder() {
-    return this == DEFAULT_INSTANCE
-        ? new Builder() : new Builder().mergeFrom(this);
+  public vsg.TextureIndexOrBuilder getDeleteTextureOrBuilder() {
+    if (commandCase_ == 12) {
+       return (vsg.TextureIndex) command_;
+    }
+    return vsg.TextureIndex.getDefaultInstance();
   }
 
+  public static final int GET_STATUS_FIELD_NUMBER = 13;
+  /**
+   * <pre>
+   * Answered right away with RootResponse.display_status. Ignores
+   * flip_ack and schedule.
+   * </pre>
+   *
+   * <code>.vsg.GetStatus get_status = 13;</code>
+   * @return Whether the getStatus field is set.
+   */
   @java.lang.Override
-  protected Builder newBuilderForType(
-      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
-    Builder builder = new Builder(parent);
-    return builder;
+  public boolean hasGetStatus() {
+    return commandCase_ == 13;
   }
   /**
-   * Protobuf type {@code vsg.RootMessage}
+   * <pre>
+   * Answered right away with RootResponse.display_status. Ignores
+   * flip_ack and schedule.
+   * </pre>
+   *
+   * <code>.vsg.GetStatus get_status = 13;</code>
+   * @return The getStatus.
    */
-  public static final class Builder extends
-      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
-      // @@protoc_insertion_point(builder_implements:vsg.RootMessage)
-      vsg.RootMessageOrBuilder {
-    public static final com.google.protobuf.Descriptors.Descriptor
-        getDescriptor() {
-      return vsg.Vsg.internal_static_vsg_RootMessage_descriptor;
+  @java.lang.Override
+  public vsg.GetStatus getGetStatus() {
+    if (commandCase_ == 13) {
+       return (vsg.GetStatus) command_;
+    }
+    return vsg.GetStatus.getDefaultInstance();
+  }
+  /**
+   * <pre>
+   * Answered right away with RootResponse.display_status. Ignores
+   * flip_ack and schedule.
+   * </pre>
+   *
+   * <code>.vsg.GetStatus get_status = 13;</code>
+   */
+  @java.lang.Override
+  public vsg.GetStatusOrBuilder getGetStatusOrBuilder() {
+    if (commandCase_ == 13) {
+       return (vsg.GetStatus) command_;
     }
+    return vsg.GetStatus.getDefaultInstance();
+  }
 
-    @java.lang.Override
-    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
-        internalGetFieldAccessorTable() {
-      return vsg.Vsg.internal_static_vsg_RootMessage_fieldAccessorTable
-          .ensureFieldAccessorsInitialized(
-              vsg.RootMessage.class, vsg.RootMessage.Builder.class);
+  public static final int HELLO_FIELD_NUMBER = 14;
+  /**
+   * <pre>
+   * Answered with RootResponse.hello, or with an error when the
+   * versions differ, after which every other message is refused.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 14;</code>
+   * @return Whether the hello field is set.
+   */
+  @java.lang.Override
+  public boolean hasHello() {
+    return commandCase_ == 14;
+  }
+  /**
+   * <pre>
+   * Answered with RootResponse.hello, or with an error when the
+   * versions differ, after which every other message is refused.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 14;</code>
+   * @return The hello.
+   */
+  @java.lang.Override
+  public vsg.Hello getHello() {
+    if (commandCase_ == 14) {
+       return (vsg.Hello) command_;
+    }
+    return vsg.Hello.getDefaultInstance();
+  }
+  /**
+   * <pre>
+   * Answered with RootResponse.hello, or with an error when the
+   * versions differ, after which every other message is refused.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 14;</code>
+   */
+  @java.lang.Override
+  public vsg.HelloOrBuilder getHelloOrBuilder() {
+    if (commandCase_ == 14) {
+       return (vsg.Hello) command_;
     }
+    return vsg.Hello.getDefaultInstance();
+  }
 
-    // Construct using vsg.RootMessage.newBuilder()
-    private Builder() {
-      maybeForceBuilderInitialization();
+  public static final int SUBSCRIBE_FIELD_NUMBER = 15;
+  /**
+   * <pre>
+   * Choose which events are pushed to this connection.
+   * </pre>
+   *
+   * <code>.vsg.Subscribe subscribe = 15;</code>
+   * @return Whether the subscribe field is set.
+   */
+  @java.lang.Override
+  public boolean hasSubscribe() {
+    return commandCase_ == 15;
+  }
+  /**
+   * <pre>
+   * Choose which events are pushed to this connection.
+   * </pre>
+   *
+   * <code>.vsg.Subscribe subscribe = 15;</code>
+   * @return The subscribe.
+   */
+  @java.lang.Override
+  public vsg.Subscribe getSubscribe() {
+    if (commandCase_ == 15) {
+       return (vsg.Subscribe) command_;
+    }
+    return vsg.Subscribe.getDefaultInstance();
+  }
+  /**
+   * <pre>
+   * Choose which events are pushed to this connection.
+   * </pre>
+   *
+   * <code>.vsg.Subscribe subscribe = 15;</code>
+   */
+  @java.lang.Override
+  public vsg.SubscribeOrBuilder getSubscribeOrBuilder() {
+    if (commandCase_ == 15) {
+       return (vsg.Subscribe) command_;
     }
+    return vsg.Subscribe.getDefaultInstance();
+  }
 
-    private Builder(
-        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
-      super(parent);
-      maybeForceBuilderInitialization();
+  public static final int CAPTURE_FRAME_FIELD_NUMBER = 16;
+  /**
+   * <pre>
+   * Answered with RootResponse.capture and RootResponse.onset once the
+   * frame the image shows has been presented.
+   * </pre>
+   *
+   * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+   * @return Whether the captureFrame field is set.
+   */
+  @java.lang.Override
+  public boolean hasCaptureFrame() {
+    return commandCase_ == 16;
+  }
+  /**
+   * <pre>
+   * Answered with RootResponse.capture and RootResponse.onset once the
+   * frame the image shows has been presented.
+   * </pre>
+   *
+   * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+   * @return The captureFrame.
+   */
+  @java.lang.Override
+  public vsg.CaptureFrame getCaptureFrame() {
+    if (commandCase_ == 16) {
+       return (vsg.CaptureFrame) command_;
     }
-    private void maybeForceBuilderInitialization() {
-      if (com.google.protobuf.GeneratedMessageV3
-              .alwaysUseFieldBuilders) {
-      }
+    return vsg.CaptureFrame.getDefaultInstance();
+  }
+  /**
+   * <pre>
+   * Answered with RootResponse.capture and RootResponse.onset once the
+   * frame the image shows has been presented.
+   * </pre>
+   *
+   * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+   */
+  @java.lang.Override
+  public vsg.CaptureFrameOrBuilder getCaptureFrameOrBuilder() {
+    if (commandCase_ == 16) {
+       return (vsg.CaptureFrame) command_;
+    }
+    return vsg.CaptureFrame.getDefaultInstance();
+  }
+
+  public static final int SEQUENCE_FIELD_NUMBER = 32;
+  private long sequence_;
+  /**
+   * <pre>
+   * Chosen by the client and echoed back in RootResponse.sequence. Over
+   * UDP, a datagram whose sequence is not above the last one from the same
+   * sender is dropped, except with sequence 0, which is always applied.
+   * </pre>
+   *
+   * <code>uint64 sequence = 32;</code>
+   * @return The sequence.
+   */
+  @java.lang.Override
+  public long getSequence() {
+    return sequence_;
+  }
+
+  public static final int FLIP_ACK_FIELD_NUMBER = 33;
+  private boolean flipAck_;
+  /**
+   * <pre>
+   * Delay the reply until the frame containing this command has been
+   * presented, and report its onset in RootResponse.onset. Without it, the
+   * reply only says the command was queued, and a CommandFailed event
+   * follows if applying it fails.
+   * </pre>
+   *
+   * <code>bool flip_ack = 33;</code>
+   * @return The flipAck.
+   */
+  @java.lang.Override
+  public boolean getFlipAck() {
+    return flipAck_;
+  }
+
+  public static final int SCHEDULE_FIELD_NUMBER = 34;
+  private vsg.Schedule schedule_;
+  /**
+   * <pre>
+   * Hold the command until a given frame or time. Applied right away when
+   * not set or already in the past.
+   * </pre>
+   *
+   * <code>.vsg.Schedule schedule = 34;</code>
+   * @return Whether the schedule field is set.
+   */
+  @java.lang.Override
+  public boolean hasSchedule() {
+    return schedule_ != null;
+  }
+  /**
+   * <pre>
+   * Hold the command until a given frame or time. Applied right away when
+   * not set or already in the past.
+   * </pre>
+   *
+   * <code>.vsg.Schedule schedule = 34;</code>
+   * @return The schedule.
+   */
+  @java.lang.Override
+  public vsg.Schedule getSchedule() {
+    return schedule_ == null ? vsg.Schedule.getDefaultInstance() : schedule_;
+  }
+  /**
+   * <pre>
+   * Hold the command until a given frame or time. Applied right away when
+   * not set or already in the past.
+   * </pre>
+   *
+   * <code>.vsg.Schedule schedule = 34;</code>
+   */
+  @java.lang.Override
+  public vsg.ScheduleOrBuilder getScheduleOrBuilder() {
+    return getSchedule();
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (commandCase_ == 1) {
+      output.writeMessage(1, (vsg.shapes.Shape) command_);
+    }
+    if (commandCase_ == 2) {
+      output.writeMessage(2, (vsg.BgColor) command_);
+    }
+    if (commandCase_ == 3) {
+      output.writeMessage(3, (vsg.Texture) command_);
+    }
+    if (commandCase_ == 4) {
+      output.writeMessage(4, (vsg.shapes.Stimulus) command_);
+    }
+    if (commandCase_ == 5) {
+      output.writeMessage(5, (vsg.UpdateStimulus) command_);
+    }
+    if (commandCase_ == 6) {
+      output.writeMessage(6, (vsg.SetVisibility) command_);
+    }
+    if (commandCase_ == 7) {
+      output.writeMessage(7, (vsg.RemoveStimulus) command_);
+    }
+    if (commandCase_ == 8) {
+      output.writeMessage(8, (vsg.SetScene) command_);
+    }
+    if (commandCase_ == 9) {
+      output.writeMessage(9, (vsg.Texture) command_);
+    }
+    if (commandCase_ == 10) {
+      output.writeMessage(10, (vsg.TextureIndex) command_);
+    }
+    if (commandCase_ == 11) {
+      output.writeMessage(11, (vsg.TextureIndex) command_);
+    }
+    if (commandCase_ == 12) {
+      output.writeMessage(12, (vsg.TextureIndex) command_);
+    }
+    if (commandCase_ == 13) {
+      output.writeMessage(13, (vsg.GetStatus) command_);
+    }
+    if (commandCase_ == 14) {
+      output.writeMessage(14, (vsg.Hello) command_);
+    }
+    if (commandCase_ == 15) {
+      output.writeMessage(15, (vsg.Subscribe) command_);
+    }
+    if (commandCase_ == 16) {
+      output.writeMessage(16, (vsg.CaptureFrame) command_);
+    }
+    if (sequence_ != 0L) {
+      output.writeUInt64(32, sequence_);
+    }
+    if (flipAck_ != false) {
+      output.writeBool(33, flipAck_);
+    }
+    if (schedule_ != null) {
+      output.writeMessage(34, getSchedule());
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (commandCase_ == 1) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(1, (vsg.shapes.Shape) command_);
+    }
+    if (commandCase_ == 2) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, (vsg.BgColor) command_);
+    }
+    if (commandCase_ == 3) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, (vsg.Texture) command_);
+    }
+    if (commandCase_ == 4) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(4, (vsg.shapes.Stimulus) command_);
+    }
+    if (commandCase_ == 5) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(5, (vsg.UpdateStimulus) command_);
+    }
+    if (commandCase_ == 6) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(6, (vsg.SetVisibility) command_);
+    }
+    if (commandCase_ == 7) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(7, (vsg.RemoveStimulus) command_);
+    }
+    if (commandCase_ == 8) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(8, (vsg.SetScene) command_);
+    }
+    if (commandCase_ == 9) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(9, (vsg.Texture) command_);
+    }
+    if (commandCase_ == 10) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(10, (vsg.TextureIndex) command_);
+    }
+    if (commandCase_ == 11) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(11, (vsg.TextureIndex) command_);
+    }
+    if (commandCase_ == 12) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(12, (vsg.TextureIndex) command_);
+    }
+    if (commandCase_ == 13) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(13, (vsg.GetStatus) command_);
+    }
+    if (commandCase_ == 14) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(14, (vsg.Hello) command_);
+    }
+    if (commandCase_ == 15) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(15, (vsg.Subscribe) command_);
+    }
+    if (commandCase_ == 16) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(16, (vsg.CaptureFrame) command_);
+    }
+    if (sequence_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(32, sequence_);
+    }
+    if (flipAck_ != false) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeBoolSize(33, flipAck_);
+    }
+    if (schedule_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(34, getSchedule());
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.RootMessage)) {
+      return super.equals(obj);
+    }
+    vsg.RootMessage other = (vsg.RootMessage) obj;
+
+    if (getSequence()
+        != other.getSequence()) return false;
+    if (getFlipAck()
+        != other.getFlipAck()) return false;
+    if (hasSchedule() != other.hasSchedule()) return false;
+    if (hasSchedule()) {
+      if (!getSchedule()
+          .equals(other.getSchedule())) return false;
+    }
+    if (!getCommandCase().equals(other.getCommandCase())) return false;
+    switch (commandCase_) {
+      case 1:
+        if (!getSetShape()
+            .equals(other.getSetShape())) return false;
+        break;
+      case 2:
+        if (!getSetBgColor()
+            .equals(other.getSetBgColor())) return false;
+        break;
+      case 3:
+        if (!getSetTexture()
+            .equals(other.getSetTexture())) return false;
+        break;
+      case 4:
+        if (!getAddStimulus()
+            .equals(other.getAddStimulus())) return false;
+        break;
+      case 5:
+        if (!getUpdateStimulus()
+            .equals(other.getUpdateStimulus())) return false;
+        break;
+      case 6:
+        if (!getSetVisibility()
+            .equals(other.getSetVisibility())) return false;
+        break;
+      case 7:
+        if (!getRemoveStimulus()
+            .equals(other.getRemoveStimulus())) return false;
+        break;
+      case 8:
+        if (!getSetScene()
+            .equals(other.getSetScene())) return false;
+        break;
+      case 9:
+        if (!getPreloadTexture()
+            .equals(other.getPreloadTexture())) return false;
+        break;
+      case 10:
+        if (!getShowTexture()
+            .equals(other.getShowTexture())) return false;
+        break;
+      case 11:
+        if (!getHideTexture()
+            .equals(other.getHideTexture())) return false;
+        break;
+      case 12:
+        if (!getDeleteTexture()
+            .equals(other.getDeleteTexture())) return false;
+        break;
+      case 13:
+        if (!getGetStatus()
+            .equals(other.getGetStatus())) return false;
+        break;
+      case 14:
+        if (!getHello()
+            .equals(other.getHello())) return false;
+        break;
+      case 15:
+        if (!getSubscribe()
+            .equals(other.getSubscribe())) return false;
+        break;
+      case 16:
+        if (!getCaptureFrame()
+            .equals(other.getCaptureFrame())) return false;
+        break;
+      case 0:
+      default:
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + SEQUENCE_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getSequence());
+    hash = (37 * hash) + FLIP_ACK_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashBoolean(
+        getFlipAck());
+    if (hasSchedule()) {
+      hash = (37 * hash) + SCHEDULE_FIELD_NUMBER;
+      hash = (53 * hash) + getSchedule().hashCode();
+    }
+    switch (commandCase_) {
+      case 1:
+        hash = (37 * hash) + SET_SHAPE_FIELD_NUMBER;
+        hash = (53 * hash) + getSetShape().hashCode();
+        break;
+      case 2:
+        hash = (37 * hash) + SET_BG_COLOR_FIELD_NUMBER;
+        hash = (53 * hash) + getSetBgColor().hashCode();
+        break;
+      case 3:
+        hash = (37 * hash) + SET_TEXTURE_FIELD_NUMBER;
+        hash = (53 * hash) + getSetTexture().hashCode();
+        break;
+      case 4:
+        hash = (37 * hash) + ADD_STIMULUS_FIELD_NUMBER;
+        hash = (53 * hash) + getAddStimulus().hashCode();
+        break;
+      case 5:
+        hash = (37 * hash) + UPDATE_STIMULUS_FIELD_NUMBER;
+        hash = (53 * hash) + getUpdateStimulus().hashCode();
+        break;
+      case 6:
+        hash = (37 * hash) + SET_VISIBILITY_FIELD_NUMBER;
+        hash = (53 * hash) + getSetVisibility().hashCode();
+        break;
+      case 7:
+        hash = (37 * hash) + REMOVE_STIMULUS_FIELD_NUMBER;
+        hash = (53 * hash) + getRemoveStimulus().hashCode();
+        break;
+      case 8:
+        hash = (37 * hash) + SET_SCENE_FIELD_NUMBER;
+        hash = (53 * hash) + getSetScene().hashCode();
+        break;
+      case 9:
+        hash = (37 * hash) + PRELOAD_TEXTURE_FIELD_NUMBER;
+        hash = (53 * hash) + getPreloadTexture().hashCode();
+        break;
+      case 10:
+        hash = (37 * hash) + SHOW_TEXTURE_FIELD_NUMBER;
+        hash = (53 * hash) + getShowTexture().hashCode();
+        break;
+      case 11:
+        hash = (37 * hash) + HIDE_TEXTURE_FIELD_NUMBER;
+        hash = (53 * hash) + getHideTexture().hashCode();
+        break;
+      case 12:
+        hash = (37 * hash) + DELETE_TEXTURE_FIELD_NUMBER;
+        hash = (53 * hash) + getDeleteTexture().hashCode();
+        break;
+      case 13:
+        hash = (37 * hash) + GET_STATUS_FIELD_NUMBER;
+        hash = (53 * hash) + getGetStatus().hashCode();
+        break;
+      case 14:
+        hash = (37 * hash) + HELLO_FIELD_NUMBER;
+        hash = (53 * hash) + getHello().hashCode();
+        break;
+      case 15:
+        hash = (37 * hash) + SUBSCRIBE_FIELD_NUMBER;
+        hash = (53 * hash) + getSubscribe().hashCode();
+        break;
+      case 16:
+        hash = (37 * hash) + CAPTURE_FRAME_FIELD_NUMBER;
+        hash = (53 * hash) + getCaptureFrame().hashCode();
+        break;
+      case 0:
+      default:
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.RootMessage parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RootMessage parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RootMessage parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RootMessage parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RootMessage parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RootMessage parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RootMessage parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.RootMessage parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.RootMessage parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.RootMessage parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.RootMessage parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.RootMessage parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.RootMessage prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * On stream connections every message is preceded by its length as a 4 byte
+   * big-endian integer, and so is every reply. A connection may open with the
+   * single byte 'J' to exchange both in the canonical protobuf JSON mapping
+   * instead, or 'P' for protobuf. The first message should then be a Hello.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.RootMessage}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.RootMessage)
+      vsg.RootMessageOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_RootMessage_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_RootMessage_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.RootMessage.class, vsg.RootMessage.Builder.class);
+    }
+
+    // Construct using vsg.RootMessage.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      sequence_ = 0L;
+
+      flipAck_ = false;
+
+      if (scheduleBuilder_ == null) {
+        schedule_ = null;
+      } else {
+        schedule_ = null;
+        scheduleBuilder_ = null;
+      }
+      commandCase_ = 0;
+      command_ = null;
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_RootMessage_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.RootMessage getDefaultInstanceForType() {
+      return vsg.RootMessage.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.RootMessage build() {
+      vsg.RootMessage result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.RootMessage buildPartial() {
+      vsg.RootMessage result = new vsg.RootMessage(this);
+      if (commandCase_ == 1) {
+        if (setShapeBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = setShapeBuilder_.build();
+        }
+      }
+      if (commandCase_ == 2) {
+        if (setBgColorBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = setBgColorBuilder_.build();
+        }
+      }
+      if (commandCase_ == 3) {
+        if (setTextureBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = setTextureBuilder_.build();
+        }
+      }
+      if (commandCase_ == 4) {
+        if (addStimulusBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = addStimulusBuilder_.build();
+        }
+      }
+      if (commandCase_ == 5) {
+        if (updateStimulusBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = updateStimulusBuilder_.build();
+        }
+      }
+      if (commandCase_ == 6) {
+        if (setVisibilityBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = setVisibilityBuilder_.build();
+        }
+      }
+      if (commandCase_ == 7) {
+        if (removeStimulusBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = removeStimulusBuilder_.build();
+        }
+      }
+      if (commandCase_ == 8) {
+        if (setSceneBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = setSceneBuilder_.build();
+        }
+      }
+      if (commandCase_ == 9) {
+        if (preloadTextureBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = preloadTextureBuilder_.build();
+        }
+      }
+      if (commandCase_ == 10) {
+        if (showTextureBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = showTextureBuilder_.build();
+        }
+      }
+      if (commandCase_ == 11) {
+        if (hideTextureBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = hideTextureBuilder_.build();
+        }
+      }
+      if (commandCase_ == 12) {
+        if (deleteTextureBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = deleteTextureBuilder_.build();
+        }
+      }
+      if (commandCase_ == 13) {
+        if (getStatusBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = getStatusBuilder_.build();
+        }
+      }
+      if (commandCase_ == 14) {
+        if (helloBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = helloBuilder_.build();
+        }
+      }
+      if (commandCase_ == 15) {
+        if (subscribeBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = subscribeBuilder_.build();
+        }
+      }
+      if (commandCase_ == 16) {
+        if (captureFrameBuilder_ == null) {
+          result.command_ = command_;
+        } else {
+          result.command_ = captureFrameBuilder_.build();
+        }
+      }
+      result.sequence_ = sequence_;
+      result.flipAck_ = flipAck_;
+      if (scheduleBuilder_ == null) {
+        result.schedule_ = schedule_;
+      } else {
+        result.schedule_ = scheduleBuilder_.build();
+      }
+      result.commandCase_ = commandCase_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.RootMessage) {
+        return mergeFrom((vsg.RootMessage)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.RootMessage other) {
+      if (other == vsg.RootMessage.getDefaultInstance()) return this;
+      if (other.getSequence() != 0L) {
+        setSequence(other.getSequence());
+      }
+      if (other.getFlipAck() != false) {
+        setFlipAck(other.getFlipAck());
+      }
+      if (other.hasSchedule()) {
+        mergeSchedule(other.getSchedule());
+      }
+      switch (other.getCommandCase()) {
+        case SET_SHAPE: {
+          mergeSetShape(other.getSetShape());
+          break;
+        }
+        case SET_BG_COLOR: {
+          mergeSetBgColor(other.getSetBgColor());
+          break;
+        }
+        case SET_TEXTURE: {
+          mergeSetTexture(other.getSetTexture());
+          break;
+        }
+        case ADD_STIMULUS: {
+          mergeAddStimulus(other.getAddStimulus());
+          break;
+        }
+        case UPDATE_STIMULUS: {
+          mergeUpdateStimulus(other.getUpdateStimulus());
+          break;
+        }
+        case SET_VISIBILITY: {
+          mergeSetVisibility(other.getSetVisibility());
+          break;
+        }
+        case REMOVE_STIMULUS: {
+          mergeRemoveStimulus(other.getRemoveStimulus());
+          break;
+        }
+        case SET_SCENE: {
+          mergeSetScene(other.getSetScene());
+          break;
+        }
+        case PRELOAD_TEXTURE: {
+          mergePreloadTexture(other.getPreloadTexture());
+          break;
+        }
+        case SHOW_TEXTURE: {
+          mergeShowTexture(other.getShowTexture());
+          break;
+        }
+        case HIDE_TEXTURE: {
+          mergeHideTexture(other.getHideTexture());
+          break;
+        }
+        case DELETE_TEXTURE: {
+          mergeDeleteTexture(other.getDeleteTexture());
+          break;
+        }
+        case GET_STATUS: {
+          mergeGetStatus(other.getGetStatus());
+          break;
+        }
+        case HELLO: {
+          mergeHello(other.getHello());
+          break;
+        }
+        case SUBSCRIBE: {
+          mergeSubscribe(other.getSubscribe());
+          break;
+        }
+        case CAPTURE_FRAME: {
+          mergeCaptureFrame(other.getCaptureFrame());
+          break;
+        }
+        case COMMAND_NOT_SET: {
+          break;
+        }
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.RootMessage parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.RootMessage) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int commandCase_ = 0;
+    private java.lang.Object command_;
+    public CommandCase
+        getCommandCase() {
+      return CommandCase.forNumber(
+          commandCase_);
+    }
+
+    public Builder clearCommand() {
+      commandCase_ = 0;
+      command_ = null;
+      onChanged();
+      return this;
+    }
+
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> setShapeBuilder_;
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     * @return Whether the setShape field is set.
+     */
+    @java.lang.Override
+    public boolean hasSetShape() {
+      return commandCase_ == 1;
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     * @return The setShape.
+     */
+    @java.lang.Override
+    public vsg.shapes.Shape getSetShape() {
+      if (setShapeBuilder_ == null) {
+        if (commandCase_ == 1) {
+          return (vsg.shapes.Shape) command_;
+        }
+        return vsg.shapes.Shape.getDefaultInstance();
+      } else {
+        if (commandCase_ == 1) {
+          return setShapeBuilder_.getMessage();
+        }
+        return vsg.shapes.Shape.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    public Builder setSetShape(vsg.shapes.Shape value) {
+      if (setShapeBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        setShapeBuilder_.setMessage(value);
+      }
+      commandCase_ = 1;
+      return this;
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    public Builder setSetShape(
+        vsg.shapes.Shape.Builder builderForValue) {
+      if (setShapeBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        setShapeBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 1;
+      return this;
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    public Builder mergeSetShape(vsg.shapes.Shape value) {
+      if (setShapeBuilder_ == null) {
+        if (commandCase_ == 1 &&
+            command_ != vsg.shapes.Shape.getDefaultInstance()) {
+          command_ = vsg.shapes.Shape.newBuilder((vsg.shapes.Shape) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 1) {
+          setShapeBuilder_.mergeFrom(value);
+        }
+        setShapeBuilder_.setMessage(value);
+      }
+      commandCase_ = 1;
+      return this;
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    public Builder clearSetShape() {
+      if (setShapeBuilder_ == null) {
+        if (commandCase_ == 1) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 1) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        setShapeBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    public vsg.shapes.Shape.Builder getSetShapeBuilder() {
+      return getSetShapeFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    @java.lang.Override
+    public vsg.shapes.ShapeOrBuilder getSetShapeOrBuilder() {
+      if ((commandCase_ == 1) && (setShapeBuilder_ != null)) {
+        return setShapeBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 1) {
+          return (vsg.shapes.Shape) command_;
+        }
+        return vsg.shapes.Shape.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Replaces every stimulus in the scene with this shape, which has no
+     * id: stimuli added later can use any id, and it stays until the
+     * next SetShape or SetScene.
+     * </pre>
+     *
+     * <code>.vsg.Shape set_shape = 1;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> 
+        getSetShapeFieldBuilder() {
+      if (setShapeBuilder_ == null) {
+        if (!(commandCase_ == 1)) {
+          command_ = vsg.shapes.Shape.getDefaultInstance();
+        }
+        setShapeBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder>(
+                (vsg.shapes.Shape) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 1;
+      onChanged();;
+      return setShapeBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder> setBgColorBuilder_;
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * @return Whether the setBgColor field is set.
+     */
+    @java.lang.Override
+    public boolean hasSetBgColor() {
+      return commandCase_ == 2;
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * @return The setBgColor.
+     */
+    @java.lang.Override
+    public vsg.BgColor getSetBgColor() {
+      if (setBgColorBuilder_ == null) {
+        if (commandCase_ == 2) {
+          return (vsg.BgColor) command_;
+        }
+        return vsg.BgColor.getDefaultInstance();
+      } else {
+        if (commandCase_ == 2) {
+          return setBgColorBuilder_.getMessage();
+        }
+        return vsg.BgColor.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    public Builder setSetBgColor(vsg.BgColor value) {
+      if (setBgColorBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        setBgColorBuilder_.setMessage(value);
+      }
+      commandCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    public Builder setSetBgColor(
+        vsg.BgColor.Builder builderForValue) {
+      if (setBgColorBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        setBgColorBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    public Builder mergeSetBgColor(vsg.BgColor value) {
+      if (setBgColorBuilder_ == null) {
+        if (commandCase_ == 2 &&
+            command_ != vsg.BgColor.getDefaultInstance()) {
+          command_ = vsg.BgColor.newBuilder((vsg.BgColor) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 2) {
+          setBgColorBuilder_.mergeFrom(value);
+        }
+        setBgColorBuilder_.setMessage(value);
+      }
+      commandCase_ = 2;
+      return this;
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    public Builder clearSetBgColor() {
+      if (setBgColorBuilder_ == null) {
+        if (commandCase_ == 2) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 2) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        setBgColorBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    public vsg.BgColor.Builder getSetBgColorBuilder() {
+      return getSetBgColorFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    @java.lang.Override
+    public vsg.BgColorOrBuilder getSetBgColorOrBuilder() {
+      if ((commandCase_ == 2) && (setBgColorBuilder_ != null)) {
+        return setBgColorBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 2) {
+          return (vsg.BgColor) command_;
+        }
+        return vsg.BgColor.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder> 
+        getSetBgColorFieldBuilder() {
+      if (setBgColorBuilder_ == null) {
+        if (!(commandCase_ == 2)) {
+          command_ = vsg.BgColor.getDefaultInstance();
+        }
+        setBgColorBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder>(
+                (vsg.BgColor) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 2;
+      onChanged();;
+      return setBgColorBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> setTextureBuilder_;
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     * @return Whether the setTexture field is set.
+     */
+    @java.lang.Override
+    public boolean hasSetTexture() {
+      return commandCase_ == 3;
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     * @return The setTexture.
+     */
+    @java.lang.Override
+    public vsg.Texture getSetTexture() {
+      if (setTextureBuilder_ == null) {
+        if (commandCase_ == 3) {
+          return (vsg.Texture) command_;
+        }
+        return vsg.Texture.getDefaultInstance();
+      } else {
+        if (commandCase_ == 3) {
+          return setTextureBuilder_.getMessage();
+        }
+        return vsg.Texture.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    public Builder setSetTexture(vsg.Texture value) {
+      if (setTextureBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        setTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 3;
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    public Builder setSetTexture(
+        vsg.Texture.Builder builderForValue) {
+      if (setTextureBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        setTextureBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 3;
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    public Builder mergeSetTexture(vsg.Texture value) {
+      if (setTextureBuilder_ == null) {
+        if (commandCase_ == 3 &&
+            command_ != vsg.Texture.getDefaultInstance()) {
+          command_ = vsg.Texture.newBuilder((vsg.Texture) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 3) {
+          setTextureBuilder_.mergeFrom(value);
+        }
+        setTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 3;
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    public Builder clearSetTexture() {
+      if (setTextureBuilder_ == null) {
+        if (commandCase_ == 3) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 3) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        setTextureBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    public vsg.Texture.Builder getSetTextureBuilder() {
+      return getSetTextureFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    @java.lang.Override
+    public vsg.TextureOrBuilder getSetTextureOrBuilder() {
+      if ((commandCase_ == 3) && (setTextureBuilder_ != null)) {
+        return setTextureBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 3) {
+          return (vsg.Texture) command_;
+        }
+        return vsg.Texture.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Stores the texture and shows it instead of any other texture.
+     * </pre>
+     *
+     * <code>.vsg.Texture set_texture = 3;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> 
+        getSetTextureFieldBuilder() {
+      if (setTextureBuilder_ == null) {
+        if (!(commandCase_ == 3)) {
+          command_ = vsg.Texture.getDefaultInstance();
+        }
+        setTextureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder>(
+                (vsg.Texture) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 3;
+      onChanged();;
+      return setTextureBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Stimulus, vsg.shapes.Stimulus.Builder, vsg.shapes.StimulusOrBuilder> addStimulusBuilder_;
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     * @return Whether the addStimulus field is set.
+     */
+    @java.lang.Override
+    public boolean hasAddStimulus() {
+      return commandCase_ == 4;
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     * @return The addStimulus.
+     */
+    @java.lang.Override
+    public vsg.shapes.Stimulus getAddStimulus() {
+      if (addStimulusBuilder_ == null) {
+        if (commandCase_ == 4) {
+          return (vsg.shapes.Stimulus) command_;
+        }
+        return vsg.shapes.Stimulus.getDefaultInstance();
+      } else {
+        if (commandCase_ == 4) {
+          return addStimulusBuilder_.getMessage();
+        }
+        return vsg.shapes.Stimulus.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    public Builder setAddStimulus(vsg.shapes.Stimulus value) {
+      if (addStimulusBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        addStimulusBuilder_.setMessage(value);
+      }
+      commandCase_ = 4;
+      return this;
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    public Builder setAddStimulus(
+        vsg.shapes.Stimulus.Builder builderForValue) {
+      if (addStimulusBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        addStimulusBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 4;
+      return this;
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    public Builder mergeAddStimulus(vsg.shapes.Stimulus value) {
+      if (addStimulusBuilder_ == null) {
+        if (commandCase_ == 4 &&
+            command_ != vsg.shapes.Stimulus.getDefaultInstance()) {
+          command_ = vsg.shapes.Stimulus.newBuilder((vsg.shapes.Stimulus) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 4) {
+          addStimulusBuilder_.mergeFrom(value);
+        }
+        addStimulusBuilder_.setMessage(value);
+      }
+      commandCase_ = 4;
+      return this;
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    public Builder clearAddStimulus() {
+      if (addStimulusBuilder_ == null) {
+        if (commandCase_ == 4) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 4) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        addStimulusBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    public vsg.shapes.Stimulus.Builder getAddStimulusBuilder() {
+      return getAddStimulusFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    @java.lang.Override
+    public vsg.shapes.StimulusOrBuilder getAddStimulusOrBuilder() {
+      if ((commandCase_ == 4) && (addStimulusBuilder_ != null)) {
+        return addStimulusBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 4) {
+          return (vsg.shapes.Stimulus) command_;
+        }
+        return vsg.shapes.Stimulus.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.Stimulus add_stimulus = 4;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Stimulus, vsg.shapes.Stimulus.Builder, vsg.shapes.StimulusOrBuilder> 
+        getAddStimulusFieldBuilder() {
+      if (addStimulusBuilder_ == null) {
+        if (!(commandCase_ == 4)) {
+          command_ = vsg.shapes.Stimulus.getDefaultInstance();
+        }
+        addStimulusBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.shapes.Stimulus, vsg.shapes.Stimulus.Builder, vsg.shapes.StimulusOrBuilder>(
+                (vsg.shapes.Stimulus) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 4;
+      onChanged();;
+      return addStimulusBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.UpdateStimulus, vsg.UpdateStimulus.Builder, vsg.UpdateStimulusOrBuilder> updateStimulusBuilder_;
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     * @return Whether the updateStimulus field is set.
+     */
+    @java.lang.Override
+    public boolean hasUpdateStimulus() {
+      return commandCase_ == 5;
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     * @return The updateStimulus.
+     */
+    @java.lang.Override
+    public vsg.UpdateStimulus getUpdateStimulus() {
+      if (updateStimulusBuilder_ == null) {
+        if (commandCase_ == 5) {
+          return (vsg.UpdateStimulus) command_;
+        }
+        return vsg.UpdateStimulus.getDefaultInstance();
+      } else {
+        if (commandCase_ == 5) {
+          return updateStimulusBuilder_.getMessage();
+        }
+        return vsg.UpdateStimulus.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    public Builder setUpdateStimulus(vsg.UpdateStimulus value) {
+      if (updateStimulusBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        updateStimulusBuilder_.setMessage(value);
+      }
+      commandCase_ = 5;
+      return this;
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    public Builder setUpdateStimulus(
+        vsg.UpdateStimulus.Builder builderForValue) {
+      if (updateStimulusBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        updateStimulusBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 5;
+      return this;
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    public Builder mergeUpdateStimulus(vsg.UpdateStimulus value) {
+      if (updateStimulusBuilder_ == null) {
+        if (commandCase_ == 5 &&
+            command_ != vsg.UpdateStimulus.getDefaultInstance()) {
+          command_ = vsg.UpdateStimulus.newBuilder((vsg.UpdateStimulus) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 5) {
+          updateStimulusBuilder_.mergeFrom(value);
+        }
+        updateStimulusBuilder_.setMessage(value);
+      }
+      commandCase_ = 5;
+      return this;
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    public Builder clearUpdateStimulus() {
+      if (updateStimulusBuilder_ == null) {
+        if (commandCase_ == 5) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 5) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        updateStimulusBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    public vsg.UpdateStimulus.Builder getUpdateStimulusBuilder() {
+      return getUpdateStimulusFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    @java.lang.Override
+    public vsg.UpdateStimulusOrBuilder getUpdateStimulusOrBuilder() {
+      if ((commandCase_ == 5) && (updateStimulusBuilder_ != null)) {
+        return updateStimulusBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 5) {
+          return (vsg.UpdateStimulus) command_;
+        }
+        return vsg.UpdateStimulus.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.UpdateStimulus, vsg.UpdateStimulus.Builder, vsg.UpdateStimulusOrBuilder> 
+        getUpdateStimulusFieldBuilder() {
+      if (updateStimulusBuilder_ == null) {
+        if (!(commandCase_ == 5)) {
+          command_ = vsg.UpdateStimulus.getDefaultInstance();
+        }
+        updateStimulusBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.UpdateStimulus, vsg.UpdateStimulus.Builder, vsg.UpdateStimulusOrBuilder>(
+                (vsg.UpdateStimulus) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 5;
+      onChanged();;
+      return updateStimulusBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.SetVisibility, vsg.SetVisibility.Builder, vsg.SetVisibilityOrBuilder> setVisibilityBuilder_;
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     * @return Whether the setVisibility field is set.
+     */
+    @java.lang.Override
+    public boolean hasSetVisibility() {
+      return commandCase_ == 6;
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     * @return The setVisibility.
+     */
+    @java.lang.Override
+    public vsg.SetVisibility getSetVisibility() {
+      if (setVisibilityBuilder_ == null) {
+        if (commandCase_ == 6) {
+          return (vsg.SetVisibility) command_;
+        }
+        return vsg.SetVisibility.getDefaultInstance();
+      } else {
+        if (commandCase_ == 6) {
+          return setVisibilityBuilder_.getMessage();
+        }
+        return vsg.SetVisibility.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    public Builder setSetVisibility(vsg.SetVisibility value) {
+      if (setVisibilityBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        setVisibilityBuilder_.setMessage(value);
+      }
+      commandCase_ = 6;
+      return this;
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    public Builder setSetVisibility(
+        vsg.SetVisibility.Builder builderForValue) {
+      if (setVisibilityBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        setVisibilityBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 6;
+      return this;
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    public Builder mergeSetVisibility(vsg.SetVisibility value) {
+      if (setVisibilityBuilder_ == null) {
+        if (commandCase_ == 6 &&
+            command_ != vsg.SetVisibility.getDefaultInstance()) {
+          command_ = vsg.SetVisibility.newBuilder((vsg.SetVisibility) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 6) {
+          setVisibilityBuilder_.mergeFrom(value);
+        }
+        setVisibilityBuilder_.setMessage(value);
+      }
+      commandCase_ = 6;
+      return this;
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    public Builder clearSetVisibility() {
+      if (setVisibilityBuilder_ == null) {
+        if (commandCase_ == 6) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 6) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        setVisibilityBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    public vsg.SetVisibility.Builder getSetVisibilityBuilder() {
+      return getSetVisibilityFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    @java.lang.Override
+    public vsg.SetVisibilityOrBuilder getSetVisibilityOrBuilder() {
+      if ((commandCase_ == 6) && (setVisibilityBuilder_ != null)) {
+        return setVisibilityBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 6) {
+          return (vsg.SetVisibility) command_;
+        }
+        return vsg.SetVisibility.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.SetVisibility set_visibility = 6;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.SetVisibility, vsg.SetVisibility.Builder, vsg.SetVisibilityOrBuilder> 
+        getSetVisibilityFieldBuilder() {
+      if (setVisibilityBuilder_ == null) {
+        if (!(commandCase_ == 6)) {
+          command_ = vsg.SetVisibility.getDefaultInstance();
+        }
+        setVisibilityBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.SetVisibility, vsg.SetVisibility.Builder, vsg.SetVisibilityOrBuilder>(
+                (vsg.SetVisibility) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 6;
+      onChanged();;
+      return setVisibilityBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.RemoveStimulus, vsg.RemoveStimulus.Builder, vsg.RemoveStimulusOrBuilder> removeStimulusBuilder_;
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     * @return Whether the removeStimulus field is set.
+     */
+    @java.lang.Override
+    public boolean hasRemoveStimulus() {
+      return commandCase_ == 7;
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     * @return The removeStimulus.
+     */
+    @java.lang.Override
+    public vsg.RemoveStimulus getRemoveStimulus() {
+      if (removeStimulusBuilder_ == null) {
+        if (commandCase_ == 7) {
+          return (vsg.RemoveStimulus) command_;
+        }
+        return vsg.RemoveStimulus.getDefaultInstance();
+      } else {
+        if (commandCase_ == 7) {
+          return removeStimulusBuilder_.getMessage();
+        }
+        return vsg.RemoveStimulus.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    public Builder setRemoveStimulus(vsg.RemoveStimulus value) {
+      if (removeStimulusBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        removeStimulusBuilder_.setMessage(value);
+      }
+      commandCase_ = 7;
+      return this;
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    public Builder setRemoveStimulus(
+        vsg.RemoveStimulus.Builder builderForValue) {
+      if (removeStimulusBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        removeStimulusBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 7;
+      return this;
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    public Builder mergeRemoveStimulus(vsg.RemoveStimulus value) {
+      if (removeStimulusBuilder_ == null) {
+        if (commandCase_ == 7 &&
+            command_ != vsg.RemoveStimulus.getDefaultInstance()) {
+          command_ = vsg.RemoveStimulus.newBuilder((vsg.RemoveStimulus) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 7) {
+          removeStimulusBuilder_.mergeFrom(value);
+        }
+        removeStimulusBuilder_.setMessage(value);
+      }
+      commandCase_ = 7;
+      return this;
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    public Builder clearRemoveStimulus() {
+      if (removeStimulusBuilder_ == null) {
+        if (commandCase_ == 7) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 7) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        removeStimulusBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    public vsg.RemoveStimulus.Builder getRemoveStimulusBuilder() {
+      return getRemoveStimulusFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    @java.lang.Override
+    public vsg.RemoveStimulusOrBuilder getRemoveStimulusOrBuilder() {
+      if ((commandCase_ == 7) && (removeStimulusBuilder_ != null)) {
+        return removeStimulusBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 7) {
+          return (vsg.RemoveStimulus) command_;
+        }
+        return vsg.RemoveStimulus.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.RemoveStimulus, vsg.RemoveStimulus.Builder, vsg.RemoveStimulusOrBuilder> 
+        getRemoveStimulusFieldBuilder() {
+      if (removeStimulusBuilder_ == null) {
+        if (!(commandCase_ == 7)) {
+          command_ = vsg.RemoveStimulus.getDefaultInstance();
+        }
+        removeStimulusBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.RemoveStimulus, vsg.RemoveStimulus.Builder, vsg.RemoveStimulusOrBuilder>(
+                (vsg.RemoveStimulus) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 7;
+      onChanged();;
+      return removeStimulusBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.SetScene, vsg.SetScene.Builder, vsg.SetSceneOrBuilder> setSceneBuilder_;
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     * @return Whether the setScene field is set.
+     */
+    @java.lang.Override
+    public boolean hasSetScene() {
+      return commandCase_ == 8;
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     * @return The setScene.
+     */
+    @java.lang.Override
+    public vsg.SetScene getSetScene() {
+      if (setSceneBuilder_ == null) {
+        if (commandCase_ == 8) {
+          return (vsg.SetScene) command_;
+        }
+        return vsg.SetScene.getDefaultInstance();
+      } else {
+        if (commandCase_ == 8) {
+          return setSceneBuilder_.getMessage();
+        }
+        return vsg.SetScene.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    public Builder setSetScene(vsg.SetScene value) {
+      if (setSceneBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        setSceneBuilder_.setMessage(value);
+      }
+      commandCase_ = 8;
+      return this;
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    public Builder setSetScene(
+        vsg.SetScene.Builder builderForValue) {
+      if (setSceneBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        setSceneBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 8;
+      return this;
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    public Builder mergeSetScene(vsg.SetScene value) {
+      if (setSceneBuilder_ == null) {
+        if (commandCase_ == 8 &&
+            command_ != vsg.SetScene.getDefaultInstance()) {
+          command_ = vsg.SetScene.newBuilder((vsg.SetScene) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 8) {
+          setSceneBuilder_.mergeFrom(value);
+        }
+        setSceneBuilder_.setMessage(value);
+      }
+      commandCase_ = 8;
+      return this;
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    public Builder clearSetScene() {
+      if (setSceneBuilder_ == null) {
+        if (commandCase_ == 8) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 8) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        setSceneBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    public vsg.SetScene.Builder getSetSceneBuilder() {
+      return getSetSceneFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    @java.lang.Override
+    public vsg.SetSceneOrBuilder getSetSceneOrBuilder() {
+      if ((commandCase_ == 8) && (setSceneBuilder_ != null)) {
+        return setSceneBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 8) {
+          return (vsg.SetScene) command_;
+        }
+        return vsg.SetScene.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.SetScene set_scene = 8;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.SetScene, vsg.SetScene.Builder, vsg.SetSceneOrBuilder> 
+        getSetSceneFieldBuilder() {
+      if (setSceneBuilder_ == null) {
+        if (!(commandCase_ == 8)) {
+          command_ = vsg.SetScene.getDefaultInstance();
+        }
+        setSceneBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.SetScene, vsg.SetScene.Builder, vsg.SetSceneOrBuilder>(
+                (vsg.SetScene) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 8;
+      onChanged();;
+      return setSceneBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> preloadTextureBuilder_;
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     * @return Whether the preloadTexture field is set.
+     */
+    @java.lang.Override
+    public boolean hasPreloadTexture() {
+      return commandCase_ == 9;
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     * @return The preloadTexture.
+     */
+    @java.lang.Override
+    public vsg.Texture getPreloadTexture() {
+      if (preloadTextureBuilder_ == null) {
+        if (commandCase_ == 9) {
+          return (vsg.Texture) command_;
+        }
+        return vsg.Texture.getDefaultInstance();
+      } else {
+        if (commandCase_ == 9) {
+          return preloadTextureBuilder_.getMessage();
+        }
+        return vsg.Texture.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    public Builder setPreloadTexture(vsg.Texture value) {
+      if (preloadTextureBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        preloadTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 9;
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    public Builder setPreloadTexture(
+        vsg.Texture.Builder builderForValue) {
+      if (preloadTextureBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        preloadTextureBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 9;
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    public Builder mergePreloadTexture(vsg.Texture value) {
+      if (preloadTextureBuilder_ == null) {
+        if (commandCase_ == 9 &&
+            command_ != vsg.Texture.getDefaultInstance()) {
+          command_ = vsg.Texture.newBuilder((vsg.Texture) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 9) {
+          preloadTextureBuilder_.mergeFrom(value);
+        }
+        preloadTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 9;
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    public Builder clearPreloadTexture() {
+      if (preloadTextureBuilder_ == null) {
+        if (commandCase_ == 9) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 9) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        preloadTextureBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    public vsg.Texture.Builder getPreloadTextureBuilder() {
+      return getPreloadTextureFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    @java.lang.Override
+    public vsg.TextureOrBuilder getPreloadTextureOrBuilder() {
+      if ((commandCase_ == 9) && (preloadTextureBuilder_ != null)) {
+        return preloadTextureBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 9) {
+          return (vsg.Texture) command_;
+        }
+        return vsg.Texture.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Stores the texture without showing it.
+     * </pre>
+     *
+     * <code>.vsg.Texture preload_texture = 9;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> 
+        getPreloadTextureFieldBuilder() {
+      if (preloadTextureBuilder_ == null) {
+        if (!(commandCase_ == 9)) {
+          command_ = vsg.Texture.getDefaultInstance();
+        }
+        preloadTextureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder>(
+                (vsg.Texture) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 9;
+      onChanged();;
+      return preloadTextureBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder> showTextureBuilder_;
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     * @return Whether the showTexture field is set.
+     */
+    @java.lang.Override
+    public boolean hasShowTexture() {
+      return commandCase_ == 10;
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     * @return The showTexture.
+     */
+    @java.lang.Override
+    public vsg.TextureIndex getShowTexture() {
+      if (showTextureBuilder_ == null) {
+        if (commandCase_ == 10) {
+          return (vsg.TextureIndex) command_;
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      } else {
+        if (commandCase_ == 10) {
+          return showTextureBuilder_.getMessage();
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    public Builder setShowTexture(vsg.TextureIndex value) {
+      if (showTextureBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        showTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 10;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    public Builder setShowTexture(
+        vsg.TextureIndex.Builder builderForValue) {
+      if (showTextureBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        showTextureBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 10;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    public Builder mergeShowTexture(vsg.TextureIndex value) {
+      if (showTextureBuilder_ == null) {
+        if (commandCase_ == 10 &&
+            command_ != vsg.TextureIndex.getDefaultInstance()) {
+          command_ = vsg.TextureIndex.newBuilder((vsg.TextureIndex) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 10) {
+          showTextureBuilder_.mergeFrom(value);
+        }
+        showTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 10;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    public Builder clearShowTexture() {
+      if (showTextureBuilder_ == null) {
+        if (commandCase_ == 10) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 10) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        showTextureBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    public vsg.TextureIndex.Builder getShowTextureBuilder() {
+      return getShowTextureFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    @java.lang.Override
+    public vsg.TextureIndexOrBuilder getShowTextureOrBuilder() {
+      if ((commandCase_ == 10) && (showTextureBuilder_ != null)) {
+        return showTextureBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 10) {
+          return (vsg.TextureIndex) command_;
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.TextureIndex show_texture = 10;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder> 
+        getShowTextureFieldBuilder() {
+      if (showTextureBuilder_ == null) {
+        if (!(commandCase_ == 10)) {
+          command_ = vsg.TextureIndex.getDefaultInstance();
+        }
+        showTextureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder>(
+                (vsg.TextureIndex) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 10;
+      onChanged();;
+      return showTextureBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder> hideTextureBuilder_;
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     * @return Whether the hideTexture field is set.
+     */
+    @java.lang.Override
+    public boolean hasHideTexture() {
+      return commandCase_ == 11;
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     * @return The hideTexture.
+     */
+    @java.lang.Override
+    public vsg.TextureIndex getHideTexture() {
+      if (hideTextureBuilder_ == null) {
+        if (commandCase_ == 11) {
+          return (vsg.TextureIndex) command_;
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      } else {
+        if (commandCase_ == 11) {
+          return hideTextureBuilder_.getMessage();
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    public Builder setHideTexture(vsg.TextureIndex value) {
+      if (hideTextureBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        hideTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 11;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    public Builder setHideTexture(
+        vsg.TextureIndex.Builder builderForValue) {
+      if (hideTextureBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        hideTextureBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 11;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    public Builder mergeHideTexture(vsg.TextureIndex value) {
+      if (hideTextureBuilder_ == null) {
+        if (commandCase_ == 11 &&
+            command_ != vsg.TextureIndex.getDefaultInstance()) {
+          command_ = vsg.TextureIndex.newBuilder((vsg.TextureIndex) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 11) {
+          hideTextureBuilder_.mergeFrom(value);
+        }
+        hideTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 11;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    public Builder clearHideTexture() {
+      if (hideTextureBuilder_ == null) {
+        if (commandCase_ == 11) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 11) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        hideTextureBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    public vsg.TextureIndex.Builder getHideTextureBuilder() {
+      return getHideTextureFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    @java.lang.Override
+    public vsg.TextureIndexOrBuilder getHideTextureOrBuilder() {
+      if ((commandCase_ == 11) && (hideTextureBuilder_ != null)) {
+        return hideTextureBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 11) {
+          return (vsg.TextureIndex) command_;
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.TextureIndex hide_texture = 11;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder> 
+        getHideTextureFieldBuilder() {
+      if (hideTextureBuilder_ == null) {
+        if (!(commandCase_ == 11)) {
+          command_ = vsg.TextureIndex.getDefaultInstance();
+        }
+        hideTextureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder>(
+                (vsg.TextureIndex) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 11;
+      onChanged();;
+      return hideTextureBuilder_;
+    }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder> deleteTextureBuilder_;
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     * @return Whether the deleteTexture field is set.
+     */
+    @java.lang.Override
+    public boolean hasDeleteTexture() {
+      return commandCase_ == 12;
+    }
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     * @return The deleteTexture.
+     */
+    @java.lang.Override
+    public vsg.TextureIndex getDeleteTexture() {
+      if (deleteTextureBuilder_ == null) {
+        if (commandCase_ == 12) {
+          return (vsg.TextureIndex) command_;
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      } else {
+        if (commandCase_ == 12) {
+          return deleteTextureBuilder_.getMessage();
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
+    public Builder setDeleteTexture(vsg.TextureIndex value) {
+      if (deleteTextureBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        deleteTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 12;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
+    public Builder setDeleteTexture(
+        vsg.TextureIndex.Builder builderForValue) {
+      if (deleteTextureBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        deleteTextureBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 12;
+      return this;
+    }
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
+    public Builder mergeDeleteTexture(vsg.TextureIndex value) {
+      if (deleteTextureBuilder_ == null) {
+        if (commandCase_ == 12 &&
+            command_ != vsg.TextureIndex.getDefaultInstance()) {
+          command_ = vsg.TextureIndex.newBuilder((vsg.TextureIndex) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
+        }
+        onChanged();
+      } else {
+        if (commandCase_ == 12) {
+          deleteTextureBuilder_.mergeFrom(value);
+        }
+        deleteTextureBuilder_.setMessage(value);
+      }
+      commandCase_ = 12;
+      return this;
     }
-    @java.lang.Override
-    public Builder clear() {
-      super.clear();
-      commandCase_ = 0;
-      command_ = null;
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
+    public Builder clearDeleteTexture() {
+      if (deleteTextureBuilder_ == null) {
+        if (commandCase_ == 12) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 12) {
+          commandCase_ = 0;
+          command_ = null;
+        }
+        deleteTextureBuilder_.clear();
+      }
       return this;
     }
-
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
+    public vsg.TextureIndex.Builder getDeleteTextureBuilder() {
+      return getDeleteTextureFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
     @java.lang.Override
-    public com.google.protobuf.Descriptors.Descriptor
-        getDescriptorForType() {
-      return vsg.Vsg.internal_static_vsg_RootMessage_descriptor;
+    public vsg.TextureIndexOrBuilder getDeleteTextureOrBuilder() {
+      if ((commandCase_ == 12) && (deleteTextureBuilder_ != null)) {
+        return deleteTextureBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 12) {
+          return (vsg.TextureIndex) command_;
+        }
+        return vsg.TextureIndex.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.TextureIndex delete_texture = 12;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder> 
+        getDeleteTextureFieldBuilder() {
+      if (deleteTextureBuilder_ == null) {
+        if (!(commandCase_ == 12)) {
+          command_ = vsg.TextureIndex.getDefaultInstance();
+        }
+        deleteTextureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.TextureIndex, vsg.TextureIndex.Builder, vsg.TextureIndexOrBuilder>(
+                (vsg.TextureIndex) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 12;
+      onChanged();;
+      return deleteTextureBuilder_;
     }
 
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.GetStatus, vsg.GetStatus.Builder, vsg.GetStatusOrBuilder> getStatusBuilder_;
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     * @return Whether the getStatus field is set.
+     */
     @java.lang.Override
-    public vsg.RootMessage getDefaultInstanceForType() {
-      return vsg.RootMessage.getDefaultInstance();
+    public boolean hasGetStatus() {
+      return commandCase_ == 13;
     }
-
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     * @return The getStatus.
+     */
     @java.lang.Override
-    public vsg.RootMessage build() {
-      vsg.RootMessage result = buildPartial();
-      if (!result.isInitialized()) {
-        throw newUninitializedMessageException(result);
+    public vsg.GetStatus getGetStatus() {
+      if (getStatusBuilder_ == null) {
+        if (commandCase_ == 13) {
+          return (vsg.GetStatus) command_;
+        }
+        return vsg.GetStatus.getDefaultInstance();
+      } else {
+        if (commandCase_ == 13) {
+          return getStatusBuilder_.getMessage();
+        }
+        return vsg.GetStatus.getDefaultInstance();
       }
-      return result;
     }
-
-    @java.lang.Override
-    public vsg.RootMessage buildPartial() {
-      vsg.RootMessage result = new vsg.RootMessage(this);
-      if (commandCase_ == 1) {
-        if (setShapeBuilder_ == null) {
-          result.command_ = command_;
-        } else {
-          result.command_ = setShapeBuilder_.build();
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
+    public Builder setGetStatus(vsg.GetStatus value) {
+      if (getStatusBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
         }
+        command_ = value;
+        onChanged();
+      } else {
+        getStatusBuilder_.setMessage(value);
       }
-      if (commandCase_ == 2) {
-        if (setBgColorBuilder_ == null) {
-          result.command_ = command_;
+      commandCase_ = 13;
+      return this;
+    }
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
+    public Builder setGetStatus(
+        vsg.GetStatus.Builder builderForValue) {
+      if (getStatusBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
+      } else {
+        getStatusBuilder_.setMessage(builderForValue.build());
+      }
+      commandCase_ = 13;
+      return this;
+    }
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
+    public Builder mergeGetStatus(vsg.GetStatus value) {
+      if (getStatusBuilder_ == null) {
+        if (commandCase_ == 13 &&
+            command_ != vsg.GetStatus.getDefaultInstance()) {
+          command_ = vsg.GetStatus.newBuilder((vsg.GetStatus) command_)
+              .mergeFrom(value).buildPartial();
         } else {
-          result.command_ = setBgColorBuilder_.build();
+          command_ = value;
         }
+        onChanged();
+      } else {
+        if (commandCase_ == 13) {
+          getStatusBuilder_.mergeFrom(value);
+        }
+        getStatusBuilder_.setMessage(value);
       }
-      if (commandCase_ == 3) {
-        if (setTextureBuilder_ == null) {
-          result.command_ = command_;
-        } else {
-          result.command_ = setTextureBuilder_.build();
+      commandCase_ = 13;
+      return this;
+    }
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
+    public Builder clearGetStatus() {
+      if (getStatusBuilder_ == null) {
+        if (commandCase_ == 13) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 13) {
+          commandCase_ = 0;
+          command_ = null;
         }
+        getStatusBuilder_.clear();
       }
-      result.commandCase_ = commandCase_;
-      onBuilt();
-      return result;
+      return this;
     }
-
-    @java.lang.Override
-    public Builder clone() {
-      return super.clone();
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
+    public vsg.GetStatus.Builder getGetStatusBuilder() {
+      return getGetStatusFieldBuilder().getBuilder();
     }
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
     @java.lang.Override
-    public Builder setField(
-        com.google.protobuf.Descriptors.FieldDescriptor field,
-        java.lang.Object value) {
-      return super.setField(field, value);
+    public vsg.GetStatusOrBuilder getGetStatusOrBuilder() {
+      if ((commandCase_ == 13) && (getStatusBuilder_ != null)) {
+        return getStatusBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 13) {
+          return (vsg.GetStatus) command_;
+        }
+        return vsg.GetStatus.getDefaultInstance();
+      }
     }
-    @java.lang.Override
-    public Builder clearField(
-        com.google.protobuf.Descriptors.FieldDescriptor field) {
-      return super.clearField(field);
+    /**
+     * <pre>
+     * Answered right away with RootResponse.display_status. Ignores
+     * flip_ack and schedule.
+     * </pre>
+     *
+     * <code>.vsg.GetStatus get_status = 13;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.GetStatus, vsg.GetStatus.Builder, vsg.GetStatusOrBuilder> 
+        getGetStatusFieldBuilder() {
+      if (getStatusBuilder_ == null) {
+        if (!(commandCase_ == 13)) {
+          command_ = vsg.GetStatus.getDefaultInstance();
+        }
+        getStatusBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.GetStatus, vsg.GetStatus.Builder, vsg.GetStatusOrBuilder>(
+                (vsg.GetStatus) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 13;
+      onChanged();;
+      return getStatusBuilder_;
     }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Hello, vsg.Hello.Builder, vsg.HelloOrBuilder> helloBuilder_;
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     * @return Whether the hello field is set.
+     */
     @java.lang.Override
-    public Builder clearOneof(
-        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
-      return super.clearOneof(oneof);
+    public boolean hasHello() {
+      return commandCase_ == 14;
     }
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     * @return The hello.
+     */
     @java.lang.Override
-    public Builder setRepeatedField(
-        com.google.protobuf.Descriptors.FieldDescriptor field,
-        int index, java.lang.Object value) {
-      return super.setRepeatedField(field, index, value);
+    public vsg.Hello getHello() {
+      if (helloBuilder_ == null) {
+        if (commandCase_ == 14) {
+          return (vsg.Hello) command_;
+        }
+        return vsg.Hello.getDefaultInstance();
+      } else {
+        if (commandCase_ == 14) {
+          return helloBuilder_.getMessage();
+        }
+        return vsg.Hello.getDefaultInstance();
+      }
     }
-    @java.lang.Override
-    public Builder addRepeatedField(
-        com.google.protobuf.Descriptors.FieldDescriptor field,
-        java.lang.Object value) {
-      return super.addRepeatedField(field, value);
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    public Builder setHello(vsg.Hello value) {
+      if (helloBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        command_ = value;
+        onChanged();
+      } else {
+        helloBuilder_.setMessage(value);
+      }
+      commandCase_ = 14;
+      return this;
     }
-    @java.lang.Override
-    public Builder mergeFrom(com.google.protobuf.Message other) {
-      if (other instanceof vsg.RootMessage) {
-        return mergeFrom((vsg.RootMessage)other);
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    public Builder setHello(
+        vsg.Hello.Builder builderForValue) {
+      if (helloBuilder_ == null) {
+        command_ = builderForValue.build();
+        onChanged();
       } else {
-        super.mergeFrom(other);
-        return this;
+        helloBuilder_.setMessage(builderForValue.build());
       }
+      commandCase_ = 14;
+      return this;
     }
-
-    public Builder mergeFrom(vsg.RootMessage other) {
-      if (other == vsg.RootMessage.getDefaultInstance()) return this;
-      switch (other.getCommandCase()) {
-        case SET_SHAPE: {
-          mergeSetShape(other.getSetShape());
-          break;
-        }
-        case SET_BG_COLOR: {
-          mergeSetBgColor(other.getSetBgColor());
-          break;
-        }
-        case SET_TEXTURE: {
-          mergeSetTexture(other.getSetTexture());
-          break;
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    public Builder mergeHello(vsg.Hello value) {
+      if (helloBuilder_ == null) {
+        if (commandCase_ == 14 &&
+            command_ != vsg.Hello.getDefaultInstance()) {
+          command_ = vsg.Hello.newBuilder((vsg.Hello) command_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          command_ = value;
         }
-        case COMMAND_NOT_SET: {
-          break;
+        onChanged();
+      } else {
+        if (commandCase_ == 14) {
+          helloBuilder_.mergeFrom(value);
         }
+        helloBuilder_.setMessage(value);
       }
-      this.mergeUnknownFields(other.unknownFields);
-      onChanged();
+      commandCase_ = 14;
       return this;
     }
-
-    @java.lang.Override
-    public final boolean isInitialized() {
-      return true;
-    }
-
-    @java.lang.Override
-    public Builder mergeFrom(
-        com.google.protobuf.CodedInputStream input,
-        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
-        throws java.io.IOException {
-      vsg.RootMessage parsedMessage = null;
-      try {
-        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
-      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
-        parsedMessage = (vsg.RootMessage) e.getUnfinishedMessage();
-        throw e.unwrapIOException();
-      } finally {
-        if (parsedMessage != null) {
-          mergeFrom(parsedMessage);
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    public Builder clearHello() {
+      if (helloBuilder_ == null) {
+        if (commandCase_ == 14) {
+          commandCase_ = 0;
+          command_ = null;
+          onChanged();
+        }
+      } else {
+        if (commandCase_ == 14) {
+          commandCase_ = 0;
+          command_ = null;
         }
+        helloBuilder_.clear();
       }
       return this;
     }
-    private int commandCase_ = 0;
-    private java.lang.Object command_;
-    public CommandCase
-        getCommandCase() {
-      return CommandCase.forNumber(
-          commandCase_);
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    public vsg.Hello.Builder getHelloBuilder() {
+      return getHelloFieldBuilder().getBuilder();
     }
-
-    public Builder clearCommand() {
-      commandCase_ = 0;
-      command_ = null;
-      onChanged();
-      return this;
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    @java.lang.Override
+    public vsg.HelloOrBuilder getHelloOrBuilder() {
+      if ((commandCase_ == 14) && (helloBuilder_ != null)) {
+        return helloBuilder_.getMessageOrBuilder();
+      } else {
+        if (commandCase_ == 14) {
+          return (vsg.Hello) command_;
+        }
+        return vsg.Hello.getDefaultInstance();
+      }
+    }
+    /**
+     * <pre>
+     * Answered with RootResponse.hello, or with an error when the
+     * versions differ, after which every other message is refused.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 14;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Hello, vsg.Hello.Builder, vsg.HelloOrBuilder> 
+        getHelloFieldBuilder() {
+      if (helloBuilder_ == null) {
+        if (!(commandCase_ == 14)) {
+          command_ = vsg.Hello.getDefaultInstance();
+        }
+        helloBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Hello, vsg.Hello.Builder, vsg.HelloOrBuilder>(
+                (vsg.Hello) command_,
+                getParentForChildren(),
+                isClean());
+        command_ = null;
+      }
+      commandCase_ = 14;
+      onChanged();;
+      return helloBuilder_;
     }
-
 
     private com.google.protobuf.SingleFieldBuilderV3<
-        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> setShapeBuilder_;
+        vsg.Subscribe, vsg.Subscribe.Builder, vsg.SubscribeOrBuilder> subscribeBuilder_;
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
-     * @return Whether the setShape field is set.
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
+     * @return Whether the subscribe field is set.
      */
     @java.lang.Override
-    public boolean hasSetShape() {
-      return commandCase_ == 1;
+    public boolean hasSubscribe() {
+      return commandCase_ == 15;
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
-     * @return The setShape.
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
+     * @return The subscribe.
      */
     @java.lang.Override
-    public vsg.shapes.Shape getSetShape() {
-      if (setShapeBuilder_ == null) {
-        if (commandCase_ == 1) {
-          return (vsg.shapes.Shape) command_;
+    public vsg.Subscribe getSubscribe() {
+      if (subscribeBuilder_ == null) {
+        if (commandCase_ == 15) {
+          return (vsg.Subscribe) command_;
         }
-        return vsg.shapes.Shape.getDefaultInstance();
+        return vsg.Subscribe.getDefaultInstance();
       } else {
-        if (commandCase_ == 1) {
-          return setShapeBuilder_.getMessage();
+        if (commandCase_ == 15) {
+          return subscribeBuilder_.getMessage();
         }
-        return vsg.shapes.Shape.getDefaultInstance();
+        return vsg.Subscribe.getDefaultInstance();
       }
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
-    public Builder setSetShape(vsg.shapes.Shape value) {
-      if (setShapeBuilder_ == null) {
+    public Builder setSubscribe(vsg.Subscribe value) {
+      if (subscribeBuilder_ == null) {
         if (value == null) {
           throw new NullPointerException();
         }
         command_ = value;
         onChanged();
       } else {
-        setShapeBuilder_.setMessage(value);
+        subscribeBuilder_.setMessage(value);
       }
-      commandCase_ = 1;
+      commandCase_ = 15;
       return this;
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
-    public Builder setSetShape(
-        vsg.shapes.Shape.Builder builderForValue) {
-      if (setShapeBuilder_ == null) {
+    public Builder setSubscribe(
+        vsg.Subscribe.Builder builderForValue) {
+      if (subscribeBuilder_ == null) {
         command_ = builderForValue.build();
         onChanged();
       } else {
-        setShapeBuilder_.setMessage(builderForValue.build());
+        subscribeBuilder_.setMessage(builderForValue.build());
       }
-      commandCase_ = 1;
+      commandCase_ = 15;
       return this;
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
-    public Builder mergeSetShape(vsg.shapes.Shape value) {
-      if (setShapeBuilder_ == null) {
-        if (commandCase_ == 1 &&
-            command_ != vsg.shapes.Shape.getDefaultInstance()) {
-          command_ = vsg.shapes.Shape.newBuilder((vsg.shapes.Shape) command_)
+    public Builder mergeSubscribe(vsg.Subscribe value) {
+      if (subscribeBuilder_ == null) {
+        if (commandCase_ == 15 &&
+            command_ != vsg.Subscribe.getDefaultInstance()) {
+          command_ = vsg.Subscribe.newBuilder((vsg.Subscribe) command_)
               .mergeFrom(value).buildPartial();
         } else {
           command_ = value;
         }
         onChanged();
       } else {
-        if (commandCase_ == 1) {
-          setShapeBuilder_.mergeFrom(value);
+        if (commandCase_ == 15) {
+          subscribeBuilder_.mergeFrom(value);
         }
-        setShapeBuilder_.setMessage(value);
+        subscribeBuilder_.setMessage(value);
       }
-      commandCase_ = 1;
+      commandCase_ = 15;
       return this;
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
-    public Builder clearSetShape() {
-      if (setShapeBuilder_ == null) {
-        if (commandCase_ == 1) {
+    public Builder clearSubscribe() {
+      if (subscribeBuilder_ == null) {
+        if (commandCase_ == 15) {
           commandCase_ = 0;
           command_ = null;
           onChanged();
         }
       } else {
-        if (commandCase_ == 1) {
+        if (commandCase_ == 15) {
           commandCase_ = 0;
           command_ = null;
         }
-        setShapeBuilder_.clear();
+        subscribeBuilder_.clear();
       }
       return this;
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
-    public vsg.shapes.Shape.Builder getSetShapeBuilder() {
-      return getSetShapeFieldBuilder().getBuilder();
+    public vsg.Subscribe.Builder getSubscribeBuilder() {
+      return getSubscribeFieldBuilder().getBuilder();
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
     @java.lang.Override
-    public vsg.shapes.ShapeOrBuilder getSetShapeOrBuilder() {
-      if ((commandCase_ == 1) && (setShapeBuilder_ != null)) {
-        return setShapeBuilder_.getMessageOrBuilder();
+    public vsg.SubscribeOrBuilder getSubscribeOrBuilder() {
+      if ((commandCase_ == 15) && (subscribeBuilder_ != null)) {
+        return subscribeBuilder_.getMessageOrBuilder();
       } else {
-        if (commandCase_ == 1) {
-          return (vsg.shapes.Shape) command_;
+        if (commandCase_ == 15) {
+          return (vsg.Subscribe) command_;
         }
-        return vsg.shapes.Shape.getDefaultInstance();
+        return vsg.Subscribe.getDefaultInstance();
       }
     }
     /**
-     * <code>.vsg.Shape set_shape = 1;</code>
+     * <pre>
+     * Choose which events are pushed to this connection.
+     * </pre>
+     *
+     * <code>.vsg.Subscribe subscribe = 15;</code>
      */
     private com.google.protobuf.SingleFieldBuilderV3<
-        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> 
-        getSetShapeFieldBuilder() {
-      if (setShapeBuilder_ == null) {
-        if (!(commandCase_ == 1)) {
-          command_ = vsg.shapes.Shape.getDefaultInstance();
+        vsg.Subscribe, vsg.Subscribe.Builder, vsg.SubscribeOrBuilder> 
+        getSubscribeFieldBuilder() {
+      if (subscribeBuilder_ == null) {
+        if (!(commandCase_ == 15)) {
+          command_ = vsg.Subscribe.getDefaultInstance();
         }
-        setShapeBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
-            vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder>(
-                (vsg.shapes.Shape) command_,
+        subscribeBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Subscribe, vsg.Subscribe.Builder, vsg.SubscribeOrBuilder>(
+                (vsg.Subscribe) command_,
                 getParentForChildren(),
                 isClean());
         command_ = null;
       }
-      commandCase_ = 1;
+      commandCase_ = 15;
       onChanged();;
-      return setShapeBuilder_;
+      return subscribeBuilder_;
     }
 
     private com.google.protobuf.SingleFieldBuilderV3<
-        vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder> setBgColorBuilder_;
+        vsg.CaptureFrame, vsg.CaptureFrame.Builder, vsg.CaptureFrameOrBuilder> captureFrameBuilder_;
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
-     * @return Whether the setBgColor field is set.
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+     * @return Whether the captureFrame field is set.
      */
     @java.lang.Override
-    public boolean hasSetBgColor() {
-      return commandCase_ == 2;
+    public boolean hasCaptureFrame() {
+      return commandCase_ == 16;
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
-     * @return The setBgColor.
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+     * @return The captureFrame.
      */
     @java.lang.Override
-    public vsg.BgColor getSetBgColor() {
-      if (setBgColorBuilder_ == null) {
-        if (commandCase_ == 2) {
-          return (vsg.BgColor) command_;
+    public vsg.CaptureFrame getCaptureFrame() {
+      if (captureFrameBuilder_ == null) {
+        if (commandCase_ == 16) {
+          return (vsg.CaptureFrame) command_;
         }
-        return vsg.BgColor.getDefaultInstance();
+        return vsg.CaptureFrame.getDefaultInstance();
       } else {
-        if (commandCase_ == 2) {
-          return setBgColorBuilder_.getMessage();
+        if (commandCase_ == 16) {
+          return captureFrameBuilder_.getMessage();
         }
-        return vsg.BgColor.getDefaultInstance();
+        return vsg.CaptureFrame.getDefaultInstance();
       }
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
-    public Builder setSetBgColor(vsg.BgColor value) {
-      if (setBgColorBuilder_ == null) {
+    public Builder setCaptureFrame(vsg.CaptureFrame value) {
+      if (captureFrameBuilder_ == null) {
         if (value == null) {
           throw new NullPointerException();
         }
         command_ = value;
         onChanged();
       } else {
-        setBgColorBuilder_.setMessage(value);
+        captureFrameBuilder_.setMessage(value);
       }
-      commandCase_ = 2;
+      commandCase_ = 16;
       return this;
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
-    public Builder setSetBgColor(
-        vsg.BgColor.Builder builderForValue) {
-      if (setBgColorBuilder_ == null) {
+    public Builder setCaptureFrame(
+        vsg.CaptureFrame.Builder builderForValue) {
+      if (captureFrameBuilder_ == null) {
         command_ = builderForValue.build();
         onChanged();
       } else {
-        setBgColorBuilder_.setMessage(builderForValue.build());
+        captureFrameBuilder_.setMessage(builderForValue.build());
       }
-      commandCase_ = 2;
+      commandCase_ = 16;
       return this;
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
-    public Builder mergeSetBgColor(vsg.BgColor value) {
-      if (setBgColorBuilder_ == null) {
-        if (commandCase_ == 2 &&
-            command_ != vsg.BgColor.getDefaultInstance()) {
-          command_ = vsg.BgColor.newBuilder((vsg.BgColor) command_)
+    public Builder mergeCaptureFrame(vsg.CaptureFrame value) {
+      if (captureFrameBuilder_ == null) {
+        if (commandCase_ == 16 &&
+            command_ != vsg.CaptureFrame.getDefaultInstance()) {
+          command_ = vsg.CaptureFrame.newBuilder((vsg.CaptureFrame) command_)
               .mergeFrom(value).buildPartial();
         } else {
           command_ = value;
         }
         onChanged();
       } else {
-        if (commandCase_ == 2) {
-          setBgColorBuilder_.mergeFrom(value);
+        if (commandCase_ == 16) {
+          captureFrameBuilder_.mergeFrom(value);
         }
-        setBgColorBuilder_.setMessage(value);
+        captureFrameBuilder_.setMessage(value);
       }
-      commandCase_ = 2;
+      commandCase_ = 16;
       return this;
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
-    public Builder clearSetBgColor() {
-      if (setBgColorBuilder_ == null) {
-        if (commandCase_ == 2) {
+    public Builder clearCaptureFrame() {
+      if (captureFrameBuilder_ == null) {
+        if (commandCase_ == 16) {
           commandCase_ = 0;
           command_ = null;
           onChanged();
         }
       } else {
-        if (commandCase_ == 2) {
+        if (commandCase_ == 16) {
           commandCase_ = 0;
           command_ = null;
         }
-        setBgColorBuilder_.clear();
+        captureFrameBuilder_.clear();
       }
       return this;
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
-    public vsg.BgColor.Builder getSetBgColorBuilder() {
-      return getSetBgColorFieldBuilder().getBuilder();
+    public vsg.CaptureFrame.Builder getCaptureFrameBuilder() {
+      return getCaptureFrameFieldBuilder().getBuilder();
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
     @java.lang.Override
-    public vsg.BgColorOrBuilder getSetBgColorOrBuilder() {
-      if ((commandCase_ == 2) && (setBgColorBuilder_ != null)) {
-        return setBgColorBuilder_.getMessageOrBuilder();
+    public vsg.CaptureFrameOrBuilder getCaptureFrameOrBuilder() {
+      if ((commandCase_ == 16) && (captureFrameBuilder_ != null)) {
+        return captureFrameBuilder_.getMessageOrBuilder();
       } else {
-        if (commandCase_ == 2) {
-          return (vsg.BgColor) command_;
+        if (commandCase_ == 16) {
+          return (vsg.CaptureFrame) command_;
         }
-        return vsg.BgColor.getDefaultInstance();
+        return vsg.CaptureFrame.getDefaultInstance();
       }
     }
     /**
-     * <code>.vsg.BgColor set_bg_color = 2;</code>
+     * <pre>
+     * Answered with RootResponse.capture and RootResponse.onset once the
+     * frame the image shows has been presented.
+     * </pre>
+     *
+     * <code>.vsg.CaptureFrame capture_frame = 16;</code>
      */
     private com.google.protobuf.SingleFieldBuilderV3<
-        vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder> 
-        getSetBgColorFieldBuilder() {
-      if (setBgColorBuilder_ == null) {
-        if (!(commandCase_ == 2)) {
-          command_ = vsg.BgColor.getDefaultInstance();
+        vsg.CaptureFrame, vsg.CaptureFrame.Builder, vsg.CaptureFrameOrBuilder> 
+        getCaptureFrameFieldBuilder() {
+      if (captureFrameBuilder_ == null) {
+        if (!(commandCase_ == 16)) {
+          command_ = vsg.CaptureFrame.getDefaultInstance();
         }
-        setBgColorBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
-            vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder>(
-                (vsg.BgColor) command_,
+        captureFrameBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.CaptureFrame, vsg.CaptureFrame.Builder, vsg.CaptureFrameOrBuilder>(
+                (vsg.CaptureFrame) command_,
                 getParentForChildren(),
                 isClean());
         command_ = null;
       }
-      commandCase_ = 2;
+      commandCase_ = 16;
       onChanged();;
-      return setBgColorBuilder_;
+      return captureFrameBuilder_;
     }
 
-    private com.google.protobuf.SingleFieldBuilderV3<
-        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> setTextureBuilder_;
+    private long sequence_ ;
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
-     * @return Whether the setTexture field is set.
+     * <pre>
+     * Chosen by the client and echoed back in RootResponse.sequence. Over
+     * UDP, a datagram whose sequence is not above the last one from the same
+     * sender is dropped, except with sequence 0, which is always applied.
+     * </pre>
+     *
+     * <code>uint64 sequence = 32;</code>
+     * @return The sequence.
      */
     @java.lang.Override
-    public boolean hasSetTexture() {
-      return commandCase_ == 3;
+    public long getSequence() {
+      return sequence_;
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
-     * @return The setTexture.
+     * <pre>
+     * Chosen by the client and echoed back in RootResponse.sequence. Over
+     * UDP, a datagram whose sequence is not above the last one from the same
+     * sender is dropped, except with sequence 0, which is always applied.
+     * </pre>
+     *
+     * <code>uint64 sequence = 32;</code>
+     * @param value The sequence to set.
+     * @return This builder for chaining.
+     */
+    public Builder setSequence(long value) {
+      
+      sequence_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Chosen by the client and echoed back in RootResponse.sequence. Over
+     * UDP, a datagram whose sequence is not above the last one from the same
+     * sender is dropped, except with sequence 0, which is always applied.
+     * </pre>
+     *
+     * <code>uint64 sequence = 32;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearSequence() {
+      
+      sequence_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private boolean flipAck_ ;
+    /**
+     * <pre>
+     * Delay the reply until the frame containing this command has been
+     * presented, and report its onset in RootResponse.onset. Without it, the
+     * reply only says the command was queued, and a CommandFailed event
+     * follows if applying it fails.
+     * </pre>
+     *
+     * <code>bool flip_ack = 33;</code>
+     * @return The flipAck.
      */
     @java.lang.Override
-    public vsg.Texture getSetTexture() {
-      if (setTextureBuilder_ == null) {
-        if (commandCase_ == 3) {
-          return (vsg.Texture) command_;
-        }
-        return vsg.Texture.getDefaultInstance();
+    public boolean getFlipAck() {
+      return flipAck_;
+    }
+    /**
+     * <pre>
+     * Delay the reply until the frame containing this command has been
+     * presented, and report its onset in RootResponse.onset. Without it, the
+     * reply only says the command was queued, and a CommandFailed event
+     * follows if applying it fails.
+     * </pre>
+     *
+     * <code>bool flip_ack = 33;</code>
+     * @param value The flipAck to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFlipAck(boolean value) {
+      
+      flipAck_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Delay the reply until the frame containing this command has been
+     * presented, and report its onset in RootResponse.onset. Without it, the
+     * reply only says the command was queued, and a CommandFailed event
+     * follows if applying it fails.
+     * </pre>
+     *
+     * <code>bool flip_ack = 33;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFlipAck() {
+      
+      flipAck_ = false;
+      onChanged();
+      return this;
+    }
+
+    private vsg.Schedule schedule_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Schedule, vsg.Schedule.Builder, vsg.ScheduleOrBuilder> scheduleBuilder_;
+    /**
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
+     * @return Whether the schedule field is set.
+     */
+    public boolean hasSchedule() {
+      return scheduleBuilder_ != null || schedule_ != null;
+    }
+    /**
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
+     * @return The schedule.
+     */
+    public vsg.Schedule getSchedule() {
+      if (scheduleBuilder_ == null) {
+        return schedule_ == null ? vsg.Schedule.getDefaultInstance() : schedule_;
       } else {
-        if (commandCase_ == 3) {
-          return setTextureBuilder_.getMessage();
-        }
-        return vsg.Texture.getDefaultInstance();
+        return scheduleBuilder_.getMessage();
       }
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
-    public Builder setSetTexture(vsg.Texture value) {
-      if (setTextureBuilder_ == null) {
+    public Builder setSchedule(vsg.Schedule value) {
+      if (scheduleBuilder_ == null) {
         if (value == null) {
           throw new NullPointerException();
         }
-        command_ = value;
+        schedule_ = value;
         onChanged();
       } else {
-        setTextureBuilder_.setMessage(value);
+        scheduleBuilder_.setMessage(value);
       }
-      commandCase_ = 3;
+
       return this;
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
-    public Builder setSetTexture(
-        vsg.Texture.Builder builderForValue) {
-      if (setTextureBuilder_ == null) {
-        command_ = builderForValue.build();
+    public Builder setSchedule(
+        vsg.Schedule.Builder builderForValue) {
+      if (scheduleBuilder_ == null) {
+        schedule_ = builderForValue.build();
         onChanged();
       } else {
-        setTextureBuilder_.setMessage(builderForValue.build());
+        scheduleBuilder_.setMessage(builderForValue.build());
       }
-      commandCase_ = 3;
+
       return this;
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
-    public Builder mergeSetTexture(vsg.Texture value) {
-      if (setTextureBuilder_ == null) {
-        if (commandCase_ == 3 &&
-            command_ != vsg.Texture.getDefaultInstance()) {
-          command_ = vsg.Texture.newBuilder((vsg.Texture) command_)
-              .mergeFrom(value).buildPartial();
+    public Builder mergeSchedule(vsg.Schedule value) {
+      if (scheduleBuilder_ == null) {
+        if (schedule_ != null) {
+          schedule_ =
+            vsg.Schedule.newBuilder(schedule_).mergeFrom(value).buildPartial();
         } else {
-          command_ = value;
+          schedule_ = value;
         }
         onChanged();
       } else {
-        if (commandCase_ == 3) {
-          setTextureBuilder_.mergeFrom(value);
-        }
-        setTextureBuilder_.setMessage(value);
+        scheduleBuilder_.mergeFrom(value);
       }
-      commandCase_ = 3;
+
       return this;
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
-    public Builder clearSetTexture() {
-      if (setTextureBuilder_ == null) {
-        if (commandCase_ == 3) {
-          commandCase_ = 0;
-          command_ = null;
-          onChanged();
-        }
+    public Builder clearSchedule() {
+      if (scheduleBuilder_ == null) {
+        schedule_ = null;
+        onChanged();
       } else {
-        if (commandCase_ == 3) {
-          commandCase_ = 0;
-          command_ = null;
-        }
-        setTextureBuilder_.clear();
+        schedule_ = null;
+        scheduleBuilder_ = null;
       }
+
       return this;
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
-    public vsg.Texture.Builder getSetTextureBuilder() {
-      return getSetTextureFieldBuilder().getBuilder();
+    public vsg.Schedule.Builder getScheduleBuilder() {
+      
+      onChanged();
+      return getScheduleFieldBuilder().getBuilder();
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
-    @java.lang.Override
-    public vsg.TextureOrBuilder getSetTextureOrBuilder() {
-      if ((commandCase_ == 3) && (setTextureBuilder_ != null)) {
-        return setTextureBuilder_.getMessageOrBuilder();
+    public vsg.ScheduleOrBuilder getScheduleOrBuilder() {
+      if (scheduleBuilder_ != null) {
+        return scheduleBuilder_.getMessageOrBuilder();
       } else {
-        if (commandCase_ == 3) {
-          return (vsg.Texture) command_;
-        }
-        return vsg.Texture.getDefaultInstance();
+        return schedule_ == null ?
+            vsg.Schedule.getDefaultInstance() : schedule_;
       }
     }
     /**
-     * <code>.vsg.Texture set_texture = 3;</code>
+     * <pre>
+     * Hold the command until a given frame or time. Applied right away when
+     * not set or already in the past.
+     * </pre>
+     *
+     * <code>.vsg.Schedule schedule = 34;</code>
      */
     private com.google.protobuf.SingleFieldBuilderV3<
-        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> 
-        getSetTextureFieldBuilder() {
-      if (setTextureBuilder_ == null) {
-        if (!(commandCase_ == 3)) {
-          command_ = vsg.Texture.getDefaultInstance();
-        }
-        setTextureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
-            vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder>(
-                (vsg.Texture) command_,
+        vsg.Schedule, vsg.Schedule.Builder, vsg.ScheduleOrBuilder> 
+        getScheduleFieldBuilder() {
+      if (scheduleBuilder_ == null) {
+        scheduleBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Schedule, vsg.Schedule.Builder, vsg.ScheduleOrBuilder>(
+                getSchedule(),
                 getParentForChildren(),
                 isClean());
-        command_ = null;
+        schedule_ = null;
       }
-      commandCase_ = 3;
-      onChanged();;
-      return setTextureBuilder_;
+      return scheduleBuilder_;
     }
     @java.lang.Override
     public final Builder setUnknownFields(
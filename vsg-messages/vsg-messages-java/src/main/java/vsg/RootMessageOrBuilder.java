@@ -8,16 +8,34 @@ public interface RootMessageOrBuilder extends
     com.google.protobuf.MessageOrBuilder {
 
   /**
+   * <pre>
+   * Replaces every stimulus in the scene with this shape, which has no
+   * id: stimuli added later can use any id, and it stays until the
+   * next SetShape or SetScene.
+   * </pre>
+   *
    * <code>.vsg.Shape set_shape = 1;</code>
    * @return Whether the setShape field is set.
    */
   boolean hasSetShape();
   /**
+   * <pre>
+   * Replaces every stimulus in the scene with this shape, which has no
+   * id: stimuli added later can use any id, and it stays until the
+   * next SetShape or SetScene.
+   * </pre>
+   *
    * <code>.vsg.Shape set_shape = 1;</code>
    * @return The setShape.
    */
   vsg.shapes.Shape getSetShape();
   /**
+   * <pre>
+   * Replaces every stimulus in the scene with this shape, which has no
+   * id: stimuli added later can use any id, and it stays until the
+   * next SetShape or SetScene.
+   * </pre>
+   *
    * <code>.vsg.Shape set_shape = 1;</code>
    */
   vsg.shapes.ShapeOrBuilder getSetShapeOrBuilder();
@@ -38,19 +56,350 @@ public interface RootMessageOrBuilder extends
   vsg.BgColorOrBuilder getSetBgColorOrBuilder();
 
   /**
+   * <pre>
+   * Stores the texture and shows it instead of any other texture.
+   * </pre>
+   *
    * <code>.vsg.Texture set_texture = 3;</code>
    * @return Whether the setTexture field is set.
    */
   boolean hasSetTexture();
   /**
+   * <pre>
+   * Stores the texture and shows it instead of any other texture.
+   * </pre>
+   *
    * <code>.vsg.Texture set_texture = 3;</code>
    * @return The setTexture.
    */
   vsg.Texture getSetTexture();
   /**
+   * <pre>
+   * Stores the texture and shows it instead of any other texture.
+   * </pre>
+   *
    * <code>.vsg.Texture set_texture = 3;</code>
    */
   vsg.TextureOrBuilder getSetTextureOrBuilder();
 
+  /**
+   * <code>.vsg.Stimulus add_stimulus = 4;</code>
+   * @return Whether the addStimulus field is set.
+   */
+  boolean hasAddStimulus();
+  /**
+   * <code>.vsg.Stimulus add_stimulus = 4;</code>
+   * @return The addStimulus.
+   */
+  vsg.shapes.Stimulus getAddStimulus();
+  /**
+   * <code>.vsg.Stimulus add_stimulus = 4;</code>
+   */
+  vsg.shapes.StimulusOrBuilder getAddStimulusOrBuilder();
+
+  /**
+   * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+   * @return Whether the updateStimulus field is set.
+   */
+  boolean hasUpdateStimulus();
+  /**
+   * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+   * @return The updateStimulus.
+   */
+  vsg.UpdateStimulus getUpdateStimulus();
+  /**
+   * <code>.vsg.UpdateStimulus update_stimulus = 5;</code>
+   */
+  vsg.UpdateStimulusOrBuilder getUpdateStimulusOrBuilder();
+
+  /**
+   * <code>.vsg.SetVisibility set_visibility = 6;</code>
+   * @return Whether the setVisibility field is set.
+   */
+  boolean hasSetVisibility();
+  /**
+   * <code>.vsg.SetVisibility set_visibility = 6;</code>
+   * @return The setVisibility.
+   */
+  vsg.SetVisibility getSetVisibility();
+  /**
+   * <code>.vsg.SetVisibility set_visibility = 6;</code>
+   */
+  vsg.SetVisibilityOrBuilder getSetVisibilityOrBuilder();
+
+  /**
+   * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+   * @return Whether the removeStimulus field is set.
+   */
+  boolean hasRemoveStimulus();
+  /**
+   * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+   * @return The removeStimulus.
+   */
+  vsg.RemoveStimulus getRemoveStimulus();
+  /**
+   * <code>.vsg.RemoveStimulus remove_stimulus = 7;</code>
+   */
+  vsg.RemoveStimulusOrBuilder getRemoveStimulusOrBuilder();
+
+  /**
+   * <code>.vsg.SetScene set_scene = 8;</code>
+   * @return Whether the setScene field is set.
+   */
+  boolean hasSetScene();
+  /**
+   * <code>.vsg.SetScene set_scene = 8;</code>
+   * @return The setScene.
+   */
+  vsg.SetScene getSetScene();
+  /**
+   * <code>.vsg.SetScene set_scene = 8;</code>
+   */
+  vsg.SetSceneOrBuilder getSetSceneOrBuilder();
+
+  /**
+   * <pre>
+   * Stores the texture without showing it.
+   * </pre>
+   *
+   * <code>.vsg.Texture preload_texture = 9;</code>
+   * @return Whether the preloadTexture field is set.
+   */
+  boolean hasPreloadTexture();
+  /**
+   * <pre>
+   * Stores the texture without showing it.
+   * </pre>
+   *
+   * <code>.vsg.Texture preload_texture = 9;</code>
+   * @return The preloadTexture.
+   */
+  vsg.Texture getPreloadTexture();
+  /**
+   * <pre>
+   * Stores the texture without showing it.
+   * </pre>
+   *
+   * <code>.vsg.Texture preload_texture = 9;</code>
+   */
+  vsg.TextureOrBuilder getPreloadTextureOrBuilder();
+
+  /**
+   * <code>.vsg.TextureIndex show_texture = 10;</code>
+   * @return Whether the showTexture field is set.
+   */
+  boolean hasShowTexture();
+  /**
+   * <code>.vsg.TextureIndex show_texture = 10;</code>
+   * @return The showTexture.
+   */
+  vsg.TextureIndex getShowTexture();
+  /**
+   * <code>.vsg.TextureIndex show_texture = 10;</code>
+   */
+  vsg.TextureIndexOrBuilder getShowTextureOrBuilder();
+
+  /**
+   * <code>.vsg.TextureIndex hide_texture = 11;</code>
+   * @return Whether the hideTexture field is set.
+   */
+  boolean hasHideTexture();
+  /**
+   * <code>.vsg.TextureIndex hide_texture = 11;</code>
+   * @return The hideTexture.
+   */
+  vsg.TextureIndex getHideTexture();
+  /**
+   * <code>.vsg.TextureIndex hide_texture = 11;</code>
+   */
+  vsg.TextureIndexOrBuilder getHideTextureOrBuilder();
+
+  /**
+   * <code>.vsg.TextureIndex delete_texture = 12;</code>
+   * @return Whether the deleteTexture field is set.
+   */
+  boolean hasDeleteTexture();
+  /**
+   * <code>.vsg.TextureIndex delete_texture = 12;</code>
+   * @return The deleteTexture.
+   */
+  vsg.TextureIndex getDeleteTexture();
+  /**
+   * <code>.vsg.TextureIndex delete_texture = 12;</code>
+   */
+  vsg.TextureIndexOrBuilder getDeleteTextureOrBuilder();
+
+  /**
+   * <pre>
+   * Answered right away with RootResponse.display_status. Ignores
+   * flip_ack and schedule.
+   * </pre>
+   *
+   * <code>.vsg.GetStatus get_status = 13;</code>
+   * @return Whether the getStatus field is set.
+   */
+  boolean hasGetStatus();
+  /**
+   * <pre>
+   * Answered right away with RootResponse.display_status. Ignores
+   * flip_ack and schedule.
+   * </pre>
+   *
+   * <code>.vsg.GetStatus get_status = 13;</code>
+   * @return The getStatus.
+   */
+  vsg.GetStatus getGetStatus();
+  /**
+   * <pre>
+   * Answered right away with RootResponse.display_status. Ignores
+   * flip_ack and schedule.
+   * </pre>
+   *
+   * <code>.vsg.GetStatus get_status = 13;</code>
+   */
+  vsg.GetStatusOrBuilder getGetStatusOrBuilder();
+
+  /**
+   * <pre>
+   * Answered with RootResponse.hello, or with an error when the
+   * versions differ, after which every other message is refused.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 14;</code>
+   * @return Whether the hello field is set.
+   */
+  boolean hasHello();
+  /**
+   * <pre>
+   * Answered with RootResponse.hello, or with an error when the
+   * versions differ, after which every other message is refused.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 14;</code>
+   * @return The hello.
+   */
+  vsg.Hello getHello();
+  /**
+   * <pre>
+   * Answered with RootResponse.hello, or with an error when the
+   * versions differ, after which every other message is refused.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 14;</code>
+   */
+  vsg.HelloOrBuilder getHelloOrBuilder();
+
+  /**
+   * <pre>
+   * Choose which events are pushed to this connection.
+   * </pre>
+   *
+   * <code>.vsg.Subscribe subscribe = 15;</code>
+   * @return Whether the subscribe field is set.
+   */
+  boolean hasSubscribe();
+  /**
+   * <pre>
+   * Choose which events are pushed to this connection.
+   * </pre>
+   *
+   * <code>.vsg.Subscribe subscribe = 15;</code>
+   * @return The subscribe.
+   */
+  vsg.Subscribe getSubscribe();
+  /**
+   * <pre>
+   * Choose which events are pushed to this connection.
+   * </pre>
+   *
+   * <code>.vsg.Subscribe subscribe = 15;</code>
+   */
+  vsg.SubscribeOrBuilder getSubscribeOrBuilder();
+
+  /**
+   * <pre>
+   * Answered with RootResponse.capture and RootResponse.onset once the
+   * frame the image shows has been presented.
+   * </pre>
+   *
+   * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+   * @return Whether the captureFrame field is set.
+   */
+  boolean hasCaptureFrame();
+  /**
+   * <pre>
+   * Answered with RootResponse.capture and RootResponse.onset once the
+   * frame the image shows has been presented.
+   * </pre>
+   *
+   * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+   * @return The captureFrame.
+   */
+  vsg.CaptureFrame getCaptureFrame();
+  /**
+   * <pre>
+   * Answered with RootResponse.capture and RootResponse.onset once the
+   * frame the image shows has been presented.
+   * </pre>
+   *
+   * <code>.vsg.CaptureFrame capture_frame = 16;</code>
+   */
+  vsg.CaptureFrameOrBuilder getCaptureFrameOrBuilder();
+
+  /**
+   * <pre>
+   * Chosen by the client and echoed back in RootResponse.sequence. Over
+   * UDP, a datagram whose sequence is not above the last one from the same
+   * sender is dropped, except with sequence 0, which is always applied.
+   * </pre>
+   *
+   * <code>uint64 sequence = 32;</code>
+   * @return The sequence.
+   */
+  long getSequence();
+
+  /**
+   * <pre>
+   * Delay the reply until the frame containing this command has been
+   * presented, and report its onset in RootResponse.onset. Without it, the
+   * reply only says the command was queued, and a CommandFailed event
+   * follows if applying it fails.
+   * </pre>
+   *
+   * <code>bool flip_ack = 33;</code>
+   * @return The flipAck.
+   */
+  boolean getFlipAck();
+
+  /**
+   * <pre>
+   * Hold the command until a given frame or time. Applied right away when
+   * not set or already in the past.
+   * </pre>
+   *
+   * <code>.vsg.Schedule schedule = 34;</code>
+   * @return Whether the schedule field is set.
+   */
+  boolean hasSchedule();
+  /**
+   * <pre>
+   * Hold the command until a given frame or time. Applied right away when
+   * not set or already in the past.
+   * </pre>
+   *
+   * <code>.vsg.Schedule schedule = 34;</code>
+   * @return The schedule.
+   */
+  vsg.Schedule getSchedule();
+  /**
+   * <pre>
+   * Hold the command until a given frame or time. Applied right away when
+   * not set or already in the past.
+   * </pre>
+   *
+   * <code>.vsg.Schedule schedule = 34;</code>
+   */
+  vsg.ScheduleOrBuilder getScheduleOrBuilder();
+
   public vsg.RootMessage.CommandCase getCommandCase();
 }
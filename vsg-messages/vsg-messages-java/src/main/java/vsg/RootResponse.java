@@ -0,0 +1,2095 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Reply sent by the server for every RootMessage it receives.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.RootResponse}
+ */
+public final class RootResponse extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.RootResponse)
+    RootResponseOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use RootResponse.newBuilder() to construct.
+  private RootResponse(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private RootResponse() {
+    status_ = 0;
+    errorCode_ = 0;
+    error_ = "";
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new RootResponse();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private RootResponse(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+            int rawValue = input.readEnum();
+
+            status_ = rawValue;
+            break;
+          }
+          case 16: {
+            int rawValue = input.readEnum();
+
+            errorCode_ = rawValue;
+            break;
+          }
+          case 26: {
+            java.lang.String s = input.readStringRequireUtf8();
+
+            error_ = s;
+            break;
+          }
+          case 32: {
+
+            sequence_ = input.readUInt64();
+            break;
+          }
+          case 42: {
+            vsg.Onset.Builder subBuilder = null;
+            if (onset_ != null) {
+              subBuilder = onset_.toBuilder();
+            }
+            onset_ = input.readMessage(vsg.Onset.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(onset_);
+              onset_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          case 48: {
+
+            serverTimeUs_ = input.readUInt64();
+            break;
+          }
+          case 58: {
+            vsg.DisplayStatus.Builder subBuilder = null;
+            if (displayStatus_ != null) {
+              subBuilder = displayStatus_.toBuilder();
+            }
+            displayStatus_ = input.readMessage(vsg.DisplayStatus.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(displayStatus_);
+              displayStatus_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          case 66: {
+            vsg.Hello.Builder subBuilder = null;
+            if (hello_ != null) {
+              subBuilder = hello_.toBuilder();
+            }
+            hello_ = input.readMessage(vsg.Hello.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(hello_);
+              hello_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          case 74: {
+            vsg.Event.Builder subBuilder = null;
+            if (event_ != null) {
+              subBuilder = event_.toBuilder();
+            }
+            event_ = input.readMessage(vsg.Event.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(event_);
+              event_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          case 82: {
+            vsg.Capture.Builder subBuilder = null;
+            if (capture_ != null) {
+              subBuilder = capture_.toBuilder();
+            }
+            capture_ = input.readMessage(vsg.Capture.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(capture_);
+              capture_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_RootResponse_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_RootResponse_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.RootResponse.class, vsg.RootResponse.Builder.class);
+  }
+
+  public static final int STATUS_FIELD_NUMBER = 1;
+  private int status_;
+  /**
+   * <code>.vsg.Status status = 1;</code>
+   * @return The enum numeric value on the wire for status.
+   */
+  @java.lang.Override public int getStatusValue() {
+    return status_;
+  }
+  /**
+   * <code>.vsg.Status status = 1;</code>
+   * @return The status.
+   */
+  @java.lang.Override public vsg.Status getStatus() {
+    @SuppressWarnings("deprecation")
+    vsg.Status result = vsg.Status.valueOf(status_);
+    return result == null ? vsg.Status.UNRECOGNIZED : result;
+  }
+
+  public static final int ERROR_CODE_FIELD_NUMBER = 2;
+  private int errorCode_;
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The enum numeric value on the wire for errorCode.
+   */
+  @java.lang.Override public int getErrorCodeValue() {
+    return errorCode_;
+  }
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The errorCode.
+   */
+  @java.lang.Override public vsg.ErrorCode getErrorCode() {
+    @SuppressWarnings("deprecation")
+    vsg.ErrorCode result = vsg.ErrorCode.valueOf(errorCode_);
+    return result == null ? vsg.ErrorCode.UNRECOGNIZED : result;
+  }
+
+  public static final int ERROR_FIELD_NUMBER = 3;
+  private volatile java.lang.Object error_;
+  /**
+   * <code>string error = 3;</code>
+   * @return The error.
+   */
+  @java.lang.Override
+  public java.lang.String getError() {
+    java.lang.Object ref = error_;
+    if (ref instanceof java.lang.String) {
+      return (java.lang.String) ref;
+    } else {
+      com.google.protobuf.ByteString bs = 
+          (com.google.protobuf.ByteString) ref;
+      java.lang.String s = bs.toStringUtf8();
+      error_ = s;
+      return s;
+    }
+  }
+  /**
+   * <code>string error = 3;</code>
+   * @return The bytes for error.
+   */
+  @java.lang.Override
+  public com.google.protobuf.ByteString
+      getErrorBytes() {
+    java.lang.Object ref = error_;
+    if (ref instanceof java.lang.String) {
+      com.google.protobuf.ByteString b = 
+          com.google.protobuf.ByteString.copyFromUtf8(
+              (java.lang.String) ref);
+      error_ = b;
+      return b;
+    } else {
+      return (com.google.protobuf.ByteString) ref;
+    }
+  }
+
+  public static final int SEQUENCE_FIELD_NUMBER = 4;
+  private long sequence_;
+  /**
+   * <code>uint64 sequence = 4;</code>
+   * @return The sequence.
+   */
+  @java.lang.Override
+  public long getSequence() {
+    return sequence_;
+  }
+
+  public static final int ONSET_FIELD_NUMBER = 5;
+  private vsg.Onset onset_;
+  /**
+   * <pre>
+   * Only set in reply to a RootMessage with flip_ack.
+   * </pre>
+   *
+   * <code>.vsg.Onset onset = 5;</code>
+   * @return Whether the onset field is set.
+   */
+  @java.lang.Override
+  public boolean hasOnset() {
+    return onset_ != null;
+  }
+  /**
+   * <pre>
+   * Only set in reply to a RootMessage with flip_ack.
+   * </pre>
+   *
+   * <code>.vsg.Onset onset = 5;</code>
+   * @return The onset.
+   */
+  @java.lang.Override
+  public vsg.Onset getOnset() {
+    return onset_ == null ? vsg.Onset.getDefaultInstance() : onset_;
+  }
+  /**
+   * <pre>
+   * Only set in reply to a RootMessage with flip_ack.
+   * </pre>
+   *
+   * <code>.vsg.Onset onset = 5;</code>
+   */
+  @java.lang.Override
+  public vsg.OnsetOrBuilder getOnsetOrBuilder() {
+    return getOnset();
+  }
+
+  public static final int SERVER_TIME_US_FIELD_NUMBER = 6;
+  private long serverTimeUs_;
+  /**
+   * <pre>
+   * Server clock when the reply was sent, as in Onset.timestamp_us.
+   * </pre>
+   *
+   * <code>uint64 server_time_us = 6;</code>
+   * @return The serverTimeUs.
+   */
+  @java.lang.Override
+  public long getServerTimeUs() {
+    return serverTimeUs_;
+  }
+
+  public static final int DISPLAY_STATUS_FIELD_NUMBER = 7;
+  private vsg.DisplayStatus displayStatus_;
+  /**
+   * <pre>
+   * Only set in reply to GetStatus.
+   * </pre>
+   *
+   * <code>.vsg.DisplayStatus display_status = 7;</code>
+   * @return Whether the displayStatus field is set.
+   */
+  @java.lang.Override
+  public boolean hasDisplayStatus() {
+    return displayStatus_ != null;
+  }
+  /**
+   * <pre>
+   * Only set in reply to GetStatus.
+   * </pre>
+   *
+   * <code>.vsg.DisplayStatus display_status = 7;</code>
+   * @return The displayStatus.
+   */
+  @java.lang.Override
+  public vsg.DisplayStatus getDisplayStatus() {
+    return displayStatus_ == null ? vsg.DisplayStatus.getDefaultInstance() : displayStatus_;
+  }
+  /**
+   * <pre>
+   * Only set in reply to GetStatus.
+   * </pre>
+   *
+   * <code>.vsg.DisplayStatus display_status = 7;</code>
+   */
+  @java.lang.Override
+  public vsg.DisplayStatusOrBuilder getDisplayStatusOrBuilder() {
+    return getDisplayStatus();
+  }
+
+  public static final int HELLO_FIELD_NUMBER = 8;
+  private vsg.Hello hello_;
+  /**
+   * <pre>
+   * Only set in reply to Hello.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 8;</code>
+   * @return Whether the hello field is set.
+   */
+  @java.lang.Override
+  public boolean hasHello() {
+    return hello_ != null;
+  }
+  /**
+   * <pre>
+   * Only set in reply to Hello.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 8;</code>
+   * @return The hello.
+   */
+  @java.lang.Override
+  public vsg.Hello getHello() {
+    return hello_ == null ? vsg.Hello.getDefaultInstance() : hello_;
+  }
+  /**
+   * <pre>
+   * Only set in reply to Hello.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 8;</code>
+   */
+  @java.lang.Override
+  public vsg.HelloOrBuilder getHelloOrBuilder() {
+    return getHello();
+  }
+
+  public static final int EVENT_FIELD_NUMBER = 9;
+  private vsg.Event event_;
+  /**
+   * <pre>
+   * Pushed to subscribed connections outside of the request and reply
+   * flow, with sequence 0.
+   * </pre>
+   *
+   * <code>.vsg.Event event = 9;</code>
+   * @return Whether the event field is set.
+   */
+  @java.lang.Override
+  public boolean hasEvent() {
+    return event_ != null;
+  }
+  /**
+   * <pre>
+   * Pushed to subscribed connections outside of the request and reply
+   * flow, with sequence 0.
+   * </pre>
+   *
+   * <code>.vsg.Event event = 9;</code>
+   * @return The event.
+   */
+  @java.lang.Override
+  public vsg.Event getEvent() {
+    return event_ == null ? vsg.Event.getDefaultInstance() : event_;
+  }
+  /**
+   * <pre>
+   * Pushed to subscribed connections outside of the request and reply
+   * flow, with sequence 0.
+   * </pre>
+   *
+   * <code>.vsg.Event event = 9;</code>
+   */
+  @java.lang.Override
+  public vsg.EventOrBuilder getEventOrBuilder() {
+    return getEvent();
+  }
+
+  public static final int CAPTURE_FIELD_NUMBER = 10;
+  private vsg.Capture capture_;
+  /**
+   * <pre>
+   * Only set in reply to CaptureFrame.
+   * </pre>
+   *
+   * <code>.vsg.Capture capture = 10;</code>
+   * @return Whether the capture field is set.
+   */
+  @java.lang.Override
+  public boolean hasCapture() {
+    return capture_ != null;
+  }
+  /**
+   * <pre>
+   * Only set in reply to CaptureFrame.
+   * </pre>
+   *
+   * <code>.vsg.Capture capture = 10;</code>
+   * @return The capture.
+   */
+  @java.lang.Override
+  public vsg.Capture getCapture() {
+    return capture_ == null ? vsg.Capture.getDefaultInstance() : capture_;
+  }
+  /**
+   * <pre>
+   * Only set in reply to CaptureFrame.
+   * </pre>
+   *
+   * <code>.vsg.Capture capture = 10;</code>
+   */
+  @java.lang.Override
+  public vsg.CaptureOrBuilder getCaptureOrBuilder() {
+    return getCapture();
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (status_ != vsg.Status.STATUS_OK.getNumber()) {
+      output.writeEnum(1, status_);
+    }
+    if (errorCode_ != vsg.ErrorCode.ERROR_CODE_NONE.getNumber()) {
+      output.writeEnum(2, errorCode_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(error_)) {
+      com.google.protobuf.GeneratedMessageV3.writeString(output, 3, error_);
+    }
+    if (sequence_ != 0L) {
+      output.writeUInt64(4, sequence_);
+    }
+    if (onset_ != null) {
+      output.writeMessage(5, getOnset());
+    }
+    if (serverTimeUs_ != 0L) {
+      output.writeUInt64(6, serverTimeUs_);
+    }
+    if (displayStatus_ != null) {
+      output.writeMessage(7, getDisplayStatus());
+    }
+    if (hello_ != null) {
+      output.writeMessage(8, getHello());
+    }
+    if (event_ != null) {
+      output.writeMessage(9, getEvent());
+    }
+    if (capture_ != null) {
+      output.writeMessage(10, getCapture());
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (status_ != vsg.Status.STATUS_OK.getNumber()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeEnumSize(1, status_);
+    }
+    if (errorCode_ != vsg.ErrorCode.ERROR_CODE_NONE.getNumber()) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeEnumSize(2, errorCode_);
+    }
+    if (!com.google.protobuf.GeneratedMessageV3.isStringEmpty(error_)) {
+      size += com.google.protobuf.GeneratedMessageV3.computeStringSize(3, error_);
+    }
+    if (sequence_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(4, sequence_);
+    }
+    if (onset_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(5, getOnset());
+    }
+    if (serverTimeUs_ != 0L) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(6, serverTimeUs_);
+    }
+    if (displayStatus_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(7, getDisplayStatus());
+    }
+    if (hello_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(8, getHello());
+    }
+    if (event_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(9, getEvent());
+    }
+    if (capture_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(10, getCapture());
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.RootResponse)) {
+      return super.equals(obj);
+    }
+    vsg.RootResponse other = (vsg.RootResponse) obj;
+
+    if (status_ != other.status_) return false;
+    if (errorCode_ != other.errorCode_) return false;
+    if (!getError()
+        .equals(other.getError())) return false;
+    if (getSequence()
+        != other.getSequence()) return false;
+    if (hasOnset() != other.hasOnset()) return false;
+    if (hasOnset()) {
+      if (!getOnset()
+          .equals(other.getOnset())) return false;
+    }
+    if (getServerTimeUs()
+        != other.getServerTimeUs()) return false;
+    if (hasDisplayStatus() != other.hasDisplayStatus()) return false;
+    if (hasDisplayStatus()) {
+      if (!getDisplayStatus()
+          .equals(other.getDisplayStatus())) return false;
+    }
+    if (hasHello() != other.hasHello()) return false;
+    if (hasHello()) {
+      if (!getHello()
+          .equals(other.getHello())) return false;
+    }
+    if (hasEvent() != other.hasEvent()) return false;
+    if (hasEvent()) {
+      if (!getEvent()
+          .equals(other.getEvent())) return false;
+    }
+    if (hasCapture() != other.hasCapture()) return false;
+    if (hasCapture()) {
+      if (!getCapture()
+          .equals(other.getCapture())) return false;
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + STATUS_FIELD_NUMBER;
+    hash = (53 * hash) + status_;
+    hash = (37 * hash) + ERROR_CODE_FIELD_NUMBER;
+    hash = (53 * hash) + errorCode_;
+    hash = (37 * hash) + ERROR_FIELD_NUMBER;
+    hash = (53 * hash) + getError().hashCode();
+    hash = (37 * hash) + SEQUENCE_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getSequence());
+    if (hasOnset()) {
+      hash = (37 * hash) + ONSET_FIELD_NUMBER;
+      hash = (53 * hash) + getOnset().hashCode();
+    }
+    hash = (37 * hash) + SERVER_TIME_US_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+        getServerTimeUs());
+    if (hasDisplayStatus()) {
+      hash = (37 * hash) + DISPLAY_STATUS_FIELD_NUMBER;
+      hash = (53 * hash) + getDisplayStatus().hashCode();
+    }
+    if (hasHello()) {
+      hash = (37 * hash) + HELLO_FIELD_NUMBER;
+      hash = (53 * hash) + getHello().hashCode();
+    }
+    if (hasEvent()) {
+      hash = (37 * hash) + EVENT_FIELD_NUMBER;
+      hash = (53 * hash) + getEvent().hashCode();
+    }
+    if (hasCapture()) {
+      hash = (37 * hash) + CAPTURE_FIELD_NUMBER;
+      hash = (53 * hash) + getCapture().hashCode();
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.RootResponse parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RootResponse parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RootResponse parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RootResponse parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RootResponse parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.RootResponse parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.RootResponse parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.RootResponse parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.RootResponse parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.RootResponse parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.RootResponse parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.RootResponse parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.RootResponse prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Reply sent by the server for every RootMessage it receives.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.RootResponse}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.RootResponse)
+      vsg.RootResponseOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_RootResponse_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_RootResponse_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.RootResponse.class, vsg.RootResponse.Builder.class);
+    }
+
+    // Construct using vsg.RootResponse.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      status_ = 0;
+
+      errorCode_ = 0;
+
+      error_ = "";
+
+      sequence_ = 0L;
+
+      if (onsetBuilder_ == null) {
+        onset_ = null;
+      } else {
+        onset_ = null;
+        onsetBuilder_ = null;
+      }
+      serverTimeUs_ = 0L;
+
+      if (displayStatusBuilder_ == null) {
+        displayStatus_ = null;
+      } else {
+        displayStatus_ = null;
+        displayStatusBuilder_ = null;
+      }
+      if (helloBuilder_ == null) {
+        hello_ = null;
+      } else {
+        hello_ = null;
+        helloBuilder_ = null;
+      }
+      if (eventBuilder_ == null) {
+        event_ = null;
+      } else {
+        event_ = null;
+        eventBuilder_ = null;
+      }
+      if (captureBuilder_ == null) {
+        capture_ = null;
+      } else {
+        capture_ = null;
+        captureBuilder_ = null;
+      }
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_RootResponse_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.RootResponse getDefaultInstanceForType() {
+      return vsg.RootResponse.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.RootResponse build() {
+      vsg.RootResponse result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.RootResponse buildPartial() {
+      vsg.RootResponse result = new vsg.RootResponse(this);
+      result.status_ = status_;
+      result.errorCode_ = errorCode_;
+      result.error_ = error_;
+      result.sequence_ = sequence_;
+      if (onsetBuilder_ == null) {
+        result.onset_ = onset_;
+      } else {
+        result.onset_ = onsetBuilder_.build();
+      }
+      result.serverTimeUs_ = serverTimeUs_;
+      if (displayStatusBuilder_ == null) {
+        result.displayStatus_ = displayStatus_;
+      } else {
+        result.displayStatus_ = displayStatusBuilder_.build();
+      }
+      if (helloBuilder_ == null) {
+        result.hello_ = hello_;
+      } else {
+        result.hello_ = helloBuilder_.build();
+      }
+      if (eventBuilder_ == null) {
+        result.event_ = event_;
+      } else {
+        result.event_ = eventBuilder_.build();
+      }
+      if (captureBuilder_ == null) {
+        result.capture_ = capture_;
+      } else {
+        result.capture_ = captureBuilder_.build();
+      }
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.RootResponse) {
+        return mergeFrom((vsg.RootResponse)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.RootResponse other) {
+      if (other == vsg.RootResponse.getDefaultInstance()) return this;
+      if (other.status_ != 0) {
+        setStatusValue(other.getStatusValue());
+      }
+      if (other.errorCode_ != 0) {
+        setErrorCodeValue(other.getErrorCodeValue());
+      }
+      if (!other.getError().isEmpty()) {
+        error_ = other.error_;
+        onChanged();
+      }
+      if (other.getSequence() != 0L) {
+        setSequence(other.getSequence());
+      }
+      if (other.hasOnset()) {
+        mergeOnset(other.getOnset());
+      }
+      if (other.getServerTimeUs() != 0L) {
+        setServerTimeUs(other.getServerTimeUs());
+      }
+      if (other.hasDisplayStatus()) {
+        mergeDisplayStatus(other.getDisplayStatus());
+      }
+      if (other.hasHello()) {
+        mergeHello(other.getHello());
+      }
+      if (other.hasEvent()) {
+        mergeEvent(other.getEvent());
+      }
+      if (other.hasCapture()) {
+        mergeCapture(other.getCapture());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.RootResponse parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.RootResponse) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private int status_ = 0;
+    /**
+     * <code>.vsg.Status status = 1;</code>
+     * @return The enum numeric value on the wire for status.
+     */
+    @java.lang.Override public int getStatusValue() {
+      return status_;
+    }
+    /**
+     * <code>.vsg.Status status = 1;</code>
+     * @param value The enum numeric value on the wire for status to set.
+     * @return This builder for chaining.
+     */
+    public Builder setStatusValue(int value) {
+      
+      status_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.Status status = 1;</code>
+     * @return The status.
+     */
+    @java.lang.Override
+    public vsg.Status getStatus() {
+      @SuppressWarnings("deprecation")
+      vsg.Status result = vsg.Status.valueOf(status_);
+      return result == null ? vsg.Status.UNRECOGNIZED : result;
+    }
+    /**
+     * <code>.vsg.Status status = 1;</code>
+     * @param value The status to set.
+     * @return This builder for chaining.
+     */
+    public Builder setStatus(vsg.Status value) {
+      if (value == null) {
+        throw new NullPointerException();
+      }
+      
+      status_ = value.getNumber();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.Status status = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearStatus() {
+      
+      status_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private int errorCode_ = 0;
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @return The enum numeric value on the wire for errorCode.
+     */
+    @java.lang.Override public int getErrorCodeValue() {
+      return errorCode_;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @param value The enum numeric value on the wire for errorCode to set.
+     * @return This builder for chaining.
+     */
+    public Builder setErrorCodeValue(int value) {
+      
+      errorCode_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @return The errorCode.
+     */
+    @java.lang.Override
+    public vsg.ErrorCode getErrorCode() {
+      @SuppressWarnings("deprecation")
+      vsg.ErrorCode result = vsg.ErrorCode.valueOf(errorCode_);
+      return result == null ? vsg.ErrorCode.UNRECOGNIZED : result;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @param value The errorCode to set.
+     * @return This builder for chaining.
+     */
+    public Builder setErrorCode(vsg.ErrorCode value) {
+      if (value == null) {
+        throw new NullPointerException();
+      }
+      
+      errorCode_ = value.getNumber();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>.vsg.ErrorCode error_code = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearErrorCode() {
+      
+      errorCode_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private java.lang.Object error_ = "";
+    /**
+     * <code>string error = 3;</code>
+     * @return The error.
+     */
+    public java.lang.String getError() {
+      java.lang.Object ref = error_;
+      if (!(ref instanceof java.lang.String)) {
+        com.google.protobuf.ByteString bs =
+            (com.google.protobuf.ByteString) ref;
+        java.lang.String s = bs.toStringUtf8();
+        error_ = s;
+        return s;
+      } else {
+        return (java.lang.String) ref;
+      }
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @return The bytes for error.
+     */
+    public com.google.protobuf.ByteString
+        getErrorBytes() {
+      java.lang.Object ref = error_;
+      if (ref instanceof String) {
+        com.google.protobuf.ByteString b = 
+            com.google.protobuf.ByteString.copyFromUtf8(
+                (java.lang.String) ref);
+        error_ = b;
+        return b;
+      } else {
+        return (com.google.protobuf.ByteString) ref;
+      }
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @param value The error to set.
+     * @return This builder for chaining.
+     */
+    public Builder setError(
+        java.lang.String value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  
+      error_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearError() {
+      
+      error_ = getDefaultInstance().getError();
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>string error = 3;</code>
+     * @param value The bytes for error to set.
+     * @return This builder for chaining.
+     */
+    public Builder setErrorBytes(
+        com.google.protobuf.ByteString value) {
+      if (value == null) {
+    throw new NullPointerException();
+  }
+  checkByteStringIsUtf8(value);
+      
+      error_ = value;
+      onChanged();
+      return this;
+    }
+
+    private long sequence_ ;
+    /**
+     * <code>uint64 sequence = 4;</code>
+     * @return The sequence.
+     */
+    @java.lang.Override
+    public long getSequence() {
+      return sequence_;
+    }
+    /**
+     * <code>uint64 sequence = 4;</code>
+     * @param value The sequence to set.
+     * @return This builder for chaining.
+     */
+    public Builder setSequence(long value) {
+      
+      sequence_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint64 sequence = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearSequence() {
+      
+      sequence_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private vsg.Onset onset_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Onset, vsg.Onset.Builder, vsg.OnsetOrBuilder> onsetBuilder_;
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     * @return Whether the onset field is set.
+     */
+    public boolean hasOnset() {
+      return onsetBuilder_ != null || onset_ != null;
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     * @return The onset.
+     */
+    public vsg.Onset getOnset() {
+      if (onsetBuilder_ == null) {
+        return onset_ == null ? vsg.Onset.getDefaultInstance() : onset_;
+      } else {
+        return onsetBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    public Builder setOnset(vsg.Onset value) {
+      if (onsetBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        onset_ = value;
+        onChanged();
+      } else {
+        onsetBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    public Builder setOnset(
+        vsg.Onset.Builder builderForValue) {
+      if (onsetBuilder_ == null) {
+        onset_ = builderForValue.build();
+        onChanged();
+      } else {
+        onsetBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    public Builder mergeOnset(vsg.Onset value) {
+      if (onsetBuilder_ == null) {
+        if (onset_ != null) {
+          onset_ =
+            vsg.Onset.newBuilder(onset_).mergeFrom(value).buildPartial();
+        } else {
+          onset_ = value;
+        }
+        onChanged();
+      } else {
+        onsetBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    public Builder clearOnset() {
+      if (onsetBuilder_ == null) {
+        onset_ = null;
+        onChanged();
+      } else {
+        onset_ = null;
+        onsetBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    public vsg.Onset.Builder getOnsetBuilder() {
+      
+      onChanged();
+      return getOnsetFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    public vsg.OnsetOrBuilder getOnsetOrBuilder() {
+      if (onsetBuilder_ != null) {
+        return onsetBuilder_.getMessageOrBuilder();
+      } else {
+        return onset_ == null ?
+            vsg.Onset.getDefaultInstance() : onset_;
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to a RootMessage with flip_ack.
+     * </pre>
+     *
+     * <code>.vsg.Onset onset = 5;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Onset, vsg.Onset.Builder, vsg.OnsetOrBuilder> 
+        getOnsetFieldBuilder() {
+      if (onsetBuilder_ == null) {
+        onsetBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Onset, vsg.Onset.Builder, vsg.OnsetOrBuilder>(
+                getOnset(),
+                getParentForChildren(),
+                isClean());
+        onset_ = null;
+      }
+      return onsetBuilder_;
+    }
+
+    private long serverTimeUs_ ;
+    /**
+     * <pre>
+     * Server clock when the reply was sent, as in Onset.timestamp_us.
+     * </pre>
+     *
+     * <code>uint64 server_time_us = 6;</code>
+     * @return The serverTimeUs.
+     */
+    @java.lang.Override
+    public long getServerTimeUs() {
+      return serverTimeUs_;
+    }
+    /**
+     * <pre>
+     * Server clock when the reply was sent, as in Onset.timestamp_us.
+     * </pre>
+     *
+     * <code>uint64 server_time_us = 6;</code>
+     * @param value The serverTimeUs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setServerTimeUs(long value) {
+      
+      serverTimeUs_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Server clock when the reply was sent, as in Onset.timestamp_us.
+     * </pre>
+     *
+     * <code>uint64 server_time_us = 6;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearServerTimeUs() {
+      
+      serverTimeUs_ = 0L;
+      onChanged();
+      return this;
+    }
+
+    private vsg.DisplayStatus displayStatus_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.DisplayStatus, vsg.DisplayStatus.Builder, vsg.DisplayStatusOrBuilder> displayStatusBuilder_;
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     * @return Whether the displayStatus field is set.
+     */
+    public boolean hasDisplayStatus() {
+      return displayStatusBuilder_ != null || displayStatus_ != null;
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     * @return The displayStatus.
+     */
+    public vsg.DisplayStatus getDisplayStatus() {
+      if (displayStatusBuilder_ == null) {
+        return displayStatus_ == null ? vsg.DisplayStatus.getDefaultInstance() : displayStatus_;
+      } else {
+        return displayStatusBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    public Builder setDisplayStatus(vsg.DisplayStatus value) {
+      if (displayStatusBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        displayStatus_ = value;
+        onChanged();
+      } else {
+        displayStatusBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    public Builder setDisplayStatus(
+        vsg.DisplayStatus.Builder builderForValue) {
+      if (displayStatusBuilder_ == null) {
+        displayStatus_ = builderForValue.build();
+        onChanged();
+      } else {
+        displayStatusBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    public Builder mergeDisplayStatus(vsg.DisplayStatus value) {
+      if (displayStatusBuilder_ == null) {
+        if (displayStatus_ != null) {
+          displayStatus_ =
+            vsg.DisplayStatus.newBuilder(displayStatus_).mergeFrom(value).buildPartial();
+        } else {
+          displayStatus_ = value;
+        }
+        onChanged();
+      } else {
+        displayStatusBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    public Builder clearDisplayStatus() {
+      if (displayStatusBuilder_ == null) {
+        displayStatus_ = null;
+        onChanged();
+      } else {
+        displayStatus_ = null;
+        displayStatusBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    public vsg.DisplayStatus.Builder getDisplayStatusBuilder() {
+      
+      onChanged();
+      return getDisplayStatusFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    public vsg.DisplayStatusOrBuilder getDisplayStatusOrBuilder() {
+      if (displayStatusBuilder_ != null) {
+        return displayStatusBuilder_.getMessageOrBuilder();
+      } else {
+        return displayStatus_ == null ?
+            vsg.DisplayStatus.getDefaultInstance() : displayStatus_;
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to GetStatus.
+     * </pre>
+     *
+     * <code>.vsg.DisplayStatus display_status = 7;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.DisplayStatus, vsg.DisplayStatus.Builder, vsg.DisplayStatusOrBuilder> 
+        getDisplayStatusFieldBuilder() {
+      if (displayStatusBuilder_ == null) {
+        displayStatusBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.DisplayStatus, vsg.DisplayStatus.Builder, vsg.DisplayStatusOrBuilder>(
+                getDisplayStatus(),
+                getParentForChildren(),
+                isClean());
+        displayStatus_ = null;
+      }
+      return displayStatusBuilder_;
+    }
+
+    private vsg.Hello hello_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Hello, vsg.Hello.Builder, vsg.HelloOrBuilder> helloBuilder_;
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     * @return Whether the hello field is set.
+     */
+    public boolean hasHello() {
+      return helloBuilder_ != null || hello_ != null;
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     * @return The hello.
+     */
+    public vsg.Hello getHello() {
+      if (helloBuilder_ == null) {
+        return hello_ == null ? vsg.Hello.getDefaultInstance() : hello_;
+      } else {
+        return helloBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    public Builder setHello(vsg.Hello value) {
+      if (helloBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        hello_ = value;
+        onChanged();
+      } else {
+        helloBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    public Builder setHello(
+        vsg.Hello.Builder builderForValue) {
+      if (helloBuilder_ == null) {
+        hello_ = builderForValue.build();
+        onChanged();
+      } else {
+        helloBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    public Builder mergeHello(vsg.Hello value) {
+      if (helloBuilder_ == null) {
+        if (hello_ != null) {
+          hello_ =
+            vsg.Hello.newBuilder(hello_).mergeFrom(value).buildPartial();
+        } else {
+          hello_ = value;
+        }
+        onChanged();
+      } else {
+        helloBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    public Builder clearHello() {
+      if (helloBuilder_ == null) {
+        hello_ = null;
+        onChanged();
+      } else {
+        hello_ = null;
+        helloBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    public vsg.Hello.Builder getHelloBuilder() {
+      
+      onChanged();
+      return getHelloFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    public vsg.HelloOrBuilder getHelloOrBuilder() {
+      if (helloBuilder_ != null) {
+        return helloBuilder_.getMessageOrBuilder();
+      } else {
+        return hello_ == null ?
+            vsg.Hello.getDefaultInstance() : hello_;
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to Hello.
+     * </pre>
+     *
+     * <code>.vsg.Hello hello = 8;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Hello, vsg.Hello.Builder, vsg.HelloOrBuilder> 
+        getHelloFieldBuilder() {
+      if (helloBuilder_ == null) {
+        helloBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Hello, vsg.Hello.Builder, vsg.HelloOrBuilder>(
+                getHello(),
+                getParentForChildren(),
+                isClean());
+        hello_ = null;
+      }
+      return helloBuilder_;
+    }
+
+    private vsg.Event event_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Event, vsg.Event.Builder, vsg.EventOrBuilder> eventBuilder_;
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     * @return Whether the event field is set.
+     */
+    public boolean hasEvent() {
+      return eventBuilder_ != null || event_ != null;
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     * @return The event.
+     */
+    public vsg.Event getEvent() {
+      if (eventBuilder_ == null) {
+        return event_ == null ? vsg.Event.getDefaultInstance() : event_;
+      } else {
+        return eventBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    public Builder setEvent(vsg.Event value) {
+      if (eventBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        event_ = value;
+        onChanged();
+      } else {
+        eventBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    public Builder setEvent(
+        vsg.Event.Builder builderForValue) {
+      if (eventBuilder_ == null) {
+        event_ = builderForValue.build();
+        onChanged();
+      } else {
+        eventBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    public Builder mergeEvent(vsg.Event value) {
+      if (eventBuilder_ == null) {
+        if (event_ != null) {
+          event_ =
+            vsg.Event.newBuilder(event_).mergeFrom(value).buildPartial();
+        } else {
+          event_ = value;
+        }
+        onChanged();
+      } else {
+        eventBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    public Builder clearEvent() {
+      if (eventBuilder_ == null) {
+        event_ = null;
+        onChanged();
+      } else {
+        event_ = null;
+        eventBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    public vsg.Event.Builder getEventBuilder() {
+      
+      onChanged();
+      return getEventFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    public vsg.EventOrBuilder getEventOrBuilder() {
+      if (eventBuilder_ != null) {
+        return eventBuilder_.getMessageOrBuilder();
+      } else {
+        return event_ == null ?
+            vsg.Event.getDefaultInstance() : event_;
+      }
+    }
+    /**
+     * <pre>
+     * Pushed to subscribed connections outside of the request and reply
+     * flow, with sequence 0.
+     * </pre>
+     *
+     * <code>.vsg.Event event = 9;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Event, vsg.Event.Builder, vsg.EventOrBuilder> 
+        getEventFieldBuilder() {
+      if (eventBuilder_ == null) {
+        eventBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Event, vsg.Event.Builder, vsg.EventOrBuilder>(
+                getEvent(),
+                getParentForChildren(),
+                isClean());
+        event_ = null;
+      }
+      return eventBuilder_;
+    }
+
+    private vsg.Capture capture_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Capture, vsg.Capture.Builder, vsg.CaptureOrBuilder> captureBuilder_;
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     * @return Whether the capture field is set.
+     */
+    public boolean hasCapture() {
+      return captureBuilder_ != null || capture_ != null;
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     * @return The capture.
+     */
+    public vsg.Capture getCapture() {
+      if (captureBuilder_ == null) {
+        return capture_ == null ? vsg.Capture.getDefaultInstance() : capture_;
+      } else {
+        return captureBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    public Builder setCapture(vsg.Capture value) {
+      if (captureBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        capture_ = value;
+        onChanged();
+      } else {
+        captureBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    public Builder setCapture(
+        vsg.Capture.Builder builderForValue) {
+      if (captureBuilder_ == null) {
+        capture_ = builderForValue.build();
+        onChanged();
+      } else {
+        captureBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    public Builder mergeCapture(vsg.Capture value) {
+      if (captureBuilder_ == null) {
+        if (capture_ != null) {
+          capture_ =
+            vsg.Capture.newBuilder(capture_).mergeFrom(value).buildPartial();
+        } else {
+          capture_ = value;
+        }
+        onChanged();
+      } else {
+        captureBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    public Builder clearCapture() {
+      if (captureBuilder_ == null) {
+        capture_ = null;
+        onChanged();
+      } else {
+        capture_ = null;
+        captureBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    public vsg.Capture.Builder getCaptureBuilder() {
+      
+      onChanged();
+      return getCaptureFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    public vsg.CaptureOrBuilder getCaptureOrBuilder() {
+      if (captureBuilder_ != null) {
+        return captureBuilder_.getMessageOrBuilder();
+      } else {
+        return capture_ == null ?
+            vsg.Capture.getDefaultInstance() : capture_;
+      }
+    }
+    /**
+     * <pre>
+     * Only set in reply to CaptureFrame.
+     * </pre>
+     *
+     * <code>.vsg.Capture capture = 10;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.Capture, vsg.Capture.Builder, vsg.CaptureOrBuilder> 
+        getCaptureFieldBuilder() {
+      if (captureBuilder_ == null) {
+        captureBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.Capture, vsg.Capture.Builder, vsg.CaptureOrBuilder>(
+                getCapture(),
+                getParentForChildren(),
+                isClean());
+        capture_ = null;
+      }
+      return captureBuilder_;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.RootResponse)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.RootResponse)
+  private static final vsg.RootResponse DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.RootResponse();
+  }
+
+  public static vsg.RootResponse getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<RootResponse>
+      PARSER = new com.google.protobuf.AbstractParser<RootResponse>() {
+    @java.lang.Override
+    public RootResponse parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new RootResponse(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<RootResponse> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<RootResponse> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.RootResponse getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
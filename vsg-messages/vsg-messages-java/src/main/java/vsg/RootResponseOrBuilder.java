@@ -0,0 +1,197 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface RootResponseOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.RootResponse)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>.vsg.Status status = 1;</code>
+   * @return The enum numeric value on the wire for status.
+   */
+  int getStatusValue();
+  /**
+   * <code>.vsg.Status status = 1;</code>
+   * @return The status.
+   */
+  vsg.Status getStatus();
+
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The enum numeric value on the wire for errorCode.
+   */
+  int getErrorCodeValue();
+  /**
+   * <code>.vsg.ErrorCode error_code = 2;</code>
+   * @return The errorCode.
+   */
+  vsg.ErrorCode getErrorCode();
+
+  /**
+   * <code>string error = 3;</code>
+   * @return The error.
+   */
+  java.lang.String getError();
+  /**
+   * <code>string error = 3;</code>
+   * @return The bytes for error.
+   */
+  com.google.protobuf.ByteString
+      getErrorBytes();
+
+  /**
+   * <code>uint64 sequence = 4;</code>
+   * @return The sequence.
+   */
+  long getSequence();
+
+  /**
+   * <pre>
+   * Only set in reply to a RootMessage with flip_ack.
+   * </pre>
+   *
+   * <code>.vsg.Onset onset = 5;</code>
+   * @return Whether the onset field is set.
+   */
+  boolean hasOnset();
+  /**
+   * <pre>
+   * Only set in reply to a RootMessage with flip_ack.
+   * </pre>
+   *
+   * <code>.vsg.Onset onset = 5;</code>
+   * @return The onset.
+   */
+  vsg.Onset getOnset();
+  /**
+   * <pre>
+   * Only set in reply to a RootMessage with flip_ack.
+   * </pre>
+   *
+   * <code>.vsg.Onset onset = 5;</code>
+   */
+  vsg.OnsetOrBuilder getOnsetOrBuilder();
+
+  /**
+   * <pre>
+   * Server clock when the reply was sent, as in Onset.timestamp_us.
+   * </pre>
+   *
+   * <code>uint64 server_time_us = 6;</code>
+   * @return The serverTimeUs.
+   */
+  long getServerTimeUs();
+
+  /**
+   * <pre>
+   * Only set in reply to GetStatus.
+   * </pre>
+   *
+   * <code>.vsg.DisplayStatus display_status = 7;</code>
+   * @return Whether the displayStatus field is set.
+   */
+  boolean hasDisplayStatus();
+  /**
+   * <pre>
+   * Only set in reply to GetStatus.
+   * </pre>
+   *
+   * <code>.vsg.DisplayStatus display_status = 7;</code>
+   * @return The displayStatus.
+   */
+  vsg.DisplayStatus getDisplayStatus();
+  /**
+   * <pre>
+   * Only set in reply to GetStatus.
+   * </pre>
+   *
+   * <code>.vsg.DisplayStatus display_status = 7;</code>
+   */
+  vsg.DisplayStatusOrBuilder getDisplayStatusOrBuilder();
+
+  /**
+   * <pre>
+   * Only set in reply to Hello.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 8;</code>
+   * @return Whether the hello field is set.
+   */
+  boolean hasHello();
+  /**
+   * <pre>
+   * Only set in reply to Hello.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 8;</code>
+   * @return The hello.
+   */
+  vsg.Hello getHello();
+  /**
+   * <pre>
+   * Only set in reply to Hello.
+   * </pre>
+   *
+   * <code>.vsg.Hello hello = 8;</code>
+   */
+  vsg.HelloOrBuilder getHelloOrBuilder();
+
+  /**
+   * <pre>
+   * Pushed to subscribed connections outside of the request and reply
+   * flow, with sequence 0.
+   * </pre>
+   *
+   * <code>.vsg.Event event = 9;</code>
+   * @return Whether the event field is set.
+   */
+  boolean hasEvent();
+  /**
+   * <pre>
+   * Pushed to subscribed connections outside of the request and reply
+   * flow, with sequence 0.
+   * </pre>
+   *
+   * <code>.vsg.Event event = 9;</code>
+   * @return The event.
+   */
+  vsg.Event getEvent();
+  /**
+   * <pre>
+   * Pushed to subscribed connections outside of the request and reply
+   * flow, with sequence 0.
+   * </pre>
+   *
+   * <code>.vsg.Event event = 9;</code>
+   */
+  vsg.EventOrBuilder getEventOrBuilder();
+
+  /**
+   * <pre>
+   * Only set in reply to CaptureFrame.
+   * </pre>
+   *
+   * <code>.vsg.Capture capture = 10;</code>
+   * @return Whether the capture field is set.
+   */
+  boolean hasCapture();
+  /**
+   * <pre>
+   * Only set in reply to CaptureFrame.
+   * </pre>
+   *
+   * <code>.vsg.Capture capture = 10;</code>
+   * @return The capture.
+   */
+  vsg.Capture getCapture();
+  /**
+   * <pre>
+   * Only set in reply to CaptureFrame.
+   * </pre>
+   *
+   * <code>.vsg.Capture capture = 10;</code>
+   */
+  vsg.CaptureOrBuilder getCaptureOrBuilder();
+}
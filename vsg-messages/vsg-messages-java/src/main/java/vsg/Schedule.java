@@ -0,0 +1,730 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * Protobuf type {@code vsg.Schedule}
+ */
+public final class Schedule extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Schedule)
+    ScheduleOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Schedule.newBuilder() to construct.
+  private Schedule(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Schedule() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Schedule();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Schedule(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+            target_ = input.readUInt64();
+            targetCase_ = 1;
+            break;
+          }
+          case 16: {
+            target_ = input.readUInt64();
+            targetCase_ = 2;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_Schedule_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_Schedule_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.Schedule.class, vsg.Schedule.Builder.class);
+  }
+
+  private int targetCase_ = 0;
+  private java.lang.Object target_;
+  public enum TargetCase
+      implements com.google.protobuf.Internal.EnumLite,
+          com.google.protobuf.AbstractMessage.InternalOneOfEnum {
+    FRAME(1),
+    DEADLINE_US(2),
+    TARGET_NOT_SET(0);
+    private final int value;
+    private TargetCase(int value) {
+      this.value = value;
+    }
+    /**
+     * @param value The number of the enum to look for.
+     * @return The enum associated with the given number.
+     * @deprecated Use {@link #forNumber(int)} instead.
+     */
+    @java.lang.Deprecated
+    public static TargetCase valueOf(int value) {
+      return forNumber(value);
+    }
+
+    public static TargetCase forNumber(int value) {
+      switch (value) {
+        case 1: return FRAME;
+        case 2: return DEADLINE_US;
+        case 0: return TARGET_NOT_SET;
+        default: return null;
+      }
+    }
+    public int getNumber() {
+      return this.value;
+    }
+  };
+
+  public TargetCase
+  getTargetCase() {
+    return TargetCase.forNumber(
+        targetCase_);
+  }
+
+  public static final int FRAME_FIELD_NUMBER = 1;
+  /**
+   * <pre>
+   * Frame number as counted in Onset.frame.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return Whether the frame field is set.
+   */
+  @java.lang.Override
+  public boolean hasFrame() {
+    return targetCase_ == 1;
+  }
+  /**
+   * <pre>
+   * Frame number as counted in Onset.frame.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return The frame.
+   */
+  @java.lang.Override
+  public long getFrame() {
+    if (targetCase_ == 1) {
+      return (java.lang.Long) target_;
+    }
+    return 0L;
+  }
+
+  public static final int DEADLINE_US_FIELD_NUMBER = 2;
+  /**
+   * <pre>
+   * Server clock as in Onset.timestamp_us. The command is applied on
+   * the first frame boundary at or after this time.
+   * </pre>
+   *
+   * <code>uint64 deadline_us = 2;</code>
+   * @return Whether the deadlineUs field is set.
+   */
+  @java.lang.Override
+  public boolean hasDeadlineUs() {
+    return targetCase_ == 2;
+  }
+  /**
+   * <pre>
+   * Server clock as in Onset.timestamp_us. The command is applied on
+   * the first frame boundary at or after this time.
+   * </pre>
+   *
+   * <code>uint64 deadline_us = 2;</code>
+   * @return The deadlineUs.
+   */
+  @java.lang.Override
+  public long getDeadlineUs() {
+    if (targetCase_ == 2) {
+      return (java.lang.Long) target_;
+    }
+    return 0L;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (targetCase_ == 1) {
+      output.writeUInt64(
+          1, (long)((java.lang.Long) target_));
+    }
+    if (targetCase_ == 2) {
+      output.writeUInt64(
+          2, (long)((java.lang.Long) target_));
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (targetCase_ == 1) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(
+            1, (long)((java.lang.Long) target_));
+    }
+    if (targetCase_ == 2) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt64Size(
+            2, (long)((java.lang.Long) target_));
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.Schedule)) {
+      return super.equals(obj);
+    }
+    vsg.Schedule other = (vsg.Schedule) obj;
+
+    if (!getTargetCase().equals(other.getTargetCase())) return false;
+    switch (targetCase_) {
+      case 1:
+        if (getFrame()
+            != other.getFrame()) return false;
+        break;
+      case 2:
+        if (getDeadlineUs()
+            != other.getDeadlineUs()) return false;
+        break;
+      case 0:
+      default:
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    switch (targetCase_) {
+      case 1:
+        hash = (37 * hash) + FRAME_FIELD_NUMBER;
+        hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+            getFrame());
+        break;
+      case 2:
+        hash = (37 * hash) + DEADLINE_US_FIELD_NUMBER;
+        hash = (53 * hash) + com.google.protobuf.Internal.hashLong(
+            getDeadlineUs());
+        break;
+      case 0:
+      default:
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.Schedule parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Schedule parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Schedule parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Schedule parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Schedule parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.Schedule parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.Schedule parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Schedule parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Schedule parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.Schedule parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.Schedule parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.Schedule parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.Schedule prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * Protobuf type {@code vsg.Schedule}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Schedule)
+      vsg.ScheduleOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_Schedule_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_Schedule_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.Schedule.class, vsg.Schedule.Builder.class);
+    }
+
+    // Construct using vsg.Schedule.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      targetCase_ = 0;
+      target_ = null;
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_Schedule_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.Schedule getDefaultInstanceForType() {
+      return vsg.Schedule.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.Schedule build() {
+      vsg.Schedule result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.Schedule buildPartial() {
+      vsg.Schedule result = new vsg.Schedule(this);
+      if (targetCase_ == 1) {
+        result.target_ = target_;
+      }
+      if (targetCase_ == 2) {
+        result.target_ = target_;
+      }
+      result.targetCase_ = targetCase_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.Schedule) {
+        return mergeFrom((vsg.Schedule)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.Schedule other) {
+      if (other == vsg.Schedule.getDefaultInstance()) return this;
+      switch (other.getTargetCase()) {
+        case FRAME: {
+          setFrame(other.getFrame());
+          break;
+        }
+        case DEADLINE_US: {
+          setDeadlineUs(other.getDeadlineUs());
+          break;
+        }
+        case TARGET_NOT_SET: {
+          break;
+        }
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.Schedule parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.Schedule) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int targetCase_ = 0;
+    private java.lang.Object target_;
+    public TargetCase
+        getTargetCase() {
+      return TargetCase.forNumber(
+          targetCase_);
+    }
+
+    public Builder clearTarget() {
+      targetCase_ = 0;
+      target_ = null;
+      onChanged();
+      return this;
+    }
+
+
+    /**
+     * <pre>
+     * Frame number as counted in Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return Whether the frame field is set.
+     */
+    public boolean hasFrame() {
+      return targetCase_ == 1;
+    }
+    /**
+     * <pre>
+     * Frame number as counted in Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return The frame.
+     */
+    public long getFrame() {
+      if (targetCase_ == 1) {
+        return (java.lang.Long) target_;
+      }
+      return 0L;
+    }
+    /**
+     * <pre>
+     * Frame number as counted in Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @param value The frame to set.
+     * @return This builder for chaining.
+     */
+    public Builder setFrame(long value) {
+      targetCase_ = 1;
+      target_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Frame number as counted in Onset.frame.
+     * </pre>
+     *
+     * <code>uint64 frame = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearFrame() {
+      if (targetCase_ == 1) {
+        targetCase_ = 0;
+        target_ = null;
+        onChanged();
+      }
+      return this;
+    }
+
+    /**
+     * <pre>
+     * Server clock as in Onset.timestamp_us. The command is applied on
+     * the first frame boundary at or after this time.
+     * </pre>
+     *
+     * <code>uint64 deadline_us = 2;</code>
+     * @return Whether the deadlineUs field is set.
+     */
+    public boolean hasDeadlineUs() {
+      return targetCase_ == 2;
+    }
+    /**
+     * <pre>
+     * Server clock as in Onset.timestamp_us. The command is applied on
+     * the first frame boundary at or after this time.
+     * </pre>
+     *
+     * <code>uint64 deadline_us = 2;</code>
+     * @return The deadlineUs.
+     */
+    public long getDeadlineUs() {
+      if (targetCase_ == 2) {
+        return (java.lang.Long) target_;
+      }
+      return 0L;
+    }
+    /**
+     * <pre>
+     * Server clock as in Onset.timestamp_us. The command is applied on
+     * the first frame boundary at or after this time.
+     * </pre>
+     *
+     * <code>uint64 deadline_us = 2;</code>
+     * @param value The deadlineUs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setDeadlineUs(long value) {
+      targetCase_ = 2;
+      target_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Server clock as in Onset.timestamp_us. The command is applied on
+     * the first frame boundary at or after this time.
+     * </pre>
+     *
+     * <code>uint64 deadline_us = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearDeadlineUs() {
+      if (targetCase_ == 2) {
+        targetCase_ = 0;
+        target_ = null;
+        onChanged();
+      }
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Schedule)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Schedule)
+  private static final vsg.Schedule DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.Schedule();
+  }
+
+  public static vsg.Schedule getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Schedule>
+      PARSER = new com.google.protobuf.AbstractParser<Schedule>() {
+    @java.lang.Override
+    public Schedule parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Schedule(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Schedule> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Schedule> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.Schedule getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,51 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface ScheduleOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Schedule)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Frame number as counted in Onset.frame.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return Whether the frame field is set.
+   */
+  boolean hasFrame();
+  /**
+   * <pre>
+   * Frame number as counted in Onset.frame.
+   * </pre>
+   *
+   * <code>uint64 frame = 1;</code>
+   * @return The frame.
+   */
+  long getFrame();
+
+  /**
+   * <pre>
+   * Server clock as in Onset.timestamp_us. The command is applied on
+   * the first frame boundary at or after this time.
+   * </pre>
+   *
+   * <code>uint64 deadline_us = 2;</code>
+   * @return Whether the deadlineUs field is set.
+   */
+  boolean hasDeadlineUs();
+  /**
+   * <pre>
+   * Server clock as in Onset.timestamp_us. The command is applied on
+   * the first frame boundary at or after this time.
+   * </pre>
+   *
+   * <code>uint64 deadline_us = 2;</code>
+   * @return The deadlineUs.
+   */
+  long getDeadlineUs();
+
+  public vsg.Schedule.TargetCase getTargetCase();
+}
@@ -0,0 +1,1546 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Replaces the whole display on a single frame.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.SetScene}
+ */
+public final class SetScene extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.SetScene)
+    SetSceneOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use SetScene.newBuilder() to construct.
+  private SetScene(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private SetScene() {
+    stimuli_ = java.util.Collections.emptyList();
+    textures_ = java.util.Collections.emptyList();
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new SetScene();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private SetScene(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    int mutable_bitField0_ = 0;
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 10: {
+            if (!((mutable_bitField0_ & 0x00000001) != 0)) {
+              stimuli_ = new java.util.ArrayList<vsg.shapes.Stimulus>();
+              mutable_bitField0_ |= 0x00000001;
+            }
+            stimuli_.add(
+                input.readMessage(vsg.shapes.Stimulus.parser(), extensionRegistry));
+            break;
+          }
+          case 18: {
+            if (!((mutable_bitField0_ & 0x00000002) != 0)) {
+              textures_ = new java.util.ArrayList<vsg.Texture>();
+              mutable_bitField0_ |= 0x00000002;
+            }
+            textures_.add(
+                input.readMessage(vsg.Texture.parser(), extensionRegistry));
+            break;
+          }
+          case 26: {
+            vsg.BgColor.Builder subBuilder = null;
+            if (bgColor_ != null) {
+              subBuilder = bgColor_.toBuilder();
+            }
+            bgColor_ = input.readMessage(vsg.BgColor.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(bgColor_);
+              bgColor_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      if (((mutable_bitField0_ & 0x00000001) != 0)) {
+        stimuli_ = java.util.Collections.unmodifiableList(stimuli_);
+      }
+      if (((mutable_bitField0_ & 0x00000002) != 0)) {
+        textures_ = java.util.Collections.unmodifiableList(textures_);
+      }
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_SetScene_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_SetScene_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.SetScene.class, vsg.SetScene.Builder.class);
+  }
+
+  public static final int STIMULI_FIELD_NUMBER = 1;
+  private java.util.List<vsg.shapes.Stimulus> stimuli_;
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  @java.lang.Override
+  public java.util.List<vsg.shapes.Stimulus> getStimuliList() {
+    return stimuli_;
+  }
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  @java.lang.Override
+  public java.util.List<? extends vsg.shapes.StimulusOrBuilder> 
+      getStimuliOrBuilderList() {
+    return stimuli_;
+  }
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  @java.lang.Override
+  public int getStimuliCount() {
+    return stimuli_.size();
+  }
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.Stimulus getStimuli(int index) {
+    return stimuli_.get(index);
+  }
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.StimulusOrBuilder getStimuliOrBuilder(
+      int index) {
+    return stimuli_.get(index);
+  }
+
+  public static final int TEXTURES_FIELD_NUMBER = 2;
+  private java.util.List<vsg.Texture> textures_;
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  @java.lang.Override
+  public java.util.List<vsg.Texture> getTexturesList() {
+    return textures_;
+  }
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  @java.lang.Override
+  public java.util.List<? extends vsg.TextureOrBuilder> 
+      getTexturesOrBuilderList() {
+    return textures_;
+  }
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  @java.lang.Override
+  public int getTexturesCount() {
+    return textures_.size();
+  }
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.Texture getTextures(int index) {
+    return textures_.get(index);
+  }
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.TextureOrBuilder getTexturesOrBuilder(
+      int index) {
+    return textures_.get(index);
+  }
+
+  public static final int BG_COLOR_FIELD_NUMBER = 3;
+  private vsg.BgColor bgColor_;
+  /**
+   * <pre>
+   * Left unchanged when not set.
+   * </pre>
+   *
+   * <code>.vsg.BgColor bg_color = 3;</code>
+   * @return Whether the bgColor field is set.
+   */
+  @java.lang.Override
+  public boolean hasBgColor() {
+    return bgColor_ != null;
+  }
+  /**
+   * <pre>
+   * Left unchanged when not set.
+   * </pre>
+   *
+   * <code>.vsg.BgColor bg_color = 3;</code>
+   * @return The bgColor.
+   */
+  @java.lang.Override
+  public vsg.BgColor getBgColor() {
+    return bgColor_ == null ? vsg.BgColor.getDefaultInstance() : bgColor_;
+  }
+  /**
+   * <pre>
+   * Left unchanged when not set.
+   * </pre>
+   *
+   * <code>.vsg.BgColor bg_color = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.BgColorOrBuilder getBgColorOrBuilder() {
+    return getBgColor();
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    for (int i = 0; i < stimuli_.size(); i++) {
+      output.writeMessage(1, stimuli_.get(i));
+    }
+    for (int i = 0; i < textures_.size(); i++) {
+      output.writeMessage(2, textures_.get(i));
+    }
+    if (bgColor_ != null) {
+      output.writeMessage(3, getBgColor());
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    for (int i = 0; i < stimuli_.size(); i++) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(1, stimuli_.get(i));
+    }
+    for (int i = 0; i < textures_.size(); i++) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, textures_.get(i));
+    }
+    if (bgColor_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, getBgColor());
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.SetScene)) {
+      return super.equals(obj);
+    }
+    vsg.SetScene other = (vsg.SetScene) obj;
+
+    if (!getStimuliList()
+        .equals(other.getStimuliList())) return false;
+    if (!getTexturesList()
+        .equals(other.getTexturesList())) return false;
+    if (hasBgColor() != other.hasBgColor()) return false;
+    if (hasBgColor()) {
+      if (!getBgColor()
+          .equals(other.getBgColor())) return false;
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    if (getStimuliCount() > 0) {
+      hash = (37 * hash) + STIMULI_FIELD_NUMBER;
+      hash = (53 * hash) + getStimuliList().hashCode();
+    }
+    if (getTexturesCount() > 0) {
+      hash = (37 * hash) + TEXTURES_FIELD_NUMBER;
+      hash = (53 * hash) + getTexturesList().hashCode();
+    }
+    if (hasBgColor()) {
+      hash = (37 * hash) + BG_COLOR_FIELD_NUMBER;
+      hash = (53 * hash) + getBgColor().hashCode();
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.SetScene parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.SetScene parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.SetScene parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.SetScene parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.SetScene parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.SetScene parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.SetScene parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.SetScene parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.SetScene parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.SetScene parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.SetScene parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.SetScene parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.SetScene prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Replaces the whole display on a single frame.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.SetScene}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.SetScene)
+      vsg.SetSceneOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_SetScene_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_SetScene_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.SetScene.class, vsg.SetScene.Builder.class);
+    }
+
+    // Construct using vsg.SetScene.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+        getStimuliFieldBuilder();
+        getTexturesFieldBuilder();
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      if (stimuliBuilder_ == null) {
+        stimuli_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000001);
+      } else {
+        stimuliBuilder_.clear();
+      }
+      if (texturesBuilder_ == null) {
+        textures_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000002);
+      } else {
+        texturesBuilder_.clear();
+      }
+      if (bgColorBuilder_ == null) {
+        bgColor_ = null;
+      } else {
+        bgColor_ = null;
+        bgColorBuilder_ = null;
+      }
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_SetScene_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.SetScene getDefaultInstanceForType() {
+      return vsg.SetScene.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.SetScene build() {
+      vsg.SetScene result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.SetScene buildPartial() {
+      vsg.SetScene result = new vsg.SetScene(this);
+      int from_bitField0_ = bitField0_;
+      if (stimuliBuilder_ == null) {
+        if (((bitField0_ & 0x00000001) != 0)) {
+          stimuli_ = java.util.Collections.unmodifiableList(stimuli_);
+          bitField0_ = (bitField0_ & ~0x00000001);
+        }
+        result.stimuli_ = stimuli_;
+      } else {
+        result.stimuli_ = stimuliBuilder_.build();
+      }
+      if (texturesBuilder_ == null) {
+        if (((bitField0_ & 0x00000002) != 0)) {
+          textures_ = java.util.Collections.unmodifiableList(textures_);
+          bitField0_ = (bitField0_ & ~0x00000002);
+        }
+        result.textures_ = textures_;
+      } else {
+        result.textures_ = texturesBuilder_.build();
+      }
+      if (bgColorBuilder_ == null) {
+        result.bgColor_ = bgColor_;
+      } else {
+        result.bgColor_ = bgColorBuilder_.build();
+      }
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.SetScene) {
+        return mergeFrom((vsg.SetScene)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.SetScene other) {
+      if (other == vsg.SetScene.getDefaultInstance()) return this;
+      if (stimuliBuilder_ == null) {
+        if (!other.stimuli_.isEmpty()) {
+          if (stimuli_.isEmpty()) {
+            stimuli_ = other.stimuli_;
+            bitField0_ = (bitField0_ & ~0x00000001);
+          } else {
+            ensureStimuliIsMutable();
+            stimuli_.addAll(other.stimuli_);
+          }
+          onChanged();
+        }
+      } else {
+        if (!other.stimuli_.isEmpty()) {
+          if (stimuliBuilder_.isEmpty()) {
+            stimuliBuilder_.dispose();
+            stimuliBuilder_ = null;
+            stimuli_ = other.stimuli_;
+            bitField0_ = (bitField0_ & ~0x00000001);
+            stimuliBuilder_ = 
+              com.google.protobuf.GeneratedMessageV3.alwaysUseFieldBuilders ?
+                 getStimuliFieldBuilder() : null;
+          } else {
+            stimuliBuilder_.addAllMessages(other.stimuli_);
+          }
+        }
+      }
+      if (texturesBuilder_ == null) {
+        if (!other.textures_.isEmpty()) {
+          if (textures_.isEmpty()) {
+            textures_ = other.textures_;
+            bitField0_ = (bitField0_ & ~0x00000002);
+          } else {
+            ensureTexturesIsMutable();
+            textures_.addAll(other.textures_);
+          }
+          onChanged();
+        }
+      } else {
+        if (!other.textures_.isEmpty()) {
+          if (texturesBuilder_.isEmpty()) {
+            texturesBuilder_.dispose();
+            texturesBuilder_ = null;
+            textures_ = other.textures_;
+            bitField0_ = (bitField0_ & ~0x00000002);
+            texturesBuilder_ = 
+              com.google.protobuf.GeneratedMessageV3.alwaysUseFieldBuilders ?
+                 getTexturesFieldBuilder() : null;
+          } else {
+            texturesBuilder_.addAllMessages(other.textures_);
+          }
+        }
+      }
+      if (other.hasBgColor()) {
+        mergeBgColor(other.getBgColor());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.SetScene parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.SetScene) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int bitField0_;
+
+    private java.util.List<vsg.shapes.Stimulus> stimuli_ =
+      java.util.Collections.emptyList();
+    private void ensureStimuliIsMutable() {
+      if (!((bitField0_ & 0x00000001) != 0)) {
+        stimuli_ = new java.util.ArrayList<vsg.shapes.Stimulus>(stimuli_);
+        bitField0_ |= 0x00000001;
+       }
+    }
+
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.shapes.Stimulus, vsg.shapes.Stimulus.Builder, vsg.shapes.StimulusOrBuilder> stimuliBuilder_;
+
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public java.util.List<vsg.shapes.Stimulus> getStimuliList() {
+      if (stimuliBuilder_ == null) {
+        return java.util.Collections.unmodifiableList(stimuli_);
+      } else {
+        return stimuliBuilder_.getMessageList();
+      }
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public int getStimuliCount() {
+      if (stimuliBuilder_ == null) {
+        return stimuli_.size();
+      } else {
+        return stimuliBuilder_.getCount();
+      }
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public vsg.shapes.Stimulus getStimuli(int index) {
+      if (stimuliBuilder_ == null) {
+        return stimuli_.get(index);
+      } else {
+        return stimuliBuilder_.getMessage(index);
+      }
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder setStimuli(
+        int index, vsg.shapes.Stimulus value) {
+      if (stimuliBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureStimuliIsMutable();
+        stimuli_.set(index, value);
+        onChanged();
+      } else {
+        stimuliBuilder_.setMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder setStimuli(
+        int index, vsg.shapes.Stimulus.Builder builderForValue) {
+      if (stimuliBuilder_ == null) {
+        ensureStimuliIsMutable();
+        stimuli_.set(index, builderForValue.build());
+        onChanged();
+      } else {
+        stimuliBuilder_.setMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder addStimuli(vsg.shapes.Stimulus value) {
+      if (stimuliBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureStimuliIsMutable();
+        stimuli_.add(value);
+        onChanged();
+      } else {
+        stimuliBuilder_.addMessage(value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder addStimuli(
+        int index, vsg.shapes.Stimulus value) {
+      if (stimuliBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureStimuliIsMutable();
+        stimuli_.add(index, value);
+        onChanged();
+      } else {
+        stimuliBuilder_.addMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder addStimuli(
+        vsg.shapes.Stimulus.Builder builderForValue) {
+      if (stimuliBuilder_ == null) {
+        ensureStimuliIsMutable();
+        stimuli_.add(builderForValue.build());
+        onChanged();
+      } else {
+        stimuliBuilder_.addMessage(builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder addStimuli(
+        int index, vsg.shapes.Stimulus.Builder builderForValue) {
+      if (stimuliBuilder_ == null) {
+        ensureStimuliIsMutable();
+        stimuli_.add(index, builderForValue.build());
+        onChanged();
+      } else {
+        stimuliBuilder_.addMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder addAllStimuli(
+        java.lang.Iterable<? extends vsg.shapes.Stimulus> values) {
+      if (stimuliBuilder_ == null) {
+        ensureStimuliIsMutable();
+        com.google.protobuf.AbstractMessageLite.Builder.addAll(
+            values, stimuli_);
+        onChanged();
+      } else {
+        stimuliBuilder_.addAllMessages(values);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder clearStimuli() {
+      if (stimuliBuilder_ == null) {
+        stimuli_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000001);
+        onChanged();
+      } else {
+        stimuliBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public Builder removeStimuli(int index) {
+      if (stimuliBuilder_ == null) {
+        ensureStimuliIsMutable();
+        stimuli_.remove(index);
+        onChanged();
+      } else {
+        stimuliBuilder_.remove(index);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public vsg.shapes.Stimulus.Builder getStimuliBuilder(
+        int index) {
+      return getStimuliFieldBuilder().getBuilder(index);
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public vsg.shapes.StimulusOrBuilder getStimuliOrBuilder(
+        int index) {
+      if (stimuliBuilder_ == null) {
+        return stimuli_.get(index);  } else {
+        return stimuliBuilder_.getMessageOrBuilder(index);
+      }
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public java.util.List<? extends vsg.shapes.StimulusOrBuilder> 
+         getStimuliOrBuilderList() {
+      if (stimuliBuilder_ != null) {
+        return stimuliBuilder_.getMessageOrBuilderList();
+      } else {
+        return java.util.Collections.unmodifiableList(stimuli_);
+      }
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public vsg.shapes.Stimulus.Builder addStimuliBuilder() {
+      return getStimuliFieldBuilder().addBuilder(
+          vsg.shapes.Stimulus.getDefaultInstance());
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public vsg.shapes.Stimulus.Builder addStimuliBuilder(
+        int index) {
+      return getStimuliFieldBuilder().addBuilder(
+          index, vsg.shapes.Stimulus.getDefaultInstance());
+    }
+    /**
+     * <pre>
+     * Become the only stimuli in the scene.
+     * </pre>
+     *
+     * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+     */
+    public java.util.List<vsg.shapes.Stimulus.Builder> 
+         getStimuliBuilderList() {
+      return getStimuliFieldBuilder().getBuilderList();
+    }
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.shapes.Stimulus, vsg.shapes.Stimulus.Builder, vsg.shapes.StimulusOrBuilder> 
+        getStimuliFieldBuilder() {
+      if (stimuliBuilder_ == null) {
+        stimuliBuilder_ = new com.google.protobuf.RepeatedFieldBuilderV3<
+            vsg.shapes.Stimulus, vsg.shapes.Stimulus.Builder, vsg.shapes.StimulusOrBuilder>(
+                stimuli_,
+                ((bitField0_ & 0x00000001) != 0),
+                getParentForChildren(),
+                isClean());
+        stimuli_ = null;
+      }
+      return stimuliBuilder_;
+    }
+
+    private java.util.List<vsg.Texture> textures_ =
+      java.util.Collections.emptyList();
+    private void ensureTexturesIsMutable() {
+      if (!((bitField0_ & 0x00000002) != 0)) {
+        textures_ = new java.util.ArrayList<vsg.Texture>(textures_);
+        bitField0_ |= 0x00000002;
+       }
+    }
+
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> texturesBuilder_;
+
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public java.util.List<vsg.Texture> getTexturesList() {
+      if (texturesBuilder_ == null) {
+        return java.util.Collections.unmodifiableList(textures_);
+      } else {
+        return texturesBuilder_.getMessageList();
+      }
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public int getTexturesCount() {
+      if (texturesBuilder_ == null) {
+        return textures_.size();
+      } else {
+        return texturesBuilder_.getCount();
+      }
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public vsg.Texture getTextures(int index) {
+      if (texturesBuilder_ == null) {
+        return textures_.get(index);
+      } else {
+        return texturesBuilder_.getMessage(index);
+      }
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder setTextures(
+        int index, vsg.Texture value) {
+      if (texturesBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureTexturesIsMutable();
+        textures_.set(index, value);
+        onChanged();
+      } else {
+        texturesBuilder_.setMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder setTextures(
+        int index, vsg.Texture.Builder builderForValue) {
+      if (texturesBuilder_ == null) {
+        ensureTexturesIsMutable();
+        textures_.set(index, builderForValue.build());
+        onChanged();
+      } else {
+        texturesBuilder_.setMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder addTextures(vsg.Texture value) {
+      if (texturesBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureTexturesIsMutable();
+        textures_.add(value);
+        onChanged();
+      } else {
+        texturesBuilder_.addMessage(value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder addTextures(
+        int index, vsg.Texture value) {
+      if (texturesBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        ensureTexturesIsMutable();
+        textures_.add(index, value);
+        onChanged();
+      } else {
+        texturesBuilder_.addMessage(index, value);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder addTextures(
+        vsg.Texture.Builder builderForValue) {
+      if (texturesBuilder_ == null) {
+        ensureTexturesIsMutable();
+        textures_.add(builderForValue.build());
+        onChanged();
+      } else {
+        texturesBuilder_.addMessage(builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder addTextures(
+        int index, vsg.Texture.Builder builderForValue) {
+      if (texturesBuilder_ == null) {
+        ensureTexturesIsMutable();
+        textures_.add(index, builderForValue.build());
+        onChanged();
+      } else {
+        texturesBuilder_.addMessage(index, builderForValue.build());
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder addAllTextures(
+        java.lang.Iterable<? extends vsg.Texture> values) {
+      if (texturesBuilder_ == null) {
+        ensureTexturesIsMutable();
+        com.google.protobuf.AbstractMessageLite.Builder.addAll(
+            values, textures_);
+        onChanged();
+      } else {
+        texturesBuilder_.addAllMessages(values);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder clearTextures() {
+      if (texturesBuilder_ == null) {
+        textures_ = java.util.Collections.emptyList();
+        bitField0_ = (bitField0_ & ~0x00000002);
+        onChanged();
+      } else {
+        texturesBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public Builder removeTextures(int index) {
+      if (texturesBuilder_ == null) {
+        ensureTexturesIsMutable();
+        textures_.remove(index);
+        onChanged();
+      } else {
+        texturesBuilder_.remove(index);
+      }
+      return this;
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public vsg.Texture.Builder getTexturesBuilder(
+        int index) {
+      return getTexturesFieldBuilder().getBuilder(index);
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public vsg.TextureOrBuilder getTexturesOrBuilder(
+        int index) {
+      if (texturesBuilder_ == null) {
+        return textures_.get(index);  } else {
+        return texturesBuilder_.getMessageOrBuilder(index);
+      }
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public java.util.List<? extends vsg.TextureOrBuilder> 
+         getTexturesOrBuilderList() {
+      if (texturesBuilder_ != null) {
+        return texturesBuilder_.getMessageOrBuilderList();
+      } else {
+        return java.util.Collections.unmodifiableList(textures_);
+      }
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public vsg.Texture.Builder addTexturesBuilder() {
+      return getTexturesFieldBuilder().addBuilder(
+          vsg.Texture.getDefaultInstance());
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public vsg.Texture.Builder addTexturesBuilder(
+        int index) {
+      return getTexturesFieldBuilder().addBuilder(
+          index, vsg.Texture.getDefaultInstance());
+    }
+    /**
+     * <pre>
+     * Become the only textures shown. Empty hides every texture.
+     * </pre>
+     *
+     * <code>repeated .vsg.Texture textures = 2;</code>
+     */
+    public java.util.List<vsg.Texture.Builder> 
+         getTexturesBuilderList() {
+      return getTexturesFieldBuilder().getBuilderList();
+    }
+    private com.google.protobuf.RepeatedFieldBuilderV3<
+        vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder> 
+        getTexturesFieldBuilder() {
+      if (texturesBuilder_ == null) {
+        texturesBuilder_ = new com.google.protobuf.RepeatedFieldBuilderV3<
+            vsg.Texture, vsg.Texture.Builder, vsg.TextureOrBuilder>(
+                textures_,
+                ((bitField0_ & 0x00000002) != 0),
+                getParentForChildren(),
+                isClean());
+        textures_ = null;
+      }
+      return texturesBuilder_;
+    }
+
+    private vsg.BgColor bgColor_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder> bgColorBuilder_;
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     * @return Whether the bgColor field is set.
+     */
+    public boolean hasBgColor() {
+      return bgColorBuilder_ != null || bgColor_ != null;
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     * @return The bgColor.
+     */
+    public vsg.BgColor getBgColor() {
+      if (bgColorBuilder_ == null) {
+        return bgColor_ == null ? vsg.BgColor.getDefaultInstance() : bgColor_;
+      } else {
+        return bgColorBuilder_.getMessage();
+      }
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    public Builder setBgColor(vsg.BgColor value) {
+      if (bgColorBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        bgColor_ = value;
+        onChanged();
+      } else {
+        bgColorBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    public Builder setBgColor(
+        vsg.BgColor.Builder builderForValue) {
+      if (bgColorBuilder_ == null) {
+        bgColor_ = builderForValue.build();
+        onChanged();
+      } else {
+        bgColorBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    public Builder mergeBgColor(vsg.BgColor value) {
+      if (bgColorBuilder_ == null) {
+        if (bgColor_ != null) {
+          bgColor_ =
+            vsg.BgColor.newBuilder(bgColor_).mergeFrom(value).buildPartial();
+        } else {
+          bgColor_ = value;
+        }
+        onChanged();
+      } else {
+        bgColorBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    public Builder clearBgColor() {
+      if (bgColorBuilder_ == null) {
+        bgColor_ = null;
+        onChanged();
+      } else {
+        bgColor_ = null;
+        bgColorBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    public vsg.BgColor.Builder getBgColorBuilder() {
+      
+      onChanged();
+      return getBgColorFieldBuilder().getBuilder();
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    public vsg.BgColorOrBuilder getBgColorOrBuilder() {
+      if (bgColorBuilder_ != null) {
+        return bgColorBuilder_.getMessageOrBuilder();
+      } else {
+        return bgColor_ == null ?
+            vsg.BgColor.getDefaultInstance() : bgColor_;
+      }
+    }
+    /**
+     * <pre>
+     * Left unchanged when not set.
+     * </pre>
+     *
+     * <code>.vsg.BgColor bg_color = 3;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder> 
+        getBgColorFieldBuilder() {
+      if (bgColorBuilder_ == null) {
+        bgColorBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.BgColor, vsg.BgColor.Builder, vsg.BgColorOrBuilder>(
+                getBgColor(),
+                getParentForChildren(),
+                isClean());
+        bgColor_ = null;
+      }
+      return bgColorBuilder_;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.SetScene)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.SetScene)
+  private static final vsg.SetScene DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.SetScene();
+  }
+
+  public static vsg.SetScene getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<SetScene>
+      PARSER = new com.google.protobuf.AbstractParser<SetScene>() {
+    @java.lang.Override
+    public SetScene parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new SetScene(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<SetScene> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<SetScene> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.SetScene getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,124 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface SetSceneOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.SetScene)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  java.util.List<vsg.shapes.Stimulus> 
+      getStimuliList();
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  vsg.shapes.Stimulus getStimuli(int index);
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  int getStimuliCount();
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  java.util.List<? extends vsg.shapes.StimulusOrBuilder> 
+      getStimuliOrBuilderList();
+  /**
+   * <pre>
+   * Become the only stimuli in the scene.
+   * </pre>
+   *
+   * <code>repeated .vsg.Stimulus stimuli = 1;</code>
+   */
+  vsg.shapes.StimulusOrBuilder getStimuliOrBuilder(
+      int index);
+
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  java.util.List<vsg.Texture> 
+      getTexturesList();
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  vsg.Texture getTextures(int index);
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  int getTexturesCount();
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  java.util.List<? extends vsg.TextureOrBuilder> 
+      getTexturesOrBuilderList();
+  /**
+   * <pre>
+   * Become the only textures shown. Empty hides every texture.
+   * </pre>
+   *
+   * <code>repeated .vsg.Texture textures = 2;</code>
+   */
+  vsg.TextureOrBuilder getTexturesOrBuilder(
+      int index);
+
+  /**
+   * <pre>
+   * Left unchanged when not set.
+   * </pre>
+   *
+   * <code>.vsg.BgColor bg_color = 3;</code>
+   * @return Whether the bgColor field is set.
+   */
+  boolean hasBgColor();
+  /**
+   * <pre>
+   * Left unchanged when not set.
+   * </pre>
+   *
+   * <code>.vsg.BgColor bg_color = 3;</code>
+   * @return The bgColor.
+   */
+  vsg.BgColor getBgColor();
+  /**
+   * <pre>
+   * Left unchanged when not set.
+   * </pre>
+   *
+   * <code>.vsg.BgColor bg_color = 3;</code>
+   */
+  vsg.BgColorOrBuilder getBgColorOrBuilder();
+}
@@ -0,0 +1,21 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface SetVisibilityOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.SetVisibility)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  int getId();
+
+  /**
+   * <code>bool visible = 2;</code>
+   * @return The visible.
+   */
+  boolean getVisible();
+}
@@ -0,0 +1,25 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface StimulusOffsetOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.StimulusOffset)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  int getId();
+
+  /**
+   * <pre>
+   * First frame without the stimulus.
+   * </pre>
+   *
+   * <code>uint64 frame = 2;</code>
+   * @return The frame.
+   */
+  long getFrame();
+}
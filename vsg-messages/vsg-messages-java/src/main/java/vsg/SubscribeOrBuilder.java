@@ -0,0 +1,38 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface SubscribeOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Subscribe)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>repeated .vsg.EventKind kinds = 1;</code>
+   * @return A list containing the kinds.
+   */
+  java.util.List<vsg.EventKind> getKindsList();
+  /**
+   * <code>repeated .vsg.EventKind kinds = 1;</code>
+   * @return The count of kinds.
+   */
+  int getKindsCount();
+  /**
+   * <code>repeated .vsg.EventKind kinds = 1;</code>
+   * @param index The index of the element to return.
+   * @return The kinds at the given index.
+   */
+  vsg.EventKind getKinds(int index);
+  /**
+   * <code>repeated .vsg.EventKind kinds = 1;</code>
+   * @return A list containing the enum numeric values on the wire for kinds.
+   */
+  java.util.List<java.lang.Integer>
+  getKindsValueList();
+  /**
+   * <code>repeated .vsg.EventKind kinds = 1;</code>
+   * @param index The index of the value to return.
+   * @return The enum numeric value on the wire of kinds at the given index.
+   */
+  int getKindsValue(int index);
+}
@@ -0,0 +1,29 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface SurfaceLostOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.SurfaceLost)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <pre>
+   * Such as "Lost" or "Outdated".
+   * </pre>
+   *
+   * <code>string reason = 1;</code>
+   * @return The reason.
+   */
+  java.lang.String getReason();
+  /**
+   * <pre>
+   * Such as "Lost" or "Outdated".
+   * </pre>
+   *
+   * <code>string reason = 1;</code>
+   * @return The bytes for reason.
+   */
+  com.google.protobuf.ByteString
+      getReasonBytes();
+}
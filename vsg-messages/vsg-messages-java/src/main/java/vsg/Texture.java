@@ -4,6 +4,11 @@
 package vsg;
 
 /**
+ * <pre>
+ * Image stored in the texture library under index. Without a source, or with
+ * empty data, it refers to the texture already stored under index.
+ * </pre>
+ *
  * Protobuf type {@code vsg.Texture}
  */
 public final class Texture extends
@@ -16,7 +21,6 @@ private static final long serialVersionUID = 0L;
     super(builder);
   }
   private Texture() {
-    data_ = com.google.protobuf.ByteString.EMPTY;
   }
 
   @java.lang.Override
@@ -55,8 +59,22 @@ private static final long serialVersionUID = 0L;
             break;
           }
           case 18: {
-
-            data_ = input.readBytes();
+            source_ = input.readBytes();
+            sourceCase_ = 2;
+            break;
+          }
+          case 26: {
+            vsg.RawPixels.Builder subBuilder = null;
+            if (sourceCase_ == 3) {
+              subBuilder = ((vsg.RawPixels) source_).toBuilder();
+            }
+            source_ =
+                input.readMessage(vsg.RawPixels.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom((vsg.RawPixels) source_);
+              source_ = subBuilder.buildPartial();
+            }
+            sourceCase_ = 3;
             break;
           }
           default: {
@@ -91,6 +109,47 @@ private static final long serialVersionUID = 0L;
             vsg.Texture.class, vsg.Texture.Builder.class);
   }
 
+  private int sourceCase_ = 0;
+  private java.lang.Object source_;
+  public enum SourceCase
+      implements com.google.protobuf.Internal.EnumLite,
+          com.google.protobuf.AbstractMessage.InternalOneOfEnum {
+    DATA(2),
+    RAW(3),
+    SOURCE_NOT_SET(0);
+    private final int value;
+    private SourceCase(int value) {
+      this.value = value;
+    }
+    /**
+     * @param value The number of the enum to look for.
+     * @return The enum associated with the given number.
+     * @deprecated Use {@link #forNumber(int)} instead.
+     */
+    @java.lang.Deprecated
+    public static SourceCase valueOf(int value) {
+      return forNumber(value);
+    }
+
+    public static SourceCase forNumber(int value) {
+      switch (value) {
+        case 2: return DATA;
+        case 3: return RAW;
+        case 0: return SOURCE_NOT_SET;
+        default: return null;
+      }
+    }
+    public int getNumber() {
+      return this.value;
+    }
+  };
+
+  public SourceCase
+  getSourceCase() {
+    return SourceCase.forNumber(
+        sourceCase_);
+  }
+
   public static final int INDEX_FIELD_NUMBER = 1;
   private int index_;
   /**
@@ -103,14 +162,63 @@ private static final long serialVersionUID = 0L;
   }
 
   public static final int DATA_FIELD_NUMBER = 2;
-  private com.google.protobuf.ByteString data_;
   /**
+   * <pre>
+   * Encoded image file such as PNG or JPEG.
+   * </pre>
+   *
+   * <code>bytes data = 2;</code>
+   * @return Whether the data field is set.
+   */
+  @java.lang.Override
+  public boolean hasData() {
+    return sourceCase_ == 2;
+  }
+  /**
+   * <pre>
+   * Encoded image file such as PNG or JPEG.
+   * </pre>
+   *
    * <code>bytes data = 2;</code>
    * @return The data.
    */
   @java.lang.Override
   public com.google.protobuf.ByteString getData() {
-    return data_;
+    if (sourceCase_ == 2) {
+      return (com.google.protobuf.ByteString) source_;
+    }
+    return com.google.protobuf.ByteString.EMPTY;
+  }
+
+  public static final int RAW_FIELD_NUMBER = 3;
+  /**
+   * <code>.vsg.RawPixels raw = 3;</code>
+   * @return Whether the raw field is set.
+   */
+  @java.lang.Override
+  public boolean hasRaw() {
+    return sourceCase_ == 3;
+  }
+  /**
+   * <code>.vsg.RawPixels raw = 3;</code>
+   * @return The raw.
+   */
+  @java.lang.Override
+  public vsg.RawPixels getRaw() {
+    if (sourceCase_ == 3) {
+       return (vsg.RawPixels) source_;
+    }
+    return vsg.RawPixels.getDefaultInstance();
+  }
+  /**
+   * <code>.vsg.RawPixels raw = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.RawPixelsOrBuilder getRawOrBuilder() {
+    if (sourceCase_ == 3) {
+       return (vsg.RawPixels) source_;
+    }
+    return vsg.RawPixels.getDefaultInstance();
   }
 
   private byte memoizedIsInitialized = -1;
@@ -130,8 +238,12 @@ private static final long serialVersionUID = 0L;
     if (index_ != 0) {
       output.writeUInt32(1, index_);
     }
-    if (!data_.isEmpty()) {
-      output.writeBytes(2, data_);
+    if (sourceCase_ == 2) {
+      output.writeBytes(
+          2, (com.google.protobuf.ByteString) source_);
+    }
+    if (sourceCase_ == 3) {
+      output.writeMessage(3, (vsg.RawPixels) source_);
     }
     unknownFields.writeTo(output);
   }
@@ -146,9 +258,14 @@ private static final long serialVersionUID = 0L;
       size += com.google.protobuf.CodedOutputStream
         .computeUInt32Size(1, index_);
     }
-    if (!data_.isEmpty()) {
+    if (sourceCase_ == 2) {
       size += com.google.protobuf.CodedOutputStream
-        .computeBytesSize(2, data_);
+        .computeBytesSize(
+            2, (com.google.protobuf.ByteString) source_);
+    }
+    if (sourceCase_ == 3) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, (vsg.RawPixels) source_);
     }
     size += unknownFields.getSerializedSize();
     memoizedSize = size;
@@ -167,8 +284,19 @@ private static final long serialVersionUID = 0L;
 
     if (getIndex()
         != other.getIndex()) return false;
-    if (!getData()
-        .equals(other.getData())) return false;
+    if (!getSourceCase().equals(other.getSourceCase())) return false;
+    switch (sourceCase_) {
+      case 2:
+        if (!getData()
+            .equals(other.getData())) return false;
+        break;
+      case 3:
+        if (!getRaw()
+            .equals(other.getRaw())) return false;
+        break;
+      case 0:
+      default:
+    }
     if (!unknownFields.equals(other.unknownFields)) return false;
     return true;
   }
@@ -182,8 +310,18 @@ private static final long serialVersionUID = 0L;
     hash = (19 * hash) + getDescriptor().hashCode();
     hash = (37 * hash) + INDEX_FIELD_NUMBER;
     hash = (53 * hash) + getIndex();
-    hash = (37 * hash) + DATA_FIELD_NUMBER;
-    hash = (53 * hash) + getData().hashCode();
+    switch (sourceCase_) {
+      case 2:
+        hash = (37 * hash) + DATA_FIELD_NUMBER;
+        hash = (53 * hash) + getData().hashCode();
+        break;
+      case 3:
+        hash = (37 * hash) + RAW_FIELD_NUMBER;
+        hash = (53 * hash) + getRaw().hashCode();
+        break;
+      case 0:
+      default:
+    }
     hash = (29 * hash) + unknownFields.hashCode();
     memoizedHashCode = hash;
     return hash;
@@ -280,6 +418,11 @@ private static final long serialVersionUID = 0L;
     return builder;
   }
   /**
+   * <pre>
+   * Image stored in the texture library under index. Without a source, or with
+   * empty data, it refers to the texture already stored under index.
+   * </pre>
+   *
    * Protobuf type {@code vsg.Texture}
    */
   public static final class Builder extends
@@ -319,8 +462,8 @@ private static final long serialVersionUID = 0L;
       super.clear();
       index_ = 0;
 
-      data_ = com.google.protobuf.ByteString.EMPTY;
-
+      sourceCase_ = 0;
+      source_ = null;
       return this;
     }
 
@@ -348,7 +491,17 @@ private static final long serialVersionUID = 0L;
     public vsg.Texture buildPartial() {
       vsg.Texture result = new vsg.Texture(this);
       result.index_ = index_;
-      result.data_ = data_;
+      if (sourceCase_ == 2) {
+        result.source_ = source_;
+      }
+      if (sourceCase_ == 3) {
+        if (rawBuilder_ == null) {
+          result.source_ = source_;
+        } else {
+          result.source_ = rawBuilder_.build();
+        }
+      }
+      result.sourceCase_ = sourceCase_;
       onBuilt();
       return result;
     }
@@ -400,8 +553,18 @@ private static final long serialVersionUID = 0L;
       if (other.getIndex() != 0) {
         setIndex(other.getIndex());
       }
-      if (other.getData() != com.google.protobuf.ByteString.EMPTY) {
-        setData(other.getData());
+      switch (other.getSourceCase()) {
+        case DATA: {
+          setData(other.getData());
+          break;
+        }
+        case RAW: {
+          mergeRaw(other.getRaw());
+          break;
+        }
+        case SOURCE_NOT_SET: {
+          break;
+        }
       }
       this.mergeUnknownFields(other.unknownFields);
       onChanged();
@@ -431,6 +594,21 @@ private static final long serialVersionUID = 0L;
       }
       return this;
     }
+    private int sourceCase_ = 0;
+    private java.lang.Object source_;
+    public SourceCase
+        getSourceCase() {
+      return SourceCase.forNumber(
+          sourceCase_);
+    }
+
+    public Builder clearSource() {
+      sourceCase_ = 0;
+      source_ = null;
+      onChanged();
+      return this;
+    }
+
 
     private int index_ ;
     /**
@@ -463,16 +641,36 @@ private static final long serialVersionUID = 0L;
       return this;
     }
 
-    private com.google.protobuf.ByteString data_ = com.google.protobuf.ByteString.EMPTY;
     /**
+     * <pre>
+     * Encoded image file such as PNG or JPEG.
+     * </pre>
+     *
+     * <code>bytes data = 2;</code>
+     * @return Whether the data field is set.
+     */
+    public boolean hasData() {
+      return sourceCase_ == 2;
+    }
+    /**
+     * <pre>
+     * Encoded image file such as PNG or JPEG.
+     * </pre>
+     *
      * <code>bytes data = 2;</code>
      * @return The data.
      */
-    @java.lang.Override
     public com.google.protobuf.ByteString getData() {
-      return data_;
+      if (sourceCase_ == 2) {
+        return (com.google.protobuf.ByteString) source_;
+      }
+      return com.google.protobuf.ByteString.EMPTY;
     }
     /**
+     * <pre>
+     * Encoded image file such as PNG or JPEG.
+     * </pre>
+     *
      * <code>bytes data = 2;</code>
      * @param value The data to set.
      * @return This builder for chaining.
@@ -481,21 +679,168 @@ private static final long serialVersionUID = 0L;
       if (value == null) {
     throw new NullPointerException();
   }
-  
-      data_ = value;
+  sourceCase_ = 2;
+      source_ = value;
       onChanged();
       return this;
     }
     /**
+     * <pre>
+     * Encoded image file such as PNG or JPEG.
+     * </pre>
+     *
      * <code>bytes data = 2;</code>
      * @return This builder for chaining.
      */
     public Builder clearData() {
-      
-      data_ = getDefaultInstance().getData();
-      onChanged();
+      if (sourceCase_ == 2) {
+        sourceCase_ = 0;
+        source_ = null;
+        onChanged();
+      }
       return this;
     }
+
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.RawPixels, vsg.RawPixels.Builder, vsg.RawPixelsOrBuilder> rawBuilder_;
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     * @return Whether the raw field is set.
+     */
+    @java.lang.Override
+    public boolean hasRaw() {
+      return sourceCase_ == 3;
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     * @return The raw.
+     */
+    @java.lang.Override
+    public vsg.RawPixels getRaw() {
+      if (rawBuilder_ == null) {
+        if (sourceCase_ == 3) {
+          return (vsg.RawPixels) source_;
+        }
+        return vsg.RawPixels.getDefaultInstance();
+      } else {
+        if (sourceCase_ == 3) {
+          return rawBuilder_.getMessage();
+        }
+        return vsg.RawPixels.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    public Builder setRaw(vsg.RawPixels value) {
+      if (rawBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        source_ = value;
+        onChanged();
+      } else {
+        rawBuilder_.setMessage(value);
+      }
+      sourceCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    public Builder setRaw(
+        vsg.RawPixels.Builder builderForValue) {
+      if (rawBuilder_ == null) {
+        source_ = builderForValue.build();
+        onChanged();
+      } else {
+        rawBuilder_.setMessage(builderForValue.build());
+      }
+      sourceCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    public Builder mergeRaw(vsg.RawPixels value) {
+      if (rawBuilder_ == null) {
+        if (sourceCase_ == 3 &&
+            source_ != vsg.RawPixels.getDefaultInstance()) {
+          source_ = vsg.RawPixels.newBuilder((vsg.RawPixels) source_)
+              .mergeFrom(value).buildPartial();
+        } else {
+          source_ = value;
+        }
+        onChanged();
+      } else {
+        if (sourceCase_ == 3) {
+          rawBuilder_.mergeFrom(value);
+        }
+        rawBuilder_.setMessage(value);
+      }
+      sourceCase_ = 3;
+      return this;
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    public Builder clearRaw() {
+      if (rawBuilder_ == null) {
+        if (sourceCase_ == 3) {
+          sourceCase_ = 0;
+          source_ = null;
+          onChanged();
+        }
+      } else {
+        if (sourceCase_ == 3) {
+          sourceCase_ = 0;
+          source_ = null;
+        }
+        rawBuilder_.clear();
+      }
+      return this;
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    public vsg.RawPixels.Builder getRawBuilder() {
+      return getRawFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    @java.lang.Override
+    public vsg.RawPixelsOrBuilder getRawOrBuilder() {
+      if ((sourceCase_ == 3) && (rawBuilder_ != null)) {
+        return rawBuilder_.getMessageOrBuilder();
+      } else {
+        if (sourceCase_ == 3) {
+          return (vsg.RawPixels) source_;
+        }
+        return vsg.RawPixels.getDefaultInstance();
+      }
+    }
+    /**
+     * <code>.vsg.RawPixels raw = 3;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.RawPixels, vsg.RawPixels.Builder, vsg.RawPixelsOrBuilder> 
+        getRawFieldBuilder() {
+      if (rawBuilder_ == null) {
+        if (!(sourceCase_ == 3)) {
+          source_ = vsg.RawPixels.getDefaultInstance();
+        }
+        rawBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.RawPixels, vsg.RawPixels.Builder, vsg.RawPixelsOrBuilder>(
+                (vsg.RawPixels) source_,
+                getParentForChildren(),
+                isClean());
+        source_ = null;
+      }
+      sourceCase_ = 3;
+      onChanged();;
+      return rawBuilder_;
+    }
     @java.lang.Override
     public final Builder setUnknownFields(
         final com.google.protobuf.UnknownFieldSet unknownFields) {
@@ -0,0 +1,15 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface TextureIndexOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.TextureIndex)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 index = 1;</code>
+   * @return The index.
+   */
+  int getIndex();
+}
@@ -14,8 +14,38 @@ public interface TextureOrBuilder extends
   int getIndex();
 
   /**
+   * <pre>
+   * Encoded image file such as PNG or JPEG.
+   * </pre>
+   *
+   * <code>bytes data = 2;</code>
+   * @return Whether the data field is set.
+   */
+  boolean hasData();
+  /**
+   * <pre>
+   * Encoded image file such as PNG or JPEG.
+   * </pre>
+   *
    * <code>bytes data = 2;</code>
    * @return The data.
    */
   com.google.protobuf.ByteString getData();
+
+  /**
+   * <code>.vsg.RawPixels raw = 3;</code>
+   * @return Whether the raw field is set.
+   */
+  boolean hasRaw();
+  /**
+   * <code>.vsg.RawPixels raw = 3;</code>
+   * @return The raw.
+   */
+  vsg.RawPixels getRaw();
+  /**
+   * <code>.vsg.RawPixels raw = 3;</code>
+   */
+  vsg.RawPixelsOrBuilder getRawOrBuilder();
+
+  public vsg.Texture.SourceCase getSourceCase();
 }
@@ -0,0 +1,679 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+/**
+ * <pre>
+ * Replaces the shape of an existing stimulus, keeping its visibility.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.UpdateStimulus}
+ */
+public final class UpdateStimulus extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.UpdateStimulus)
+    UpdateStimulusOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use UpdateStimulus.newBuilder() to construct.
+  private UpdateStimulus(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private UpdateStimulus() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new UpdateStimulus();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private UpdateStimulus(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            id_ = input.readUInt32();
+            break;
+          }
+          case 18: {
+            vsg.shapes.Shape.Builder subBuilder = null;
+            if (shape_ != null) {
+              subBuilder = shape_.toBuilder();
+            }
+            shape_ = input.readMessage(vsg.shapes.Shape.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(shape_);
+              shape_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.Vsg.internal_static_vsg_UpdateStimulus_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.Vsg.internal_static_vsg_UpdateStimulus_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.UpdateStimulus.class, vsg.UpdateStimulus.Builder.class);
+  }
+
+  public static final int ID_FIELD_NUMBER = 1;
+  private int id_;
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  @java.lang.Override
+  public int getId() {
+    return id_;
+  }
+
+  public static final int SHAPE_FIELD_NUMBER = 2;
+  private vsg.shapes.Shape shape_;
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return Whether the shape field is set.
+   */
+  @java.lang.Override
+  public boolean hasShape() {
+    return shape_ != null;
+  }
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return The shape.
+   */
+  @java.lang.Override
+  public vsg.shapes.Shape getShape() {
+    return shape_ == null ? vsg.shapes.Shape.getDefaultInstance() : shape_;
+  }
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.ShapeOrBuilder getShapeOrBuilder() {
+    return getShape();
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (id_ != 0) {
+      output.writeUInt32(1, id_);
+    }
+    if (shape_ != null) {
+      output.writeMessage(2, getShape());
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (id_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(1, id_);
+    }
+    if (shape_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, getShape());
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.UpdateStimulus)) {
+      return super.equals(obj);
+    }
+    vsg.UpdateStimulus other = (vsg.UpdateStimulus) obj;
+
+    if (getId()
+        != other.getId()) return false;
+    if (hasShape() != other.hasShape()) return false;
+    if (hasShape()) {
+      if (!getShape()
+          .equals(other.getShape())) return false;
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + ID_FIELD_NUMBER;
+    hash = (53 * hash) + getId();
+    if (hasShape()) {
+      hash = (37 * hash) + SHAPE_FIELD_NUMBER;
+      hash = (53 * hash) + getShape().hashCode();
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.UpdateStimulus parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.UpdateStimulus parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.UpdateStimulus parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.UpdateStimulus parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.UpdateStimulus parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.UpdateStimulus parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.UpdateStimulus prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * Replaces the shape of an existing stimulus, keeping its visibility.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.UpdateStimulus}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.UpdateStimulus)
+      vsg.UpdateStimulusOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.Vsg.internal_static_vsg_UpdateStimulus_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.Vsg.internal_static_vsg_UpdateStimulus_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.UpdateStimulus.class, vsg.UpdateStimulus.Builder.class);
+    }
+
+    // Construct using vsg.UpdateStimulus.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      id_ = 0;
+
+      if (shapeBuilder_ == null) {
+        shape_ = null;
+      } else {
+        shape_ = null;
+        shapeBuilder_ = null;
+      }
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.Vsg.internal_static_vsg_UpdateStimulus_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.UpdateStimulus getDefaultInstanceForType() {
+      return vsg.UpdateStimulus.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.UpdateStimulus build() {
+      vsg.UpdateStimulus result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.UpdateStimulus buildPartial() {
+      vsg.UpdateStimulus result = new vsg.UpdateStimulus(this);
+      result.id_ = id_;
+      if (shapeBuilder_ == null) {
+        result.shape_ = shape_;
+      } else {
+        result.shape_ = shapeBuilder_.build();
+      }
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.UpdateStimulus) {
+        return mergeFrom((vsg.UpdateStimulus)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.UpdateStimulus other) {
+      if (other == vsg.UpdateStimulus.getDefaultInstance()) return this;
+      if (other.getId() != 0) {
+        setId(other.getId());
+      }
+      if (other.hasShape()) {
+        mergeShape(other.getShape());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.UpdateStimulus parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.UpdateStimulus) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+
+    private int id_ ;
+    /**
+     * <code>uint32 id = 1;</code>
+     * @return The id.
+     */
+    @java.lang.Override
+    public int getId() {
+      return id_;
+    }
+    /**
+     * <code>uint32 id = 1;</code>
+     * @param value The id to set.
+     * @return This builder for chaining.
+     */
+    public Builder setId(int value) {
+      
+      id_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 id = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearId() {
+      
+      id_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private vsg.shapes.Shape shape_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> shapeBuilder_;
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     * @return Whether the shape field is set.
+     */
+    public boolean hasShape() {
+      return shapeBuilder_ != null || shape_ != null;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     * @return The shape.
+     */
+    public vsg.shapes.Shape getShape() {
+      if (shapeBuilder_ == null) {
+        return shape_ == null ? vsg.shapes.Shape.getDefaultInstance() : shape_;
+      } else {
+        return shapeBuilder_.getMessage();
+      }
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder setShape(vsg.shapes.Shape value) {
+      if (shapeBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        shape_ = value;
+        onChanged();
+      } else {
+        shapeBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder setShape(
+        vsg.shapes.Shape.Builder builderForValue) {
+      if (shapeBuilder_ == null) {
+        shape_ = builderForValue.build();
+        onChanged();
+      } else {
+        shapeBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder mergeShape(vsg.shapes.Shape value) {
+      if (shapeBuilder_ == null) {
+        if (shape_ != null) {
+          shape_ =
+            vsg.shapes.Shape.newBuilder(shape_).mergeFrom(value).buildPartial();
+        } else {
+          shape_ = value;
+        }
+        onChanged();
+      } else {
+        shapeBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder clearShape() {
+      if (shapeBuilder_ == null) {
+        shape_ = null;
+        onChanged();
+      } else {
+        shape_ = null;
+        shapeBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public vsg.shapes.Shape.Builder getShapeBuilder() {
+      
+      onChanged();
+      return getShapeFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public vsg.shapes.ShapeOrBuilder getShapeOrBuilder() {
+      if (shapeBuilder_ != null) {
+        return shapeBuilder_.getMessageOrBuilder();
+      } else {
+        return shape_ == null ?
+            vsg.shapes.Shape.getDefaultInstance() : shape_;
+      }
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> 
+        getShapeFieldBuilder() {
+      if (shapeBuilder_ == null) {
+        shapeBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder>(
+                getShape(),
+                getParentForChildren(),
+                isClean());
+        shape_ = null;
+      }
+      return shapeBuilder_;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.UpdateStimulus)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.UpdateStimulus)
+  private static final vsg.UpdateStimulus DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.UpdateStimulus();
+  }
+
+  public static vsg.UpdateStimulus getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<UpdateStimulus>
+      PARSER = new com.google.protobuf.AbstractParser<UpdateStimulus>() {
+    @java.lang.Override
+    public UpdateStimulus parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new UpdateStimulus(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<UpdateStimulus> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<UpdateStimulus> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.UpdateStimulus getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
@@ -0,0 +1,30 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: message.proto
+
+package vsg;
+
+public interface UpdateStimulusOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.UpdateStimulus)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  int getId();
+
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return Whether the shape field is set.
+   */
+  boolean hasShape();
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return The shape.
+   */
+  vsg.shapes.Shape getShape();
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   */
+  vsg.shapes.ShapeOrBuilder getShapeOrBuilder();
+}
@@ -19,16 +19,141 @@ public final class Vsg {
   static final 
     com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
       internal_static_vsg_RootMessage_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Schedule_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Schedule_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_RootResponse_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_RootResponse_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Onset_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Onset_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_StimulusOffset_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_StimulusOffset_fieldAccessorTable;
   static final com.google.protobuf.Descriptors.Descriptor
     internal_static_vsg_BgColor_descriptor;
   static final 
     com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
       internal_static_vsg_BgColor_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_UpdateStimulus_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_UpdateStimulus_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_SetVisibility_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_SetVisibility_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_RemoveStimulus_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_RemoveStimulus_fieldAccessorTable;
   static final com.google.protobuf.Descriptors.Descriptor
     internal_static_vsg_Texture_descriptor;
   static final 
     com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
       internal_static_vsg_Texture_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_RawPixels_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_RawPixels_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_TextureIndex_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_TextureIndex_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_SetScene_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_SetScene_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_CaptureFrame_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_CaptureFrame_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Capture_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Capture_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_GetStatus_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_GetStatus_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_DisplayStatus_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_DisplayStatus_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Monitor_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Monitor_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Hello_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Hello_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Subscribe_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Subscribe_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Event_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Event_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_FrameDrop_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_FrameDrop_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_SurfaceLost_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_SurfaceLost_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_CommandFailed_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_CommandFailed_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Resize_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Resize_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_InputEvent_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_InputEvent_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Key_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Key_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_MouseButton_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_MouseButton_fieldAccessorTable;
 
   public static com.google.protobuf.Descriptors.FileDescriptor
       getDescriptor() {
@@ -38,13 +163,110 @@ public final class Vsg {
       descriptor;
   static {
     java.lang.String[] descriptorData = {
-      "\n\rmessage.proto\022\003vsg\032\014shapes.proto\"\204\001\n\013R" +
+      "\n\rmessage.proto\022\003vsg\032\014shapes.proto\"\342\005\n\013R" +
       "ootMessage\022\037\n\tset_shape\030\001 \001(\0132\n.vsg.Shap" +
       "eH\000\022$\n\014set_bg_color\030\002 \001(\0132\014.vsg.BgColorH" +
-      "\000\022#\n\013set_texture\030\003 \001(\0132\014.vsg.TextureH\000B\t" +
-      "\n\007command\"\030\n\007BgColor\022\r\n\005color\030\001 \003(\002\"&\n\007T" +
-      "exture\022\r\n\005index\030\001 \001(\r\022\014\n\004data\030\002 \001(\014B\014\n\003v" +
-      "sgB\003VsgP\001b\006proto3"
+      "\000\022#\n\013set_texture\030\003 \001(\0132\014.vsg.TextureH\000\022%" +
+      "\n\014add_stimulus\030\004 \001(\0132\r.vsg.StimulusH\000\022.\n" +
+      "\017update_stimulus\030\005 \001(\0132\023.vsg.UpdateStimu" +
+      "lusH\000\022,\n\016set_visibility\030\006 \001(\0132\022.vsg.SetV" +
+      "isibilityH\000\022.\n\017remove_stimulus\030\007 \001(\0132\023.v" +
+      "sg.RemoveStimulusH\000\022\"\n\tset_scene\030\010 \001(\0132\r" +
+      ".vsg.SetSceneH\000\022\'\n\017preload_texture\030\t \001(\013" +
+      "2\014.vsg.TextureH\000\022)\n\014show_texture\030\n \001(\0132\021" +
+      ".vsg.TextureIndexH\000\022)\n\014hide_texture\030\013 \001(" +
+      "\0132\021.vsg.TextureIndexH\000\022+\n\016delete_texture" +
+      "\030\014 \001(\0132\021.vsg.TextureIndexH\000\022$\n\nget_statu" +
+      "s\030\r \001(\0132\016.vsg.GetStatusH\000\022\033\n\005hello\030\016 \001(\013" +
+      "2\n.vsg.HelloH\000\022#\n\tsubscribe\030\017 \001(\0132\016.vsg." +
+      "SubscribeH\000\022*\n\rcapture_frame\030\020 \001(\0132\021.vsg" +
+      ".CaptureFrameH\000\022\020\n\010sequence\030  \001(\004\022\020\n\010fli" +
+      "p_ack\030! \001(\010\022\037\n\010schedule\030\" \001(\0132\r.vsg.Sche" +
+      "duleB\t\n\007command\"<\n\010Schedule\022\017\n\005frame\030\001 \001" +
+      "(\004H\000\022\025\n\013deadline_us\030\002 \001(\004H\000B\010\n\006target\"\244\002" +
+      "\n\014RootResponse\022\033\n\006status\030\001 \001(\0162\013.vsg.Sta" +
+      "tus\022\"\n\nerror_code\030\002 \001(\0162\016.vsg.ErrorCode\022" +
+      "\r\n\005error\030\003 \001(\t\022\020\n\010sequence\030\004 \001(\004\022\031\n\005onse" +
+      "t\030\005 \001(\0132\n.vsg.Onset\022\026\n\016server_time_us\030\006 " +
+      "\001(\004\022*\n\016display_status\030\007 \001(\0132\022.vsg.Displa" +
+      "yStatus\022\031\n\005hello\030\010 \001(\0132\n.vsg.Hello\022\031\n\005ev" +
+      "ent\030\t \001(\0132\n.vsg.Event\022\035\n\007capture\030\n \001(\0132\014" +
+      ".vsg.Capture\"R\n\005Onset\022\r\n\005frame\030\001 \001(\004\022\024\n\014" +
+      "timestamp_us\030\002 \001(\004\022$\n\007offsets\030\003 \003(\0132\023.vs" +
+      "g.StimulusOffset\"+\n\016StimulusOffset\022\n\n\002id" +
+      "\030\001 \001(\r\022\r\n\005frame\030\002 \001(\004\"\030\n\007BgColor\022\r\n\005colo" +
+      "r\030\001 \003(\002\"7\n\016UpdateStimulus\022\n\n\002id\030\001 \001(\r\022\031\n" +
+      "\005shape\030\002 \001(\0132\n.vsg.Shape\",\n\rSetVisibilit" +
+      "y\022\n\n\002id\030\001 \001(\r\022\017\n\007visible\030\002 \001(\010\"\034\n\016Remove" +
+      "Stimulus\022\n\n\002id\030\001 \001(\r\"Q\n\007Texture\022\r\n\005index" +
+      "\030\001 \001(\r\022\016\n\004data\030\002 \001(\014H\000\022\035\n\003raw\030\003 \001(\0132\016.vs" +
+      "g.RawPixelsH\000B\010\n\006source\"j\n\tRawPixels\022\r\n\005" +
+      "width\030\001 \001(\r\022\016\n\006height\030\002 \001(\r\022 \n\006format\030\003 " +
+      "\001(\0162\020.vsg.PixelFormat\022\016\n\006stride\030\004 \001(\r\022\014\n" +
+      "\004data\030\005 \001(\014\"\035\n\014TextureIndex\022\r\n\005index\030\001 \001" +
+      "(\r\"j\n\010SetScene\022\036\n\007stimuli\030\001 \003(\0132\r.vsg.St" +
+      "imulus\022\036\n\010textures\030\002 \003(\0132\014.vsg.Texture\022\036" +
+      "\n\010bg_color\030\003 \001(\0132\014.vsg.BgColor\"@\n\014Captur" +
+      "eFrame\022\"\n\006format\030\001 \001(\0162\022.vsg.CaptureForm" +
+      "at\022\014\n\004path\030\002 \001(\t\"h\n\007Capture\022\r\n\005width\030\001 \001" +
+      "(\r\022\016\n\006height\030\002 \001(\r\022\"\n\006format\030\003 \001(\0162\022.vsg" +
+      ".CaptureFormat\022\014\n\004data\030\004 \001(\014\022\014\n\004path\030\005 \001" +
+      "(\t\"\013\n\tGetStatus\"\356\001\n\rDisplayStatus\022\024\n\014ada" +
+      "pter_name\030\001 \001(\t\022\017\n\007backend\030\002 \001(\t\022\026\n\016surf" +
+      "ace_format\030\003 \001(\t\022\r\n\005width\030\004 \001(\r\022\016\n\006heigh" +
+      "t\030\005 \001(\r\022\024\n\014scale_factor\030\006 \001(\001\022\027\n\017refresh" +
+      "_rate_hz\030\007 \001(\001\022\036\n\010monitors\030\010 \003(\0132\014.vsg.M" +
+      "onitor\022\030\n\020frames_presented\030\t \001(\004\022\026\n\016fram" +
+      "es_skipped\030\n \001(\004\"\224\001\n\007Monitor\022\014\n\004name\030\001 \001" +
+      "(\t\022\r\n\005width\030\002 \001(\r\022\016\n\006height\030\003 \001(\r\022\t\n\001x\030\004" +
+      " \001(\005\022\t\n\001y\030\005 \001(\005\022\024\n\014scale_factor\030\006 \001(\001\022\037\n" +
+      "\027refresh_rate_millihertz\030\007 \001(\r\022\017\n\007curren" +
+      "t\030\010 \001(\010\"B\n\005Hello\022\030\n\020protocol_version\030\001 \001" +
+      "(\r\022\020\n\010features\030\002 \003(\t\022\r\n\005agent\030\003 \001(\t\"*\n\tS" +
+      "ubscribe\022\035\n\005kinds\030\001 \003(\0162\016.vsg.EventKind\"" +
+      "\250\002\n\005Event\022\024\n\014timestamp_us\030\001 \001(\004\022 \n\005input" +
+      "\030\002 \001(\0132\017.vsg.InputEventH\000\022$\n\nframe_drop\030" +
+      "\003 \001(\0132\016.vsg.FrameDropH\000\022\033\n\005onset\030\004 \001(\0132\n" +
+      ".vsg.OnsetH\000\022%\n\006offset\030\005 \001(\0132\023.vsg.Stimu" +
+      "lusOffsetH\000\022(\n\014surface_lost\030\006 \001(\0132\020.vsg." +
+      "SurfaceLostH\000\022\035\n\006resize\030\007 \001(\0132\013.vsg.Resi" +
+      "zeH\000\022,\n\016command_failed\030\010 \001(\0132\022.vsg.Comma" +
+      "ndFailedH\000B\006\n\004kind\"/\n\tFrameDrop\022\r\n\005frame" +
+      "\030\001 \001(\004\022\023\n\013interval_us\030\002 \001(\004\"\035\n\013SurfaceLo" +
+      "st\022\016\n\006reason\030\001 \001(\t\"T\n\rCommandFailed\022\020\n\010s" +
+      "equence\030\001 \001(\004\022\"\n\nerror_code\030\002 \001(\0162\016.vsg." +
+      "ErrorCode\022\r\n\005error\030\003 \001(\t\"=\n\006Resize\022\r\n\005wi" +
+      "dth\030\001 \001(\r\022\016\n\006height\030\002 \001(\r\022\024\n\014scale_facto" +
+      "r\030\003 \001(\001\"\304\001\n\nInputEvent\022\017\n\007pressed\030\001 \001(\010\022" +
+      "\027\n\003key\030\002 \001(\0132\010.vsg.KeyH\000\022(\n\014mouse_button" +
+      "\030\003 \001(\0132\020.vsg.MouseButtonH\000\022\030\n\013onset_fram" +
+      "e\030\004 \001(\004H\001\210\001\001\022\033\n\016since_onset_us\030\005 \001(\004H\002\210\001" +
+      "\001B\010\n\006sourceB\016\n\014_onset_frameB\021\n\017_since_on" +
+      "set_us\"%\n\003Key\022\014\n\004name\030\001 \001(\t\022\020\n\010scancode\030" +
+      "\002 \001(\r\"?\n\013MouseButton\022\014\n\004name\030\001 \001(\t\022\"\n\010po" +
+      "sition\030\002 \001(\0132\020.vsg.Coordinates*)\n\006Status" +
+      "\022\r\n\tSTATUS_OK\020\000\022\020\n\014STATUS_ERROR\020\001*\334\002\n\tEr" +
+      "rorCode\022\023\n\017ERROR_CODE_NONE\020\000\022\025\n\021ERROR_CO" +
+      "DE_DECODE\020\001\022\036\n\032ERROR_CODE_UNKNOWN_COMMAN" +
+      "D\020\002\022\037\n\033ERROR_CODE_INVALID_ARGUMENT\020\003\022\030\n\024" +
+      "ERROR_CODE_NOT_FOUND\020\004\022\035\n\031ERROR_CODE_ALR" +
+      "EADY_EXISTS\020\005\022 \n\034ERROR_CODE_TEXTURE_NOT_" +
+      "FOUND\020\006\022\037\n\033ERROR_CODE_VERSION_MISMATCH\020\007" +
+      "\022\"\n\036ERROR_CODE_UNSUPPORTED_FEATURE\020\010\022 \n\034" +
+      "ERROR_CODE_MESSAGE_TOO_LARGE\020\t\022 \n\034ERROR_" +
+      "CODE_PERMISSION_DENIED\020\n*j\n\013PixelFormat\022" +
+      "\026\n\022PIXEL_FORMAT_RGBA8\020\000\022\023\n\017PIXEL_FORMAT_" +
+      "R8\020\001\022\024\n\020PIXEL_FORMAT_R16\020\002\022\030\n\024PIXEL_FORM" +
+      "AT_RGBA32F\020\003*@\n\rCaptureFormat\022\026\n\022CAPTURE" +
+      "_FORMAT_PNG\020\000\022\027\n\023CAPTURE_FORMAT_RGBA\020\001*Q" +
+      "\n\017ProtocolVersion\022 \n\034PROTOCOL_VERSION_UN" +
+      "SPECIFIED\020\000\022\034\n\030PROTOCOL_VERSION_CURRENT\020" +
+      "\001*\271\001\n\tEventKind\022\032\n\026EVENT_KIND_UNSPECIFIE" +
+      "D\020\000\022\024\n\020EVENT_KIND_INPUT\020\001\022\031\n\025EVENT_KIND_" +
+      "FRAME_DROP\020\002\022\024\n\020EVENT_KIND_ONSET\020\003\022\025\n\021EV" +
+      "ENT_KIND_OFFSET\020\004\022\033\n\027EVENT_KIND_SURFACE_" +
+      "LOST\020\005\022\025\n\021EVENT_KIND_RESIZE\020\006B\014\n\003vsgB\003Vs" +
+      "gP\001b\006proto3"
     };
     descriptor = com.google.protobuf.Descriptors.FileDescriptor
       .internalBuildGeneratedFileFrom(descriptorData,
@@ -56,19 +278,169 @@ public final class Vsg {
     internal_static_vsg_RootMessage_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_RootMessage_descriptor,
-        new java.lang.String[] { "SetShape", "SetBgColor", "SetTexture", "Command", });
-    internal_static_vsg_BgColor_descriptor =
+        new java.lang.String[] { "SetShape", "SetBgColor", "SetTexture", "AddStimulus", "UpdateStimulus", "SetVisibility", "RemoveStimulus", "SetScene", "PreloadTexture", "ShowTexture", "HideTexture", "DeleteTexture", "GetStatus", "Hello", "Subscribe", "CaptureFrame", "Sequence", "FlipAck", "Schedule", "Command", });
+    internal_static_vsg_Schedule_descriptor =
       getDescriptor().getMessageTypes().get(1);
+    internal_static_vsg_Schedule_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Schedule_descriptor,
+        new java.lang.String[] { "Frame", "DeadlineUs", "Target", });
+    internal_static_vsg_RootResponse_descriptor =
+      getDescriptor().getMessageTypes().get(2);
+    internal_static_vsg_RootResponse_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_RootResponse_descriptor,
+        new java.lang.String[] { "Status", "ErrorCode", "Error", "Sequence", "Onset", "ServerTimeUs", "DisplayStatus", "Hello", "Event", "Capture", });
+    internal_static_vsg_Onset_descriptor =
+      getDescriptor().getMessageTypes().get(3);
+    internal_static_vsg_Onset_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Onset_descriptor,
+        new java.lang.String[] { "Frame", "TimestampUs", "Offsets", });
+    internal_static_vsg_StimulusOffset_descriptor =
+      getDescriptor().getMessageTypes().get(4);
+    internal_static_vsg_StimulusOffset_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_StimulusOffset_descriptor,
+        new java.lang.String[] { "Id", "Frame", });
+    internal_static_vsg_BgColor_descriptor =
+      getDescriptor().getMessageTypes().get(5);
     internal_static_vsg_BgColor_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_BgColor_descriptor,
         new java.lang.String[] { "Color", });
+    internal_static_vsg_UpdateStimulus_descriptor =
+      getDescriptor().getMessageTypes().get(6);
+    internal_static_vsg_UpdateStimulus_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_UpdateStimulus_descriptor,
+        new java.lang.String[] { "Id", "Shape", });
+    internal_static_vsg_SetVisibility_descriptor =
+      getDescriptor().getMessageTypes().get(7);
+    internal_static_vsg_SetVisibility_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_SetVisibility_descriptor,
+        new java.lang.String[] { "Id", "Visible", });
+    internal_static_vsg_RemoveStimulus_descriptor =
+      getDescriptor().getMessageTypes().get(8);
+    internal_static_vsg_RemoveStimulus_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_RemoveStimulus_descriptor,
+        new java.lang.String[] { "Id", });
     internal_static_vsg_Texture_descriptor =
-      getDescriptor().getMessageTypes().get(2);
+      getDescriptor().getMessageTypes().get(9);
     internal_static_vsg_Texture_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_Texture_descriptor,
-        new java.lang.String[] { "Index", "Data", });
+        new java.lang.String[] { "Index", "Data", "Raw", "Source", });
+    internal_static_vsg_RawPixels_descriptor =
+      getDescriptor().getMessageTypes().get(10);
+    internal_static_vsg_RawPixels_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_RawPixels_descriptor,
+        new java.lang.String[] { "Width", "Height", "Format", "Stride", "Data", });
+    internal_static_vsg_TextureIndex_descriptor =
+      getDescriptor().getMessageTypes().get(11);
+    internal_static_vsg_TextureIndex_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_TextureIndex_descriptor,
+        new java.lang.String[] { "Index", });
+    internal_static_vsg_SetScene_descriptor =
+      getDescriptor().getMessageTypes().get(12);
+    internal_static_vsg_SetScene_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_SetScene_descriptor,
+        new java.lang.String[] { "Stimuli", "Textures", "BgColor", });
+    internal_static_vsg_CaptureFrame_descriptor =
+      getDescriptor().getMessageTypes().get(13);
+    internal_static_vsg_CaptureFrame_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_CaptureFrame_descriptor,
+        new java.lang.String[] { "Format", "Path", });
+    internal_static_vsg_Capture_descriptor =
+      getDescriptor().getMessageTypes().get(14);
+    internal_static_vsg_Capture_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Capture_descriptor,
+        new java.lang.String[] { "Width", "Height", "Format", "Data", "Path", });
+    internal_static_vsg_GetStatus_descriptor =
+      getDescriptor().getMessageTypes().get(15);
+    internal_static_vsg_GetStatus_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_GetStatus_descriptor,
+        new java.lang.String[] { });
+    internal_static_vsg_DisplayStatus_descriptor =
+      getDescriptor().getMessageTypes().get(16);
+    internal_static_vsg_DisplayStatus_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_DisplayStatus_descriptor,
+        new java.lang.String[] { "AdapterName", "Backend", "SurfaceFormat", "Width", "Height", "ScaleFactor", "RefreshRateHz", "Monitors", "FramesPresented", "FramesSkipped", });
+    internal_static_vsg_Monitor_descriptor =
+      getDescriptor().getMessageTypes().get(17);
+    internal_static_vsg_Monitor_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Monitor_descriptor,
+        new java.lang.String[] { "Name", "Width", "Height", "X", "Y", "ScaleFactor", "RefreshRateMillihertz", "Current", });
+    internal_static_vsg_Hello_descriptor =
+      getDescriptor().getMessageTypes().get(18);
+    internal_static_vsg_Hello_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Hello_descriptor,
+        new java.lang.String[] { "ProtocolVersion", "Features", "Agent", });
+    internal_static_vsg_Subscribe_descriptor =
+      getDescriptor().getMessageTypes().get(19);
+    internal_static_vsg_Subscribe_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Subscribe_descriptor,
+        new java.lang.String[] { "Kinds", });
+    internal_static_vsg_Event_descriptor =
+      getDescriptor().getMessageTypes().get(20);
+    internal_static_vsg_Event_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Event_descriptor,
+        new java.lang.String[] { "TimestampUs", "Input", "FrameDrop", "Onset", "Offset", "SurfaceLost", "Resize", "CommandFailed", "Kind", });
+    internal_static_vsg_FrameDrop_descriptor =
+      getDescriptor().getMessageTypes().get(21);
+    internal_static_vsg_FrameDrop_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_FrameDrop_descriptor,
+        new java.lang.String[] { "Frame", "IntervalUs", });
+    internal_static_vsg_SurfaceLost_descriptor =
+      getDescriptor().getMessageTypes().get(22);
+    internal_static_vsg_SurfaceLost_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_SurfaceLost_descriptor,
+        new java.lang.String[] { "Reason", });
+    internal_static_vsg_CommandFailed_descriptor =
+      getDescriptor().getMessageTypes().get(23);
+    internal_static_vsg_CommandFailed_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_CommandFailed_descriptor,
+        new java.lang.String[] { "Sequence", "ErrorCode", "Error", });
+    internal_static_vsg_Resize_descriptor =
+      getDescriptor().getMessageTypes().get(24);
+    internal_static_vsg_Resize_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Resize_descriptor,
+        new java.lang.String[] { "Width", "Height", "ScaleFactor", });
+    internal_static_vsg_InputEvent_descriptor =
+      getDescriptor().getMessageTypes().get(25);
+    internal_static_vsg_InputEvent_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_InputEvent_descriptor,
+        new java.lang.String[] { "Pressed", "Key", "MouseButton", "OnsetFrame", "SinceOnsetUs", "Source", "OnsetFrame", "SinceOnsetUs", });
+    internal_static_vsg_Key_descriptor =
+      getDescriptor().getMessageTypes().get(26);
+    internal_static_vsg_Key_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Key_descriptor,
+        new java.lang.String[] { "Name", "Scancode", });
+    internal_static_vsg_MouseButton_descriptor =
+      getDescriptor().getMessageTypes().get(27);
+    internal_static_vsg_MouseButton_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_MouseButton_descriptor,
+        new java.lang.String[] { "Name", "Position", });
     vsg.shapes.VsgShapes.getDescriptor();
   }
 
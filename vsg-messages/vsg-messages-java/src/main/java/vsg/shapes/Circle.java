@@ -66,6 +66,19 @@ private static final long serialVersionUID = 0L;
 
             break;
           }
+          case 26: {
+            vsg.shapes.Color.Builder subBuilder = null;
+            if (color_ != null) {
+              subBuilder = color_.toBuilder();
+            }
+            color_ = input.readMessage(vsg.shapes.Color.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(color_);
+              color_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
           default: {
             if (!parseUnknownField(
                 input, unknownFields, extensionRegistry, tag)) {
@@ -135,6 +148,32 @@ private static final long serialVersionUID = 0L;
     return getCtr();
   }
 
+  public static final int COLOR_FIELD_NUMBER = 3;
+  private vsg.shapes.Color color_;
+  /**
+   * <code>.vsg.Color color = 3;</code>
+   * @return Whether the color field is set.
+   */
+  @java.lang.Override
+  public boolean hasColor() {
+    return color_ != null;
+  }
+  /**
+   * <code>.vsg.Color color = 3;</code>
+   * @return The color.
+   */
+  @java.lang.Override
+  public vsg.shapes.Color getColor() {
+    return color_ == null ? vsg.shapes.Color.getDefaultInstance() : color_;
+  }
+  /**
+   * <code>.vsg.Color color = 3;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.ColorOrBuilder getColorOrBuilder() {
+    return getColor();
+  }
+
   private byte memoizedIsInitialized = -1;
   @java.lang.Override
   public final boolean isInitialized() {
@@ -149,12 +188,15 @@ private static final long serialVersionUID = 0L;
   @java.lang.Override
   public void writeTo(com.google.protobuf.CodedOutputStream output)
                       throws java.io.IOException {
-    if (radius_ != 0F) {
+    if (java.lang.Float.floatToRawIntBits(radius_) != 0) {
       output.writeFloat(1, radius_);
     }
     if (ctr_ != null) {
       output.writeMessage(2, getCtr());
     }
+    if (color_ != null) {
+      output.writeMessage(3, getColor());
+    }
     unknownFields.writeTo(output);
   }
 
@@ -164,7 +206,7 @@ private static final long serialVersionUID = 0L;
     if (size != -1) return size;
 
     size = 0;
-    if (radius_ != 0F) {
+    if (java.lang.Float.floatToRawIntBits(radius_) != 0) {
       size += com.google.protobuf.CodedOutputStream
         .computeFloatSize(1, radius_);
     }
@@ -172,6 +214,10 @@ private static final long serialVersionUID = 0L;
       size += com.google.protobuf.CodedOutputStream
         .computeMessageSize(2, getCtr());
     }
+    if (color_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(3, getColor());
+    }
     size += unknownFields.getSerializedSize();
     memoizedSize = size;
     return size;
@@ -195,6 +241,11 @@ private static final long serialVersionUID = 0L;
       if (!getCtr()
           .equals(other.getCtr())) return false;
     }
+    if (hasColor() != other.hasColor()) return false;
+    if (hasColor()) {
+      if (!getColor()
+          .equals(other.getColor())) return false;
+    }
     if (!unknownFields.equals(other.unknownFields)) return false;
     return true;
   }
@@ -213,6 +264,10 @@ private static final long serialVersionUID = 0L;
       hash = (37 * hash) + CTR_FIELD_NUMBER;
       hash = (53 * hash) + getCtr().hashCode();
     }
+    if (hasColor()) {
+      hash = (37 * hash) + COLOR_FIELD_NUMBER;
+      hash = (53 * hash) + getColor().hashCode();
+    }
     hash = (29 * hash) + unknownFields.hashCode();
     memoizedHashCode = hash;
     return hash;
@@ -354,6 +409,12 @@ private static final long serialVersionUID = 0L;
         ctr_ = null;
         ctrBuilder_ = null;
       }
+      if (colorBuilder_ == null) {
+        color_ = null;
+      } else {
+        color_ = null;
+        colorBuilder_ = null;
+      }
       return this;
     }
 
@@ -386,6 +447,11 @@ private static final long serialVersionUID = 0L;
       } else {
         result.ctr_ = ctrBuilder_.build();
       }
+      if (colorBuilder_ == null) {
+        result.color_ = color_;
+      } else {
+        result.color_ = colorBuilder_.build();
+      }
       onBuilt();
       return result;
     }
@@ -440,6 +506,9 @@ private static final long serialVersionUID = 0L;
       if (other.hasCtr()) {
         mergeCtr(other.getCtr());
       }
+      if (other.hasColor()) {
+        mergeColor(other.getColor());
+      }
       this.mergeUnknownFields(other.unknownFields);
       onChanged();
       return this;
@@ -618,6 +687,125 @@ private static final long serialVersionUID = 0L;
       }
       return ctrBuilder_;
     }
+
+    private vsg.shapes.Color color_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Color, vsg.shapes.Color.Builder, vsg.shapes.ColorOrBuilder> colorBuilder_;
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     * @return Whether the color field is set.
+     */
+    public boolean hasColor() {
+      return colorBuilder_ != null || color_ != null;
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     * @return The color.
+     */
+    public vsg.shapes.Color getColor() {
+      if (colorBuilder_ == null) {
+        return color_ == null ? vsg.shapes.Color.getDefaultInstance() : color_;
+      } else {
+        return colorBuilder_.getMessage();
+      }
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    public Builder setColor(vsg.shapes.Color value) {
+      if (colorBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        color_ = value;
+        onChanged();
+      } else {
+        colorBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    public Builder setColor(
+        vsg.shapes.Color.Builder builderForValue) {
+      if (colorBuilder_ == null) {
+        color_ = builderForValue.build();
+        onChanged();
+      } else {
+        colorBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    public Builder mergeColor(vsg.shapes.Color value) {
+      if (colorBuilder_ == null) {
+        if (color_ != null) {
+          color_ =
+            vsg.shapes.Color.newBuilder(color_).mergeFrom(value).buildPartial();
+        } else {
+          color_ = value;
+        }
+        onChanged();
+      } else {
+        colorBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    public Builder clearColor() {
+      if (colorBuilder_ == null) {
+        color_ = null;
+        onChanged();
+      } else {
+        color_ = null;
+        colorBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    public vsg.shapes.Color.Builder getColorBuilder() {
+      
+      onChanged();
+      return getColorFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    public vsg.shapes.ColorOrBuilder getColorOrBuilder() {
+      if (colorBuilder_ != null) {
+        return colorBuilder_.getMessageOrBuilder();
+      } else {
+        return color_ == null ?
+            vsg.shapes.Color.getDefaultInstance() : color_;
+      }
+    }
+    /**
+     * <code>.vsg.Color color = 3;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Color, vsg.shapes.Color.Builder, vsg.shapes.ColorOrBuilder> 
+        getColorFieldBuilder() {
+      if (colorBuilder_ == null) {
+        colorBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.shapes.Color, vsg.shapes.Color.Builder, vsg.shapes.ColorOrBuilder>(
+                getColor(),
+                getParentForChildren(),
+                isClean());
+        color_ = null;
+      }
+      return colorBuilder_;
+    }
     @java.lang.Override
     public final Builder setUnknownFields(
         final com.google.protobuf.UnknownFieldSet unknownFields) {
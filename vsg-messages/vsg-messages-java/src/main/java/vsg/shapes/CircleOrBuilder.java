@@ -27,4 +27,19 @@ public interface CircleOrBuilder extends
    * <code>.vsg.Coordinates ctr = 2;</code>
    */
   vsg.shapes.CoordinatesOrBuilder getCtrOrBuilder();
+
+  /**
+   * <code>.vsg.Color color = 3;</code>
+   * @return Whether the color field is set.
+   */
+  boolean hasColor();
+  /**
+   * <code>.vsg.Color color = 3;</code>
+   * @return The color.
+   */
+  vsg.shapes.Color getColor();
+  /**
+   * <code>.vsg.Color color = 3;</code>
+   */
+  vsg.shapes.ColorOrBuilder getColorOrBuilder();
 }
@@ -0,0 +1,745 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: shapes.proto
+
+package vsg.shapes;
+
+/**
+ * <pre>
+ * RGBA with every component in [0, 1].
+ * </pre>
+ *
+ * Protobuf type {@code vsg.Color}
+ */
+public final class Color extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Color)
+    ColorOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Color.newBuilder() to construct.
+  private Color(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Color() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Color();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Color(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    int mutable_bitField0_ = 0;
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 13: {
+
+            r_ = input.readFloat();
+            break;
+          }
+          case 21: {
+
+            g_ = input.readFloat();
+            break;
+          }
+          case 29: {
+
+            b_ = input.readFloat();
+            break;
+          }
+          case 37: {
+            bitField0_ |= 0x00000001;
+            a_ = input.readFloat();
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.shapes.VsgShapes.internal_static_vsg_Color_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.shapes.VsgShapes.internal_static_vsg_Color_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.shapes.Color.class, vsg.shapes.Color.Builder.class);
+  }
+
+  private int bitField0_;
+  public static final int R_FIELD_NUMBER = 1;
+  private float r_;
+  /**
+   * <code>float r = 1;</code>
+   * @return The r.
+   */
+  @java.lang.Override
+  public float getR() {
+    return r_;
+  }
+
+  public static final int G_FIELD_NUMBER = 2;
+  private float g_;
+  /**
+   * <code>float g = 2;</code>
+   * @return The g.
+   */
+  @java.lang.Override
+  public float getG() {
+    return g_;
+  }
+
+  public static final int B_FIELD_NUMBER = 3;
+  private float b_;
+  /**
+   * <code>float b = 3;</code>
+   * @return The b.
+   */
+  @java.lang.Override
+  public float getB() {
+    return b_;
+  }
+
+  public static final int A_FIELD_NUMBER = 4;
+  private float a_;
+  /**
+   * <pre>
+   * Opaque when not set.
+   * </pre>
+   *
+   * <code>optional float a = 4;</code>
+   * @return Whether the a field is set.
+   */
+  @java.lang.Override
+  public boolean hasA() {
+    return ((bitField0_ & 0x00000001) != 0);
+  }
+  /**
+   * <pre>
+   * Opaque when not set.
+   * </pre>
+   *
+   * <code>optional float a = 4;</code>
+   * @return The a.
+   */
+  @java.lang.Override
+  public float getA() {
+    return a_;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (java.lang.Float.floatToRawIntBits(r_) != 0) {
+      output.writeFloat(1, r_);
+    }
+    if (java.lang.Float.floatToRawIntBits(g_) != 0) {
+      output.writeFloat(2, g_);
+    }
+    if (java.lang.Float.floatToRawIntBits(b_) != 0) {
+      output.writeFloat(3, b_);
+    }
+    if (((bitField0_ & 0x00000001) != 0)) {
+      output.writeFloat(4, a_);
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (java.lang.Float.floatToRawIntBits(r_) != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeFloatSize(1, r_);
+    }
+    if (java.lang.Float.floatToRawIntBits(g_) != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeFloatSize(2, g_);
+    }
+    if (java.lang.Float.floatToRawIntBits(b_) != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeFloatSize(3, b_);
+    }
+    if (((bitField0_ & 0x00000001) != 0)) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeFloatSize(4, a_);
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.shapes.Color)) {
+      return super.equals(obj);
+    }
+    vsg.shapes.Color other = (vsg.shapes.Color) obj;
+
+    if (java.lang.Float.floatToIntBits(getR())
+        != java.lang.Float.floatToIntBits(
+            other.getR())) return false;
+    if (java.lang.Float.floatToIntBits(getG())
+        != java.lang.Float.floatToIntBits(
+            other.getG())) return false;
+    if (java.lang.Float.floatToIntBits(getB())
+        != java.lang.Float.floatToIntBits(
+            other.getB())) return false;
+    if (hasA() != other.hasA()) return false;
+    if (hasA()) {
+      if (java.lang.Float.floatToIntBits(getA())
+          != java.lang.Float.floatToIntBits(
+              other.getA())) return false;
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + R_FIELD_NUMBER;
+    hash = (53 * hash) + java.lang.Float.floatToIntBits(
+        getR());
+    hash = (37 * hash) + G_FIELD_NUMBER;
+    hash = (53 * hash) + java.lang.Float.floatToIntBits(
+        getG());
+    hash = (37 * hash) + B_FIELD_NUMBER;
+    hash = (53 * hash) + java.lang.Float.floatToIntBits(
+        getB());
+    if (hasA()) {
+      hash = (37 * hash) + A_FIELD_NUMBER;
+      hash = (53 * hash) + java.lang.Float.floatToIntBits(
+          getA());
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.shapes.Color parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.shapes.Color parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.shapes.Color parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.shapes.Color parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.shapes.Color parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.shapes.Color parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.shapes.Color parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.shapes.Color parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.shapes.Color parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.shapes.Color parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.shapes.Color parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.shapes.Color parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.shapes.Color prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * RGBA with every component in [0, 1].
+   * </pre>
+   *
+   * Protobuf type {@code vsg.Color}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Color)
+      vsg.shapes.ColorOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.shapes.VsgShapes.internal_static_vsg_Color_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.shapes.VsgShapes.internal_static_vsg_Color_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.shapes.Color.class, vsg.shapes.Color.Builder.class);
+    }
+
+    // Construct using vsg.shapes.Color.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      r_ = 0F;
+
+      g_ = 0F;
+
+      b_ = 0F;
+
+      a_ = 0F;
+      bitField0_ = (bitField0_ & ~0x00000001);
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.shapes.VsgShapes.internal_static_vsg_Color_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.shapes.Color getDefaultInstanceForType() {
+      return vsg.shapes.Color.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.shapes.Color build() {
+      vsg.shapes.Color result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.shapes.Color buildPartial() {
+      vsg.shapes.Color result = new vsg.shapes.Color(this);
+      int from_bitField0_ = bitField0_;
+      int to_bitField0_ = 0;
+      result.r_ = r_;
+      result.g_ = g_;
+      result.b_ = b_;
+      if (((from_bitField0_ & 0x00000001) != 0)) {
+        result.a_ = a_;
+        to_bitField0_ |= 0x00000001;
+      }
+      result.bitField0_ = to_bitField0_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.shapes.Color) {
+        return mergeFrom((vsg.shapes.Color)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.shapes.Color other) {
+      if (other == vsg.shapes.Color.getDefaultInstance()) return this;
+      if (other.getR() != 0F) {
+        setR(other.getR());
+      }
+      if (other.getG() != 0F) {
+        setG(other.getG());
+      }
+      if (other.getB() != 0F) {
+        setB(other.getB());
+      }
+      if (other.hasA()) {
+        setA(other.getA());
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.shapes.Color parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.shapes.Color) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int bitField0_;
+
+    private float r_ ;
+    /**
+     * <code>float r = 1;</code>
+     * @return The r.
+     */
+    @java.lang.Override
+    public float getR() {
+      return r_;
+    }
+    /**
+     * <code>float r = 1;</code>
+     * @param value The r to set.
+     * @return This builder for chaining.
+     */
+    public Builder setR(float value) {
+      
+      r_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>float r = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearR() {
+      
+      r_ = 0F;
+      onChanged();
+      return this;
+    }
+
+    private float g_ ;
+    /**
+     * <code>float g = 2;</code>
+     * @return The g.
+     */
+    @java.lang.Override
+    public float getG() {
+      return g_;
+    }
+    /**
+     * <code>float g = 2;</code>
+     * @param value The g to set.
+     * @return This builder for chaining.
+     */
+    public Builder setG(float value) {
+      
+      g_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>float g = 2;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearG() {
+      
+      g_ = 0F;
+      onChanged();
+      return this;
+    }
+
+    private float b_ ;
+    /**
+     * <code>float b = 3;</code>
+     * @return The b.
+     */
+    @java.lang.Override
+    public float getB() {
+      return b_;
+    }
+    /**
+     * <code>float b = 3;</code>
+     * @param value The b to set.
+     * @return This builder for chaining.
+     */
+    public Builder setB(float value) {
+      
+      b_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>float b = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearB() {
+      
+      b_ = 0F;
+      onChanged();
+      return this;
+    }
+
+    private float a_ ;
+    /**
+     * <pre>
+     * Opaque when not set.
+     * </pre>
+     *
+     * <code>optional float a = 4;</code>
+     * @return Whether the a field is set.
+     */
+    @java.lang.Override
+    public boolean hasA() {
+      return ((bitField0_ & 0x00000001) != 0);
+    }
+    /**
+     * <pre>
+     * Opaque when not set.
+     * </pre>
+     *
+     * <code>optional float a = 4;</code>
+     * @return The a.
+     */
+    @java.lang.Override
+    public float getA() {
+      return a_;
+    }
+    /**
+     * <pre>
+     * Opaque when not set.
+     * </pre>
+     *
+     * <code>optional float a = 4;</code>
+     * @param value The a to set.
+     * @return This builder for chaining.
+     */
+    public Builder setA(float value) {
+      bitField0_ |= 0x00000001;
+      a_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Opaque when not set.
+     * </pre>
+     *
+     * <code>optional float a = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearA() {
+      bitField0_ = (bitField0_ & ~0x00000001);
+      a_ = 0F;
+      onChanged();
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Color)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Color)
+  private static final vsg.shapes.Color DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.shapes.Color();
+  }
+
+  public static vsg.shapes.Color getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Color>
+      PARSER = new com.google.protobuf.AbstractParser<Color>() {
+    @java.lang.Override
+    public Color parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Color(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Color> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Color> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.shapes.Color getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
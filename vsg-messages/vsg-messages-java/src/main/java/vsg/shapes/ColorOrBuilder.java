@@ -0,0 +1,46 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: shapes.proto
+
+package vsg.shapes;
+
+public interface ColorOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Color)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>float r = 1;</code>
+   * @return The r.
+   */
+  float getR();
+
+  /**
+   * <code>float g = 2;</code>
+   * @return The g.
+   */
+  float getG();
+
+  /**
+   * <code>float b = 3;</code>
+   * @return The b.
+   */
+  float getB();
+
+  /**
+   * <pre>
+   * Opaque when not set.
+   * </pre>
+   *
+   * <code>optional float a = 4;</code>
+   * @return Whether the a field is set.
+   */
+  boolean hasA();
+  /**
+   * <pre>
+   * Opaque when not set.
+   * </pre>
+   *
+   * <code>optional float a = 4;</code>
+   * @return The a.
+   */
+  float getA();
+}
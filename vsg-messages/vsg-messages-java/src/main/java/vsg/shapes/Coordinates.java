@@ -126,10 +126,10 @@ private static final long serialVersionUID = 0L;
   @java.lang.Override
   public void writeTo(com.google.protobuf.CodedOutputStream output)
                       throws java.io.IOException {
-    if (x_ != 0F) {
+    if (java.lang.Float.floatToRawIntBits(x_) != 0) {
       output.writeFloat(1, x_);
     }
-    if (y_ != 0F) {
+    if (java.lang.Float.floatToRawIntBits(y_) != 0) {
       output.writeFloat(2, y_);
     }
     unknownFields.writeTo(output);
@@ -141,11 +141,11 @@ private static final long serialVersionUID = 0L;
     if (size != -1) return size;
 
     size = 0;
-    if (x_ != 0F) {
+    if (java.lang.Float.floatToRawIntBits(x_) != 0) {
       size += com.google.protobuf.CodedOutputStream
         .computeFloatSize(1, x_);
     }
-    if (y_ != 0F) {
+    if (java.lang.Float.floatToRawIntBits(y_) != 0) {
       size += com.google.protobuf.CodedOutputStream
         .computeFloatSize(2, y_);
     }
@@ -33,4 +33,19 @@ public interface CrossOrBuilder extends
    * <code>.vsg.Coordinates ctr = 3;</code>
    */
   vsg.shapes.CoordinatesOrBuilder getCtrOrBuilder();
+
+  /**
+   * <code>.vsg.Color color = 4;</code>
+   * @return Whether the color field is set.
+   */
+  boolean hasColor();
+  /**
+   * <code>.vsg.Color color = 4;</code>
+   * @return The color.
+   */
+  vsg.shapes.Color getColor();
+  /**
+   * <code>.vsg.Color color = 4;</code>
+   */
+  vsg.shapes.ColorOrBuilder getColorOrBuilder();
 }
@@ -0,0 +1,1025 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: shapes.proto
+
+package vsg.shapes;
+
+/**
+ * <pre>
+ * A shape kept in the scene under a client-chosen id.
+ * </pre>
+ *
+ * Protobuf type {@code vsg.Stimulus}
+ */
+public final class Stimulus extends
+    com.google.protobuf.GeneratedMessageV3 implements
+    // @@protoc_insertion_point(message_implements:vsg.Stimulus)
+    StimulusOrBuilder {
+private static final long serialVersionUID = 0L;
+  // Use Stimulus.newBuilder() to construct.
+  private Stimulus(com.google.protobuf.GeneratedMessageV3.Builder<?> builder) {
+    super(builder);
+  }
+  private Stimulus() {
+  }
+
+  @java.lang.Override
+  @SuppressWarnings({"unused"})
+  protected java.lang.Object newInstance(
+      UnusedPrivateParameter unused) {
+    return new Stimulus();
+  }
+
+  @java.lang.Override
+  public final com.google.protobuf.UnknownFieldSet
+  getUnknownFields() {
+    return this.unknownFields;
+  }
+  private Stimulus(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    this();
+    if (extensionRegistry == null) {
+      throw new java.lang.NullPointerException();
+    }
+    com.google.protobuf.UnknownFieldSet.Builder unknownFields =
+        com.google.protobuf.UnknownFieldSet.newBuilder();
+    try {
+      boolean done = false;
+      while (!done) {
+        int tag = input.readTag();
+        switch (tag) {
+          case 0:
+            done = true;
+            break;
+          case 8: {
+
+            id_ = input.readUInt32();
+            break;
+          }
+          case 18: {
+            vsg.shapes.Shape.Builder subBuilder = null;
+            if (shape_ != null) {
+              subBuilder = shape_.toBuilder();
+            }
+            shape_ = input.readMessage(vsg.shapes.Shape.parser(), extensionRegistry);
+            if (subBuilder != null) {
+              subBuilder.mergeFrom(shape_);
+              shape_ = subBuilder.buildPartial();
+            }
+
+            break;
+          }
+          case 24: {
+
+            hidden_ = input.readBool();
+            break;
+          }
+          case 32: {
+            lifetime_ = input.readUInt32();
+            lifetimeCase_ = 4;
+            break;
+          }
+          case 45: {
+            lifetime_ = input.readFloat();
+            lifetimeCase_ = 5;
+            break;
+          }
+          default: {
+            if (!parseUnknownField(
+                input, unknownFields, extensionRegistry, tag)) {
+              done = true;
+            }
+            break;
+          }
+        }
+      }
+    } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+      throw e.setUnfinishedMessage(this);
+    } catch (java.io.IOException e) {
+      throw new com.google.protobuf.InvalidProtocolBufferException(
+          e).setUnfinishedMessage(this);
+    } finally {
+      this.unknownFields = unknownFields.build();
+      makeExtensionsImmutable();
+    }
+  }
+  public static final com.google.protobuf.Descriptors.Descriptor
+      getDescriptor() {
+    return vsg.shapes.VsgShapes.internal_static_vsg_Stimulus_descriptor;
+  }
+
+  @java.lang.Override
+  protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internalGetFieldAccessorTable() {
+    return vsg.shapes.VsgShapes.internal_static_vsg_Stimulus_fieldAccessorTable
+        .ensureFieldAccessorsInitialized(
+            vsg.shapes.Stimulus.class, vsg.shapes.Stimulus.Builder.class);
+  }
+
+  private int lifetimeCase_ = 0;
+  private java.lang.Object lifetime_;
+  public enum LifetimeCase
+      implements com.google.protobuf.Internal.EnumLite,
+          com.google.protobuf.AbstractMessage.InternalOneOfEnum {
+    DURATION_FRAMES(4),
+    DURATION_MS(5),
+    LIFETIME_NOT_SET(0);
+    private final int value;
+    private LifetimeCase(int value) {
+      this.value = value;
+    }
+    /**
+     * @param value The number of the enum to look for.
+     * @return The enum associated with the given number.
+     * @deprecated Use {@link #forNumber(int)} instead.
+     */
+    @java.lang.Deprecated
+    public static LifetimeCase valueOf(int value) {
+      return forNumber(value);
+    }
+
+    public static LifetimeCase forNumber(int value) {
+      switch (value) {
+        case 4: return DURATION_FRAMES;
+        case 5: return DURATION_MS;
+        case 0: return LIFETIME_NOT_SET;
+        default: return null;
+      }
+    }
+    public int getNumber() {
+      return this.value;
+    }
+  };
+
+  public LifetimeCase
+  getLifetimeCase() {
+    return LifetimeCase.forNumber(
+        lifetimeCase_);
+  }
+
+  public static final int ID_FIELD_NUMBER = 1;
+  private int id_;
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  @java.lang.Override
+  public int getId() {
+    return id_;
+  }
+
+  public static final int SHAPE_FIELD_NUMBER = 2;
+  private vsg.shapes.Shape shape_;
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return Whether the shape field is set.
+   */
+  @java.lang.Override
+  public boolean hasShape() {
+    return shape_ != null;
+  }
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return The shape.
+   */
+  @java.lang.Override
+  public vsg.shapes.Shape getShape() {
+    return shape_ == null ? vsg.shapes.Shape.getDefaultInstance() : shape_;
+  }
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   */
+  @java.lang.Override
+  public vsg.shapes.ShapeOrBuilder getShapeOrBuilder() {
+    return getShape();
+  }
+
+  public static final int HIDDEN_FIELD_NUMBER = 3;
+  private boolean hidden_;
+  /**
+   * <code>bool hidden = 3;</code>
+   * @return The hidden.
+   */
+  @java.lang.Override
+  public boolean getHidden() {
+    return hidden_;
+  }
+
+  public static final int DURATION_FRAMES_FIELD_NUMBER = 4;
+  /**
+   * <code>uint32 duration_frames = 4;</code>
+   * @return Whether the durationFrames field is set.
+   */
+  @java.lang.Override
+  public boolean hasDurationFrames() {
+    return lifetimeCase_ == 4;
+  }
+  /**
+   * <code>uint32 duration_frames = 4;</code>
+   * @return The durationFrames.
+   */
+  @java.lang.Override
+  public int getDurationFrames() {
+    if (lifetimeCase_ == 4) {
+      return (java.lang.Integer) lifetime_;
+    }
+    return 0;
+  }
+
+  public static final int DURATION_MS_FIELD_NUMBER = 5;
+  /**
+   * <pre>
+   * Rounded to the nearest number of frames, at least one.
+   * </pre>
+   *
+   * <code>float duration_ms = 5;</code>
+   * @return Whether the durationMs field is set.
+   */
+  @java.lang.Override
+  public boolean hasDurationMs() {
+    return lifetimeCase_ == 5;
+  }
+  /**
+   * <pre>
+   * Rounded to the nearest number of frames, at least one.
+   * </pre>
+   *
+   * <code>float duration_ms = 5;</code>
+   * @return The durationMs.
+   */
+  @java.lang.Override
+  public float getDurationMs() {
+    if (lifetimeCase_ == 5) {
+      return (java.lang.Float) lifetime_;
+    }
+    return 0F;
+  }
+
+  private byte memoizedIsInitialized = -1;
+  @java.lang.Override
+  public final boolean isInitialized() {
+    byte isInitialized = memoizedIsInitialized;
+    if (isInitialized == 1) return true;
+    if (isInitialized == 0) return false;
+
+    memoizedIsInitialized = 1;
+    return true;
+  }
+
+  @java.lang.Override
+  public void writeTo(com.google.protobuf.CodedOutputStream output)
+                      throws java.io.IOException {
+    if (id_ != 0) {
+      output.writeUInt32(1, id_);
+    }
+    if (shape_ != null) {
+      output.writeMessage(2, getShape());
+    }
+    if (hidden_ != false) {
+      output.writeBool(3, hidden_);
+    }
+    if (lifetimeCase_ == 4) {
+      output.writeUInt32(
+          4, (int)((java.lang.Integer) lifetime_));
+    }
+    if (lifetimeCase_ == 5) {
+      output.writeFloat(
+          5, (float)((java.lang.Float) lifetime_));
+    }
+    unknownFields.writeTo(output);
+  }
+
+  @java.lang.Override
+  public int getSerializedSize() {
+    int size = memoizedSize;
+    if (size != -1) return size;
+
+    size = 0;
+    if (id_ != 0) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(1, id_);
+    }
+    if (shape_ != null) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeMessageSize(2, getShape());
+    }
+    if (hidden_ != false) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeBoolSize(3, hidden_);
+    }
+    if (lifetimeCase_ == 4) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeUInt32Size(
+            4, (int)((java.lang.Integer) lifetime_));
+    }
+    if (lifetimeCase_ == 5) {
+      size += com.google.protobuf.CodedOutputStream
+        .computeFloatSize(
+            5, (float)((java.lang.Float) lifetime_));
+    }
+    size += unknownFields.getSerializedSize();
+    memoizedSize = size;
+    return size;
+  }
+
+  @java.lang.Override
+  public boolean equals(final java.lang.Object obj) {
+    if (obj == this) {
+     return true;
+    }
+    if (!(obj instanceof vsg.shapes.Stimulus)) {
+      return super.equals(obj);
+    }
+    vsg.shapes.Stimulus other = (vsg.shapes.Stimulus) obj;
+
+    if (getId()
+        != other.getId()) return false;
+    if (hasShape() != other.hasShape()) return false;
+    if (hasShape()) {
+      if (!getShape()
+          .equals(other.getShape())) return false;
+    }
+    if (getHidden()
+        != other.getHidden()) return false;
+    if (!getLifetimeCase().equals(other.getLifetimeCase())) return false;
+    switch (lifetimeCase_) {
+      case 4:
+        if (getDurationFrames()
+            != other.getDurationFrames()) return false;
+        break;
+      case 5:
+        if (java.lang.Float.floatToIntBits(getDurationMs())
+            != java.lang.Float.floatToIntBits(
+                other.getDurationMs())) return false;
+        break;
+      case 0:
+      default:
+    }
+    if (!unknownFields.equals(other.unknownFields)) return false;
+    return true;
+  }
+
+  @java.lang.Override
+  public int hashCode() {
+    if (memoizedHashCode != 0) {
+      return memoizedHashCode;
+    }
+    int hash = 41;
+    hash = (19 * hash) + getDescriptor().hashCode();
+    hash = (37 * hash) + ID_FIELD_NUMBER;
+    hash = (53 * hash) + getId();
+    if (hasShape()) {
+      hash = (37 * hash) + SHAPE_FIELD_NUMBER;
+      hash = (53 * hash) + getShape().hashCode();
+    }
+    hash = (37 * hash) + HIDDEN_FIELD_NUMBER;
+    hash = (53 * hash) + com.google.protobuf.Internal.hashBoolean(
+        getHidden());
+    switch (lifetimeCase_) {
+      case 4:
+        hash = (37 * hash) + DURATION_FRAMES_FIELD_NUMBER;
+        hash = (53 * hash) + getDurationFrames();
+        break;
+      case 5:
+        hash = (37 * hash) + DURATION_MS_FIELD_NUMBER;
+        hash = (53 * hash) + java.lang.Float.floatToIntBits(
+            getDurationMs());
+        break;
+      case 0:
+      default:
+    }
+    hash = (29 * hash) + unknownFields.hashCode();
+    memoizedHashCode = hash;
+    return hash;
+  }
+
+  public static vsg.shapes.Stimulus parseFrom(
+      java.nio.ByteBuffer data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      java.nio.ByteBuffer data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      com.google.protobuf.ByteString data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      com.google.protobuf.ByteString data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.shapes.Stimulus parseFrom(byte[] data)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      byte[] data,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws com.google.protobuf.InvalidProtocolBufferException {
+    return PARSER.parseFrom(data, extensionRegistry);
+  }
+  public static vsg.shapes.Stimulus parseFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.shapes.Stimulus parseDelimitedFrom(java.io.InputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input);
+  }
+  public static vsg.shapes.Stimulus parseDelimitedFrom(
+      java.io.InputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseDelimitedWithIOException(PARSER, input, extensionRegistry);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      com.google.protobuf.CodedInputStream input)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input);
+  }
+  public static vsg.shapes.Stimulus parseFrom(
+      com.google.protobuf.CodedInputStream input,
+      com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+      throws java.io.IOException {
+    return com.google.protobuf.GeneratedMessageV3
+        .parseWithIOException(PARSER, input, extensionRegistry);
+  }
+
+  @java.lang.Override
+  public Builder newBuilderForType() { return newBuilder(); }
+  public static Builder newBuilder() {
+    return DEFAULT_INSTANCE.toBuilder();
+  }
+  public static Builder newBuilder(vsg.shapes.Stimulus prototype) {
+    return DEFAULT_INSTANCE.toBuilder().mergeFrom(prototype);
+  }
+  @java.lang.Override
+  public Builder toBuilder() {
+    return this == DEFAULT_INSTANCE
+        ? new Builder() : new Builder().mergeFrom(this);
+  }
+
+  @java.lang.Override
+  protected Builder newBuilderForType(
+      com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+    Builder builder = new Builder(parent);
+    return builder;
+  }
+  /**
+   * <pre>
+   * A shape kept in the scene under a client-chosen id.
+   * </pre>
+   *
+   * Protobuf type {@code vsg.Stimulus}
+   */
+  public static final class Builder extends
+      com.google.protobuf.GeneratedMessageV3.Builder<Builder> implements
+      // @@protoc_insertion_point(builder_implements:vsg.Stimulus)
+      vsg.shapes.StimulusOrBuilder {
+    public static final com.google.protobuf.Descriptors.Descriptor
+        getDescriptor() {
+      return vsg.shapes.VsgShapes.internal_static_vsg_Stimulus_descriptor;
+    }
+
+    @java.lang.Override
+    protected com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+        internalGetFieldAccessorTable() {
+      return vsg.shapes.VsgShapes.internal_static_vsg_Stimulus_fieldAccessorTable
+          .ensureFieldAccessorsInitialized(
+              vsg.shapes.Stimulus.class, vsg.shapes.Stimulus.Builder.class);
+    }
+
+    // Construct using vsg.shapes.Stimulus.newBuilder()
+    private Builder() {
+      maybeForceBuilderInitialization();
+    }
+
+    private Builder(
+        com.google.protobuf.GeneratedMessageV3.BuilderParent parent) {
+      super(parent);
+      maybeForceBuilderInitialization();
+    }
+    private void maybeForceBuilderInitialization() {
+      if (com.google.protobuf.GeneratedMessageV3
+              .alwaysUseFieldBuilders) {
+      }
+    }
+    @java.lang.Override
+    public Builder clear() {
+      super.clear();
+      id_ = 0;
+
+      if (shapeBuilder_ == null) {
+        shape_ = null;
+      } else {
+        shape_ = null;
+        shapeBuilder_ = null;
+      }
+      hidden_ = false;
+
+      lifetimeCase_ = 0;
+      lifetime_ = null;
+      return this;
+    }
+
+    @java.lang.Override
+    public com.google.protobuf.Descriptors.Descriptor
+        getDescriptorForType() {
+      return vsg.shapes.VsgShapes.internal_static_vsg_Stimulus_descriptor;
+    }
+
+    @java.lang.Override
+    public vsg.shapes.Stimulus getDefaultInstanceForType() {
+      return vsg.shapes.Stimulus.getDefaultInstance();
+    }
+
+    @java.lang.Override
+    public vsg.shapes.Stimulus build() {
+      vsg.shapes.Stimulus result = buildPartial();
+      if (!result.isInitialized()) {
+        throw newUninitializedMessageException(result);
+      }
+      return result;
+    }
+
+    @java.lang.Override
+    public vsg.shapes.Stimulus buildPartial() {
+      vsg.shapes.Stimulus result = new vsg.shapes.Stimulus(this);
+      result.id_ = id_;
+      if (shapeBuilder_ == null) {
+        result.shape_ = shape_;
+      } else {
+        result.shape_ = shapeBuilder_.build();
+      }
+      result.hidden_ = hidden_;
+      if (lifetimeCase_ == 4) {
+        result.lifetime_ = lifetime_;
+      }
+      if (lifetimeCase_ == 5) {
+        result.lifetime_ = lifetime_;
+      }
+      result.lifetimeCase_ = lifetimeCase_;
+      onBuilt();
+      return result;
+    }
+
+    @java.lang.Override
+    public Builder clone() {
+      return super.clone();
+    }
+    @java.lang.Override
+    public Builder setField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.setField(field, value);
+    }
+    @java.lang.Override
+    public Builder clearField(
+        com.google.protobuf.Descriptors.FieldDescriptor field) {
+      return super.clearField(field);
+    }
+    @java.lang.Override
+    public Builder clearOneof(
+        com.google.protobuf.Descriptors.OneofDescriptor oneof) {
+      return super.clearOneof(oneof);
+    }
+    @java.lang.Override
+    public Builder setRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        int index, java.lang.Object value) {
+      return super.setRepeatedField(field, index, value);
+    }
+    @java.lang.Override
+    public Builder addRepeatedField(
+        com.google.protobuf.Descriptors.FieldDescriptor field,
+        java.lang.Object value) {
+      return super.addRepeatedField(field, value);
+    }
+    @java.lang.Override
+    public Builder mergeFrom(com.google.protobuf.Message other) {
+      if (other instanceof vsg.shapes.Stimulus) {
+        return mergeFrom((vsg.shapes.Stimulus)other);
+      } else {
+        super.mergeFrom(other);
+        return this;
+      }
+    }
+
+    public Builder mergeFrom(vsg.shapes.Stimulus other) {
+      if (other == vsg.shapes.Stimulus.getDefaultInstance()) return this;
+      if (other.getId() != 0) {
+        setId(other.getId());
+      }
+      if (other.hasShape()) {
+        mergeShape(other.getShape());
+      }
+      if (other.getHidden() != false) {
+        setHidden(other.getHidden());
+      }
+      switch (other.getLifetimeCase()) {
+        case DURATION_FRAMES: {
+          setDurationFrames(other.getDurationFrames());
+          break;
+        }
+        case DURATION_MS: {
+          setDurationMs(other.getDurationMs());
+          break;
+        }
+        case LIFETIME_NOT_SET: {
+          break;
+        }
+      }
+      this.mergeUnknownFields(other.unknownFields);
+      onChanged();
+      return this;
+    }
+
+    @java.lang.Override
+    public final boolean isInitialized() {
+      return true;
+    }
+
+    @java.lang.Override
+    public Builder mergeFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws java.io.IOException {
+      vsg.shapes.Stimulus parsedMessage = null;
+      try {
+        parsedMessage = PARSER.parsePartialFrom(input, extensionRegistry);
+      } catch (com.google.protobuf.InvalidProtocolBufferException e) {
+        parsedMessage = (vsg.shapes.Stimulus) e.getUnfinishedMessage();
+        throw e.unwrapIOException();
+      } finally {
+        if (parsedMessage != null) {
+          mergeFrom(parsedMessage);
+        }
+      }
+      return this;
+    }
+    private int lifetimeCase_ = 0;
+    private java.lang.Object lifetime_;
+    public LifetimeCase
+        getLifetimeCase() {
+      return LifetimeCase.forNumber(
+          lifetimeCase_);
+    }
+
+    public Builder clearLifetime() {
+      lifetimeCase_ = 0;
+      lifetime_ = null;
+      onChanged();
+      return this;
+    }
+
+
+    private int id_ ;
+    /**
+     * <code>uint32 id = 1;</code>
+     * @return The id.
+     */
+    @java.lang.Override
+    public int getId() {
+      return id_;
+    }
+    /**
+     * <code>uint32 id = 1;</code>
+     * @param value The id to set.
+     * @return This builder for chaining.
+     */
+    public Builder setId(int value) {
+      
+      id_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 id = 1;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearId() {
+      
+      id_ = 0;
+      onChanged();
+      return this;
+    }
+
+    private vsg.shapes.Shape shape_;
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> shapeBuilder_;
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     * @return Whether the shape field is set.
+     */
+    public boolean hasShape() {
+      return shapeBuilder_ != null || shape_ != null;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     * @return The shape.
+     */
+    public vsg.shapes.Shape getShape() {
+      if (shapeBuilder_ == null) {
+        return shape_ == null ? vsg.shapes.Shape.getDefaultInstance() : shape_;
+      } else {
+        return shapeBuilder_.getMessage();
+      }
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder setShape(vsg.shapes.Shape value) {
+      if (shapeBuilder_ == null) {
+        if (value == null) {
+          throw new NullPointerException();
+        }
+        shape_ = value;
+        onChanged();
+      } else {
+        shapeBuilder_.setMessage(value);
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder setShape(
+        vsg.shapes.Shape.Builder builderForValue) {
+      if (shapeBuilder_ == null) {
+        shape_ = builderForValue.build();
+        onChanged();
+      } else {
+        shapeBuilder_.setMessage(builderForValue.build());
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder mergeShape(vsg.shapes.Shape value) {
+      if (shapeBuilder_ == null) {
+        if (shape_ != null) {
+          shape_ =
+            vsg.shapes.Shape.newBuilder(shape_).mergeFrom(value).buildPartial();
+        } else {
+          shape_ = value;
+        }
+        onChanged();
+      } else {
+        shapeBuilder_.mergeFrom(value);
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public Builder clearShape() {
+      if (shapeBuilder_ == null) {
+        shape_ = null;
+        onChanged();
+      } else {
+        shape_ = null;
+        shapeBuilder_ = null;
+      }
+
+      return this;
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public vsg.shapes.Shape.Builder getShapeBuilder() {
+      
+      onChanged();
+      return getShapeFieldBuilder().getBuilder();
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    public vsg.shapes.ShapeOrBuilder getShapeOrBuilder() {
+      if (shapeBuilder_ != null) {
+        return shapeBuilder_.getMessageOrBuilder();
+      } else {
+        return shape_ == null ?
+            vsg.shapes.Shape.getDefaultInstance() : shape_;
+      }
+    }
+    /**
+     * <code>.vsg.Shape shape = 2;</code>
+     */
+    private com.google.protobuf.SingleFieldBuilderV3<
+        vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder> 
+        getShapeFieldBuilder() {
+      if (shapeBuilder_ == null) {
+        shapeBuilder_ = new com.google.protobuf.SingleFieldBuilderV3<
+            vsg.shapes.Shape, vsg.shapes.Shape.Builder, vsg.shapes.ShapeOrBuilder>(
+                getShape(),
+                getParentForChildren(),
+                isClean());
+        shape_ = null;
+      }
+      return shapeBuilder_;
+    }
+
+    private boolean hidden_ ;
+    /**
+     * <code>bool hidden = 3;</code>
+     * @return The hidden.
+     */
+    @java.lang.Override
+    public boolean getHidden() {
+      return hidden_;
+    }
+    /**
+     * <code>bool hidden = 3;</code>
+     * @param value The hidden to set.
+     * @return This builder for chaining.
+     */
+    public Builder setHidden(boolean value) {
+      
+      hidden_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>bool hidden = 3;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearHidden() {
+      
+      hidden_ = false;
+      onChanged();
+      return this;
+    }
+
+    /**
+     * <code>uint32 duration_frames = 4;</code>
+     * @return Whether the durationFrames field is set.
+     */
+    public boolean hasDurationFrames() {
+      return lifetimeCase_ == 4;
+    }
+    /**
+     * <code>uint32 duration_frames = 4;</code>
+     * @return The durationFrames.
+     */
+    public int getDurationFrames() {
+      if (lifetimeCase_ == 4) {
+        return (java.lang.Integer) lifetime_;
+      }
+      return 0;
+    }
+    /**
+     * <code>uint32 duration_frames = 4;</code>
+     * @param value The durationFrames to set.
+     * @return This builder for chaining.
+     */
+    public Builder setDurationFrames(int value) {
+      lifetimeCase_ = 4;
+      lifetime_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <code>uint32 duration_frames = 4;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearDurationFrames() {
+      if (lifetimeCase_ == 4) {
+        lifetimeCase_ = 0;
+        lifetime_ = null;
+        onChanged();
+      }
+      return this;
+    }
+
+    /**
+     * <pre>
+     * Rounded to the nearest number of frames, at least one.
+     * </pre>
+     *
+     * <code>float duration_ms = 5;</code>
+     * @return Whether the durationMs field is set.
+     */
+    public boolean hasDurationMs() {
+      return lifetimeCase_ == 5;
+    }
+    /**
+     * <pre>
+     * Rounded to the nearest number of frames, at least one.
+     * </pre>
+     *
+     * <code>float duration_ms = 5;</code>
+     * @return The durationMs.
+     */
+    public float getDurationMs() {
+      if (lifetimeCase_ == 5) {
+        return (java.lang.Float) lifetime_;
+      }
+      return 0F;
+    }
+    /**
+     * <pre>
+     * Rounded to the nearest number of frames, at least one.
+     * </pre>
+     *
+     * <code>float duration_ms = 5;</code>
+     * @param value The durationMs to set.
+     * @return This builder for chaining.
+     */
+    public Builder setDurationMs(float value) {
+      lifetimeCase_ = 5;
+      lifetime_ = value;
+      onChanged();
+      return this;
+    }
+    /**
+     * <pre>
+     * Rounded to the nearest number of frames, at least one.
+     * </pre>
+     *
+     * <code>float duration_ms = 5;</code>
+     * @return This builder for chaining.
+     */
+    public Builder clearDurationMs() {
+      if (lifetimeCase_ == 5) {
+        lifetimeCase_ = 0;
+        lifetime_ = null;
+        onChanged();
+      }
+      return this;
+    }
+    @java.lang.Override
+    public final Builder setUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.setUnknownFields(unknownFields);
+    }
+
+    @java.lang.Override
+    public final Builder mergeUnknownFields(
+        final com.google.protobuf.UnknownFieldSet unknownFields) {
+      return super.mergeUnknownFields(unknownFields);
+    }
+
+
+    // @@protoc_insertion_point(builder_scope:vsg.Stimulus)
+  }
+
+  // @@protoc_insertion_point(class_scope:vsg.Stimulus)
+  private static final vsg.shapes.Stimulus DEFAULT_INSTANCE;
+  static {
+    DEFAULT_INSTANCE = new vsg.shapes.Stimulus();
+  }
+
+  public static vsg.shapes.Stimulus getDefaultInstance() {
+    return DEFAULT_INSTANCE;
+  }
+
+  private static final com.google.protobuf.Parser<Stimulus>
+      PARSER = new com.google.protobuf.AbstractParser<Stimulus>() {
+    @java.lang.Override
+    public Stimulus parsePartialFrom(
+        com.google.protobuf.CodedInputStream input,
+        com.google.protobuf.ExtensionRegistryLite extensionRegistry)
+        throws com.google.protobuf.InvalidProtocolBufferException {
+      return new Stimulus(input, extensionRegistry);
+    }
+  };
+
+  public static com.google.protobuf.Parser<Stimulus> parser() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public com.google.protobuf.Parser<Stimulus> getParserForType() {
+    return PARSER;
+  }
+
+  @java.lang.Override
+  public vsg.shapes.Stimulus getDefaultInstanceForType() {
+    return DEFAULT_INSTANCE;
+  }
+
+}
+
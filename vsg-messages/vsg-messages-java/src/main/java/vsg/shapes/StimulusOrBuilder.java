@@ -0,0 +1,68 @@
+// Generated by the protocol buffer compiler.  DO NOT EDIT!
+// source: shapes.proto
+
+package vsg.shapes;
+
+public interface StimulusOrBuilder extends
+    // @@protoc_insertion_point(interface_extends:vsg.Stimulus)
+    com.google.protobuf.MessageOrBuilder {
+
+  /**
+   * <code>uint32 id = 1;</code>
+   * @return The id.
+   */
+  int getId();
+
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return Whether the shape field is set.
+   */
+  boolean hasShape();
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   * @return The shape.
+   */
+  vsg.shapes.Shape getShape();
+  /**
+   * <code>.vsg.Shape shape = 2;</code>
+   */
+  vsg.shapes.ShapeOrBuilder getShapeOrBuilder();
+
+  /**
+   * <code>bool hidden = 3;</code>
+   * @return The hidden.
+   */
+  boolean getHidden();
+
+  /**
+   * <code>uint32 duration_frames = 4;</code>
+   * @return Whether the durationFrames field is set.
+   */
+  boolean hasDurationFrames();
+  /**
+   * <code>uint32 duration_frames = 4;</code>
+   * @return The durationFrames.
+   */
+  int getDurationFrames();
+
+  /**
+   * <pre>
+   * Rounded to the nearest number of frames, at least one.
+   * </pre>
+   *
+   * <code>float duration_ms = 5;</code>
+   * @return Whether the durationMs field is set.
+   */
+  boolean hasDurationMs();
+  /**
+   * <pre>
+   * Rounded to the nearest number of frames, at least one.
+   * </pre>
+   *
+   * <code>float duration_ms = 5;</code>
+   * @return The durationMs.
+   */
+  float getDurationMs();
+
+  public vsg.shapes.Stimulus.LifetimeCase getLifetimeCase();
+}
@@ -19,11 +19,21 @@ public final class VsgShapes {
   static final 
     com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
       internal_static_vsg_Shape_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Stimulus_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Stimulus_fieldAccessorTable;
   static final com.google.protobuf.Descriptors.Descriptor
     internal_static_vsg_Coordinates_descriptor;
   static final 
     com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
       internal_static_vsg_Coordinates_fieldAccessorTable;
+  static final com.google.protobuf.Descriptors.Descriptor
+    internal_static_vsg_Color_descriptor;
+  static final 
+    com.google.protobuf.GeneratedMessageV3.FieldAccessorTable
+      internal_static_vsg_Color_fieldAccessorTable;
   static final com.google.protobuf.Descriptors.Descriptor
     internal_static_vsg_Square_descriptor;
   static final 
@@ -51,13 +61,20 @@ public final class VsgShapes {
       "\n\014shapes.proto\022\003vsg\"k\n\005Shape\022\035\n\006square\030\001" +
       " \001(\0132\013.vsg.SquareH\000\022\035\n\006circle\030\002 \001(\0132\013.vs" +
       "g.CircleH\000\022\033\n\005cross\030\003 \001(\0132\n.vsg.CrossH\000B" +
-      "\007\n\005shape\"#\n\013Coordinates\022\t\n\001x\030\001 \001(\002\022\t\n\001y\030" +
-      "\002 \001(\002\"5\n\006Square\022\014\n\004size\030\001 \001(\002\022\035\n\003ctr\030\002 \001" +
-      "(\0132\020.vsg.Coordinates\"7\n\006Circle\022\016\n\006radius" +
-      "\030\001 \001(\002\022\035\n\003ctr\030\002 \001(\0132\020.vsg.Coordinates\"H\n" +
-      "\005Cross\022\014\n\004size\030\001 \001(\002\022\022\n\nline_width\030\002 \001(\002" +
-      "\022\035\n\003ctr\030\003 \001(\0132\020.vsg.CoordinatesB\031\n\nvsg.s" +
-      "hapesB\tVsgShapesP\001b\006proto3"
+      "\007\n\005shape\"\177\n\010Stimulus\022\n\n\002id\030\001 \001(\r\022\031\n\005shap" +
+      "e\030\002 \001(\0132\n.vsg.Shape\022\016\n\006hidden\030\003 \001(\010\022\031\n\017d" +
+      "uration_frames\030\004 \001(\rH\000\022\025\n\013duration_ms\030\005 " +
+      "\001(\002H\000B\n\n\010lifetime\"#\n\013Coordinates\022\t\n\001x\030\001 " +
+      "\001(\002\022\t\n\001y\030\002 \001(\002\">\n\005Color\022\t\n\001r\030\001 \001(\002\022\t\n\001g\030" +
+      "\002 \001(\002\022\t\n\001b\030\003 \001(\002\022\016\n\001a\030\004 \001(\002H\000\210\001\001B\004\n\002_a\"P" +
+      "\n\006Square\022\014\n\004size\030\001 \001(\002\022\035\n\003ctr\030\002 \001(\0132\020.vs" +
+      "g.Coordinates\022\031\n\005color\030\003 \001(\0132\n.vsg.Color" +
+      "\"R\n\006Circle\022\016\n\006radius\030\001 \001(\002\022\035\n\003ctr\030\002 \001(\0132" +
+      "\020.vsg.Coordinates\022\031\n\005color\030\003 \001(\0132\n.vsg.C" +
+      "olor\"c\n\005Cross\022\014\n\004size\030\001 \001(\002\022\022\n\nline_widt" +
+      "h\030\002 \001(\002\022\035\n\003ctr\030\003 \001(\0132\020.vsg.Coordinates\022\031" +
+      "\n\005color\030\004 \001(\0132\n.vsg.ColorB\031\n\nvsg.shapesB" +
+      "\tVsgShapesP\001b\006proto3"
     };
     descriptor = com.google.protobuf.Descriptors.FileDescriptor
       .internalBuildGeneratedFileFrom(descriptorData,
@@ -69,30 +86,42 @@ public final class VsgShapes {
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_Shape_descriptor,
         new java.lang.String[] { "Square", "Circle", "Cross", "Shape", });
-    internal_static_vsg_Coordinates_descriptor =
+    internal_static_vsg_Stimulus_descriptor =
       getDescriptor().getMessageTypes().get(1);
+    internal_static_vsg_Stimulus_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Stimulus_descriptor,
+        new java.lang.String[] { "Id", "Shape", "Hidden", "DurationFrames", "DurationMs", "Lifetime", });
+    internal_static_vsg_Coordinates_descriptor =
+      getDescriptor().getMessageTypes().get(2);
     internal_static_vsg_Coordinates_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_Coordinates_descriptor,
         new java.lang.String[] { "X", "Y", });
+    internal_static_vsg_Color_descriptor =
+      getDescriptor().getMessageTypes().get(3);
+    internal_static_vsg_Color_fieldAccessorTable = new
+      com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
+        internal_static_vsg_Color_descriptor,
+        new java.lang.String[] { "R", "G", "B", "A", "A", });
     internal_static_vsg_Square_descriptor =
-      getDescriptor().getMessageTypes().get(2);
+      getDescriptor().getMessageTypes().get(4);
     internal_static_vsg_Square_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_Square_descriptor,
-        new java.lang.String[] { "Size", "Ctr", });
+        new java.lang.String[] { "Size", "Ctr", "Color", });
     internal_static_vsg_Circle_descriptor =
-      getDescriptor().getMessageTypes().get(3);
+      getDescriptor().getMessageTypes().get(5);
     internal_static_vsg_Circle_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_Circle_descriptor,
-        new java.lang.String[] { "Radius", "Ctr", });
+        new java.lang.String[] { "Radius", "Ctr", "Color", });
     internal_static_vsg_Cross_descriptor =
-      getDescriptor().getMessageTypes().get(4);
+      getDescriptor().getMessageTypes().get(6);
     internal_static_vsg_Cross_fieldAccessorTable = new
       com.google.protobuf.GeneratedMessageV3.FieldAccessorTable(
         internal_static_vsg_Cross_descriptor,
-        new java.lang.String[] { "Size", "LineWidth", "Ctr", });
+        new java.lang.String[] { "Size", "LineWidth", "Ctr", "Color", });
   }
 
   // @@protoc_insertion_point(outer_class_scope)